@@ -0,0 +1,42 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Which comments are written when serializing with `SerializerOptions`.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum CommentSerialization
+{
+	/// Write all comments (the default, and what `ToCss` always does).
+	KeepAll,
+	
+	/// Write only `/*! ... */` comments, such as licence banners.
+	KeepOnlySignificant,
+	
+	/// Write no comments.
+	KeepNone,
+}
+
+impl Default for CommentSerialization
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		CommentSerialization::KeepAll
+	}
+}
+
+impl CommentSerialization
+{
+	#[inline(always)]
+	pub(crate) fn keeps(self, comment: &CssComment) -> bool
+	{
+		use self::CommentSerialization::*;
+		
+		match self
+		{
+			KeepAll => true,
+			KeepOnlySignificant => comment.is_significant(),
+			KeepNone => false,
+		}
+	}
+}
@@ -104,9 +104,84 @@ pub enum CustomParseError<'i>
 	UnsignedIntegersCanNotBeNegative(i32),
 	UnsignedIntegersCanNotBeFloats(f32),
 	
+	// colors
+	InvalidHexColor(CowRcStr<'i>),
+	UnknownColorKeyword(CowRcStr<'i>),
+	UnknownColorFunction(CowRcStr<'i>),
+	UnknownPredefinedColorSpace(CowRcStr<'i>),
+	
+	// fonts
+	FontWeightMustBeANumberFrom1To1000(f32),
+	UnknownOrRepeatedFontVariantKeyword(CowRcStr<'i>),
+	UnknownOrRepeatedFontVariantAlternatesFunction(CowRcStr<'i>),
+	FontVariationSettingAxisTagMustBeFourCharacters(CowRcStr<'i>),
+	FontVariationSettingAxisTagMustBePrintableAscii(CowRcStr<'i>),
+	
+	// images
+	UnknownImageFunction(CowRcStr<'i>),
+	GradientMustHaveAtLeastTwoColorStops,
+	GradientTransitionHintMustBeBetweenTwoColorStops,
+	InvalidRadialGradientSize,
+	InvalidPosition,
+	ImageSetResolutionMustBeAConstant,
+	ImageSetCanNotBeNestedInImageSet,
+	BackgroundColorMustBeInTheFinalLayer,
+	
+	// transforms
+	UnknownTransformFunction(CowRcStr<'i>),
+	TransformPerspectiveMustNotBeNegative,
+	
+	// grid and flexbox
+	DisplayListItemMustBeFlowOrFlowRoot(DisplayInside),
+	GridMinmaxMinimumMustNotBeFlexible,
+	GridRepeatCountMustBePositive(i32),
+	GridTrackListMustHaveAtLeastOneTrack,
+	GridTrackListMayOnlyHaveOneAutomaticRepeat,
+	GridTrackListWithAutomaticRepeatMustOnlyHaveFixedSizes,
+	GridTemplateAreasContainsAnInvalidCharacter(char),
+	GridTemplateAreasRowMustHaveAtLeastOneCell,
+	GridTemplateAreasRowsMustHaveTheSameNumberOfCells,
+	GridTemplateAreaIsNotRectangular(Atom),
+	GridLineMustHaveANumberOrName,
+	GridLineNumberMustNotBeZero,
+	GridLineSpanMustBePositive(i32),
+	
+	// shadows and filters
+	ShadowMustHaveHorizontalAndVerticalOffsets,
+	ShadowBlurRadiusMayNotBeNegative,
+	UnknownFilterFunction(CowRcStr<'i>),
+	
+	// transitions and animations
+	UnknownTimingFunction(CowRcStr<'i>),
+	CubicBezierXMustBeBetweenZeroAndOne,
+	StepsCountIsTooSmall(i32),
+	LinearTimingFunctionMustHaveAtLeastTwoStops,
+	TransitionPropertyNoneMustBeTheOnlyValue,
+	EmptyTransitionOrAnimation,
+	
+	// generated content and counters
+	AttrTypeOrUnitIsNotAllowedInContent(TypeOrUnit),
+	ReversedCounterIsOnlyAllowedInCounterReset,
+	QuotesMustBePairsOfStrings,
+	
 	// expressions (calc(), var(), attr())
 	UnknownFunctionInValueExpression(CowRcStr<'i>),
 	CssVariablesInVarExpressionsMustStartWithTwoDashes(CowRcStr<'i>),
+	UnknownAttrTypeOrUnit(CowRcStr<'i>),
+	
+	// value definition syntax grammars
+	UnknownPropertyForGrammar(CowRcStr<'i>),
+	UnknownGrammarDataType(CowRcStr<'i>),
+	GrammarDataTypeIsRecursive(CowRcStr<'i>),
+	GrammarDataTypeRangeIsInvalid(f32, f32),
+	GrammarDataTypeRangeBoundHasARelativeUnit(CowRcStr<'i>),
+	GrammarHasTooManyComponentsInAnyOrder(usize),
+	GrammarMultiplierRangeIsInvalid(i32, Option<i32>),
+	UnexpectedTokenForGrammar(Token<'i>, Vec<String>),
+	UnexpectedEndOfInputForGrammar(Vec<String>),
+	
+	// limits (see ParseLimits)
+	ParseLimitExceeded(ParseLimit),
 }
 
 impl<'i> CustomParseError<'i>
@@ -117,6 +192,12 @@ impl<'i> CustomParseError<'i>
 		Err(ParseError::Basic(BasicParseError::UnexpectedToken(unexpectedToken.clone())))
 	}
 	
+	#[inline(always)]
+	pub(crate) fn parseLimitExceeded<T>(parseLimit: ParseLimit) -> Result<T, ParseError<'i, CustomParseError<'i>>>
+	{
+		Err(ParseError::Custom(CustomParseError::ParseLimitExceeded(parseLimit)))
+	}
+	
 	#[inline(always)]
 	fn dimensionless<T>(value: f32) -> Result<T, ParseError<'i, CustomParseError<'i>>>
 	{
@@ -0,0 +1,275 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Walks a stylesheet's rules emitting fallbacks for property declarations using `var()`, with the custom properties defined by root style rules substituted, as `CustomPropertyInliningPolicy` configures.
+pub(crate) struct CustomPropertyInliner<'a>
+{
+	policy: &'a CustomPropertyInliningPolicy,
+	
+	/// Keyed by name without the leading `--`; custom property names are case-sensitive, so `--Color` and `--color` are different keys: https://drafts.csswg.org/css-variables/#defining-variables. The value's CSS and whether it is `!important`.
+	root_values: HashMap<String, (String, bool)>,
+	
+	/// Keyed as for `root_values`.
+	redefined_custom_properties: HashSet<String>,
+	
+	pub(crate) refusals: Vec<CustomPropertyInliningRefusal>,
+}
+
+impl<'a> CssVariableConversion for CustomPropertyInliner<'a>
+{
+	#[inline(always)]
+	fn cssVariableValue(&self, css_variable_name_without_leading_double_dash: &str) -> Option<&str>
+	{
+		self.root_values.get(css_variable_name_without_leading_double_dash).map(|&(ref css, _)| &css[..])
+	}
+}
+
+impl<'a> CustomPropertyInliner<'a>
+{
+	#[inline(always)]
+	pub(crate) fn new(policy: &'a CustomPropertyInliningPolicy) -> Self
+	{
+		Self
+		{
+			policy,
+			root_values: HashMap::new(),
+			redefined_custom_properties: HashSet::new(),
+			refusals: Vec::new(),
+		}
+	}
+	
+	/// Finds the custom properties defined by root style rules, and those defined elsewhere.
+	pub(crate) fn find_definitions(&mut self, cssRules: &CssRules, isNested: bool)
+	{
+		use self::CssRule::*;
+		
+		for cssRule in cssRules.0.iter()
+		{
+			match *cssRule
+			{
+				Style(ref rule) =>
+				{
+					let isRoot = !isNested && rule.selectors.0.iter().all(|selector| self.policy.root_selectors.contains(&selector.to_css_string()));
+					
+					for propertyDeclaration in rule.property_declarations.0.iter().filter(|propertyDeclaration| propertyDeclaration.hasACustomPropertyName())
+					{
+						let key = Self::key(&propertyDeclaration.name);
+						
+						match propertyDeclaration.value
+						{
+							UnparsedPropertyValue::SpecifiedValue(ref specifiedValue) if isRoot =>
+							{
+								let isImportant = propertyDeclaration.importance.isImportant();
+								
+								// A later definition wins unless an earlier one is important and it is not.
+								let isOverridden = match self.root_values.get(&key)
+								{
+									Some(&(_, wasImportant)) => wasImportant && !isImportant,
+									None => false,
+								};
+								
+								if !isOverridden
+								{
+									self.root_values.insert(key, (specifiedValue.original_css().to_owned(), isImportant));
+								}
+							}
+							
+							_ =>
+							{
+								self.redefined_custom_properties.insert(key);
+							}
+						}
+					}
+				}
+				
+				Document(ref rule) => self.find_definitions(&rule.rules, true),
+				
+				Media(ref rule) => self.find_definitions(&rule.rules, true),
+				
+				Supports(ref rule) => self.find_definitions(&rule.rules, true),
+				
+				_ => (),
+			}
+		}
+	}
+	
+	pub(crate) fn inline_css_rules(&mut self, cssRules: &mut CssRules)
+	{
+		use self::CssRule::*;
+		
+		for cssRule in cssRules.0.iter_mut()
+		{
+			match *cssRule
+			{
+				Keyframes(ref mut rule) => for keyframe in rule.keyframes.iter_mut()
+				{
+					self.inline_property_declarations(&mut keyframe.property_declarations)
+				},
+				
+				Page(ref mut rule) => self.inline_property_declarations(&mut rule.property_declarations),
+				
+				Style(ref mut rule) => self.inline_property_declarations(&mut rule.property_declarations),
+				
+				Document(ref mut rule) => self.inline_css_rules(&mut rule.rules),
+				
+				Media(ref mut rule) => self.inline_css_rules(&mut rule.rules),
+				
+				Supports(ref mut rule) => self.inline_css_rules(&mut rule.rules),
+				
+				_ => (),
+			}
+		}
+	}
+	
+	fn inline_property_declarations<I: HasImportance>(&mut self, propertyDeclarations: &mut PropertyDeclarations<I>)
+	{
+		let originalPropertyDeclarations = replace(&mut propertyDeclarations.0, Vec::new());
+		for mut propertyDeclaration in originalPropertyDeclarations
+		{
+			// Browsers which do not support custom properties ignore their definitions, so these do not need fallbacks.
+			if propertyDeclaration.references.is_empty() || propertyDeclaration.hasACustomPropertyName()
+			{
+				propertyDeclarations.0.push(propertyDeclaration);
+				continue;
+			}
+			
+			let result = match propertyDeclaration.value
+			{
+				UnparsedPropertyValue::CssWideKeyword(_) => None,
+				UnparsedPropertyValue::SpecifiedValue(ref specifiedValue) => Some(self.substitute(specifiedValue.component_values(), &mut Vec::new())),
+			};
+			
+			match result
+			{
+				None => propertyDeclarations.0.push(propertyDeclaration),
+				
+				Some(Ok(componentValues)) =>
+				{
+					let mut fallback = propertyDeclaration.clone();
+					fallback.value = UnparsedPropertyValue::SpecifiedValue(SpecifiedValue::from_component_values(componentValues));
+					fallback.references.clear();
+					
+					let keepOriginal = self.policy.keep_original_declarations;
+					if keepOriginal
+					{
+						// The fallback is now first, so has the comments.
+						propertyDeclaration.comments_before.clear();
+					}
+					
+					propertyDeclarations.0.push(fallback);
+					if keepOriginal
+					{
+						propertyDeclarations.0.push(propertyDeclaration);
+					}
+				}
+				
+				Some(Err(reason)) =>
+				{
+					self.refusals.push
+					(
+						CustomPropertyInliningRefusal
+						{
+							reason,
+							property_name: propertyDeclaration.name.clone(),
+							source_span: propertyDeclaration.source_span,
+						}
+					);
+					propertyDeclarations.0.push(propertyDeclaration);
+				}
+			}
+		}
+	}
+	
+	/// Substitutes every `var()` function in these component values, including those nested in functions, blocks and fallbacks.
+	/// `substituting` holds the keys of the custom properties whose values are being substituted, to detect cycles.
+	fn substitute(&self, componentValues: &[ComponentValue], substituting: &mut Vec<String>) -> Result<Vec<ComponentValue>, CustomPropertyInliningRefusalReason>
+	{
+		let mut substituted = Vec::with_capacity(componentValues.len());
+		for componentValue in componentValues.iter()
+		{
+			if let Some((name, fallback)) = componentValue.var_function_arguments()
+			{
+				substituted.extend(self.substitute_var_function(name, fallback, substituting)?);
+				continue;
+			}
+			
+			let mut componentValue = componentValue.clone();
+			if let Some(children) = componentValue.children_mut()
+			{
+				*children = self.substitute(children, substituting)?;
+			}
+			substituted.push(componentValue);
+		}
+		Ok(substituted)
+	}
+	
+	/// As for `VarExpression`, the custom property's value is used if it is defined and valid, otherwise the fallback, if any; a value is invalid if it depends on itself or on an undefined custom property without a fallback: https://drafts.csswg.org/css-variables/#substitute-a-var
+	fn substitute_var_function(&self, name: &str, fallback: Option<&[ComponentValue]>, substituting: &mut Vec<String>) -> Result<Vec<ComponentValue>, CustomPropertyInliningRefusalReason>
+	{
+		use self::CustomPropertyInliningRefusalReason::*;
+		
+		let key = Self::key(name);
+		
+		if self.redefined_custom_properties.contains(&key)
+		{
+			return Err(RedefinedInDescendantScope(Atom::from(name)));
+		}
+		
+		let reason = match self.cssVariableValue(&key)
+		{
+			None => Undefined(Atom::from(name)),
+			
+			Some(_) if substituting.contains(&key) => Cycle(Atom::from(name)),
+			
+			Some(css) =>
+			{
+				let componentValues = ComponentValue::parse_css(css).expect("custom property values have already been parsed");
+				
+				substituting.push(key);
+				let result = self.substitute(&componentValues, substituting);
+				substituting.pop();
+				
+				match result
+				{
+					Ok(substituted) => return Ok(Self::trim_whitespace(substituted)),
+					Err(RedefinedInDescendantScope(name)) => return Err(RedefinedInDescendantScope(name)),
+					Err(reason) => reason,
+				}
+			}
+		};
+		
+		match fallback
+		{
+			Some(fallback) => self.substitute(fallback, substituting).map(Self::trim_whitespace),
+			None => Err(reason),
+		}
+	}
+	
+	#[inline(always)]
+	fn trim_whitespace(mut componentValues: Vec<ComponentValue>) -> Vec<ComponentValue>
+	{
+		while componentValues.last().map_or(false, ComponentValue::is_whitespace)
+		{
+			componentValues.pop();
+		}
+		
+		let leadingWhitespace = componentValues.iter().take_while(|componentValue| componentValue.is_whitespace()).count();
+		componentValues.drain(..leadingWhitespace);
+		componentValues
+	}
+	
+	/// The key of a custom property name such as `--Main-Color`, which is `Main-Color`.
+	#[inline(always)]
+	fn key(name: &str) -> String
+	{
+		if name.starts_with("--")
+		{
+			name[2..].to_owned()
+		}
+		else
+		{
+			name.to_owned()
+		}
+	}
+}
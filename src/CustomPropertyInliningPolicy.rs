@@ -0,0 +1,29 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// What `Stylesheet::inline_custom_properties()` substitutes for `var()` functions, and how, so that browsers which do not support custom properties have a fallback.
+///
+/// The default policy substitutes the custom properties defined by `:root` style rules and keeps the original property declarations after the fallbacks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomPropertyInliningPolicy
+{
+	/// Selectors, serialized as CSS (eg `:root` or `html`), of the style rules whose custom property definitions are substituted; a style rule is only used if all of its selectors are in this set and it is not nested in another rule.
+	pub root_selectors: HashSet<String>,
+	
+	/// Whether to keep each property declaration using `var()` after its fallback; if false, the fallback replaces it.
+	pub keep_original_declarations: bool,
+}
+
+impl Default for CustomPropertyInliningPolicy
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			root_selectors: [":root"].iter().map(|selector| selector.to_string()).collect(),
+			keep_original_declarations: true,
+		}
+	}
+}
@@ -0,0 +1,17 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Why `Stylesheet::inline_custom_properties()` did not emit a fallback for a property declaration; each holds the name of the custom property, as used in `var()`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CustomPropertyInliningRefusalReason
+{
+	/// The custom property is also defined by a style rule which is not a root style rule, or which is nested in another rule, or by a CSS-wide keyword, so its value depends on the element it is used for.
+	RedefinedInDescendantScope(Atom),
+	
+	/// The custom property is not defined by a root style rule (or its value is invalid) and the `var()` function has no fallback.
+	Undefined(Atom),
+	
+	/// The custom property depends on itself, directly or through others, and the `var()` function has no fallback.
+	Cycle(Atom),
+}
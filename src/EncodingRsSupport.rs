@@ -0,0 +1,29 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Lets cssparser determine a stylesheet's encoding using the encodings of `encoding_rs`.
+pub(crate) struct EncodingRsSupport;
+
+impl EncodingSupport for EncodingRsSupport
+{
+	type Encoding = &'static Encoding;
+	
+	#[inline(always)]
+	fn from_label(ascii_label: &[u8]) -> Option<Self::Encoding>
+	{
+		Encoding::for_label(ascii_label)
+	}
+	
+	#[inline(always)]
+	fn utf8() -> Self::Encoding
+	{
+		UTF_8
+	}
+	
+	#[inline(always)]
+	fn is_utf16_be_or_le(encoding: &Self::Encoding) -> bool
+	{
+		*encoding == UTF_16BE || *encoding == UTF_16LE
+	}
+}
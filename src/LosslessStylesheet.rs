@@ -0,0 +1,105 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A stylesheet that, when serialized, reproduces the CSS it was parsed from byte for byte, except for those rules, keyframes and property declarations that have been mutated (or added) since.
+/// Intended for codemods over hand-written CSS, where the diff should only show what was actually changed.
+#[derive(Debug, Clone)]
+pub struct LosslessStylesheet
+{
+	/// The stylesheet; mutate its rules as required.
+	/// Rules, keyframes and property declarations are matched back to the CSS they were parsed from by their source spans; changing a source span makes a node be serialized afresh.
+	pub stylesheet: Stylesheet,
+	
+	original_css: String,
+	
+	original_rules: CssRules,
+}
+
+impl HasCssRules for LosslessStylesheet
+{
+	#[inline(always)]
+	fn css_rules(&self) -> &CssRules
+	{
+		&self.stylesheet.rules
+	}
+	
+	#[inline(always)]
+	fn css_rules_mut(&mut self) -> &mut CssRules
+	{
+		&mut self.stylesheet.rules
+	}
+	
+	#[inline(always)]
+	fn css_rules_slice(&self) -> &[CssRule]
+	{
+		&self.stylesheet.rules.0[..]
+	}
+	
+	#[inline(always)]
+	fn css_rules_vec(&self) -> &Vec<CssRule>
+	{
+		&self.stylesheet.rules.0
+	}
+	
+	#[inline(always)]
+	fn css_rules_vec_mut(&mut self) -> &mut Vec<CssRule>
+	{
+		&mut self.stylesheet.rules.0
+	}
+}
+
+impl LosslessStylesheet
+{
+	/// Parses a string of CSS, as for `Stylesheet::parse_with_options()`, keeping the original CSS.
+	/// Source spans are always recorded and comments always preserved, whatever `options` says.
+	/// If recovering from errors, invalid rules and declarations are kept as written.
+	pub fn parse<'i>(css: &'i str, options: &StylesheetParseOptions) -> Result<(Self, Vec<RecoveredParseError>), PreciseParseError<'i, CustomParseError<'i>>>
+	{
+		let options = StylesheetParseOptions
+		{
+			record_source_spans: true,
+			preserve_comments: true,
+			.. options.clone()
+		};
+		
+		let (stylesheet, recoveredParseErrors) = Stylesheet::parse_with_options(css, &options)?;
+		
+		Ok
+		(
+			(
+				Self
+				{
+					original_rules: stylesheet.rules.clone(),
+					stylesheet,
+					original_css: css.to_owned(),
+				},
+				recoveredParseErrors,
+			)
+		)
+	}
+	
+	/// The CSS that was parsed.
+	#[inline(always)]
+	pub fn original_css(&self) -> &str
+	{
+		&self.original_css
+	}
+	
+	/// Serializes, reproducing the original CSS except for those nodes that have been mutated or added.
+	#[inline(always)]
+	pub fn to_css<W: fmt::Write>(&self, destination: &mut W) -> fmt::Result
+	{
+		LosslessSerializer::new(destination, &self.original_css).write_stylesheet(&self.stylesheet.rules, &self.original_rules)
+	}
+	
+	/// Serializes as a string.
+	/// Convenience method wrapped `to_css()`.
+	#[inline(always)]
+	pub fn to_css_string(&self) -> String
+	{
+		let mut string = String::new();
+		self.to_css(&mut string).unwrap();
+		string
+	}
+}
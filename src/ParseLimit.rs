@@ -0,0 +1,26 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Which of the `ParseLimits` was exceeded.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ParseLimit
+{
+	/// `ParseLimits.maximum_input_size`.
+	InputSize,
+	
+	/// `ParseLimits.maximum_nesting_depth`.
+	NestingDepth,
+	
+	/// `ParseLimits.maximum_rule_count`.
+	RuleCount,
+	
+	/// `ParseLimits.maximum_property_declarations_per_block`.
+	PropertyDeclarationsPerBlock,
+	
+	/// `ParseLimits.maximum_selector_list_length`.
+	SelectorListLength,
+	
+	/// `ParseLimits.maximum_calc_expression_depth`.
+	CalcExpressionDepth,
+}
@@ -0,0 +1,71 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Limits on what parsing a stylesheet may consume, to stop hostile (or just absurd) stylesheets, such as those uploaded by untrusted users, exhausting the stack or memory.
+/// Exceeding a limit fails parsing with `CustomParseError::ParseLimitExceeded`, even if recovering from errors.
+///
+/// By default there are no limits; `ParseLimits::for_untrusted_input()` has limits that no reasonable hand-written stylesheet should exceed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ParseLimits
+{
+	/// Maximum size of the CSS, in bytes (once decoded to UTF-8).
+	pub maximum_input_size: usize,
+	
+	/// Maximum depth to which `@media`, `@supports` and `@document` rules can be nested; top-level rules are at a depth of zero.
+	pub maximum_nesting_depth: usize,
+	
+	/// Maximum number of rules, including nested rules and keyframes, in a stylesheet.
+	pub maximum_rule_count: usize,
+	
+	/// Maximum number of property declarations in any one block.
+	pub maximum_property_declarations_per_block: usize,
+	
+	/// Maximum number of selectors in any one style rule.
+	pub maximum_selector_list_length: usize,
+	
+	/// Maximum depth to which `calc()` expressions (and parentheses within them) can be nested.
+	pub maximum_calc_expression_depth: usize,
+}
+
+impl Default for ParseLimits
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self::unlimited()
+	}
+}
+
+impl ParseLimits
+{
+	/// No limits at all.
+	#[inline(always)]
+	pub fn unlimited() -> Self
+	{
+		Self
+		{
+			maximum_input_size: ::std::usize::MAX,
+			maximum_nesting_depth: ::std::usize::MAX,
+			maximum_rule_count: ::std::usize::MAX,
+			maximum_property_declarations_per_block: ::std::usize::MAX,
+			maximum_selector_list_length: ::std::usize::MAX,
+			maximum_calc_expression_depth: ::std::usize::MAX,
+		}
+	}
+	
+	/// Limits suitable for stylesheets from untrusted sources, generous enough for even the largest frameworks.
+	#[inline(always)]
+	pub fn for_untrusted_input() -> Self
+	{
+		Self
+		{
+			maximum_input_size: 4 * 1024 * 1024,
+			maximum_nesting_depth: 16,
+			maximum_rule_count: 100_000,
+			maximum_property_declarations_per_block: 1_000,
+			maximum_selector_list_length: 1_000,
+			maximum_calc_expression_depth: 32,
+		}
+	}
+}
@@ -0,0 +1,33 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A parse error that was recovered from by skipping the invalid rule or declaration, as CSS Syntax Level 3 specifies.
+///
+/// Only produced when parsing with `StylesheetParseOptions::recover_from_errors` set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecoveredParseError
+{
+	/// The line number and column number of the start of the skipped CSS.
+	pub location: SourceLocation,
+	
+	/// The CSS that was skipped.
+	pub skipped_css: String,
+	
+	/// Why the CSS was skipped.
+	pub reason: String,
+}
+
+impl<'i> From<PreciseParseError<'i, CustomParseError<'i>>> for RecoveredParseError
+{
+	#[inline(always)]
+	fn from(preciseParseError: PreciseParseError<'i, CustomParseError<'i>>) -> Self
+	{
+		Self
+		{
+			location: preciseParseError.location,
+			skipped_css: preciseParseError.slice.to_owned(),
+			reason: format!("{:?}", preciseParseError.error),
+		}
+	}
+}
@@ -0,0 +1,246 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Walks a stylesheet's rules removing what `SanitizerPolicy` does not allow.
+pub(crate) struct Sanitizer<'a>
+{
+	policy: &'a SanitizerPolicy,
+	pub(crate) removals: Vec<SanitizerRemoval>,
+}
+
+impl<'a> Sanitizer<'a>
+{
+	#[inline(always)]
+	pub(crate) fn new(policy: &'a SanitizerPolicy) -> Self
+	{
+		Self
+		{
+			policy,
+			removals: Vec::new(),
+		}
+	}
+	
+	pub(crate) fn sanitize_css_rules(&mut self, cssRules: &mut CssRules)
+	{
+		let originalCssRules = replace(&mut cssRules.0, Vec::new());
+		for mut cssRule in originalCssRules
+		{
+			match self.sanitize_css_rule(&mut cssRule)
+			{
+				Ok(()) => cssRules.0.push(cssRule),
+				Err(reason) => self.removed(reason, &cssRule, cssRule.source_span()),
+			}
+		}
+	}
+	
+	fn sanitize_css_rule(&mut self, cssRule: &mut CssRule) -> Result<(), SanitizerRemovalReason>
+	{
+		use self::CssRule::*;
+		use self::SanitizerRemovalReason::*;
+		
+		match *cssRule
+		{
+			Comment(_) => return Ok(()),
+			
+			Import(_) => return Err(ImportAtRule),
+			
+			Namespace(_) => return Err(NamespaceAtRule),
+			
+			Document(_) => return Err(DocumentAtRule),
+			
+			_ => (),
+		}
+		
+		let ruleType = cssRule.rule_type();
+		if !self.policy.is_rule_type_allowed(ruleType)
+		{
+			return Err(DisallowedRuleType(ruleType));
+		}
+		
+		match *cssRule
+		{
+			FontFace(ref mut rule) => self.sanitize_font_face(rule),
+			
+			Keyframes(ref mut rule) =>
+			{
+				if !self.policy.is_rule_type_allowed(CssRuleType::Keyframe)
+				{
+					return Err(DisallowedRuleType(CssRuleType::Keyframe));
+				}
+				
+				for keyframe in rule.keyframes.iter_mut()
+				{
+					self.sanitize_property_declarations(&mut keyframe.property_declarations);
+				}
+			}
+			
+			Media(ref mut rule) => self.sanitize_css_rules(&mut rule.rules),
+			
+			Page(ref mut rule) => self.sanitize_property_declarations(&mut rule.property_declarations),
+			
+			Style(ref mut rule) => self.sanitize_property_declarations(&mut rule.property_declarations),
+			
+			Supports(ref mut rule) => self.sanitize_css_rules(&mut rule.rules),
+			
+			// These can not load resources or run script.
+			CounterStyle(_) | FontFeatureValues(_) | Viewport(_) => (),
+			
+			Comment(_) | Document(_) | Import(_) | Namespace(_) => unreachable!(),
+		}
+		
+		Ok(())
+	}
+	
+	fn sanitize_font_face(&mut self, fontFaceAtRule: &mut FontFaceAtRule)
+	{
+		let sources = match fontFaceAtRule.sources.take()
+		{
+			None => return,
+			Some(sources) => sources,
+		};
+		
+		let mut allowedSources = Vec::with_capacity(sources.len());
+		for source in sources
+		{
+			let result = match source
+			{
+				Source::Url(ref fontUrlSource) => self.policy.check_url(&fontUrlSource.url.0),
+				Source::Local(_) => Ok(()),
+			};
+			
+			match result
+			{
+				Ok(()) => allowedSources.push(source),
+				Err(reason) => self.removed(reason, &source, fontFaceAtRule.source_span),
+			}
+		}
+		
+		// A `src` descriptor without any sources does not represent a font face.
+		if !allowedSources.is_empty()
+		{
+			fontFaceAtRule.sources = Some(allowedSources);
+		}
+	}
+	
+	fn sanitize_property_declarations<I: HasImportance>(&mut self, propertyDeclarations: &mut PropertyDeclarations<I>)
+	{
+		let originalPropertyDeclarations = replace(&mut propertyDeclarations.0, Vec::new());
+		for propertyDeclaration in originalPropertyDeclarations
+		{
+			match self.check_property_declaration(&propertyDeclaration)
+			{
+				Ok(()) => propertyDeclarations.0.push(propertyDeclaration),
+				Err(reason) => self.removed(reason, &propertyDeclaration, propertyDeclaration.source_span),
+			}
+		}
+	}
+	
+	fn check_property_declaration<I: HasImportance>(&self, propertyDeclaration: &PropertyDeclaration<I>) -> Result<(), SanitizerRemovalReason>
+	{
+		use self::SanitizerRemovalReason::*;
+		
+		let name = &propertyDeclaration.name;
+		
+		// `-moz-binding` (XBL) and `behavior` (HTC) both attach script to elements.
+		if propertyDeclaration.hasAsciiNameIgnoringCase("binding") || propertyDeclaration.hasAsciiNameIgnoringCase("behavior")
+		{
+			return Err(DangerousProperty(name.clone()));
+		}
+		
+		if !self.policy.is_property_name_allowed(name)
+		{
+			return Err(DisallowedProperty(name.clone()));
+		}
+		
+		match propertyDeclaration.value
+		{
+			UnparsedPropertyValue::CssWideKeyword(_) => Ok(()),
+			
+			UnparsedPropertyValue::SpecifiedValue(ref specifiedValue) =>
+			{
+				let mut parserInput = ParserInput::new(specifiedValue.original_css());
+				let mut input = Parser::new(&mut parserInput);
+				self.check_component_values(name, &mut input, false)
+			}
+		}
+	}
+	
+	/// If `stringsAreUrls`, then any strings are URLs, as they are in `url()` and `image-set()`.
+	fn check_component_values<'i, 't>(&self, name: &Atom, input: &mut Parser<'i, 't>, stringsAreUrls: bool) -> Result<(), SanitizerRemovalReason>
+	{
+		use ::cssparser::Token::*;
+		
+		loop
+		{
+			let token = match input.next_including_whitespace_and_comments()
+			{
+				Ok(token) => token.clone(),
+				Err(_) => return Ok(()),
+			};
+			
+			let nestedStringsAreUrls = match token
+			{
+				UnquotedUrl(ref url) =>
+				{
+					self.policy.check_url(url)?;
+					continue
+				}
+				
+				QuotedString(ref string) =>
+				{
+					if stringsAreUrls
+					{
+						self.policy.check_url(string)?;
+					}
+					continue
+				}
+				
+				Function(ref functionName) =>
+				{
+					if functionName.eq_ignore_ascii_case("expression")
+					{
+						return Err(SanitizerRemovalReason::ExpressionFunction(name.clone()));
+					}
+					
+					match_ignore_ascii_case!
+					{
+						&functionName,
+						
+						"url" | "src" | "image" | "image-set" | "-webkit-image-set" => true,
+						
+						_ => false,
+					}
+				}
+				
+				ParenthesisBlock | SquareBracketBlock | CurlyBracketBlock => false,
+				
+				_ => continue,
+			};
+			
+			let result = input.parse_nested_block(|input|
+			{
+				self.check_component_values(name, input, nestedStringsAreUrls).map_err(ParseError::Custom)
+			});
+			
+			if let Err(ParseError::Custom(reason)) = result
+			{
+				return Err(reason);
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn removed<T: ToCss>(&mut self, reason: SanitizerRemovalReason, removed: &T, source_span: Option<SourceSpan>)
+	{
+		self.removals.push
+		(
+			SanitizerRemoval
+			{
+				reason,
+				removed_css: removed.to_css_string(),
+				source_span,
+			}
+		)
+	}
+}
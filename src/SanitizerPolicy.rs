@@ -0,0 +1,235 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// What `Stylesheet::sanitize()` allows to remain in a stylesheet, such as one submitted by an untrusted user.
+///
+/// Regardless of policy, `@import`, `@namespace` and `@document` rules, the `-moz-binding` and `behavior` properties and any property declaration using the legacy `expression()` function are always removed.
+///
+/// `@counter-style`, `@viewport` and `@font-feature-values` rules can not load resources (`<image>` counter symbols are not supported), so are kept unless `allowed_rule_types` disallows them.
+///
+/// The default policy allows `https:` and `http:` URLs to any host, relative URLs, and all other property names and rule types.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SanitizerPolicy
+{
+	/// URL schemes (in ASCII lower case and without the trailing `:`) that `url()` values may use; a property declaration or `@font-face` source with a URL using any other scheme is removed.
+	pub allowed_url_schemes: HashSet<String>,
+	
+	/// Hosts (in ASCII lower case and without any port) that absolute and scheme-relative `url()` values may point at; `None` allows any host.
+	///
+	/// When this is `Some`, absolute URLs without a host (such as `data:` URLs) are also removed.
+	pub allowed_url_hosts: Option<HashSet<String>>,
+	
+	/// Whether `url()` values may be relative URLs (ie without a scheme or host); these are resolved against the stylesheet's own URL.
+	pub allow_relative_urls: bool,
+	
+	/// Unprefixed property names (in ASCII lower case) that property declarations may use; `None` allows any property name.
+	pub allowed_property_names: Option<HashSet<Atom>>,
+	
+	/// Rule types that may be present, at any depth; `None` allows any rule type.
+	pub allowed_rule_types: Option<HashSet<CssRuleType>>,
+}
+
+impl Default for SanitizerPolicy
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			allowed_url_schemes: ["https", "http"].iter().map(|scheme| scheme.to_string()).collect(),
+			allowed_url_hosts: None,
+			allow_relative_urls: true,
+			allowed_property_names: None,
+			allowed_rule_types: None,
+		}
+	}
+}
+
+impl SanitizerPolicy
+{
+	/// Is this property name allowed?
+	#[inline(always)]
+	pub fn is_property_name_allowed(&self, name: &Atom) -> bool
+	{
+		match self.allowed_property_names
+		{
+			None => true,
+			Some(ref allowed_property_names) => allowed_property_names.contains(name),
+		}
+	}
+	
+	/// Is this rule type allowed?
+	#[inline(always)]
+	pub fn is_rule_type_allowed(&self, rule_type: CssRuleType) -> bool
+	{
+		match self.allowed_rule_types
+		{
+			None => true,
+			Some(ref allowed_rule_types) => allowed_rule_types.contains(&rule_type),
+		}
+	}
+	
+	/// Checks a URL, as found inside `url()`, against this policy.
+	pub fn check_url(&self, url: &str) -> Result<(), SanitizerRemovalReason>
+	{
+		// As the URL standard does, ignore leading and trailing C0 controls and spaces and remove tabs and new lines everywhere, so `java\nscript:` is still `javascript:`.
+		let url: String = url.trim_matches(|character: char| character <= ' ').chars().filter(|character| match *character
+		{
+			'\t' | '\n' | '\r' => false,
+			_ => true,
+		}).collect();
+		
+		let (scheme, afterScheme) = match Self::scheme(&url)
+		{
+			Some((scheme, afterScheme)) =>
+			{
+				if !self.allowed_url_schemes.contains(&scheme)
+				{
+					return Err(SanitizerRemovalReason::DisallowedUrlScheme(url.clone()));
+				}
+				(Some(scheme), afterScheme)
+			}
+			
+			None => (None, &url[..]),
+		};
+		
+		// Relative URLs are resolved against the stylesheet's own URL, which has a special scheme.
+		let (requiresTwoSlashes, ignoresAnySlashes) = match scheme
+		{
+			None => (true, true),
+			Some(ref scheme) if scheme == "file" => (true, false),
+			Some(ref scheme) if Self::is_special_scheme(scheme) => (false, true),
+			Some(_) => (true, false),
+		};
+		
+		match Self::host(afterScheme, requiresTwoSlashes, ignoresAnySlashes)
+		{
+			Some(host) => match self.allowed_url_hosts
+			{
+				Some(ref allowed_url_hosts) if !allowed_url_hosts.contains(&host) => Err(SanitizerRemovalReason::DisallowedUrlHost(url.clone())),
+				_ => Ok(()),
+			},
+			
+			None => match scheme
+			{
+				// An absolute URL without a host we can find; if hosts are restricted, fail closed.
+				Some(_) => if self.allowed_url_hosts.is_some()
+				{
+					Err(SanitizerRemovalReason::DisallowedUrlHost(url.clone()))
+				}
+				else
+				{
+					Ok(())
+				},
+				
+				None => if self.allow_relative_urls
+				{
+					Ok(())
+				}
+				else
+				{
+					Err(SanitizerRemovalReason::DisallowedRelativeUrl(url.clone()))
+				},
+			},
+		}
+	}
+	
+	/// https://url.spec.whatwg.org/#scheme-state; returns the scheme in ASCII lower case and what follows the `:`.
+	fn scheme(url: &str) -> Option<(String, &str)>
+	{
+		let colon = url.find(':')?;
+		let scheme = &url[..colon];
+		
+		let mut characters = scheme.chars();
+		match characters.next()
+		{
+			Some(first) if first.is_ascii_alphabetic() => (),
+			_ => return None,
+		}
+		if characters.all(|character| match character
+		{
+			'+' | '-' | '.' => true,
+			_ => character.is_ascii_alphanumeric(),
+		})
+		{
+			Some((scheme.to_ascii_lowercase(), &url[colon + 1..]))
+		}
+		else
+		{
+			None
+		}
+	}
+	
+	/// https://url.spec.whatwg.org/#special-scheme
+	#[inline(always)]
+	fn is_special_scheme(scheme: &str) -> bool
+	{
+		match scheme
+		{
+			"http" | "https" | "ftp" | "ws" | "wss" | "file" => true,
+			_ => false,
+		}
+	}
+	
+	/// Returns the host, in ASCII lower case and without any user information or port, of what follows the scheme (if any); browsers treat `\` as `/`.
+	///
+	/// The authority must follow `//` if `requiresTwoSlashes`; if `ignoresAnySlashes`, as for special schemes other than `file`, browsers skip any number of slashes before it, so `https:evil.com` and `http:/evil.com` point at `evil.com`.
+	fn host(afterScheme: &str, requiresTwoSlashes: bool, ignoresAnySlashes: bool) -> Option<String>
+	{
+		let isSlash = |character: char| character == '/' || character == '\\';
+		
+		let leadingSlashes = afterScheme.chars().take_while(|character| isSlash(*character)).count();
+		if requiresTwoSlashes && leadingSlashes < 2
+		{
+			return None;
+		}
+		
+		let authority = if ignoresAnySlashes
+		{
+			&afterScheme[leadingSlashes..]
+		}
+		else
+		{
+			&afterScheme[2..]
+		};
+		let authority = match authority.find(|character: char| isSlash(character) || character == '?' || character == '#')
+		{
+			Some(end) => &authority[..end],
+			None => authority,
+		};
+		
+		let hostAndPort = match authority.rfind('@')
+		{
+			Some(at) => &authority[at + 1..],
+			None => authority,
+		};
+		
+		let host = if hostAndPort.starts_with('[')
+		{
+			match hostAndPort.find(']')
+			{
+				Some(end) => &hostAndPort[..end + 1],
+				None => hostAndPort,
+			}
+		}
+		else
+		{
+			match hostAndPort.find(':')
+			{
+				Some(colon) => &hostAndPort[..colon],
+				None => hostAndPort,
+			}
+		};
+		
+		let host = host.trim_right_matches('.');
+		if host.is_empty()
+		{
+			None
+		}
+		else
+		{
+			Some(host.to_ascii_lowercase())
+		}
+	}
+}
@@ -0,0 +1,17 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Something that `Stylesheet::sanitize()` removed, and why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SanitizerRemoval
+{
+	/// Why it was removed.
+	pub reason: SanitizerRemovalReason,
+	
+	/// What was removed, serialized as CSS; a rule, a property declaration or a `@font-face` source.
+	pub removed_css: String,
+	
+	/// Where what was removed was in the source CSS, if source spans were recorded when parsing.
+	pub source_span: Option<SourceSpan>,
+}
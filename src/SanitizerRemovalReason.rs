@@ -0,0 +1,38 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Why `Stylesheet::sanitize()` removed something.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SanitizerRemovalReason
+{
+	/// `@import` rules can load any stylesheet, so are always removed.
+	ImportAtRule,
+	
+	/// `@namespace` rules are always removed.
+	NamespaceAtRule,
+	
+	/// `@document` (and `@-moz-document`) rules are always removed.
+	DocumentAtRule,
+	
+	/// The rule's type is not in `SanitizerPolicy.allowed_rule_types`.
+	DisallowedRuleType(CssRuleType),
+	
+	/// The property (`-moz-binding` or `behavior`) can run script, so is always removed.
+	DangerousProperty(Atom),
+	
+	/// The property's name is not in `SanitizerPolicy.allowed_property_names`.
+	DisallowedProperty(Atom),
+	
+	/// The property's value used the legacy `expression()` function, which can run script.
+	ExpressionFunction(Atom),
+	
+	/// The URL's scheme is not in `SanitizerPolicy.allowed_url_schemes`.
+	DisallowedUrlScheme(String),
+	
+	/// The URL's host is not in `SanitizerPolicy.allowed_url_hosts`.
+	DisallowedUrlHost(String),
+	
+	/// The URL is relative but `SanitizerPolicy.allow_relative_urls` is false.
+	DisallowedRelativeUrl(String),
+}
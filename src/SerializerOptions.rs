@@ -0,0 +1,54 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Options controlling how `Stylesheet::to_css_with_options()` writes CSS.
+///
+/// The default options write the most compact form, exactly as `Stylesheet::to_css()` does; `SerializerOptions::pretty()` is a good starting point for a formatter.
+///
+/// Only style, `@page` and `@keyframes` declaration blocks and the rules nested in `@media`, `@supports` and `@document` are laid out; other at-rules (such as `@font-face`) are always written in the compact form, albeit on a line of their own.
+#[derive(Default, Debug, Clone)]
+pub struct SerializerOptions
+{
+	/// Written once for each level of nesting at the start of every line; typically a tab or some spaces.
+	pub indentation: String,
+	
+	/// Put each declaration, and each rule, on a line of its own.
+	pub one_declaration_per_line: bool,
+	
+	/// Write `color: red !important` rather than `color:red!important`; any whitespace around values in the source CSS is removed.
+	pub space_after_colon: bool,
+	
+	/// Separate rules with a blank line; implies each rule is on a line of its own.
+	pub blank_line_between_rules: bool,
+	
+	/// Whether the names of vendor-prefixed property declarations are written as they were in the source CSS (eg `-WebKit-transition`) or in lower case.
+	pub vendor_prefix_case: VendorPrefixCase,
+	
+	/// Which comments to write, if comments were preserved when parsing.
+	pub comments: CommentSerialization,
+}
+
+impl SerializerOptions
+{
+	/// Options for readable, hand-maintainable CSS: tab indentation, one declaration per line, a space after colons and a blank line between rules.
+	#[inline(always)]
+	pub fn pretty() -> Self
+	{
+		Self
+		{
+			indentation: "\t".to_owned(),
+			one_declaration_per_line: true,
+			space_after_colon: true,
+			blank_line_between_rules: true,
+			vendor_prefix_case: VendorPrefixCase::default(),
+			comments: CommentSerialization::default(),
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn rules_are_on_lines_of_their_own(&self) -> bool
+	{
+		self.one_declaration_per_line || self.blank_line_between_rules
+	}
+}
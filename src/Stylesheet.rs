@@ -105,7 +105,80 @@ impl Stylesheet
 		Ok(())
 	}
 	
+	/// Serializes a Stylesheet laid out as specified by `options`, without source-map and source-url comments.
+	/// With the default options the CSS is identical to that written by `to_css()`.
+	#[inline(always)]
+	pub fn to_css_with_options<W: fmt::Write>(&self, destination: &mut W, options: &SerializerOptions) -> fmt::Result
+	{
+		StylesheetSerializer::new(destination, options, None).write_stylesheet(&self.rules)
+	}
+	
+	/// Serializes a Stylesheet as a string laid out as specified by `options`.
+	/// Convenience method wrapped `to_css_with_options()`.
+	#[inline(always)]
+	pub fn to_css_string_with_options(&self, options: &SerializerOptions) -> String
+	{
+		let mut string = String::new();
+		self.to_css_with_options(&mut string, options).unwrap();
+		string
+	}
+	
+	/// Serializes a Stylesheet as for `to_css_with_options()`, whilst mapping each rule, at-rule prelude, selector, keyframe, property declaration and descriptor back to where it was in the source `source_index` (see `SourceMapGenerator::add_source()`).
+	/// Only stylesheets parsed with `StylesheetParseOptions.record_source_spans` set have anything to map.
+	/// Can be called for several stylesheets in turn with the same `destination` and `source_map_generator` to concatenate them.
+	#[inline(always)]
+	pub fn to_css_with_source_map<W: fmt::Write>(&self, destination: &mut W, options: &SerializerOptions, source_map_generator: &mut SourceMapGenerator, source_index: usize) -> fmt::Result
+	{
+		StylesheetSerializer::new(destination, options, Some((source_map_generator, source_index))).write_stylesheet(&self.rules)
+	}
+	
+	/// Removes rules, property declarations and `@font-face` sources that could load resources or run script contrary to `policy`, such as those in a stylesheet submitted by an untrusted user.
+	/// Nested rules are sanitized too; a property declaration is removed if any `url()` (or `image-set()` URL) in its value is not allowed.
+	/// Returns what was removed, and why, in the order it was found.
+	pub fn sanitize(&mut self, policy: &SanitizerPolicy) -> Vec<SanitizerRemoval>
+	{
+		let mut sanitizer = Sanitizer::new(policy);
+		sanitizer.sanitize_css_rules(&mut self.rules);
+		sanitizer.removals
+	}
+	
+	/// Emits a fallback before each property declaration using `var()`, including those in nested rules, with the custom properties defined by root style rules (see `CustomPropertyInliningPolicy`) substituted, for browsers which do not support custom properties.
+	/// No fallback is emitted if a custom property used is also defined by another rule, or is undefined (or invalid) without a fallback in its `var()` function.
+	/// Returns the property declarations for which no fallback was emitted, and why, in the order they were found.
+	pub fn inline_custom_properties(&mut self, policy: &CustomPropertyInliningPolicy) -> Vec<CustomPropertyInliningRefusal>
+	{
+		let mut inliner = CustomPropertyInliner::new(policy);
+		inliner.find_definitions(&self.rules, false);
+		inliner.inline_css_rules(&mut self.rules);
+		inliner.refusals
+	}
+	
+	/// Finds the names used by `animation-name` and `animation` property declarations, including those in nested rules, for which there is no `@keyframes` rule in this stylesheet.
+	/// Returns them in the order they were found; a name is returned once for each declaration using it.
+	#[inline(always)]
+	pub fn undefined_animation_names(&self) -> Vec<UndefinedAnimationName>
+	{
+		undefined_animation_names(&self.rules)
+	}
+	
+	/// Finds the counter styles used by `counter()` and `counters()` in `content` property declarations, including those in nested rules, which are neither predefined nor declared by a `@counter-style` rule in this stylesheet.
+	/// Returns them in the order they were found; a counter style is returned once for each time it is used.
+	#[inline(always)]
+	pub fn undeclared_counter_styles(&self) -> Vec<UndeclaredCounterStyle>
+	{
+		undeclared_counter_styles(&self.rules)
+	}
+	
+	/// Builds the graph of the custom properties (`--*`) defined by property declarations, including those in nested rules, and of their uses in `var()` functions.
+	/// Use it to find dependency cycles, uses of undefined custom properties without fallbacks and custom properties which are never used.
+	#[inline(always)]
+	pub fn custom_property_graph(&self) -> CustomPropertyGraph
+	{
+		CustomPropertyGraph::new(&self.rules)
+	}
+	
 	/// Loads and parses a Stylesheet.
+	/// The file's encoding is determined as for `parse_bytes()`, falling back to UTF-8.
 	#[inline(always)]
 	pub fn from_file_path<P: AsRef<Path>>(html_document_file_path: P) -> Result<Self, StylesheetError>
 	{
@@ -113,45 +186,107 @@ impl Stylesheet
 		let metadata = path.metadata().context(path)?;
 		
 		let mut file = File::open(path).context(path)?;
-		let mut css = String::with_capacity(metadata.len() as usize);
-		file.read_to_string(&mut css).context(path)?;
+		let mut bytes = Vec::with_capacity(metadata.len() as usize);
+		file.read_to_end(&mut bytes).context(path)?;
 		
-		let result = Self::parse(&css);
+		let result = Self::parse_bytes(&bytes, None, None, &StylesheetParseOptions::default());
 		
 		match result
 		{
-			Ok(stylesheet) => Ok(stylesheet),
+			Ok((stylesheet, _recoveredParseErrors, _encoding)) => Ok(stylesheet),
 			Err(cause) => Err(StylesheetError::Parse
 			(
 				path.to_path_buf(),
 				cause.location,
-				format!("{:?}", cause.error),
+				cause.reason,
 			)),
 			
 		}
 	}
 	
+	/// Parses a stream of bytes, such as the contents of a CSS file or a HTTP response body, to produce a stylesheet.
+	///
+	/// The encoding is determined as CSS Syntax Level 3 §3.2 specifies: from a byte order mark, then from `protocol_encoding_label` (eg the `charset` parameter of a HTTP `Content-Type` header), then from a leading `@charset` rule, then `fallback_encoding` (eg that of the referring document), and finally UTF-8.
+	/// The bytes are decoded to UTF-8 (malformed sequences are replaced with U+FFFD) and parsed as for `parse_with_options()`; the encoding that was used is returned alongside the stylesheet.
+	///
+	/// As the decoded CSS does not outlive this method, a parse error is returned as a `RecoveredParseError`, describing the CSS that failed to parse.
+	pub fn parse_bytes(bytes: &[u8], protocol_encoding_label: Option<&str>, fallback_encoding: Option<&'static Encoding>, options: &StylesheetParseOptions) -> Result<(Self, Vec<RecoveredParseError>, &'static Encoding), RecoveredParseError>
+	{
+		let encoding = stylesheet_encoding::<EncodingRsSupport>(bytes, protocol_encoding_label.map(str::as_bytes), fallback_encoding);
+		
+		// Decoding sniffs for a byte order mark, which takes precedence over everything else.
+		let (css, encoding, _hadMalformedSequences) = encoding.decode(bytes);
+		
+		let (stylesheet, recoveredParseErrors) = Self::parse_with_options(&css, options).map_err(RecoveredParseError::from)?;
+		Ok((stylesheet, recoveredParseErrors, encoding))
+	}
+	
 	/// Parses a string of CSS to produce a stylesheet.
 	/// Can be used with the contents of a CSS file.
 	/// Assumes the string is UTF-8 encoded.
 	/// Does not use a stream of bytes as parsing CSS involves going backwards and forwards a lot... CSS parsing is somewhat evil and is not particularly efficient.
 	/// The parser does apply a few small modifications to the incoming CSS, normalizing some pseudo-class, psuedo-element and media query names.
 	/// The parser does not parse properties as such, simply keeping them as a CSS string. Hopefully it will one day - there are only 200 odd specialist rules to implement.
+	/// Fails on the first invalid rule or declaration; use `parse_with_options()` to recover from errors instead.
+	#[inline(always)]
 	pub fn parse<'i>(css: &'i str) -> Result<Self, PreciseParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_with_options(css, &StylesheetParseOptions::default()).map(|(stylesheet, _recoveredParseErrors)| stylesheet)
+	}
+	
+	/// Parses a string of CSS to produce a stylesheet, as for `parse()`, but using the supplied options.
+	/// If `options.recover_from_errors` is set, invalid rules and declarations are skipped and returned as a list of errors alongside the (partial) stylesheet; otherwise this list is always empty.
+	pub fn parse_with_options<'i>(css: &'i str, options: &StylesheetParseOptions) -> Result<(Self, Vec<RecoveredParseError>), PreciseParseError<'i, CustomParseError<'i>>>
 	{
 		const LineNumberingIsZeroBased: u32 = 0;
 		
 		let mut parserInput = ParserInput::new_with_line_number_offset(css, LineNumberingIsZeroBased);
 		let mut input = Parser::new(&mut parserInput);
 		
+		if css.len() > options.limits.maximum_input_size
+		{
+			return Err
+			(
+				PreciseParseError
+				{
+					error: ParseError::Custom(CustomParseError::ParseLimitExceeded(ParseLimit::InputSize)),
+					slice: css,
+					location: input.current_source_location(),
+				}
+			);
+		}
+		
 		let mut rules = Vec::new();
 		
+		let recoveredParseErrors = if options.recover_from_errors
+		{
+			Some(Rc::new(RefCell::new(Vec::new())))
+		}
+		else
+		{
+			None
+		};
+		
 		let topLevelRuleParser = TopLevelRuleParser
 		{
 			context: ParserContext
 			{
 				rule_type: None,
 				parsing_mode: ParsingMode::Default,
+				recovered_parse_errors: recoveredParseErrors.clone(),
+				record_source_spans_from: if options.record_source_spans
+				{
+					Some(input.position())
+				}
+				else
+				{
+					None
+				},
+				preserve_comments: options.preserve_comments,
+				limits: options.limits,
+				nesting_depth: 0,
+				rule_count: Rc::new(Cell::new(0)),
+				calc_expression_depth: Cell::new(0),
 			},
 			state: State::Start,
 			namespaces: Namespaces::empty(),
@@ -160,24 +295,83 @@ impl Stylesheet
 		{
 			let mut iter = RuleListParser::new_for_stylesheet(&mut input, topLevelRuleParser);
 			
-			while let Some(result) = iter.next()
+			loop
 			{
-				match result
+				for comment in iter.parser.context.parse_comments(iter.input, false)
+				{
+					rules.push(CssRule::Comment(comment));
+				}
+				
+				// @charset is only used to determine the encoding (see `parse_bytes()`); it is not a rule, and is ignored wherever it is, as browsers do.
+				// cssparser silently drops a leading @charset itself, but doing so here stops it being included in the first rule's source span and from swallowing any comments after it.
+				if Self::skip_charset_at_rule(iter.input)
 				{
-					Ok(rule) => rules.push(rule),
-					Err(preciseParseError) => return Err(preciseParseError),
+					continue;
+				}
+				
+				let start = iter.parser.context.source_span_start(iter.input, false);
+				
+				match iter.next()
+				{
+					None => break,
+					
+					Some(Ok(mut rule)) =>
+					{
+						*rule.source_span_mut() = iter.parser.context.source_span(iter.input, &start);
+						rules.push(rule)
+					}
+					
+					Some(Err(preciseParseError)) => match recoveredParseErrors
+					{
+						Some(ref recoveredParseErrors) if ParserContext::is_recoverable(&preciseParseError) => recoveredParseErrors.borrow_mut().push(RecoveredParseError::from(preciseParseError)),
+						_ => return Err(preciseParseError),
+					},
 				}
 			}
 		}
 		
+		let recoveredParseErrors = match recoveredParseErrors
+		{
+			None => Vec::new(),
+			Some(recoveredParseErrors) => recoveredParseErrors.borrow_mut().drain(..).collect(),
+		};
+		
 		Ok
 		(
-			Self
+			(
+				Self
+				{
+					rules: CssRules(rules),
+					source_map_url: input.current_source_map_url().map(String::from),
+					source_url: input.current_source_url().map(String::from),
+				},
+				recoveredParseErrors,
+			)
+		)
+	}
+	
+	#[inline(always)]
+	fn skip_charset_at_rule<'i, 't>(input: &mut Parser<'i, 't>) -> bool
+	{
+		let result: Result<(), ParseError<()>> = input.try(|input|
+		{
+			input.skip_whitespace();
+			let isCharset = match *input.next()?
 			{
-				rules: CssRules(rules),
-				source_map_url: input.current_source_map_url().map(String::from),
-				source_url: input.current_source_url().map(String::from),
+				Token::AtKeyword(ref name) => name.eq_ignore_ascii_case("charset"),
+				_ => false,
+			};
+			
+			if isCharset
+			{
+				let _: Result<(), ParseError<()>> = input.parse_until_after(Delimiter::Semicolon | Delimiter::CurlyBracketBlock, |_| Ok(()));
+				Ok(())
 			}
-		)
+			else
+			{
+				Err(ParseError::Custom(()))
+			}
+		});
+		result.is_ok()
 	}
 }
@@ -0,0 +1,28 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Options controlling how `Stylesheet::parse_with_options()` behaves.
+///
+/// The default options are those used by `Stylesheet::parse()`.
+#[derive(Default, Debug, Clone)]
+pub struct StylesheetParseOptions
+{
+	/// Rather than failing on the first invalid rule or declaration, skip it as CSS Syntax Level 3 specifies (just as a browser would) and carry on.
+	///
+	/// Every skipped rule or declaration is reported as a `RecoveredParseError`.
+	pub recover_from_errors: bool,
+	
+	/// Record where each rule, at-rule prelude, declaration and selector was in the source CSS (see `SourceSpan`).
+	///
+	/// Off by default, as it is of no use when just minifying.
+	pub record_source_spans: bool,
+	
+	/// Keep comments between rules (as `CssRule::Comment`) and between property declarations (see `PropertyDeclaration.comments_before` and the `comments_after_property_declarations` of style rules, `@page` rules and keyframes), rather than discarding them.
+	pub preserve_comments: bool,
+	
+	/// Limits on what parsing may consume; there are none by default.
+	///
+	/// Use `ParseLimits::for_untrusted_input()` when parsing stylesheets from untrusted sources.
+	pub limits: ParseLimits,
+}
@@ -0,0 +1,23 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// How the names of vendor-prefixed property declarations are written when serializing with `SerializerOptions`.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum VendorPrefixCase
+{
+	/// Write names in lower case, eg `-webkit-transition` (the default, and what `ToCss` always does).
+	Normalise,
+	
+	/// Write names as they were in the source CSS, eg `-WebKit-transition`.
+	Preserve,
+}
+
+impl Default for VendorPrefixCase
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		VendorPrefixCase::Normalise
+	}
+}
@@ -0,0 +1,78 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A comment, such as a licence banner.
+///
+/// Comments are only kept if `StylesheetParseOptions.preserve_comments` was set when parsing.
+///
+/// Only the text is compared and hashed; the source span is not.
+#[derive(Debug, Clone)]
+pub struct CssComment
+{
+	/// The text between `/*` and `*/`.
+	pub text: String,
+	
+	/// Where this comment was in the source CSS, if source spans were recorded when parsing.
+	pub source_span: Option<SourceSpan>,
+}
+
+impl PartialEq for CssComment
+{
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool
+	{
+		self.text == other.text
+	}
+}
+
+impl Eq for CssComment
+{
+}
+
+impl PartialOrd for CssComment
+{
+	#[inline(always)]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for CssComment
+{
+	#[inline(always)]
+	fn cmp(&self, other: &Self) -> Ordering
+	{
+		self.text.cmp(&other.text)
+	}
+}
+
+impl Hash for CssComment
+{
+	#[inline(always)]
+	fn hash<H: Hasher>(&self, state: &mut H)
+	{
+		self.text.hash(state)
+	}
+}
+
+impl ToCss for CssComment
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		dest.write_str("/*")?;
+		dest.write_str(&self.text)?;
+		dest.write_str("*/")
+	}
+}
+
+impl CssComment
+{
+	/// Is this a `/*! ... */` comment? By convention, these are licence banners and the like which minifiers must keep.
+	#[inline(always)]
+	pub fn is_significant(&self) -> bool
+	{
+		self.text.starts_with('!')
+	}
+}
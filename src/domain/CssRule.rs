@@ -6,6 +6,9 @@
 #[derive(Debug, Clone)]
 pub enum CssRule
 {
+	/// A comment between rules; only present if comments were preserved when parsing
+	Comment(CssComment),
+	
 	/// @counter-style
 	CounterStyle(CounterStyleAtRule),
 	
@@ -52,6 +55,8 @@ impl ToCss for CssRule
 		
 		match *self
 		{
+			Comment(ref comment) => comment.to_css(dest),
+			
 			CounterStyle(ref rule) => rule.to_css(dest),
 			
 			Document(ref rule) => rule.to_css(dest),
@@ -89,6 +94,9 @@ impl CssRule
 		
 		match *self
 		{
+			// CSSOM has no comment rules; the obsolete type 0 is the closest.
+			Comment(_) => CssRuleType::Unknown,
+			
 			CounterStyle(_) => CssRuleType::CounterStyle,
 			
 			Document(_)  => CssRuleType::Document,
@@ -114,4 +122,76 @@ impl CssRule
 			Viewport(_) => CssRuleType::Viewport,
 		}
 	}
+	
+	/// Where this rule was in the source CSS, if source spans were recorded when parsing.
+	#[inline(always)]
+	pub fn source_span(&self) -> Option<SourceSpan>
+	{
+		use self::CssRule::*;
+		
+		match *self
+		{
+			Comment(ref rule) => rule.source_span,
+			
+			CounterStyle(ref rule) => rule.source_span,
+			
+			Document(ref rule) => rule.source_span,
+			
+			FontFace(ref rule) => rule.source_span,
+			
+			FontFeatureValues(ref rule) => rule.source_span,
+			
+			Import(ref rule) => rule.source_span,
+			
+			Keyframes(ref rule) => rule.source_span,
+			
+			Media(ref rule) => rule.source_span,
+			
+			Namespace(ref rule) => rule.source_span,
+			
+			Page(ref rule) => rule.source_span,
+			
+			Style(ref rule) => rule.source_span,
+			
+			Supports(ref rule) => rule.source_span,
+			
+			Viewport(ref rule) => rule.source_span,
+		}
+	}
+	
+	/// Where this rule was in the source CSS, if source spans were recorded when parsing; mutable so that rules created or moved by code can have their span changed or cleared.
+	#[inline(always)]
+	pub fn source_span_mut(&mut self) -> &mut Option<SourceSpan>
+	{
+		use self::CssRule::*;
+		
+		match *self
+		{
+			Comment(ref mut rule) => &mut rule.source_span,
+			
+			CounterStyle(ref mut rule) => &mut rule.source_span,
+			
+			Document(ref mut rule) => &mut rule.source_span,
+			
+			FontFace(ref mut rule) => &mut rule.source_span,
+			
+			FontFeatureValues(ref mut rule) => &mut rule.source_span,
+			
+			Import(ref mut rule) => &mut rule.source_span,
+			
+			Keyframes(ref mut rule) => &mut rule.source_span,
+			
+			Media(ref mut rule) => &mut rule.source_span,
+			
+			Namespace(ref mut rule) => &mut rule.source_span,
+			
+			Page(ref mut rule) => &mut rule.source_span,
+			
+			Style(ref mut rule) => &mut rule.source_span,
+			
+			Supports(ref mut rule) => &mut rule.source_span,
+			
+			Viewport(ref mut rule) => &mut rule.source_span,
+		}
+	}
 }
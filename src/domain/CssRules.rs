@@ -115,7 +115,7 @@ impl CssRules
 		{
 			match *r
 			{
-				Namespace(..) | Import(..) => true,
+				Comment(..) | Namespace(..) | Import(..) => true,
 				_ => false
 			}
 		})
@@ -0,0 +1,46 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Where something (a rule, a prelude, a declaration or a selector) was in the source CSS of a stylesheet.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct SourceSpan
+{
+	/// Where it started (inclusive).
+	pub start: SourceSpanLocation,
+	
+	/// Where it ended (exclusive).
+	pub end: SourceSpanLocation,
+}
+
+impl SourceSpan
+{
+	/// The number of bytes of source CSS covered by this span.
+	#[inline(always)]
+	pub fn len(&self) -> usize
+	{
+		self.end.byte_offset - self.start.byte_offset
+	}
+	
+	/// The source CSS covered by this span.
+	/// `css` must be the string the stylesheet was parsed from.
+	#[inline(always)]
+	pub fn slice<'a>(&self, css: &'a str) -> &'a str
+	{
+		&css[self.start.byte_offset .. self.end.byte_offset]
+	}
+	
+	/// Creates a span from `start` to the current position of `input`, less any trailing whitespace; byte offsets are relative to `stylesheetStart`.
+	#[inline(always)]
+	pub(crate) fn new<'i, 't>(stylesheetStart: SourcePosition, input: &Parser<'i, 't>, start: &ParserState) -> Self
+	{
+		let css = input.slice_from(start.position()).trim_right();
+		let start = SourceSpanLocation::new(stylesheetStart, input, start);
+		
+		Self
+		{
+			start,
+			end: start.advanced_by(css),
+		}
+	}
+}
@@ -0,0 +1,73 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A location in the source CSS of a stylesheet.
+#[derive(Default, Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct SourceSpanLocation
+{
+	/// The line number, starting at 0 for the first line.
+	pub line: u32,
+	
+	/// The column number within a line, starting at 1 for the first character of the line.
+	/// Column numbers are counted in UTF-16 code units (as they are by browsers' developer tools).
+	pub column: u32,
+	
+	/// The offset in bytes from the start of the (UTF-8) source CSS.
+	pub byte_offset: usize,
+}
+
+impl SourceSpanLocation
+{
+	#[inline(always)]
+	fn new<'i, 't>(stylesheetStart: SourcePosition, input: &Parser<'i, 't>, state: &ParserState) -> Self
+	{
+		let sourceLocation = state.source_location();
+		
+		Self
+		{
+			line: sourceLocation.line,
+			column: sourceLocation.column,
+			byte_offset: input.slice(stylesheetStart .. state.position()).len(),
+		}
+	}
+	
+	/// The location after `css`, which starts at this location; newlines are as for CSS (`\n`, `\r\n`, `\r` and `\f`).
+	#[inline(always)]
+	fn advanced_by(&self, css: &str) -> Self
+	{
+		let mut line = self.line;
+		let mut column = self.column;
+		let mut characters = css.chars().peekable();
+		while let Some(character) = characters.next()
+		{
+			match character
+			{
+				'\r' =>
+				{
+					if characters.peek() == Some(&'\n')
+					{
+						characters.next();
+					}
+					line += 1;
+					column = 1;
+				}
+				
+				'\n' | '\x0C' =>
+				{
+					line += 1;
+					column = 1;
+				}
+				
+				_ => column += character.len_utf16() as u32,
+			}
+		}
+		
+		Self
+		{
+			line,
+			column,
+			byte_offset: self.byte_offset + css.len(),
+		}
+	}
+}
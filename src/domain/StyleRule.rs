@@ -9,8 +9,17 @@ pub struct StyleRule
 	/// The list of selectors in this rule.
 	pub selectors: DeduplicatedSelectors,
 	
+	/// Where each of `selectors` was in the source CSS, in the same order, if source spans were recorded when parsing; otherwise empty.
+	pub selector_source_spans: Vec<SourceSpan>,
+	
 	/// The declaration block with the properties it contains.
 	pub property_declarations: PropertyDeclarations<Importance>,
+	
+	/// Any comments after the last property declaration; only present if comments were preserved when parsing.
+	pub comments_after_property_declarations: Vec<CssComment>,
+	
+	/// Where this rule was in the source CSS, if source spans were recorded when parsing.
+	pub source_span: Option<SourceSpan>,
 }
 
 impl ToCss for StyleRule
@@ -22,7 +31,7 @@ impl ToCss for StyleRule
 		
 		dest.write_char('{')?;
 		
-		self.property_declarations.to_css(dest)?;
+		self.property_declarations.to_css_followed_by_comments(&self.comments_after_property_declarations, dest)?;
 		
 		dest.write_char('}')
 	}
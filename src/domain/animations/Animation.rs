@@ -0,0 +1,144 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The `animation` shorthand property: https://drafts.csswg.org/css-animations-1/#animation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Animation(pub Vec<SingleAnimation>);
+
+impl Default for Animation
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Animation(vec![SingleAnimation::default()])
+	}
+}
+
+impl ToCss for Animation
+{
+	#[inline(always)]
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		AnimationList::write_list(&self.0, dest)
+	}
+}
+
+impl PropertyValue for Animation
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"animation",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		input.parse_comma_separated(|input| SingleAnimation::parse(context, input)).map(Animation)
+	}
+}
+
+impl Shorthand for Animation
+{
+	#[inline(always)]
+	fn longhand_property_names(_shorthand_property_name: &str) -> &'static [&'static str]
+	{
+		Self::LonghandPropertyNames
+	}
+	
+	#[inline(always)]
+	fn is_related_property(_shorthand_property_name: &str, property_name: &str) -> bool
+	{
+		const Prefix: &'static str = "animation";
+		
+		property_name.len() >= Prefix.len() && property_name.is_char_boundary(Prefix.len()) && property_name[..Prefix.len()].eq_ignore_ascii_case(Prefix)
+	}
+	
+	fn expand(&self) -> Option<Vec<SpecifiedValue>>
+	{
+		if self.is_var()
+		{
+			return None;
+		}
+		
+		let animations = &self.0;
+		Some
+		(
+			vec!
+			[
+				SpecifiedValue::from_typed_value(&AnimationList(animations.iter().map(|animation| animation.name.clone()).collect())).ok()?,
+				SpecifiedValue::from_typed_value(&AnimationList(animations.iter().map(|animation| animation.duration.clone()).collect())).ok()?,
+				SpecifiedValue::from_typed_value(&AnimationList(animations.iter().map(|animation| animation.timing_function.clone()).collect())).ok()?,
+				SpecifiedValue::from_typed_value(&AnimationList(animations.iter().map(|animation| animation.delay.clone()).collect())).ok()?,
+				SpecifiedValue::from_typed_value(&AnimationList(animations.iter().map(|animation| animation.iteration_count.clone()).collect())).ok()?,
+				SpecifiedValue::from_typed_value(&AnimationList(animations.iter().map(|animation| animation.direction).collect())).ok()?,
+				SpecifiedValue::from_typed_value(&AnimationList(animations.iter().map(|animation| animation.fill_mode).collect())).ok()?,
+				SpecifiedValue::from_typed_value(&AnimationList(animations.iter().map(|animation| animation.play_state).collect())).ok()?,
+			]
+		)
+	}
+	
+	/// Only folds longhand properties which all have one value for each name in `animation-name`.
+	fn fold(longhands: &[&SpecifiedValue]) -> Option<Self>
+	{
+		if longhands.len() != Self::LonghandPropertyNames.len()
+		{
+			return None;
+		}
+		
+		let names = AnimationList::<AnimationName>::parse_property_value_from_css(longhands[0].original_css()).ok()?.0;
+		let durations = AnimationList::<CalculablePropertyValue<TimeUnit<CssUnsignedNumber>>>::parse_property_value_from_css(longhands[1].original_css()).ok()?.0;
+		let timingFunctions = AnimationList::<TimingFunction>::parse_property_value_from_css(longhands[2].original_css()).ok()?.0;
+		let delays = AnimationList::<CalculablePropertyValue<TimeUnit<CssSignedNumber>>>::parse_property_value_from_css(longhands[3].original_css()).ok()?.0;
+		let iterationCounts = AnimationList::<AnimationIterationCount>::parse_property_value_from_css(longhands[4].original_css()).ok()?.0;
+		let directions = AnimationList::<AnimationDirection>::parse_property_value_from_css(longhands[5].original_css()).ok()?.0;
+		let fillModes = AnimationList::<AnimationFillMode>::parse_property_value_from_css(longhands[6].original_css()).ok()?.0;
+		let playStates = AnimationList::<AnimationPlayState>::parse_property_value_from_css(longhands[7].original_css()).ok()?.0;
+		
+		let numberOfAnimations = names.len();
+		if [durations.len(), timingFunctions.len(), delays.len(), iterationCounts.len(), directions.len(), fillModes.len(), playStates.len()].iter().any(|length| *length != numberOfAnimations)
+		{
+			return None;
+		}
+		
+		let mut animations = Vec::with_capacity(numberOfAnimations);
+		let mut iterator = names.into_iter().zip(durations).zip(timingFunctions).zip(delays).zip(iterationCounts).zip(directions).zip(fillModes).zip(playStates);
+		while let Some(((((((((name, duration), timing_function), delay), iteration_count), direction), fill_mode), play_state))) = iterator.next()
+		{
+			animations.push(SingleAnimation { name, duration, timing_function, delay, iteration_count, direction, fill_mode, play_state });
+		}
+		
+		let animation = Animation(animations);
+		if animation.is_var()
+		{
+			None
+		}
+		else
+		{
+			Some(animation)
+		}
+	}
+}
+
+impl Animation
+{
+	/// The longhand properties set by the `animation` shorthand property.
+	pub const LonghandPropertyNames: &'static [&'static str] = &
+	[
+		"animation-name",
+		"animation-duration",
+		"animation-timing-function",
+		"animation-delay",
+		"animation-iteration-count",
+		"animation-direction",
+		"animation-fill-mode",
+		"animation-play-state",
+	];
+	
+	/// Whether any animation uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		self.0.iter().any(SingleAnimation::is_var)
+	}
+}
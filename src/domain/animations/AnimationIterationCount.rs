@@ -0,0 +1,60 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// One value of `animation-iteration-count`: https://drafts.csswg.org/css-animations-1/#animation-iteration-count
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnimationIterationCount
+{
+	/// `infinite`.
+	infinite,
+	
+	/// A number of iterations, which may be fractional.
+	count(CalculablePropertyValue<CssUnsignedNumber>),
+}
+
+impl Default for AnimationIterationCount
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		AnimationIterationCount::count(CalculablePropertyValue::Constant(CssUnsignedNumber::One))
+	}
+}
+
+impl ToCss for AnimationIterationCount
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		match *self
+		{
+			AnimationIterationCount::infinite => dest.write_str("infinite"),
+			
+			AnimationIterationCount::count(ref count) => count.to_css(dest),
+		}
+	}
+}
+
+impl AnimationIterationCount
+{
+	/// Whether the count uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		match *self
+		{
+			AnimationIterationCount::infinite => false,
+			AnimationIterationCount::count(ref count) => count.is_var(),
+		}
+	}
+	
+	pub(crate) fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.try(|input| input.expect_ident_matching("infinite")).is_ok()
+		{
+			return Ok(AnimationIterationCount::infinite);
+		}
+		
+		CssUnsignedNumber::parse_one_outside_calc_function(context, input).map(AnimationIterationCount::count)
+	}
+}
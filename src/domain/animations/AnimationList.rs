@@ -0,0 +1,179 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A comma-separated list of values, one for each transition or animation; see https://drafts.csswg.org/css-animations-1/#animation-name for how lists of differing lengths are coordinated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimationList<T>(pub Vec<T>);
+
+impl<T: ToCss> ToCss for AnimationList<T>
+{
+	#[inline(always)]
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		Self::write_list(&self.0, dest)
+	}
+}
+
+impl PropertyValue for AnimationList<TransitionProperty>
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"transition-property",
+	];
+	
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let transitionProperties = Self::parse_with(context, input, |_context, input| TransitionProperty::parse(input))?;
+		if transitionProperties.0.len() > 1 && transitionProperties.0.contains(&TransitionProperty::none)
+		{
+			return Err(ParseError::Custom(CustomParseError::TransitionPropertyNoneMustBeTheOnlyValue));
+		}
+		Ok(transitionProperties)
+	}
+}
+
+impl PropertyValue for AnimationList<CalculablePropertyValue<TimeUnit<CssUnsignedNumber>>>
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"transition-duration",
+		"animation-duration",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_with(context, input, TimeUnit::parse_one_outside_calc_function)
+	}
+}
+
+impl PropertyValue for AnimationList<CalculablePropertyValue<TimeUnit<CssSignedNumber>>>
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"transition-delay",
+		"animation-delay",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_with(context, input, TimeUnit::parse_one_outside_calc_function)
+	}
+}
+
+impl PropertyValue for AnimationList<TimingFunction>
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"transition-timing-function",
+		"animation-timing-function",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_with(context, input, |_context, input| TimingFunction::parse(input))
+	}
+}
+
+impl PropertyValue for AnimationList<AnimationName>
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"animation-name",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_with(context, input, |_context, input| AnimationName::parse(input))
+	}
+}
+
+impl PropertyValue for AnimationList<AnimationIterationCount>
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"animation-iteration-count",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_with(context, input, AnimationIterationCount::parse)
+	}
+}
+
+impl PropertyValue for AnimationList<AnimationDirection>
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"animation-direction",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_with(context, input, |_context, input| AnimationDirection::parse(input))
+	}
+}
+
+impl PropertyValue for AnimationList<AnimationFillMode>
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"animation-fill-mode",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_with(context, input, |_context, input| AnimationFillMode::parse(input))
+	}
+}
+
+impl PropertyValue for AnimationList<AnimationPlayState>
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"animation-play-state",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_with(context, input, |_context, input| AnimationPlayState::parse(input))
+	}
+}
+
+impl<T: ToCss> AnimationList<T>
+{
+	pub(crate) fn write_list<W: fmt::Write>(values: &[T], dest: &mut W) -> fmt::Result
+	{
+		let mut afterFirst = false;
+		for value in values.iter()
+		{
+			if afterFirst
+			{
+				dest.write_char(',')?;
+			}
+			else
+			{
+				afterFirst = true;
+			}
+			value.to_css(dest)?;
+		}
+		Ok(())
+	}
+}
+
+impl<T> AnimationList<T>
+{
+	#[inline(always)]
+	fn parse_with<'i, 't, Parse: for<'tt> Fn(&ParserContext, &mut Parser<'i, 'tt>) -> Result<T, ParseError<'i, CustomParseError<'i>>>>(context: &ParserContext, input: &mut Parser<'i, 't>, parse: Parse) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		input.parse_comma_separated(|input| parse(context, input)).map(AnimationList)
+	}
+}
@@ -0,0 +1,60 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// One value of `animation-name`: https://drafts.csswg.org/css-animations-1/#animation-name
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum AnimationName
+{
+	/// `none`; no keyframes.
+	none,
+	
+	/// The name of a `@keyframes` rule.
+	name(KeyframesName),
+}
+
+impl Default for AnimationName
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		AnimationName::none
+	}
+}
+
+impl ToCss for AnimationName
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		match *self
+		{
+			AnimationName::none => dest.write_str("none"),
+			
+			AnimationName::name(ref keyframesName) => keyframesName.to_css(dest),
+		}
+	}
+}
+
+impl AnimationName
+{
+	/// The name of the `@keyframes` rule, if any.
+	#[inline(always)]
+	pub fn keyframes_name(&self) -> Option<&KeyframesName>
+	{
+		match *self
+		{
+			AnimationName::none => None,
+			AnimationName::name(ref keyframesName) => Some(keyframesName),
+		}
+	}
+	
+	pub(crate) fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			return Ok(AnimationName::none);
+		}
+		
+		KeyframesName::parse(input).map(AnimationName::name)
+	}
+}
@@ -0,0 +1,77 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// One stop of a `linear()` timing function: an output and up to two input progress values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearStop
+{
+	/// The output progress value.
+	pub output: CssSignedNumber,
+	
+	/// The input progress value, if specified.
+	pub input: Option<PercentageUnit<CssSignedNumber>>,
+	
+	/// A second input progress value, if specified; the same as a second stop with the same output.
+	pub second_input: Option<PercentageUnit<CssSignedNumber>>,
+}
+
+impl ToCss for LinearStop
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		self.output.to_css(dest)?;
+		if let Some(ref input) = self.input
+		{
+			dest.write_char(' ')?;
+			input.to_css(dest)?;
+		}
+		if let Some(ref second_input) = self.second_input
+		{
+			dest.write_char(' ')?;
+			second_input.to_css(dest)?;
+		}
+		Ok(())
+	}
+}
+
+impl LinearStop
+{
+	pub(crate) fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		#[inline(always)]
+		fn percentage<'i, 't>(input: &mut Parser<'i, 't>) -> Result<PercentageUnit<CssSignedNumber>, ParseError<'i, CustomParseError<'i>>>
+		{
+			PercentageUnit::parse_percentage(input.expect_percentage()?)
+		}
+		
+		// `<number> && <percentage>{0,2}`: the percentages come either all before or all after the number.
+		let mut inputs = (input.try(percentage).ok(), None);
+		if inputs.0.is_some()
+		{
+			inputs.1 = input.try(percentage).ok();
+		}
+		
+		let output = CssSignedNumber::parseNumber(input.expect_number()?, None)?;
+		
+		if inputs.0.is_none()
+		{
+			inputs.0 = input.try(percentage).ok();
+			if inputs.0.is_some()
+			{
+				inputs.1 = input.try(percentage).ok();
+			}
+		}
+		let (input, second_input) = inputs;
+		
+		Ok
+		(
+			Self
+			{
+				output,
+				input,
+				second_input,
+			}
+		)
+	}
+}
@@ -0,0 +1,209 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// One animation of the `animation` shorthand property.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SingleAnimation
+{
+	/// The value of `animation-name` for this animation.
+	pub name: AnimationName,
+	
+	/// The value of `animation-duration` for this animation.
+	pub duration: CalculablePropertyValue<TimeUnit<CssUnsignedNumber>>,
+	
+	/// The value of `animation-timing-function` for this animation.
+	pub timing_function: TimingFunction,
+	
+	/// The value of `animation-delay` for this animation.
+	pub delay: CalculablePropertyValue<TimeUnit<CssSignedNumber>>,
+	
+	/// The value of `animation-iteration-count` for this animation.
+	pub iteration_count: AnimationIterationCount,
+	
+	/// The value of `animation-direction` for this animation.
+	pub direction: AnimationDirection,
+	
+	/// The value of `animation-fill-mode` for this animation.
+	pub fill_mode: AnimationFillMode,
+	
+	/// The value of `animation-play-state` for this animation.
+	pub play_state: AnimationPlayState,
+}
+
+impl Default for SingleAnimation
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			name: AnimationName::default(),
+			duration: CalculablePropertyValue::Constant(TimeUnit::s(CssUnsignedNumber::Zero)),
+			timing_function: TimingFunction::default(),
+			delay: CalculablePropertyValue::Constant(TimeUnit::s(CssSignedNumber::Zero)),
+			iteration_count: AnimationIterationCount::default(),
+			direction: AnimationDirection::default(),
+			fill_mode: AnimationFillMode::default(),
+			play_state: AnimationPlayState::default(),
+		}
+	}
+}
+
+/// Writes the values which are not initial values, with the name last; a name which could be mistaken for a keyword is written as a string.
+impl ToCss for SingleAnimation
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		let initial = Self::default();
+		let mut afterFirst = false;
+		
+		macro_rules! write_if_not_initial
+		{
+			($condition: expr, $value: expr) =>
+			{
+				if $condition
+				{
+					if afterFirst
+					{
+						dest.write_char(' ')?;
+					}
+					$value.to_css(dest)?;
+					afterFirst = true;
+				}
+			}
+		}
+		
+		write_if_not_initial!(self.duration != initial.duration || self.delay != initial.delay, self.duration);
+		write_if_not_initial!(self.timing_function != initial.timing_function, self.timing_function);
+		write_if_not_initial!(self.delay != initial.delay, self.delay);
+		write_if_not_initial!(self.iteration_count != initial.iteration_count, self.iteration_count);
+		write_if_not_initial!(self.direction != initial.direction, self.direction);
+		write_if_not_initial!(self.fill_mode != initial.fill_mode, self.fill_mode);
+		write_if_not_initial!(self.play_state != initial.play_state, self.play_state);
+		
+		match self.name
+		{
+			AnimationName::none => if !afterFirst
+			{
+				dest.write_str("none")?;
+			},
+			
+			AnimationName::name(ref keyframesName) =>
+			{
+				if afterFirst
+				{
+					dest.write_char(' ')?;
+				}
+				
+				let name = keyframesName.as_atom();
+				if Self::is_keyword(name)
+				{
+					serialize_string(name, dest)?;
+				}
+				else
+				{
+					keyframesName.to_css(dest)?;
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+impl SingleAnimation
+{
+	/// Whether the duration, delay or iteration count uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		self.duration.is_var() || self.delay.is_var() || self.iteration_count.is_var()
+	}
+	
+	/// Keywords of the other longhand properties take precedence over an animation name when parsing.
+	pub(crate) fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut name = None;
+		let mut duration = None;
+		let mut timing_function = None;
+		let mut delay = None;
+		let mut iteration_count = None;
+		let mut direction = None;
+		let mut fill_mode = None;
+		let mut play_state = None;
+		
+		macro_rules! try_parse
+		{
+			($value: ident, $parse: expr) =>
+			{
+				if $value.is_none()
+				{
+					if let Ok(value) = input.try($parse)
+					{
+						$value = Some(value);
+						continue;
+					}
+				}
+			}
+		}
+		
+		loop
+		{
+			if duration.is_none()
+			{
+				try_parse!(duration, |input| TimeUnit::parse_one_outside_calc_function(context, input));
+			}
+			else
+			{
+				try_parse!(delay, |input| TimeUnit::parse_one_outside_calc_function(context, input));
+			}
+			try_parse!(timing_function, TimingFunction::parse);
+			try_parse!(iteration_count, |input| AnimationIterationCount::parse(context, input));
+			try_parse!(direction, AnimationDirection::parse);
+			try_parse!(fill_mode, AnimationFillMode::parse);
+			try_parse!(play_state, AnimationPlayState::parse);
+			try_parse!(name, AnimationName::parse);
+			break;
+		}
+		
+		if name.is_none() && duration.is_none() && timing_function.is_none() && iteration_count.is_none() && direction.is_none() && fill_mode.is_none() && play_state.is_none()
+		{
+			return Err(ParseError::Custom(CustomParseError::EmptyTransitionOrAnimation));
+		}
+		
+		let initial = Self::default();
+		Ok
+		(
+			Self
+			{
+				name: name.unwrap_or(initial.name),
+				duration: duration.unwrap_or(initial.duration),
+				timing_function: timing_function.unwrap_or(initial.timing_function),
+				delay: delay.unwrap_or(initial.delay),
+				iteration_count: iteration_count.unwrap_or(initial.iteration_count),
+				direction: direction.unwrap_or(initial.direction),
+				fill_mode: fill_mode.unwrap_or(initial.fill_mode),
+				play_state: play_state.unwrap_or(initial.play_state),
+			}
+		)
+	}
+	
+	#[inline(always)]
+	fn is_keyword(name: &str) -> bool
+	{
+		const Keywords: &'static [&'static str] = &
+		[
+			"none",
+			"infinite",
+			"linear",
+			"ease",
+			"ease-in",
+			"ease-out",
+			"ease-in-out",
+			"step-start",
+			"step-end",
+		];
+		
+		Keywords.iter().any(|keyword| keyword.eq_ignore_ascii_case(name)) || AnimationDirection::from_ident(name).is_ok() || AnimationFillMode::from_ident(name).is_ok() || AnimationPlayState::from_ident(name).is_ok()
+	}
+}
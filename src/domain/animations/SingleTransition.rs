@@ -0,0 +1,158 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// One transition of the `transition` shorthand property.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SingleTransition
+{
+	/// The value of `transition-property` for this transition.
+	pub property: TransitionProperty,
+	
+	/// The value of `transition-duration` for this transition.
+	pub duration: CalculablePropertyValue<TimeUnit<CssUnsignedNumber>>,
+	
+	/// The value of `transition-timing-function` for this transition.
+	pub timing_function: TimingFunction,
+	
+	/// The value of `transition-delay` for this transition.
+	pub delay: CalculablePropertyValue<TimeUnit<CssSignedNumber>>,
+}
+
+impl Default for SingleTransition
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			property: TransitionProperty::default(),
+			duration: CalculablePropertyValue::Constant(TimeUnit::s(CssUnsignedNumber::Zero)),
+			timing_function: TimingFunction::default(),
+			delay: CalculablePropertyValue::Constant(TimeUnit::s(CssSignedNumber::Zero)),
+		}
+	}
+}
+
+/// Writes the values which are not initial values; the duration is written if the delay is, as the first time is always the duration.
+impl ToCss for SingleTransition
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		let initial = Self::default();
+		let mut afterFirst = false;
+		
+		if self.property != initial.property
+		{
+			self.property.to_css(dest)?;
+			afterFirst = true;
+		}
+		
+		if self.duration != initial.duration || self.delay != initial.delay
+		{
+			if afterFirst
+			{
+				dest.write_char(' ')?;
+			}
+			self.duration.to_css(dest)?;
+			afterFirst = true;
+		}
+		
+		if self.timing_function != initial.timing_function
+		{
+			if afterFirst
+			{
+				dest.write_char(' ')?;
+			}
+			self.timing_function.to_css(dest)?;
+			afterFirst = true;
+		}
+		
+		if self.delay != initial.delay
+		{
+			dest.write_char(' ')?;
+			self.delay.to_css(dest)?;
+		}
+		
+		if !afterFirst
+		{
+			self.property.to_css(dest)?;
+		}
+		Ok(())
+	}
+}
+
+impl SingleTransition
+{
+	/// Whether the duration or delay uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		self.duration.is_var() || self.delay.is_var()
+	}
+	
+	pub(crate) fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut property = None;
+		let mut duration = None;
+		let mut timing_function = None;
+		let mut delay = None;
+		
+		loop
+		{
+			if duration.is_none()
+			{
+				if let Ok(value) = input.try(|input| TimeUnit::parse_one_outside_calc_function(context, input))
+				{
+					duration = Some(value);
+					continue;
+				}
+			}
+			else if delay.is_none()
+			{
+				if let Ok(value) = input.try(|input| TimeUnit::parse_one_outside_calc_function(context, input))
+				{
+					delay = Some(value);
+					continue;
+				}
+			}
+			
+			if timing_function.is_none()
+			{
+				if let Ok(value) = input.try(TimingFunction::parse)
+				{
+					timing_function = Some(value);
+					continue;
+				}
+			}
+			
+			if property.is_none()
+			{
+				if let Ok(value) = input.try(TransitionProperty::parse)
+				{
+					property = Some(value);
+					continue;
+				}
+			}
+			
+			break;
+		}
+		
+		if property.is_none() && duration.is_none() && timing_function.is_none()
+		{
+			return Err(ParseError::Custom(CustomParseError::EmptyTransitionOrAnimation));
+		}
+		
+		let initial = Self::default();
+		Ok
+		(
+			Self
+			{
+				property: property.unwrap_or(initial.property),
+				duration: duration.unwrap_or(initial.duration),
+				timing_function: timing_function.unwrap_or(initial.timing_function),
+				delay: delay.unwrap_or(initial.delay),
+			}
+		)
+	}
+}
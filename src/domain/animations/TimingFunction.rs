@@ -0,0 +1,502 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A timing (easing) function, as used by `transition-timing-function` and `animation-timing-function`: https://drafts.csswg.org/css-easing-2/#easing-functions
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimingFunction
+{
+	/// `linear`.
+	linear,
+	
+	/// `ease`; the same as `cubic-bezier(0.25, 0.1, 0.25, 1)`.
+	ease,
+	
+	/// `ease-in`; the same as `cubic-bezier(0.42, 0, 1, 1)`.
+	ease_in,
+	
+	/// `ease-out`; the same as `cubic-bezier(0, 0, 0.58, 1)`.
+	ease_out,
+	
+	/// `ease-in-out`; the same as `cubic-bezier(0.42, 0, 0.58, 1)`.
+	ease_in_out,
+	
+	/// `cubic-bezier(x1, y1, x2, y2)`; `x1` and `x2` are between zero and one inclusive.
+	cubic_bezier(CssSignedNumber, CssSignedNumber, CssSignedNumber, CssSignedNumber),
+	
+	/// `step-start`; the same as `steps(1, start)`.
+	step_start,
+	
+	/// `step-end`; the same as `steps(1, end)`.
+	step_end,
+	
+	/// `steps(count, position)`; `count` is at least one, or at least two for `jump-none`.
+	steps(CssUnsignedInteger, StepPosition),
+	
+	/// `linear(stops)`; there are always at least two stops.
+	linear_function(Vec<LinearStop>),
+}
+
+impl Default for TimingFunction
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		TimingFunction::ease
+	}
+}
+
+impl ToCss for TimingFunction
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::TimingFunction::*;
+		
+		match *self
+		{
+			linear => dest.write_str("linear"),
+			
+			ease => dest.write_str("ease"),
+			
+			ease_in => dest.write_str("ease-in"),
+			
+			ease_out => dest.write_str("ease-out"),
+			
+			ease_in_out => dest.write_str("ease-in-out"),
+			
+			cubic_bezier(ref x1, ref y1, ref x2, ref y2) =>
+			{
+				dest.write_str("cubic-bezier(")?;
+				x1.to_css(dest)?;
+				dest.write_char(',')?;
+				y1.to_css(dest)?;
+				dest.write_char(',')?;
+				x2.to_css(dest)?;
+				dest.write_char(',')?;
+				y2.to_css(dest)?;
+				dest.write_char(')')
+			}
+			
+			step_start => dest.write_str("step-start"),
+			
+			step_end => dest.write_str("step-end"),
+			
+			steps(ref count, position) =>
+			{
+				dest.write_str("steps(")?;
+				count.to_css(dest)?;
+				match position
+				{
+					StepPosition::end | StepPosition::jump_end => (),
+					_ =>
+					{
+						dest.write_char(',')?;
+						position.to_css(dest)?;
+					}
+				}
+				dest.write_char(')')
+			}
+			
+			linear_function(ref stops) =>
+			{
+				dest.write_str("linear(")?;
+				let mut afterFirst = false;
+				for stop in stops.iter()
+				{
+					if afterFirst
+					{
+						dest.write_char(',')?;
+					}
+					else
+					{
+						afterFirst = true;
+					}
+					stop.to_css(dest)?;
+				}
+				dest.write_char(')')
+			}
+		}
+	}
+}
+
+impl TimingFunction
+{
+	/// The output progress value for an input progress value, `progress`, which is usually between zero and one; the output may be outside this range, eg for `cubic-bezier(0.5, -0.5, 0.5, 1.5)`.
+	///
+	/// The before flag of step timing functions is taken to be unset, ie `progress` is not in the before phase of an animation.
+	pub fn evaluate(&self, progress: f32) -> f32
+	{
+		use self::TimingFunction::*;
+		
+		match *self
+		{
+			linear => progress,
+			
+			ease => Self::evaluate_cubic_bezier(0.25, 0.1, 0.25, 1.0, progress),
+			
+			ease_in => Self::evaluate_cubic_bezier(0.42, 0.0, 1.0, 1.0, progress),
+			
+			ease_out => Self::evaluate_cubic_bezier(0.0, 0.0, 0.58, 1.0, progress),
+			
+			ease_in_out => Self::evaluate_cubic_bezier(0.42, 0.0, 0.58, 1.0, progress),
+			
+			cubic_bezier(x1, y1, x2, y2) => Self::evaluate_cubic_bezier(x1.as_f32(), y1.as_f32(), x2.as_f32(), y2.as_f32(), progress),
+			
+			step_start => Self::evaluate_steps(1, StepPosition::start, progress),
+			
+			step_end => Self::evaluate_steps(1, StepPosition::end, progress),
+			
+			steps(count, position) => Self::evaluate_steps(count.as_u32(), position, progress),
+			
+			linear_function(ref stops) => Self::evaluate_linear_function(stops, progress),
+		}
+	}
+	
+	/// Samples this timing function at `numberOfSamples` evenly spaced input progress values from zero to one inclusive, eg to pre-compute an animation curve.
+	pub fn sample(&self, numberOfSamples: usize) -> Vec<f32>
+	{
+		match numberOfSamples
+		{
+			0 => vec![],
+			1 => vec![self.evaluate(0.0)],
+			_ =>
+			{
+				let lastIndex = (numberOfSamples - 1) as f32;
+				(0..numberOfSamples).map(|index| self.evaluate(index as f32 / lastIndex)).collect()
+			}
+		}
+	}
+	
+	pub(crate) fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::TimingFunction::*;
+		
+		if let Ok(ident) = input.try(|input| input.expect_ident_cloned())
+		{
+			return match_ignore_ascii_case!
+			{
+				&*ident,
+				
+				"linear" => Ok(linear),
+				
+				"ease" => Ok(ease),
+				
+				"ease-in" => Ok(ease_in),
+				
+				"ease-out" => Ok(ease_out),
+				
+				"ease-in-out" => Ok(ease_in_out),
+				
+				"step-start" => Ok(step_start),
+				
+				"step-end" => Ok(step_end),
+				
+				_ => Err(ParseError::Basic(BasicParseError::UnexpectedToken(Token::Ident(ident.clone())))),
+			};
+		}
+		
+		let name = input.expect_function()?.clone();
+		input.parse_nested_block(|input|
+		{
+			match_ignore_ascii_case!
+			{
+				&*name,
+				
+				"cubic-bezier" =>
+				{
+					let mut values = [CssSignedNumber::Zero; 4];
+					for (index, value) in values.iter_mut().enumerate()
+					{
+						if index != 0
+						{
+							input.expect_comma()?;
+						}
+						*value = CssSignedNumber::parseNumber(input.expect_number()?, None)?;
+					}
+					
+					let isBetweenZeroAndOne = |value: CssSignedNumber| value >= CssSignedNumber::Zero && value <= CssSignedNumber::One;
+					if !isBetweenZeroAndOne(values[0]) || !isBetweenZeroAndOne(values[2])
+					{
+						return Err(ParseError::Custom(CustomParseError::CubicBezierXMustBeBetweenZeroAndOne));
+					}
+					
+					Ok(cubic_bezier(values[0], values[1], values[2], values[3]))
+				}
+				
+				"steps" =>
+				{
+					let count = input.expect_integer()?;
+					let position = if input.try(|input| input.expect_comma()).is_ok()
+					{
+						StepPosition::parse(input)?
+					}
+					else
+					{
+						StepPosition::end
+					};
+					
+					let minimumCount = if position == StepPosition::jump_none
+					{
+						2
+					}
+					else
+					{
+						1
+					};
+					if count < minimumCount
+					{
+						return Err(ParseError::Custom(CustomParseError::StepsCountIsTooSmall(count)));
+					}
+					
+					Ok(steps(CssUnsignedInteger::from(count as u32), position))
+				}
+				
+				"linear" =>
+				{
+					let stops = input.parse_comma_separated(LinearStop::parse)?;
+					if stops.len() < 2
+					{
+						return Err(ParseError::Custom(CustomParseError::LinearTimingFunctionMustHaveAtLeastTwoStops));
+					}
+					Ok(linear_function(stops))
+				}
+				
+				_ => Err(ParseError::Custom(CustomParseError::UnknownTimingFunction(name.clone()))),
+			}
+		})
+	}
+	
+	/// See https://drafts.csswg.org/css-easing-2/#cubic-bezier-algo; input progress values outside of zero to one are extrapolated along the tangents at the end points.
+	fn evaluate_cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, progress: f32) -> f32
+	{
+		if progress < 0.0
+		{
+			let gradient = if x1 > 0.0
+			{
+				y1 / x1
+			}
+			else if y1 == 0.0 && x2 > 0.0
+			{
+				y2 / x2
+			}
+			else
+			{
+				0.0
+			};
+			return gradient * progress;
+		}
+		
+		if progress > 1.0
+		{
+			let gradient = if x2 < 1.0
+			{
+				(y2 - 1.0) / (x2 - 1.0)
+			}
+			else if y2 == 1.0 && x1 < 1.0
+			{
+				(y1 - 1.0) / (x1 - 1.0)
+			}
+			else
+			{
+				0.0
+			};
+			return 1.0 + gradient * (progress - 1.0);
+		}
+		
+		// The curve's polynomial coefficients; the end points are (0, 0) and (1, 1).
+		let (cx, cy) = (3.0 * x1, 3.0 * y1);
+		let (bx, by) = (3.0 * (x2 - x1) - cx, 3.0 * (y2 - y1) - cy);
+		let (ax, ay) = (1.0 - cx - bx, 1.0 - cy - by);
+		
+		let curveX = |t: f32| ((ax * t + bx) * t + cx) * t;
+		let curveY = |t: f32| ((ay * t + by) * t + cy) * t;
+		let derivativeX = |t: f32| (3.0 * ax * t + 2.0 * bx) * t + cx;
+		
+		const Epsilon: f32 = 1e-6;
+		
+		// Newton-Raphson iteration usually converges quickly...
+		let mut t = progress;
+		for _ in 0..8
+		{
+			let error = curveX(t) - progress;
+			if error.abs() < Epsilon
+			{
+				return curveY(t);
+			}
+			let derivative = derivativeX(t);
+			if derivative.abs() < Epsilon
+			{
+				break;
+			}
+			t -= error / derivative;
+		}
+		
+		// ... but bisection always does, as `curveX` is monotonic for `x1` and `x2` between zero and one.
+		let (mut lower, mut upper) = (0.0, 1.0);
+		t = progress;
+		while lower < upper
+		{
+			let x = curveX(t);
+			if (x - progress).abs() < Epsilon
+			{
+				break;
+			}
+			if progress > x
+			{
+				lower = t;
+			}
+			else
+			{
+				upper = t;
+			}
+			let midpoint = (upper - lower) / 2.0 + lower;
+			if midpoint == t
+			{
+				break;
+			}
+			t = midpoint;
+		}
+		curveY(t)
+	}
+	
+	/// See https://drafts.csswg.org/css-easing-2/#step-easing-algo
+	fn evaluate_steps(count: u32, position: StepPosition, progress: f32) -> f32
+	{
+		use self::StepPosition::*;
+		
+		let count = count as f32;
+		let mut currentStep = (progress * count).floor();
+		
+		if let jump_start | start | jump_both = position
+		{
+			currentStep += 1.0;
+		}
+		
+		if progress >= 0.0 && currentStep < 0.0
+		{
+			currentStep = 0.0;
+		}
+		
+		let jumps = match position
+		{
+			jump_none => count - 1.0,
+			jump_both => count + 1.0,
+			_ => count,
+		};
+		
+		if progress <= 1.0 && currentStep > jumps
+		{
+			currentStep = jumps;
+		}
+		
+		currentStep / jumps
+	}
+	
+	/// See https://drafts.csswg.org/css-easing-2/#linear-easing-function-output
+	fn evaluate_linear_function(stops: &[LinearStop], progress: f32) -> f32
+	{
+		let points = Self::linear_function_control_points(stops);
+		
+		let first = points[0];
+		let last = points[points.len() - 1];
+		
+		// Extrapolate before the first point and after the last one, along the first and last segments respectively.
+		let (start, end) = if progress < first.0
+		{
+			(first, points[1])
+		}
+		else if progress >= last.0
+		{
+			(points[points.len() - 2], last)
+		}
+		else
+		{
+			let index = points.iter().rposition(|point| point.0 <= progress).unwrap();
+			(points[index], points[index + 1])
+		};
+		
+		let inputRange = end.0 - start.0;
+		if inputRange == 0.0
+		{
+			return if progress < first.0
+			{
+				start.1
+			}
+			else
+			{
+				end.1
+			};
+		}
+		start.1 + (progress - start.0) / inputRange * (end.1 - start.1)
+	}
+	
+	/// `(input, output)` control points with all of their inputs filled in and in ascending order, as https://drafts.csswg.org/css-easing-2/#linear-canonicalization
+	fn linear_function_control_points(stops: &[LinearStop]) -> Vec<(f32, f32)>
+	{
+		let mut points: Vec<(Option<f32>, f32)> = Vec::with_capacity(stops.len() * 2);
+		for stop in stops.iter()
+		{
+			let output = stop.output.as_f32();
+			points.push((stop.input.map(|input| input.0.as_f32()), output));
+			if let Some(second_input) = stop.second_input
+			{
+				points.push((Some(second_input.0.as_f32()), output));
+			}
+		}
+		
+		let lastIndex = points.len() - 1;
+		if points[0].0.is_none()
+		{
+			points[0].0 = Some(0.0);
+		}
+		if points[lastIndex].0.is_none()
+		{
+			points[lastIndex].0 = Some(1.0);
+		}
+		
+		let mut largestInput = ::std::f32::NEG_INFINITY;
+		for point in points.iter_mut()
+		{
+			if let Some(input) = point.0
+			{
+				if input < largestInput
+				{
+					point.0 = Some(largestInput);
+				}
+				else
+				{
+					largestInput = input;
+				}
+			}
+		}
+		
+		// Runs of points without inputs are spaced evenly between the points either side of them.
+		let mut index = 1;
+		while index < lastIndex
+		{
+			if points[index].0.is_some()
+			{
+				index += 1;
+				continue;
+			}
+			
+			let runStart = index - 1;
+			let mut runEnd = index;
+			while points[runEnd].0.is_none()
+			{
+				runEnd += 1;
+			}
+			
+			let startInput = points[runStart].0.unwrap();
+			let endInput = points[runEnd].0.unwrap();
+			let divisions = (runEnd - runStart) as f32;
+			for (offset, point) in points[index..runEnd].iter_mut().enumerate()
+			{
+				point.0 = Some(startInput + (endInput - startInput) * (offset + 1) as f32 / divisions);
+			}
+			index = runEnd;
+		}
+		
+		points.into_iter().map(|(input, output)| (input.unwrap(), output)).collect()
+	}
+}
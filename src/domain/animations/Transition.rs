@@ -0,0 +1,136 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The `transition` shorthand property: https://drafts.csswg.org/css-transitions-1/#transition-shorthand-property
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transition(pub Vec<SingleTransition>);
+
+impl Default for Transition
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Transition(vec![SingleTransition::default()])
+	}
+}
+
+impl ToCss for Transition
+{
+	#[inline(always)]
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		AnimationList::write_list(&self.0, dest)
+	}
+}
+
+impl PropertyValue for Transition
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"transition",
+	];
+	
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let transitions = input.parse_comma_separated(|input| SingleTransition::parse(context, input))?;
+		if transitions.len() > 1 && transitions.iter().any(|transition| transition.property == TransitionProperty::none)
+		{
+			return Err(ParseError::Custom(CustomParseError::TransitionPropertyNoneMustBeTheOnlyValue));
+		}
+		Ok(Transition(transitions))
+	}
+}
+
+impl Shorthand for Transition
+{
+	#[inline(always)]
+	fn longhand_property_names(_shorthand_property_name: &str) -> &'static [&'static str]
+	{
+		Self::LonghandPropertyNames
+	}
+	
+	#[inline(always)]
+	fn is_related_property(_shorthand_property_name: &str, property_name: &str) -> bool
+	{
+		const Prefix: &'static str = "transition";
+		
+		property_name.len() >= Prefix.len() && property_name.is_char_boundary(Prefix.len()) && property_name[..Prefix.len()].eq_ignore_ascii_case(Prefix)
+	}
+	
+	fn expand(&self) -> Option<Vec<SpecifiedValue>>
+	{
+		if self.is_var()
+		{
+			return None;
+		}
+		
+		let transitions = &self.0;
+		Some
+		(
+			vec!
+			[
+				SpecifiedValue::from_typed_value(&AnimationList(transitions.iter().map(|transition| transition.property.clone()).collect())).ok()?,
+				SpecifiedValue::from_typed_value(&AnimationList(transitions.iter().map(|transition| transition.duration.clone()).collect())).ok()?,
+				SpecifiedValue::from_typed_value(&AnimationList(transitions.iter().map(|transition| transition.timing_function.clone()).collect())).ok()?,
+				SpecifiedValue::from_typed_value(&AnimationList(transitions.iter().map(|transition| transition.delay.clone()).collect())).ok()?,
+			]
+		)
+	}
+	
+	/// Only folds longhand properties which all have one value for each name in `transition-property`.
+	fn fold(longhands: &[&SpecifiedValue]) -> Option<Self>
+	{
+		if longhands.len() != Self::LonghandPropertyNames.len()
+		{
+			return None;
+		}
+		
+		let properties = AnimationList::<TransitionProperty>::parse_property_value_from_css(longhands[0].original_css()).ok()?.0;
+		let durations = AnimationList::<CalculablePropertyValue<TimeUnit<CssUnsignedNumber>>>::parse_property_value_from_css(longhands[1].original_css()).ok()?.0;
+		let timingFunctions = AnimationList::<TimingFunction>::parse_property_value_from_css(longhands[2].original_css()).ok()?.0;
+		let delays = AnimationList::<CalculablePropertyValue<TimeUnit<CssSignedNumber>>>::parse_property_value_from_css(longhands[3].original_css()).ok()?.0;
+		
+		let numberOfTransitions = properties.len();
+		if [durations.len(), timingFunctions.len(), delays.len()].iter().any(|length| *length != numberOfTransitions)
+		{
+			return None;
+		}
+		
+		let mut transitions = Vec::with_capacity(numberOfTransitions);
+		let mut iterator = properties.into_iter().zip(durations).zip(timingFunctions).zip(delays);
+		while let Some((((property, duration), timing_function), delay)) = iterator.next()
+		{
+			transitions.push(SingleTransition { property, duration, timing_function, delay });
+		}
+		
+		let transition = Transition(transitions);
+		if transition.is_var()
+		{
+			None
+		}
+		else
+		{
+			Some(transition)
+		}
+	}
+}
+
+impl Transition
+{
+	/// The longhand properties set by the `transition` shorthand property.
+	pub const LonghandPropertyNames: &'static [&'static str] = &
+	[
+		"transition-property",
+		"transition-duration",
+		"transition-timing-function",
+		"transition-delay",
+	];
+	
+	/// Whether any transition uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		self.0.iter().any(SingleTransition::is_var)
+	}
+}
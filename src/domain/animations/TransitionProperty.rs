@@ -0,0 +1,63 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// One value of `transition-property`: https://drafts.csswg.org/css-transitions-1/#transition-property-property
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum TransitionProperty
+{
+	/// `none`; only valid as the sole value.
+	none,
+	
+	/// `all`.
+	all,
+	
+	/// A property name; unknown property names are permitted.
+	property(CustomIdent),
+}
+
+impl Default for TransitionProperty
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		TransitionProperty::all
+	}
+}
+
+impl ToCss for TransitionProperty
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::TransitionProperty::*;
+		
+		match *self
+		{
+			none => dest.write_str("none"),
+			
+			all => dest.write_str("all"),
+			
+			property(ref name) => name.to_css(dest),
+		}
+	}
+}
+
+impl TransitionProperty
+{
+	pub(crate) fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::TransitionProperty::*;
+		
+		let ident = input.expect_ident_cloned()?;
+		match_ignore_ascii_case!
+		{
+			&*ident,
+			
+			"none" => Ok(none),
+			
+			"all" => Ok(all),
+			
+			_ => CustomIdent::from_ident(&ident, &[]).map(property),
+		}
+	}
+}
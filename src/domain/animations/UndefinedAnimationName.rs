@@ -0,0 +1,14 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// An `animation-name` (or a name in the `animation` shorthand property) for which there is no `@keyframes` rule; see `Stylesheet::undefined_animation_names()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UndefinedAnimationName
+{
+	/// The name.
+	pub name: KeyframesName,
+	
+	/// Where the property declaration using the name was in the source CSS, if source spans were recorded when parsing.
+	pub source_span: Option<SourceSpan>,
+}
@@ -0,0 +1,31 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+use super::atRules::keyframes::KeyframesName;
+use super::properties::PropertyValue;
+use super::properties::Shorthand;
+use super::properties::SpecifiedValue;
+
+
+#[cfg(test)]
+mod tests;
+
+
+include!("Animation.rs");
+include!("AnimationDirection.rs");
+include!("AnimationFillMode.rs");
+include!("AnimationIterationCount.rs");
+include!("AnimationList.rs");
+include!("AnimationName.rs");
+include!("AnimationPlayState.rs");
+include!("LinearStop.rs");
+include!("SingleAnimation.rs");
+include!("SingleTransition.rs");
+include!("StepPosition.rs");
+include!("TimingFunction.rs");
+include!("Transition.rs");
+include!("TransitionProperty.rs");
+include!("undefined_animation_names.rs");
+include!("UndefinedAnimationName.rs");
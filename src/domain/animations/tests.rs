@@ -0,0 +1,64 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+
+
+fn timing_function(css: &str) -> TimingFunction
+{
+	let mut timingFunctions = AnimationList::<TimingFunction>::parse_property_value_from_css(css).unwrap();
+	assert_eq!(timingFunctions.0.len(), 1);
+	timingFunctions.0.remove(0)
+}
+
+fn assert_samples_near(css: &str, expected: &[f32])
+{
+	let samples = timing_function(css).sample(expected.len());
+	for (sample, expected) in samples.iter().zip(expected.iter())
+	{
+		assert!((sample - expected).abs() < 1e-4, "{}: {:?} is not near {:?}", css, samples, expected);
+	}
+}
+
+#[test]
+fn cubic_bezier_timing_functions_are_sampled()
+{
+	assert_samples_near("linear", &[0.0, 0.25, 0.5, 0.75, 1.0]);
+	assert_samples_near("cubic-bezier(0, 0, 1, 1)", &[0.0, 0.25, 0.5, 0.75, 1.0]);
+	assert_samples_near("ease", &[0.0, 0.8024, 1.0]);
+	assert_samples_near("ease-in-out", &[0.0, 0.5, 1.0]);
+	assert_samples_near("ease-in", &[0.0, 0.3153, 1.0]);
+	assert_samples_near("ease-out", &[0.0, 0.6847, 1.0]);
+	
+	let overshooting = timing_function("cubic-bezier(0.5, -0.5, 0.5, 1.5)");
+	assert!(overshooting.evaluate(0.1) < 0.0);
+	assert!(overshooting.evaluate(0.9) > 1.0);
+}
+
+#[test]
+fn step_timing_functions_are_sampled()
+{
+	assert_samples_near("steps(4)", &[0.0, 0.25, 0.5, 0.75, 1.0]);
+	assert_samples_near("steps(4, jump-end)", &[0.0, 0.25, 0.5, 0.75, 1.0]);
+	assert_samples_near("steps(4, start)", &[0.25, 0.5, 0.75, 1.0, 1.0]);
+	assert_samples_near("steps(4, jump-none)", &[0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0]);
+	assert_samples_near("steps(4, jump-both)", &[0.2, 0.4, 0.6, 0.8, 1.0]);
+	assert_samples_near("step-start", &[1.0, 1.0, 1.0]);
+	assert_samples_near("step-end", &[0.0, 0.0, 1.0]);
+	
+	assert_eq!(timing_function("steps(4)").evaluate(0.3), 0.25);
+}
+
+#[test]
+fn linear_timing_functions_are_sampled()
+{
+	assert_samples_near("linear(0, 0.5, 1)", &[0.0, 0.25, 0.5, 0.75, 1.0]);
+	assert_samples_near("linear(0, 0.25 75%, 1)", &[0.0, 1.0 / 12.0, 1.0 / 6.0, 0.25, 1.0]);
+	assert_samples_near("linear(1, 0)", &[1.0, 0.5, 0.0]);
+	
+	let function = timing_function("linear(0, 0.25 75%, 1)");
+	assert!((function.evaluate(0.875) - 0.625).abs() < 1e-4);
+	assert!((function.evaluate(1.5) - 2.5).abs() < 1e-4);
+	assert!((function.evaluate(-0.75) - -0.25).abs() < 1e-4);
+}
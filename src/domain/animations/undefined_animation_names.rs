@@ -0,0 +1,56 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Finds the names used by `animation-name` and `animation` property declarations in style rules, including those nested in `@media`, `@supports` and `@document` rules, for which there is no `@keyframes` rule (vendor-prefixed or otherwise) anywhere in `cssRules`.
+/// Names in values which can not be parsed or use `var()` are not checked.
+pub(crate) fn undefined_animation_names(cssRules: &CssRules) -> Vec<UndefinedAnimationName>
+{
+	fn collect<'a>(cssRules: &'a CssRules, keyframesNames: &mut HashSet<&'a KeyframesName>, usedNames: &mut Vec<UndefinedAnimationName>)
+	{
+		use self::CssRule::*;
+		
+		for cssRule in cssRules.0.iter()
+		{
+			match *cssRule
+			{
+				Keyframes(ref rule) =>
+				{
+					keyframesNames.insert(&rule.name);
+				}
+				
+				Style(ref rule) => for propertyDeclaration in rule.property_declarations.0.iter()
+				{
+					let mut used = |animationName: &AnimationName| if let Some(keyframesName) = animationName.keyframes_name()
+					{
+						usedNames.push(UndefinedAnimationName { name: keyframesName.clone(), source_span: propertyDeclaration.source_span });
+					};
+					
+					if let Some(Ok(animationNames)) = propertyDeclaration.typed_value::<AnimationList<AnimationName>>()
+					{
+						animationNames.0.iter().for_each(&mut used);
+					}
+					else if let Some(Ok(animation)) = propertyDeclaration.typed_value::<Animation>()
+					{
+						animation.0.iter().map(|singleAnimation| &singleAnimation.name).for_each(&mut used);
+					}
+				},
+				
+				Document(ref rule) => collect(&rule.rules, keyframesNames, usedNames),
+				
+				Media(ref rule) => collect(&rule.rules, keyframesNames, usedNames),
+				
+				Supports(ref rule) => collect(&rule.rules, keyframesNames, usedNames),
+				
+				_ => (),
+			}
+		}
+	}
+	
+	let mut keyframesNames = HashSet::new();
+	let mut usedNames = Vec::new();
+	collect(cssRules, &mut keyframesNames, &mut usedNames);
+	
+	usedNames.retain(|usedName| !keyframesNames.contains(&usedName.name));
+	usedNames
+}
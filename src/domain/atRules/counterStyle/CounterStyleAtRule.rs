@@ -37,14 +37,33 @@ pub struct CounterStyleAtRule
 	
 	/// https://drafts.csswg.org/css-counter-styles/#counter-style-speak-as
 	pub speak_as: Option<SpeakAs>,
+	
+	/// Where each descriptor was in the source CSS, keyed by the descriptor's name (eg `symbols`), if source spans were recorded when parsing.
+	pub descriptor_source_spans: BTreeMap<&'static str, SourceSpan>,
+	
+	/// Where this rule was in the source CSS, if source spans were recorded when parsing.
+	pub source_span: Option<SourceSpan>,
+	
+	/// Where this rule's prelude (everything between the at-keyword and the block or semicolon) was in the source CSS, if source spans were recorded when parsing.
+	pub prelude_source_span: Option<SourceSpan>,
 }
 
 impl ToCss for CounterStyleAtRule
 {
+	#[inline(always)]
 	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		self.to_css_mapping_descriptors(dest, |_, _| ())
+	}
+}
+
+impl CounterStyleAtRule
+{
+	/// As for `to_css()`, but calls `map` with the source span of each descriptor just before writing it.
+	pub(crate) fn to_css_mapping_descriptors<W: fmt::Write, Map: FnMut(&mut W, Option<SourceSpan>)>(&self, dest: &mut W, mut map: Map) -> fmt::Result
 	{
 		#[inline(always)]
-		fn write<W: fmt::Write, T: ToCss>(afterFirst: &mut bool, dest: &mut W, name: &str, value: &Option<T>) -> fmt::Result
+		fn write<W: fmt::Write, T: ToCss, MapDescriptor: FnMut(&mut W, &'static str)>(afterFirst: &mut bool, dest: &mut W, mapDescriptor: &mut MapDescriptor, name: &'static str, value: &Option<T>) -> fmt::Result
 		{
 			if let &Some(ref value) = value
 			{
@@ -56,6 +75,7 @@ impl ToCss for CounterStyleAtRule
 				{
 					*afterFirst = true;
 				}
+				mapDescriptor(dest, name);
 				dest.write_str(name)?;
 				dest.write_char(':')?;
 				value.to_css(dest)
@@ -66,28 +86,27 @@ impl ToCss for CounterStyleAtRule
 			}
 		}
 		
+		let mut mapDescriptor = |dest: &mut W, name: &'static str| map(dest, self.descriptor_source_spans.get(name).cloned());
+		
 		dest.write_str("@counter-style ")?;
 		self.name.to_css(dest)?;
 		dest.write_char('{')?;
 		let mut afterFirst = false;
-		write(&mut afterFirst, dest, "system", &self.system)?;
-		write(&mut afterFirst, dest, "negative", &self.negative)?;
-		write(&mut afterFirst, dest, "prefix", &self.prefix)?;
-		write(&mut afterFirst, dest, "suffix", &self.suffix)?;
-		write(&mut afterFirst, dest, "range", &self.range)?;
-		write(&mut afterFirst, dest, "pad", &self.pad)?;
-		write(&mut afterFirst, dest, "fallback", &self.fallback)?;
-		write(&mut afterFirst, dest, "symbols", &self.symbols)?;
-		write(&mut afterFirst, dest, "additive-symbols", &self.additive_symbols)?;
-		write(&mut afterFirst, dest, "speak-as", &self.speak_as)?;
+		write(&mut afterFirst, dest, &mut mapDescriptor, "system", &self.system)?;
+		write(&mut afterFirst, dest, &mut mapDescriptor, "negative", &self.negative)?;
+		write(&mut afterFirst, dest, &mut mapDescriptor, "prefix", &self.prefix)?;
+		write(&mut afterFirst, dest, &mut mapDescriptor, "suffix", &self.suffix)?;
+		write(&mut afterFirst, dest, &mut mapDescriptor, "range", &self.range)?;
+		write(&mut afterFirst, dest, &mut mapDescriptor, "pad", &self.pad)?;
+		write(&mut afterFirst, dest, &mut mapDescriptor, "fallback", &self.fallback)?;
+		write(&mut afterFirst, dest, &mut mapDescriptor, "symbols", &self.symbols)?;
+		write(&mut afterFirst, dest, &mut mapDescriptor, "additive-symbols", &self.additive_symbols)?;
+		write(&mut afterFirst, dest, &mut mapDescriptor, "speak-as", &self.speak_as)?;
 		dest.write_char('}')
 	}
-}
-
-impl CounterStyleAtRule
-{
+	
 	#[inline(always)]
-	fn empty(name: CounterStyleIdent) -> Self
+	fn empty(name: CounterStyleIdent, prelude_source_span: Option<SourceSpan>) -> Self
 	{
 		Self
 		{
@@ -102,6 +121,9 @@ impl CounterStyleAtRule
 			symbols: None,
 			additive_symbols: None,
 			speak_as: None,
+			descriptor_source_spans: BTreeMap::new(),
+			source_span: None,
+			prelude_source_span,
 		}
 	}
 	
@@ -239,9 +261,9 @@ impl CounterStyleAtRule
 	}
 	
 	/// Parse the body (inside `{}`) of an @counter-style rule
-	pub(crate) fn parse_body<'i, 't>(name: CounterStyleIdent, context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<CounterStyleAtRule, ParseError<'i, CustomParseError<'i>>>
+	pub(crate) fn parse_body<'i, 't>(name: CounterStyleIdent, prelude_source_span: Option<SourceSpan>, context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<CounterStyleAtRule, ParseError<'i, CustomParseError<'i>>>
 	{
-		let mut rule = CounterStyleAtRule::empty(name);
+		let mut rule = CounterStyleAtRule::empty(name, prelude_source_span);
 		
 		{
 			let parser = CounterStyleAtRuleParser
@@ -250,11 +272,20 @@ impl CounterStyleAtRule
 				rule: &mut rule,
 			};
 			let mut iterator = DeclarationListParser::new(input, parser);
-			while let Some(declaration) = iterator.next()
+			loop
 			{
-				if declaration.is_err()
+				let start = context.source_span_start(iterator.input, true);
+				
+				match iterator.next()
 				{
-					return Err(declaration.unwrap_err().error);
+					None => break,
+					
+					Some(Ok(name)) => if let Some(source_span) = context.source_span(iterator.input, &start)
+					{
+						iterator.parser.rule.descriptor_source_spans.insert(name, source_span);
+					},
+					
+					Some(Err(preciseParseError)) => context.recover_from_error(preciseParseError)?,
 				}
 			}
 		}
@@ -5,6 +5,7 @@
 use super::*;
 use super::parsers::CounterStyleAtRuleParser;
 use ::std::borrow::Cow;
+use ::std::collections::BTreeMap;
 use ::std::fmt;
 use ::std::fmt::Write;
 
@@ -34,14 +34,33 @@ pub struct FontFaceAtRule
 	
 	/// The language override of this font face.
 	pub language_override: Option<FontLanguageOverride>,
+	
+	/// Where each descriptor was in the source CSS, keyed by the descriptor's name (eg `font-family`), if source spans were recorded when parsing.
+	pub descriptor_source_spans: BTreeMap<&'static str, SourceSpan>,
+	
+	/// Where this rule was in the source CSS, if source spans were recorded when parsing.
+	pub source_span: Option<SourceSpan>,
+	
+	/// Where this rule's prelude (everything between the at-keyword and the block or semicolon) was in the source CSS, if source spans were recorded when parsing.
+	pub prelude_source_span: Option<SourceSpan>,
 }
 
 impl ToCss for FontFaceAtRule
 {
+	#[inline(always)]
 	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		self.to_css_mapping_descriptors(dest, |_, _| ())
+	}
+}
+
+impl FontFaceAtRule
+{
+	/// As for `to_css()`, but calls `map` with the source span of each descriptor just before writing it.
+	pub(crate) fn to_css_mapping_descriptors<W: fmt::Write, Map: FnMut(&mut W, Option<SourceSpan>)>(&self, dest: &mut W, mut map: Map) -> fmt::Result
 	{
 		#[inline(always)]
-		fn writePropertyDeclaration<W: fmt::Write, T: ToCss>(afterFirst: &mut bool, dest: &mut W, name: &str, value: &Option<T>) -> fmt::Result
+		fn writePropertyDeclaration<W: fmt::Write, T: ToCss, MapDescriptor: FnMut(&mut W, &'static str)>(afterFirst: &mut bool, dest: &mut W, mapDescriptor: &mut MapDescriptor, name: &'static str, value: &Option<T>) -> fmt::Result
 		{
 			if let &Some(ref value) = value
 			{
@@ -54,6 +73,7 @@ impl ToCss for FontFaceAtRule
 					*afterFirst = true;
 				}
 				
+				mapDescriptor(dest, name);
 				dest.write_str(name)?;
 				dest.write_char(':')?;
 				value.to_css(dest)
@@ -65,7 +85,7 @@ impl ToCss for FontFaceAtRule
 		}
 		
 		#[inline(always)]
-		fn writePropertyDeclarationValues<W: fmt::Write, T: ToCss>(afterFirst: &mut bool, dest: &mut W, name: &str, value: &Option<Vec<T>>) -> fmt::Result
+		fn writePropertyDeclarationValues<W: fmt::Write, T: ToCss, MapDescriptor: FnMut(&mut W, &'static str)>(afterFirst: &mut bool, dest: &mut W, mapDescriptor: &mut MapDescriptor, name: &'static str, value: &Option<Vec<T>>) -> fmt::Result
 		{
 			if let &Some(ref value) = value
 			{
@@ -83,6 +103,7 @@ impl ToCss for FontFaceAtRule
 					*afterFirst = true;
 				}
 				
+				mapDescriptor(dest, name);
 				dest.write_str(name)?;
 				dest.write_char(':')?;
 				
@@ -98,27 +119,26 @@ impl ToCss for FontFaceAtRule
 			Ok(())
 		}
 		
+		let mut mapDescriptor = |dest: &mut W, name: &'static str| map(dest, self.descriptor_source_spans.get(name).cloned());
+		
 		dest.write_str("@font-face{")?;
 		
 		let mut afterFirst = false;
-		writePropertyDeclaration(&mut afterFirst, dest, "font-family", &self.family)?;
-		writePropertyDeclarationValues(&mut afterFirst, dest, "src", &self.sources)?;
-		writePropertyDeclaration(&mut afterFirst, dest, "font-style", &self.style)?;
-		writePropertyDeclaration(&mut afterFirst, dest, "font-weight", &self.weight)?;
-		writePropertyDeclaration(&mut afterFirst, dest, "font-stretch", &self.stretch)?;
-		writePropertyDeclaration(&mut afterFirst, dest, "font-display", &self.display)?;
-		writePropertyDeclarationValues(&mut afterFirst, dest, "unicode-range", &self.unicode_range)?;
-		writePropertyDeclaration(&mut afterFirst, dest, "font-feature-settings", &self.feature_settings)?;
-		writePropertyDeclaration(&mut afterFirst, dest, "font-language-override", &self.language_override)?;
+		writePropertyDeclaration(&mut afterFirst, dest, &mut mapDescriptor, "font-family", &self.family)?;
+		writePropertyDeclarationValues(&mut afterFirst, dest, &mut mapDescriptor, "src", &self.sources)?;
+		writePropertyDeclaration(&mut afterFirst, dest, &mut mapDescriptor, "font-style", &self.style)?;
+		writePropertyDeclaration(&mut afterFirst, dest, &mut mapDescriptor, "font-weight", &self.weight)?;
+		writePropertyDeclaration(&mut afterFirst, dest, &mut mapDescriptor, "font-stretch", &self.stretch)?;
+		writePropertyDeclaration(&mut afterFirst, dest, &mut mapDescriptor, "font-display", &self.display)?;
+		writePropertyDeclarationValues(&mut afterFirst, dest, &mut mapDescriptor, "unicode-range", &self.unicode_range)?;
+		writePropertyDeclaration(&mut afterFirst, dest, &mut mapDescriptor, "font-feature-settings", &self.feature_settings)?;
+		writePropertyDeclaration(&mut afterFirst, dest, &mut mapDescriptor, "font-language-override", &self.language_override)?;
 		
 		dest.write_char('}')
 	}
-}
-
-impl FontFaceAtRule
-{
+	
 	#[inline(always)]
-	fn empty() -> Self
+	fn empty(prelude_source_span: Option<SourceSpan>) -> Self
 	{
 		Self
 		{
@@ -131,13 +151,16 @@ impl FontFaceAtRule
 			unicode_range: None,
 			feature_settings: None,
 			language_override: None,
+			descriptor_source_spans: BTreeMap::new(),
+			source_span: None,
+			prelude_source_span,
 		}
 	}
 
 	/// Parse the block inside a `@font-face` rule.
-	pub(crate) fn parse_body<'i: 't, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<FontFaceAtRule, ParseError<'i, CustomParseError<'i>>>
+	pub(crate) fn parse_body<'i: 't, 't>(prelude_source_span: Option<SourceSpan>, context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<FontFaceAtRule, ParseError<'i, CustomParseError<'i>>>
 	{
-		let mut rule = Self::empty();
+		let mut rule = Self::empty(prelude_source_span);
 		
 		{
 			let parser = FontFaceAtRuleParser
@@ -147,11 +170,20 @@ impl FontFaceAtRule
 			};
 			
 			let mut iter = DeclarationListParser::new(input, parser);
-			while let Some(declaration) = iter.next()
+			loop
 			{
-				if declaration.is_err()
+				let start = context.source_span_start(iter.input, true);
+				
+				match iter.next()
 				{
-					return Err(declaration.unwrap_err().error);
+					None => break,
+					
+					Some(Ok(name)) => if let Some(source_span) = context.source_span(iter.input, &start)
+					{
+						iter.parser.rule.descriptor_source_spans.insert(name, source_span);
+					},
+					
+					Some(Err(preciseParseError)) => context.recover_from_error(preciseParseError)?,
 				}
 			}
 		}
@@ -39,10 +39,8 @@ impl FontFeatureSetting
 				Ok(FontFeatureSetting(openTypeFeatureTag, integer as u32))
 			}
 		}
-		else
+		else if let Ok(ident) = input.try(|input| input.expect_ident_cloned())
 		{
-			let ident = input.expect_ident()?;
-			
 			match_ignore_ascii_case!
 			{
 				&ident,
@@ -54,5 +52,10 @@ impl FontFeatureSetting
 				_ => Err(ParseError::Custom(CustomParseError::FontFeatureSettingIfNotAnIntegerMustBeOnOrOff(ident.clone())))
 			}
 		}
+		else
+		{
+			// The value defaults to 1 (on) if omitted.
+			Ok(FontFeatureSetting(openTypeFeatureTag, 1))
+		}
 	}
 }
@@ -2,7 +2,7 @@
 // Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
 
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct FontFeatureSettings(pub BTreeMap<String, u32>);
 
 impl ToCss for FontFeatureSettings
@@ -15,8 +15,17 @@ impl ToCss for FontFeatureSettings
 		}
 		else
 		{
+			let mut afterFirst = false;
 			for (openTypeFeatureTag, integer) in self.0.iter()
 			{
+				if afterFirst
+				{
+					dest.write_char(',')?;
+				}
+				else
+				{
+					afterFirst = true;
+				}
 				serialize_string(openTypeFeatureTag, dest)?;
 				let integer = *integer;
 				if integer != 1
@@ -50,6 +59,20 @@ impl Parse for FontFeatureSettings
 	}
 }
 
+impl PropertyValue for FontFeatureSettings
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"font-feature-settings",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		<FontFeatureSettings as Parse>::parse(context, input)
+	}
+}
+
 impl FontFeatureSettings
 {
 	#[inline(always)]
@@ -60,3 +60,17 @@ impl FontLanguageOverride
 		}
 	}
 }
+
+impl PropertyValue for FontLanguageOverride
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"font-language-override",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		FontLanguageOverride::parse(input)
+	}
+}
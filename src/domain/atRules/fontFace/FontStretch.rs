@@ -23,3 +23,17 @@ impl Parse for FontStretch
 		FontStretch::parse(input)
 	}
 }
+
+impl PropertyValue for FontStretch
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"font-stretch",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		FontStretch::parse(input)
+	}
+}
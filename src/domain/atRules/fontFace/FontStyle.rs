@@ -17,3 +17,17 @@ impl Parse for FontStyle
 		FontStyle::parse(input)
 	}
 }
+
+impl PropertyValue for FontStyle
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"font-style",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		FontStyle::parse(input)
+	}
+}
@@ -26,15 +26,15 @@ impl ToCss for FontWeight
 		
 		match *self
 		{
-			_100 => serialize_identifier("100", dest),
-			_200 => serialize_identifier("200", dest),
-			_300 => serialize_identifier("300", dest),
-			_400 => serialize_identifier("400", dest),
-			_500 => serialize_identifier("500", dest),
-			_600 => serialize_identifier("600", dest),
-			_700 => serialize_identifier("700", dest),
-			_800 => serialize_identifier("800", dest),
-			_900 => serialize_identifier("900", dest),
+			_100 => dest.write_str("100"),
+			_200 => dest.write_str("200"),
+			_300 => dest.write_str("300"),
+			_400 => dest.write_str("400"),
+			_500 => dest.write_str("500"),
+			_600 => dest.write_str("600"),
+			_700 => dest.write_str("700"),
+			_800 => dest.write_str("800"),
+			_900 => dest.write_str("900"),
 		}
 	}
 }
@@ -30,24 +30,27 @@ pub struct FontFeatureValuesAtRule
 	
 	/// A @styleset block. Specifies a feature name that will work with the character-variant() functional notation of font-variant-alternates. The value can be a list.
 	pub styleset: Vec<FontFeatureValuesDeclaration<VectorValues>>,
+	
+	/// Where this rule was in the source CSS, if source spans were recorded when parsing.
+	pub source_span: Option<SourceSpan>,
+	
+	/// Where this rule's prelude (everything between the at-keyword and the block or semicolon) was in the source CSS, if source spans were recorded when parsing.
+	pub prelude_source_span: Option<SourceSpan>,
 }
 
 impl ToCss for FontFeatureValuesAtRule
 {
+	#[inline(always)]
 	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
 	{
-		dest.write_str("@font-feature-values ")?;
-		self.font_family_to_css(dest)?;
-		dest.write_char('{')?;
-		self.value_to_css(dest)?;
-		dest.write_char('}')
+		self.to_css_mapping_declarations(dest, |_, _| ())
 	}
 }
 
 impl FontFeatureValuesAtRule
 {
 	#[inline(always)]
-	fn new(family_names: Vec<FamilyName>) -> Self
+	fn new(family_names: Vec<FamilyName>, prelude_source_span: Option<SourceSpan>) -> Self
 	{
 		Self
 		{
@@ -58,12 +61,14 @@ impl FontFeatureValuesAtRule
 			annotation: vec![],
 			character_variant: vec![],
 			styleset: vec![],
+			source_span: None,
+			prelude_source_span,
 		}
 	}
 	
-	pub(crate) fn parse_body<'i: 't, 't>(context: &ParserContext, input: &mut Parser<'i, 't>, family_names: Vec<FamilyName>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	pub(crate) fn parse_body<'i: 't, 't>(context: &ParserContext, input: &mut Parser<'i, 't>, family_names: Vec<FamilyName>, prelude_source_span: Option<SourceSpan>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
 	{
-		let mut fontFeatureValuesRule = Self::new(family_names);
+		let mut fontFeatureValuesRule = Self::new(family_names, prelude_source_span);
 		
 		{
 			let mut iterator = RuleListParser::new_for_nested_rule(input, FontFeatureValuesAtRuleParser
@@ -73,9 +78,9 @@ impl FontFeatureValuesAtRule
 			});
 			while let Some(possiblePreciseParseError) = iterator.next()
 			{
-				if possiblePreciseParseError.is_err()
+				if let Err(preciseParseError) = possiblePreciseParseError
 				{
-					return Err(possiblePreciseParseError.unwrap_err().error);
+					context.recover_from_error(preciseParseError)?;
 				}
 			}
 		}
@@ -83,6 +88,16 @@ impl FontFeatureValuesAtRule
 		Ok(fontFeatureValuesRule)
 	}
 	
+	/// As for `to_css()`, but calls `map` with the source span of each declaration just before writing it.
+	pub(crate) fn to_css_mapping_declarations<W: fmt::Write, Map: FnMut(&mut W, Option<SourceSpan>)>(&self, dest: &mut W, mut map: Map) -> fmt::Result
+	{
+		dest.write_str("@font-feature-values ")?;
+		self.font_family_to_css(dest)?;
+		dest.write_char('{')?;
+		self.value_to_css(dest, &mut map)?;
+		dest.write_char('}')
+	}
+	
 	/// Prints font family names.
 	pub(crate) fn font_family_to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
 	{
@@ -97,10 +112,10 @@ impl FontFeatureValuesAtRule
 	}
 	
 	/// Prints inside of `@font-feature-values` block.
-	pub(crate) fn value_to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	pub(crate) fn value_to_css<W: fmt::Write, Map: FnMut(&mut W, Option<SourceSpan>)>(&self, dest: &mut W, map: &mut Map) -> fmt::Result
 	{
 		#[inline(always)]
-		fn writeBlock<W: fmt::Write, T: ToCss>(dest: &mut W, name: &str, block: &Vec<FontFeatureValuesDeclaration<T>>) -> fmt::Result
+		fn writeBlock<W: fmt::Write, T: ToCss, Map: FnMut(&mut W, Option<SourceSpan>)>(dest: &mut W, map: &mut Map, name: &str, block: &Vec<FontFeatureValuesDeclaration<T>>) -> fmt::Result
 		{
 			if block.len() > 0
 			{
@@ -113,9 +128,13 @@ impl FontFeatureValuesAtRule
 				{
 					for index in 0..(length - 1)
 					{
-						(unsafe { block.get_unchecked(index) }).to_css(dest)?;
+						let declaration = unsafe { block.get_unchecked(index) };
+						map(dest, declaration.source_span);
+						declaration.to_css(dest)?;
 					}
-					(unsafe { block.get_unchecked(length - 1) }).to_css_without_trailing_semicolon(dest)?;
+					let declaration = unsafe { block.get_unchecked(length - 1) };
+					map(dest, declaration.source_span);
+					declaration.to_css_without_trailing_semicolon(dest)?;
 				}
 				
 				dest.write_char('}')
@@ -126,12 +145,12 @@ impl FontFeatureValuesAtRule
 			}
 		}
 		
-		writeBlock(dest, "swash", &self.swash)?;
-		writeBlock(dest, "stylistic", &self.stylistic)?;
-		writeBlock(dest, "ornaments", &self.ornaments)?;
-		writeBlock(dest, "annotation", &self.annotation)?;
-		writeBlock(dest, "character-variant", &self.character_variant)?;
-		writeBlock(dest, "styleset", &self.styleset)
+		writeBlock(dest, map, "swash", &self.swash)?;
+		writeBlock(dest, map, "stylistic", &self.stylistic)?;
+		writeBlock(dest, map, "ornaments", &self.ornaments)?;
+		writeBlock(dest, map, "annotation", &self.annotation)?;
+		writeBlock(dest, map, "character-variant", &self.character_variant)?;
+		writeBlock(dest, map, "styleset", &self.styleset)
 	}
 	
 	/// Returns length of all at-rules.
@@ -8,7 +8,9 @@
 /// - `SingleValue` is to keep just one unsigned integer value.
 /// - `PairValues` is to keep one or two unsigned integer values.
 /// - `VectorValues` is to keep a list of unsigned integer values.
-#[derive(Clone, Debug, PartialEq)]
+///
+/// Where the declaration was in the source CSS is not compared.
+#[derive(Clone, Debug)]
 pub struct FontFeatureValuesDeclaration<T: ToCss>
 {
 	/// An `<ident>` for declaration name.
@@ -16,6 +18,18 @@ pub struct FontFeatureValuesDeclaration<T: ToCss>
 	
 	/// An `<integer>+` for declaration value.
 	pub value: T,
+	
+	/// Where this declaration was in the source CSS, if source spans were recorded when parsing.
+	pub source_span: Option<SourceSpan>,
+}
+
+impl<T: ToCss + PartialEq> PartialEq for FontFeatureValuesDeclaration<T>
+{
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool
+	{
+		self.name == other.name && self.value == other.value
+	}
 }
 
 impl<T: ToCss> ToCss for FontFeatureValuesDeclaration<T>
@@ -11,6 +11,12 @@ pub struct Keyframe
 	
 	/// The declaration block that was declared inside this keyframe.
 	pub property_declarations: PropertyDeclarations<DoesNotHaveImportance>,
+	
+	/// Any comments after the last property declaration; only present if comments were preserved when parsing.
+	pub comments_after_property_declarations: Vec<CssComment>,
+	
+	/// Where this keyframe was in the source CSS, if source spans were recorded when parsing.
+	pub source_span: Option<SourceSpan>,
 }
 
 impl ToCss for Keyframe
@@ -19,7 +25,7 @@ impl ToCss for Keyframe
 	{
 		self.selector.to_css(dest)?;
 		dest.write_char('{')?;
-		self.property_declarations.to_css(dest)?;
+		self.property_declarations.to_css_followed_by_comments(&self.comments_after_property_declarations, dest)?;
 		dest.write_char('}')?;
 		Ok(())
 	}
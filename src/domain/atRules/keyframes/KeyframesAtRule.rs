@@ -15,6 +15,12 @@ pub struct KeyframesAtRule
 	
 	/// The keyframes specified for this CSS rule.
 	pub keyframes: Vec<Keyframe>,
+	
+	/// Where this rule was in the source CSS, if source spans were recorded when parsing.
+	pub source_span: Option<SourceSpan>,
+	
+	/// Where this rule's prelude (everything between the at-keyword and the block or semicolon) was in the source CSS, if source spans were recorded when parsing.
+	pub prelude_source_span: Option<SourceSpan>,
 }
 
 impl ToCss for KeyframesAtRule
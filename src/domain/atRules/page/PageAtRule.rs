@@ -5,13 +5,65 @@
 /// A [`@page`][page] rule.
 /// [page]: https://drafts.csswg.org/css2/page.html#page-box
 /// [page-selectors]: https://drafts.csswg.org/css2/page.html#page-selectors
-#[derive(Default, Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+///
+/// Only the page selector and property declarations are compared and hashed; comments and source spans are not.
+#[derive(Default, Debug, Clone)]
 pub struct PageAtRule
 {
 	pub page_selector_pseudo_class: Option<PageSelectorPseudoClass>,
 	
 	/// The declaration block this page rule contains.
 	pub property_declarations: PropertyDeclarations<Importance>,
+	
+	/// Any comments after the last property declaration; only present if comments were preserved when parsing.
+	pub comments_after_property_declarations: Vec<CssComment>,
+	
+	/// Where this rule was in the source CSS, if source spans were recorded when parsing.
+	pub source_span: Option<SourceSpan>,
+	
+	/// Where this rule's prelude (everything between the at-keyword and the block or semicolon) was in the source CSS, if source spans were recorded when parsing.
+	pub prelude_source_span: Option<SourceSpan>,
+}
+
+impl PartialEq for PageAtRule
+{
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool
+	{
+		self.page_selector_pseudo_class == other.page_selector_pseudo_class && self.property_declarations == other.property_declarations
+	}
+}
+
+impl Eq for PageAtRule
+{
+}
+
+impl PartialOrd for PageAtRule
+{
+	#[inline(always)]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for PageAtRule
+{
+	#[inline(always)]
+	fn cmp(&self, other: &Self) -> Ordering
+	{
+		(&self.page_selector_pseudo_class, &self.property_declarations).cmp(&(&other.page_selector_pseudo_class, &other.property_declarations))
+	}
+}
+
+impl Hash for PageAtRule
+{
+	#[inline(always)]
+	fn hash<H: Hasher>(&self, state: &mut H)
+	{
+		self.page_selector_pseudo_class.hash(state);
+		self.property_declarations.hash(state)
+	}
 }
 
 impl ToCss for PageAtRule
@@ -25,7 +77,7 @@ impl ToCss for PageAtRule
 			page_selector_pseudo_class.to_css(dest)?;
 		}
 		dest.write_char('{')?;
-		self.property_declarations.to_css(dest)?;
+		self.property_declarations.to_css_followed_by_comments(&self.comments_after_property_declarations, dest)?;
 		dest.write_char('}')
 	}
 }
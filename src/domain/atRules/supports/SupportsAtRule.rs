@@ -13,6 +13,12 @@ pub struct SupportsAtRule
 	
 	/// Child rules
 	pub rules: CssRules,
+	
+	/// Where this rule was in the source CSS, if source spans were recorded when parsing.
+	pub source_span: Option<SourceSpan>,
+	
+	/// Where this rule's prelude (everything between the at-keyword and the block or semicolon) was in the source CSS, if source spans were recorded when parsing.
+	pub prelude_source_span: Option<SourceSpan>,
 }
 
 impl HasCssRules for SupportsAtRule
@@ -9,37 +9,53 @@ pub struct ViewportAtRule
 	pub vendor_prefix: Option<VendorPrefix>,
 	
 	/// The declarations contained in this @viewport rule.
-	pub declarations: Vec<ViewportDescriptorDeclaration>
+	pub declarations: Vec<ViewportDescriptorDeclaration>,
+	
+	/// Where each of `declarations` was in the source CSS, if source spans were recorded when parsing; otherwise, empty.
+	pub declaration_source_spans: Vec<SourceSpan>,
+	
+	/// Where this rule was in the source CSS, if source spans were recorded when parsing.
+	pub source_span: Option<SourceSpan>,
+	
+	/// Where this rule's prelude (everything between the at-keyword and the block or semicolon) was in the source CSS, if source spans were recorded when parsing.
+	pub prelude_source_span: Option<SourceSpan>,
 }
 
 impl ViewportAtRule
 {
 	/// Parse a single @viewport rule.
-	pub(crate) fn parse_body<'i, 't>(vendor_prefix: Option<VendorPrefix>, context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	pub(crate) fn parse_body<'i, 't>(vendor_prefix: Option<VendorPrefix>, prelude_source_span: Option<SourceSpan>, context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
 	{
 		let parser = ViewportAtRuleParser { context };
 		
 		let mut declarations = Vec::new();
+		let mut declaration_source_spans = Vec::new();
 		let mut parser = DeclarationListParser::new(input, parser);
-		while let Some(result) = parser.next()
+		loop
 		{
-			match result
+			let start = context.source_span_start(parser.input, true);
+			
+			match parser.next()
 			{
-				Ok(viewportDescriptorDeclaration) =>
+				None => break,
+				
+				Some(Ok(viewportDescriptorDeclaration)) =>
 				{
 					declarations.push(viewportDescriptorDeclaration);
+					if let Some(source_span) = context.source_span(parser.input, &start)
+					{
+						declaration_source_spans.push(source_span);
+					}
 				}
 				
-				Err(preciseParseError) => return Err(preciseParseError.error),
+				Some(Err(preciseParseError)) => context.recover_from_error(preciseParseError)?,
 			}
 		}
-		Ok(Self { vendor_prefix, declarations })
+		Ok(Self { vendor_prefix, declarations, declaration_source_spans, source_span: None, prelude_source_span })
 	}
-}
-
-impl ToCss for ViewportAtRule
-{
-	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	
+	/// As for `to_css()`, but calls `map` with the source span of each declaration just before writing it.
+	pub(crate) fn to_css_mapping_declarations<W: fmt::Write, Map: FnMut(&mut W, Option<SourceSpan>)>(&self, dest: &mut W, mut map: Map) -> fmt::Result
 	{
 		dest.write_char('@')?;
 		if let Some(ref vendorPrefix) = self.vendor_prefix
@@ -53,9 +69,11 @@ impl ToCss for ViewportAtRule
 		{
 			for index in 0..(length - 1)
 			{
+				map(dest, self.declaration_source_spans.get(index).cloned());
 				(unsafe { self.declarations.get_unchecked(index) }).to_css(dest)?;
 			}
 			
+			map(dest, self.declaration_source_spans.get(length - 1).cloned());
 			(unsafe { self.declarations.get_unchecked(length -1) }).to_css_without_trailing_semicolon(dest)?
 		}
 		
@@ -63,6 +81,15 @@ impl ToCss for ViewportAtRule
 	}
 }
 
+impl ToCss for ViewportAtRule
+{
+	#[inline(always)]
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		self.to_css_mapping_declarations(dest, |_, _| ())
+	}
+}
+
 impl HasVendorPrefix for ViewportAtRule
 {
 	#[inline(always)]
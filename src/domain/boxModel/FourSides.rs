@@ -0,0 +1,234 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of a shorthand property, such as `margin`, `padding` or `inset`, which sets the top, right, bottom and left sides of a box from one to four values.
+/// Serializes to the fewest values which represent the same sides.
+/// See https://drafts.csswg.org/css-box-3/#margin-shorthand
+#[derive(Debug, Default, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct FourSides<T>
+{
+	/// The value of the top side.
+	pub top: T,
+	
+	/// The value of the right side.
+	pub right: T,
+	
+	/// The value of the bottom side.
+	pub bottom: T,
+	
+	/// The value of the left side.
+	pub left: T,
+}
+
+impl<T: ToCss + PartialEq> ToCss for FourSides<T>
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		self.top.to_css(dest)?;
+		
+		if self.left == self.right
+		{
+			if self.bottom == self.top
+			{
+				if self.right == self.top
+				{
+					return Ok(());
+				}
+				
+				dest.write_char(' ')?;
+				return self.right.to_css(dest);
+			}
+			
+			dest.write_char(' ')?;
+			self.right.to_css(dest)?;
+			dest.write_char(' ')?;
+			return self.bottom.to_css(dest);
+		}
+		
+		dest.write_char(' ')?;
+		self.right.to_css(dest)?;
+		dest.write_char(' ')?;
+		self.bottom.to_css(dest)?;
+		dest.write_char(' ')?;
+		self.left.to_css(dest)
+	}
+}
+
+impl PropertyValue for FourSides<LengthOrPercentageOrAuto<CssSignedNumber>>
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"inset",
+		"margin",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_with(context, input, LengthOrPercentageOrAuto::parse)
+	}
+}
+
+impl Shorthand for FourSides<LengthOrPercentageOrAuto<CssSignedNumber>>
+{
+	#[inline(always)]
+	fn longhand_property_names(shorthand_property_name: &str) -> &'static [&'static str]
+	{
+		if shorthand_property_name.eq_ignore_ascii_case("inset")
+		{
+			&["top", "right", "bottom", "left"]
+		}
+		else
+		{
+			&["margin-top", "margin-right", "margin-bottom", "margin-left"]
+		}
+	}
+	
+	#[inline(always)]
+	fn is_related_property(shorthand_property_name: &str, property_name: &str) -> bool
+	{
+		is_related_box_model_property(shorthand_property_name, property_name)
+	}
+	
+	#[inline(always)]
+	fn expand(&self) -> Option<Vec<SpecifiedValue>>
+	{
+		self.expand_with(LengthOrPercentageOrAuto::is_var)
+	}
+	
+	#[inline(always)]
+	fn fold(longhands: &[&SpecifiedValue]) -> Option<Self>
+	{
+		Self::fold_with(longhands, LengthOrPercentageOrAuto::is_var)
+	}
+}
+
+impl PropertyValue for FourSides<CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>>
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"padding",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_with(context, input, LengthOrPercentageUnit::parse_one_outside_calc_function)
+	}
+}
+
+impl Shorthand for FourSides<CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>>
+{
+	#[inline(always)]
+	fn longhand_property_names(_shorthand_property_name: &str) -> &'static [&'static str]
+	{
+		&["padding-top", "padding-right", "padding-bottom", "padding-left"]
+	}
+	
+	#[inline(always)]
+	fn is_related_property(shorthand_property_name: &str, property_name: &str) -> bool
+	{
+		is_related_box_model_property(shorthand_property_name, property_name)
+	}
+	
+	#[inline(always)]
+	fn expand(&self) -> Option<Vec<SpecifiedValue>>
+	{
+		self.expand_with(CalculablePropertyValue::is_var)
+	}
+	
+	#[inline(always)]
+	fn fold(longhands: &[&SpecifiedValue]) -> Option<Self>
+	{
+		Self::fold_with(longhands, CalculablePropertyValue::is_var)
+	}
+}
+
+impl<T: PropertyValue + Clone> FourSides<T>
+{
+	/// The same value for all four sides.
+	#[inline(always)]
+	pub fn all(value: T) -> Self
+	{
+		Self
+		{
+			top: value.clone(),
+			right: value.clone(),
+			bottom: value.clone(),
+			left: value,
+		}
+	}
+	
+	/// The values of the sides, in the order top, right, bottom and left.
+	#[inline(always)]
+	pub fn sides(&self) -> [&T; 4]
+	{
+		[&self.top, &self.right, &self.bottom, &self.left]
+	}
+	
+	/// Parses one to four values; a missing right side is the same as the top, a missing bottom side is the same as the top and a missing left side is the same as the right.
+	pub(crate) fn parse_with<'i, 't, Parse: Fn(&ParserContext, &mut Parser<'i, 't>) -> Result<T, ParseError<'i, CustomParseError<'i>>>>(context: &ParserContext, input: &mut Parser<'i, 't>, parse: Parse) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let top = parse(context, input)?;
+		
+		let right = match input.try(|input| parse(context, input))
+		{
+			Err(_) => return Ok(Self::all(top)),
+			Ok(right) => right,
+		};
+		
+		let bottom = match input.try(|input| parse(context, input))
+		{
+			Err(_) => return Ok(Self { bottom: top.clone(), left: right.clone(), top, right }),
+			Ok(bottom) => bottom,
+		};
+		
+		let left = match input.try(|input| parse(context, input))
+		{
+			Err(_) => right.clone(),
+			Ok(left) => left,
+		};
+		
+		Ok(Self { top, right, bottom, left })
+	}
+	
+	#[inline(always)]
+	fn expand_with<IsVar: Fn(&T) -> bool>(&self, is_var: IsVar) -> Option<Vec<SpecifiedValue>>
+	{
+		let sides = self.sides();
+		
+		if sides.iter().any(|side| is_var(side))
+		{
+			return None;
+		}
+		
+		sides.iter().map(|side| SpecifiedValue::from_typed_value(*side).ok()).collect()
+	}
+	
+	#[inline(always)]
+	fn fold_with<IsVar: Fn(&T) -> bool>(longhands: &[&SpecifiedValue], is_var: IsVar) -> Option<Self>
+	{
+		if longhands.len() != 4
+		{
+			return None;
+		}
+		
+		let mut sides = Vec::with_capacity(4);
+		for longhand in longhands.iter()
+		{
+			let side = T::parse_property_value_from_css(longhand.original_css()).ok()?;
+			if is_var(&side)
+			{
+				return None;
+			}
+			sides.push(side);
+		}
+		
+		let left = sides.pop().unwrap();
+		let bottom = sides.pop().unwrap();
+		let right = sides.pop().unwrap();
+		let top = sides.pop().unwrap();
+		Some(Self { top, right, bottom, left })
+	}
+}
@@ -0,0 +1,118 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A `<length-percentage> | auto`, as used by the `margin` and `inset` families of properties.
+/// Margins and insets can be negative; use `CssSignedNumber` for `Number`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum LengthOrPercentageOrAuto<Number: CssNumber>
+{
+	/// Automatic length
+	auto,
+	
+	/// invariant or calculated length or percentage
+	value(CalculablePropertyValue<LengthOrPercentageUnit<Number>>),
+}
+
+impl<Number: CssNumber> Default for LengthOrPercentageOrAuto<Number>
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		LengthOrPercentageOrAuto::value(CalculablePropertyValue::default())
+	}
+}
+
+impl<Number: CssNumber> ToCss for LengthOrPercentageOrAuto<Number>
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::LengthOrPercentageOrAuto::*;
+		
+		match *self
+		{
+			auto => dest.write_str("auto"),
+			
+			value(ref numeric_value) => numeric_value.to_css(dest),
+		}
+	}
+}
+
+impl PropertyValue for LengthOrPercentageOrAuto<CssSignedNumber>
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"bottom",
+		"inset-block-end",
+		"inset-block-start",
+		"inset-inline-end",
+		"inset-inline-start",
+		"left",
+		"margin-block-end",
+		"margin-block-start",
+		"margin-bottom",
+		"margin-inline-end",
+		"margin-inline-start",
+		"margin-left",
+		"margin-right",
+		"margin-top",
+		"right",
+		"top",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse(context, input)
+	}
+}
+
+impl PropertyValue for CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"padding-block-end",
+		"padding-block-start",
+		"padding-bottom",
+		"padding-inline-end",
+		"padding-inline-start",
+		"padding-left",
+		"padding-right",
+		"padding-top",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		LengthOrPercentageUnit::parse_one_outside_calc_function(context, input)
+	}
+}
+
+impl<Number: CssNumber> LengthOrPercentageOrAuto<Number>
+{
+	/// Whether this value is a `var()`, which might be substituted by more than one value.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		use self::LengthOrPercentageOrAuto::*;
+		
+		match *self
+		{
+			auto => false,
+			
+			value(ref numeric_value) => numeric_value.is_var(),
+		}
+	}
+	
+	pub(crate) fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::LengthOrPercentageOrAuto::*;
+		
+		if input.try(|i| i.expect_ident_matching("auto")).is_ok()
+		{
+			return Ok(auto);
+		}
+		
+		Ok(value(LengthOrPercentageUnit::parse_one_outside_calc_function(context, input)?))
+	}
+}
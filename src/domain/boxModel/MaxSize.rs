@@ -0,0 +1,90 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `max-width` and `max-height` properties and their logical equivalents.
+/// See https://drafts.csswg.org/css-sizing-3/#sizing-values
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum MaxSize
+{
+	/// No limit
+	none,
+	
+	/// invariant or calculated non-negative length or non-negative percentage
+	value(CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>),
+	
+	/// `min-content`
+	min_content,
+	
+	/// `max-content`
+	max_content,
+	
+	/// `fit-content`
+	fit_content,
+}
+
+impl Default for MaxSize
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		MaxSize::none
+	}
+}
+
+impl ToCss for MaxSize
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::MaxSize::*;
+		
+		match *self
+		{
+			none => dest.write_str("none"),
+			
+			value(ref numeric_value) => numeric_value.to_css(dest),
+			
+			min_content => dest.write_str("min-content"),
+			
+			max_content => dest.write_str("max-content"),
+			
+			fit_content => dest.write_str("fit-content"),
+		}
+	}
+}
+
+impl PropertyValue for MaxSize
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"max-block-size",
+		"max-height",
+		"max-inline-size",
+		"max-width",
+	];
+	
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::MaxSize::*;
+		
+		if let Ok(ident) = input.try(|i| i.expect_ident_cloned())
+		{
+			return match_ignore_ascii_case!
+			{
+				&*ident,
+				
+				"none" => Ok(none),
+				
+				"min-content" => Ok(min_content),
+				
+				"max-content" => Ok(max_content),
+				
+				"fit-content" => Ok(fit_content),
+				
+				_ => Err(ParseError::Basic(BasicParseError::UnexpectedToken(Token::Ident(ident)))),
+			}
+		}
+		
+		Ok(value(LengthOrPercentageUnit::parse_one_outside_calc_function(context, input)?))
+	}
+}
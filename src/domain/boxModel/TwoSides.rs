@@ -0,0 +1,191 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of a logical shorthand property, such as `margin-inline`, `padding-block` or `inset-inline`, which sets the start and end sides of a box in one dimension from one or two values.
+/// Serializes to the fewest values which represent the same sides.
+/// See https://drafts.csswg.org/css-logical-1/#logical-shorthand-keyword
+#[derive(Debug, Default, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct TwoSides<T>
+{
+	/// The value of the start side.
+	pub start: T,
+	
+	/// The value of the end side.
+	pub end: T,
+}
+
+impl<T: ToCss + PartialEq> ToCss for TwoSides<T>
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		self.start.to_css(dest)?;
+		
+		if self.end == self.start
+		{
+			return Ok(());
+		}
+		
+		dest.write_char(' ')?;
+		self.end.to_css(dest)
+	}
+}
+
+impl PropertyValue for TwoSides<LengthOrPercentageOrAuto<CssSignedNumber>>
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"inset-block",
+		"inset-inline",
+		"margin-block",
+		"margin-inline",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_with(context, input, LengthOrPercentageOrAuto::parse)
+	}
+}
+
+impl Shorthand for TwoSides<LengthOrPercentageOrAuto<CssSignedNumber>>
+{
+	#[inline(always)]
+	fn longhand_property_names(shorthand_property_name: &str) -> &'static [&'static str]
+	{
+		match_ignore_ascii_case!
+		{
+			shorthand_property_name,
+			
+			"inset-block" => &["inset-block-start", "inset-block-end"],
+			
+			"inset-inline" => &["inset-inline-start", "inset-inline-end"],
+			
+			"margin-block" => &["margin-block-start", "margin-block-end"],
+			
+			_ => &["margin-inline-start", "margin-inline-end"],
+		}
+	}
+	
+	#[inline(always)]
+	fn is_related_property(shorthand_property_name: &str, property_name: &str) -> bool
+	{
+		is_related_box_model_property(shorthand_property_name, property_name)
+	}
+	
+	#[inline(always)]
+	fn expand(&self) -> Option<Vec<SpecifiedValue>>
+	{
+		self.expand_with(LengthOrPercentageOrAuto::is_var)
+	}
+	
+	#[inline(always)]
+	fn fold(longhands: &[&SpecifiedValue]) -> Option<Self>
+	{
+		Self::fold_with(longhands, LengthOrPercentageOrAuto::is_var)
+	}
+}
+
+impl PropertyValue for TwoSides<CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>>
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"padding-block",
+		"padding-inline",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_with(context, input, LengthOrPercentageUnit::parse_one_outside_calc_function)
+	}
+}
+
+impl Shorthand for TwoSides<CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>>
+{
+	#[inline(always)]
+	fn longhand_property_names(shorthand_property_name: &str) -> &'static [&'static str]
+	{
+		if shorthand_property_name.eq_ignore_ascii_case("padding-block")
+		{
+			&["padding-block-start", "padding-block-end"]
+		}
+		else
+		{
+			&["padding-inline-start", "padding-inline-end"]
+		}
+	}
+	
+	#[inline(always)]
+	fn is_related_property(shorthand_property_name: &str, property_name: &str) -> bool
+	{
+		is_related_box_model_property(shorthand_property_name, property_name)
+	}
+	
+	#[inline(always)]
+	fn expand(&self) -> Option<Vec<SpecifiedValue>>
+	{
+		self.expand_with(CalculablePropertyValue::is_var)
+	}
+	
+	#[inline(always)]
+	fn fold(longhands: &[&SpecifiedValue]) -> Option<Self>
+	{
+		Self::fold_with(longhands, CalculablePropertyValue::is_var)
+	}
+}
+
+impl<T: PropertyValue + Clone> TwoSides<T>
+{
+	/// The same value for both sides.
+	#[inline(always)]
+	pub fn both(value: T) -> Self
+	{
+		Self
+		{
+			start: value.clone(),
+			end: value,
+		}
+	}
+	
+	/// Parses one or two values; a missing end side is the same as the start.
+	pub(crate) fn parse_with<'i, 't, Parse: Fn(&ParserContext, &mut Parser<'i, 't>) -> Result<T, ParseError<'i, CustomParseError<'i>>>>(context: &ParserContext, input: &mut Parser<'i, 't>, parse: Parse) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let start = parse(context, input)?;
+		
+		match input.try(|input| parse(context, input))
+		{
+			Err(_) => Ok(Self::both(start)),
+			Ok(end) => Ok(Self { start, end }),
+		}
+	}
+	
+	#[inline(always)]
+	fn expand_with<IsVar: Fn(&T) -> bool>(&self, is_var: IsVar) -> Option<Vec<SpecifiedValue>>
+	{
+		if is_var(&self.start) || is_var(&self.end)
+		{
+			return None;
+		}
+		
+		Some(vec![SpecifiedValue::from_typed_value(&self.start).ok()?, SpecifiedValue::from_typed_value(&self.end).ok()?])
+	}
+	
+	#[inline(always)]
+	fn fold_with<IsVar: Fn(&T) -> bool>(longhands: &[&SpecifiedValue], is_var: IsVar) -> Option<Self>
+	{
+		if longhands.len() != 2
+		{
+			return None;
+		}
+		
+		let start = T::parse_property_value_from_css(longhands[0].original_css()).ok()?;
+		let end = T::parse_property_value_from_css(longhands[1].original_css()).ok()?;
+		if is_var(&start) || is_var(&end)
+		{
+			return None;
+		}
+		
+		Some(Self { start, end })
+	}
+}
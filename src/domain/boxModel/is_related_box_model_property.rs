@@ -0,0 +1,36 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Whether a property sets any of the same values as a `margin`, `padding` or `inset` shorthand property (including the logical shorthands such as `margin-inline`); for example, `margin-inline-start` and `margin-left` are related to `margin`.
+pub(crate) fn is_related_box_model_property(shorthand_property_name: &str, property_name: &str) -> bool
+{
+	#[inline(always)]
+	fn starts_with_ignoring_ascii_case(value: &str, prefix: &str) -> bool
+	{
+		value.len() >= prefix.len() && value.is_char_boundary(prefix.len()) && value[..prefix.len()].eq_ignore_ascii_case(prefix)
+	}
+	
+	for family in ["margin", "padding"].iter()
+	{
+		if starts_with_ignoring_ascii_case(shorthand_property_name, family)
+		{
+			return starts_with_ignoring_ascii_case(property_name, family);
+		}
+	}
+	
+	match_ignore_ascii_case!
+	{
+		property_name,
+		
+		"top" => true,
+		
+		"right" => true,
+		
+		"bottom" => true,
+		
+		"left" => true,
+		
+		_ => starts_with_ignoring_ascii_case(property_name, "inset"),
+	}
+}
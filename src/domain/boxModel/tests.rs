@@ -0,0 +1,94 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+use super::properties::Importance;
+use super::properties::PropertyDeclarations;
+
+
+type Margin = FourSides<LengthOrPercentageOrAuto<CssSignedNumber>>;
+
+type Padding = FourSides<CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>>;
+
+type MarginInline = TwoSides<LengthOrPercentageOrAuto<CssSignedNumber>>;
+
+fn rewritten<Rewrite: FnOnce(&mut PropertyDeclarations<Importance>)>(css: &str, rewrite: Rewrite) -> String
+{
+	let mut stylesheet = Stylesheet::parse(css).ok().unwrap();
+	match stylesheet.rules.0[0]
+	{
+		CssRule::Style(ref mut styleRule) => rewrite(&mut styleRule.property_declarations),
+		_ => panic!("not a style rule"),
+	}
+	stylesheet.to_css_string(false)
+}
+
+fn expanded<S: Shorthand>(css: &str) -> String
+{
+	rewritten(css, PropertyDeclarations::expand_shorthands::<S>)
+}
+
+fn folded<S: Shorthand>(css: &str) -> String
+{
+	rewritten(css, PropertyDeclarations::fold_into_shorthands::<S>)
+}
+
+#[test]
+fn four_sides_are_expanded()
+{
+	assert_eq!(expanded::<Margin>("a{margin:1px 2px}"), "a{margin-top:1px;margin-right:2px;margin-bottom:1px;margin-left:2px}");
+	assert_eq!(expanded::<Margin>("a{inset:1px auto 3px}"), "a{top:1px;right:auto;bottom:3px;left:auto}");
+	assert_eq!(expanded::<Padding>("a{padding:1px!important;color:red}"), "a{padding-top:1px!important;padding-right:1px!important;padding-bottom:1px!important;padding-left:1px!important;color:red}");
+	assert_eq!(expanded::<Margin>("a{margin:inherit}"), "a{margin-top:inherit;margin-right:inherit;margin-bottom:inherit;margin-left:inherit}");
+	assert_eq!(expanded::<Margin>("a{-webkit-margin:1px}"), "a{-webkit-margin:1px}");
+}
+
+#[test]
+fn four_sides_are_folded()
+{
+	assert_eq!(folded::<Margin>("a{margin-top:1px;margin-right:2px;margin-bottom:1px;margin-left:2px}"), "a{margin:1px 2px}");
+	assert_eq!(folded::<Margin>("a{color:red;margin-left:4px;margin-top:1px;margin-right:2px;margin-bottom:3px}"), "a{color:red;margin:1px 2px 3px 4px}");
+	assert_eq!(folded::<Padding>("a{padding-top:1px!important;padding-right:1px!important;padding-bottom:1px!important;padding-left:1px!important}"), "a{padding:1px!important}");
+	assert_eq!(folded::<Margin>("a{margin-top:unset;margin-right:unset;margin-bottom:unset;margin-left:unset}"), "a{margin:unset}");
+}
+
+#[test]
+fn four_sides_are_not_folded_unless_complete_and_alike()
+{
+	let unfolded =
+	[
+		"a{margin-top:1px;margin-right:2px;margin-bottom:1px}",
+		"a{margin-top:1px;margin-right:2px!important;margin-bottom:1px;margin-left:2px}",
+		"a{margin-top:inherit;margin-right:inherit;margin-bottom:initial;margin-left:inherit}",
+		"a{margin-top:inherit;margin-right:2px;margin-bottom:1px;margin-left:2px}",
+		"a{margin-top:1px;margin-right:2px;margin-top:3px;margin-bottom:1px;margin-left:2px}",
+		"a{margin-top:1px;margin-right:2px;margin-inline-start:0;margin-bottom:1px;margin-left:2px}",
+	];
+	for css in unfolded.iter()
+	{
+		assert_eq!(&folded::<Margin>(css), css);
+	}
+}
+
+#[test]
+fn two_sides_are_expanded_and_folded()
+{
+	assert_eq!(expanded::<MarginInline>("a{margin-inline:1px auto}"), "a{margin-inline-start:1px;margin-inline-end:auto}");
+	assert_eq!(expanded::<MarginInline>("a{margin-block:initial!important}"), "a{margin-block-start:initial!important;margin-block-end:initial!important}");
+	assert_eq!(folded::<MarginInline>("a{margin-inline-start:1px;margin-inline-end:1px}"), "a{margin-inline:1px}");
+	assert_eq!(folded::<MarginInline>("a{margin-inline-start:1px!important;margin-inline-end:1px}"), "a{margin-inline-start:1px!important;margin-inline-end:1px}");
+}
+
+#[test]
+fn values_using_var_are_neither_expanded_nor_folded()
+{
+	for css in ["a{margin:var(--a) 0}", "a{margin:calc(var(--a) * 2) 0}", "a{margin:0 max(1px, var(--a))}", "a{margin-inline:calc(1px + var(--a))}"].iter()
+	{
+		assert_eq!(&expanded::<Margin>(css), css);
+		assert_eq!(&expanded::<MarginInline>(css), css);
+	}
+	
+	let css = "a{margin-top:calc(var(--a) * 2);margin-right:0;margin-bottom:0;margin-left:0}";
+	assert_eq!(folded::<Margin>(css), css);
+}
@@ -0,0 +1,594 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A color that does not depend on where it is used; everything but `currentcolor` and the system colors.
+///
+/// Missing (`none`) components are treated as zero.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AbsoluteColor
+{
+	/// The color space that `components` are in.
+	pub color_space: ColorSpace,
+	
+	/// The components, in the reference ranges of `color_space`.
+	pub components: [f32; 3],
+	
+	/// The alpha (opacity), from 0 (transparent) to 1 (opaque).
+	pub alpha: f32,
+}
+
+impl ToCss for AbsoluteColor
+{
+	/// Writes the shortest equivalent form; sRGB-compatible colors may become a named color, a hex color or `rgb()`, whatever function they were written with.
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if !self.color_space.is_srgb_compatible()
+		{
+			return self.write_function(dest);
+		}
+		
+		let mut candidates = Vec::with_capacity(3);
+		
+		if let Some(rgba) = self.exact_rgba8()
+		{
+			candidates.push(Self::named_or_hex(rgba));
+		}
+		
+		if self.color_space != Srgb
+		{
+			let mut function = String::new();
+			self.write_function(&mut function)?;
+			candidates.push(function);
+		}
+		
+		let mut rgb = String::new();
+		self.to_color_space(Srgb).write_function(&mut rgb)?;
+		candidates.push(rgb);
+		
+		dest.write_str(candidates.iter().min_by_key(|candidate| candidate.len()).unwrap())
+	}
+}
+
+impl AbsoluteColor
+{
+	const Opaque: f32 = 1.0;
+	
+	/// Creates a new color.
+	#[inline(always)]
+	pub fn new(color_space: ColorSpace, components: [f32; 3], alpha: f32) -> Self
+	{
+		Self
+		{
+			color_space,
+			components,
+			alpha,
+		}
+	}
+	
+	/// Creates a new sRGB color from 8-bit red, green, blue and alpha, as a hex color would.
+	#[inline(always)]
+	pub fn from_rgba8(red: u8, green: u8, blue: u8, alpha: u8) -> Self
+	{
+		Self::new(Srgb, [red as f32 / 255.0, green as f32 / 255.0, blue as f32 / 255.0], alpha as f32 / 255.0)
+	}
+	
+	/// `transparent`.
+	#[inline(always)]
+	pub fn transparent() -> Self
+	{
+		Self::from_rgba8(0, 0, 0, 0)
+	}
+	
+	/// Converts this color to an equivalent color in another color space.
+	/// The result may be outside the gamut of `color_space` (eg have negative components).
+	#[inline(always)]
+	pub fn to_color_space(&self, color_space: ColorSpace) -> Self
+	{
+		let components = self.color_space.convert(self.components_f64(), color_space);
+		Self::new(color_space, [components[0] as f32, components[1] as f32, components[2] as f32], self.alpha)
+	}
+	
+	/// Whether this color can be displayed on an sRGB screen without clipping.
+	#[inline(always)]
+	pub fn is_in_srgb_gamut(&self) -> bool
+	{
+		const Tolerance: f64 = 1e-4;
+		
+		self.srgb().iter().all(|&value| value >= -Tolerance && value <= 1.0 + Tolerance)
+	}
+	
+	/// Converts this color to 8-bit sRGB red, green, blue and alpha; colors outside the sRGB gamut are clipped rather than gamut mapped.
+	#[inline(always)]
+	pub fn to_rgba8(&self) -> [u8; 4]
+	{
+		let eightBit = |value: f64| (value.max(0.0).min(1.0) * 255.0).round() as u8;
+		
+		let srgb = self.srgb();
+		[eightBit(srgb[0]), eightBit(srgb[1]), eightBit(srgb[2]), eightBit(self.alpha as f64)]
+	}
+	
+	/// The relative luminance (https://www.w3.org/TR/WCAG21/#dfn-relative-luminance) of this color, clipped to the sRGB gamut, ignoring alpha; from 0 (black) to 1 (white).
+	#[inline(always)]
+	pub fn relative_luminance(&self) -> f64
+	{
+		let srgb = self.srgb();
+		let clipped = [srgb[0].max(0.0).min(1.0), srgb[1].max(0.0).min(1.0), srgb[2].max(0.0).min(1.0)];
+		Srgb.to_xyz_d65(clipped)[1]
+	}
+	
+	/// The contrast ratio (https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio) between this color and another, from 1 to 21.
+	#[inline(always)]
+	pub fn contrast_ratio(&self, other: &AbsoluteColor) -> f64
+	{
+		let ourLuminance = self.relative_luminance();
+		let otherLuminance = other.relative_luminance();
+		(ourLuminance.max(otherLuminance) + 0.05) / (ourLuminance.min(otherLuminance) + 0.05)
+	}
+	
+	/// Parses the value of a hex color, without the leading `#`.
+	pub(crate) fn parse_hex<'i>(value: &CowRcStr<'i>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let invalid = || ParseError::Custom(CustomParseError::InvalidHexColor(value.clone()));
+		
+		let mut digits = Vec::with_capacity(8);
+		for character in value.chars()
+		{
+			digits.push(character.to_digit(16).ok_or_else(&invalid)? as u8);
+		}
+		
+		let byte = |index: usize| digits[index] * 16 + digits[index + 1];
+		let nibble = |index: usize| digits[index] * 17;
+		
+		match digits.len()
+		{
+			3 => Ok(Self::from_rgba8(nibble(0), nibble(1), nibble(2), 255)),
+			4 => Ok(Self::from_rgba8(nibble(0), nibble(1), nibble(2), nibble(3))),
+			6 => Ok(Self::from_rgba8(byte(0), byte(2), byte(4), 255)),
+			8 => Ok(Self::from_rgba8(byte(0), byte(2), byte(4), byte(6))),
+			_ => Err(invalid()),
+		}
+	}
+	
+	/// A named color or `transparent`.
+	pub(crate) fn from_name(name: &str) -> Option<Self>
+	{
+		if name.eq_ignore_ascii_case("transparent")
+		{
+			return Some(Self::transparent());
+		}
+		
+		NamedColors.iter().find(|namedColor| namedColor.0.eq_ignore_ascii_case(name)).map(|&(_, red, green, blue)| Self::from_rgba8(red, green, blue, 255))
+	}
+	
+	/// Parses the arguments of a color function such as `rgb()` or `lab()`.
+	pub(crate) fn parse_function<'i, 't>(name: &CowRcStr<'i>, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		const Hue: Option<f64> = None;
+		
+		let color = match_ignore_ascii_case!
+		{
+			&*name,
+			
+			"rgb" | "rgba" => Self::parse_rgb(input)?,
+			
+			"hsl" | "hsla" => Self::parse_hsl(input)?,
+			
+			"hwb" => Self::parse_modern_syntax(Hwb, input, [Hue, Some(100.0), Some(100.0)], None)?,
+			
+			"lab" => Self::parse_modern_syntax(Lab, input, [Some(100.0), Some(125.0), Some(125.0)], None)?,
+			
+			"lch" => Self::parse_modern_syntax(Lch, input, [Some(100.0), Some(150.0), Hue], None)?,
+			
+			"oklab" => Self::parse_modern_syntax(Oklab, input, [Some(1.0), Some(0.4), Some(0.4)], None)?,
+			
+			"oklch" => Self::parse_modern_syntax(Oklch, input, [Some(1.0), Some(0.4), Hue], None)?,
+			
+			"color" =>
+			{
+				let colorSpaceName = input.expect_ident_cloned()?;
+				let colorSpace = ColorSpace::from_predefined_name(&colorSpaceName).ok_or(ParseError::Custom(CustomParseError::UnknownPredefinedColorSpace(colorSpaceName)))?;
+				Self::parse_modern_syntax(colorSpace, input, [Some(1.0), Some(1.0), Some(1.0)], None)?
+			},
+			
+			_ => return Err(ParseError::Custom(CustomParseError::UnknownColorFunction(name.clone()))),
+		};
+		
+		Ok(color.clamped())
+	}
+	
+	/// https://drafts.csswg.org/css-color-4/#rgb-functions, accepting both the legacy comma-separated and the modern space-separated syntax.
+	fn parse_rgb<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		const PercentageReference: Option<f64> = Some(255.0);
+		
+		let (first, isPercentage) = match *input.next()?
+		{
+			Token::Number { value, .. } => (value as f64, false),
+			
+			Token::Percentage { unit_value, .. } => (unit_value as f64 * 255.0, true),
+			
+			Token::Ident(ref ident) if ident.eq_ignore_ascii_case("none") => (0.0, false),
+			
+			ref unexpectedToken @ _ => return CustomParseError::unexpectedToken(unexpectedToken),
+		};
+		
+		let color = if input.try(|input| input.expect_comma()).is_ok()
+		{
+			let second = Self::parse_legacy_rgb_component(input, isPercentage)?;
+			input.expect_comma()?;
+			let third = Self::parse_legacy_rgb_component(input, isPercentage)?;
+			let alpha = Self::parse_legacy_alpha(input)?;
+			Self::new(Srgb, [first as f32, second as f32, third as f32], alpha)
+		}
+		else
+		{
+			Self::parse_modern_syntax(Srgb, input, [PercentageReference, PercentageReference, PercentageReference], Some(first))?
+		};
+		
+		Ok(Self::new(Srgb, [color.components[0] / 255.0, color.components[1] / 255.0, color.components[2] / 255.0], color.alpha))
+	}
+	
+	#[inline(always)]
+	fn parse_legacy_rgb_component<'i, 't>(input: &mut Parser<'i, 't>, isPercentage: bool) -> Result<f64, ParseError<'i, CustomParseError<'i>>>
+	{
+		if isPercentage
+		{
+			Ok(input.expect_percentage()? as f64 * 255.0)
+		}
+		else
+		{
+			Ok(input.expect_number()? as f64)
+		}
+	}
+	
+	/// https://drafts.csswg.org/css-color-4/#the-hsl-notation, accepting both the legacy comma-separated and the modern space-separated syntax.
+	fn parse_hsl<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let hue = Self::parse_hue(input)?;
+		
+		if input.try(|input| input.expect_comma()).is_ok()
+		{
+			let saturation = input.expect_percentage()? * 100.0;
+			input.expect_comma()?;
+			let lightness = input.expect_percentage()? * 100.0;
+			let alpha = Self::parse_legacy_alpha(input)?;
+			Ok(Self::new(Hsl, [hue as f32, saturation, lightness], alpha))
+		}
+		else
+		{
+			Self::parse_modern_syntax(Hsl, input, [None, Some(100.0), Some(100.0)], Some(hue))
+		}
+	}
+	
+	/// Parses three space-separated components then an optional `/` and alpha.
+	/// Each of `percentageReferences` is either the value of `100%` for that component, or `None` if that component is a hue.
+	fn parse_modern_syntax<'i, 't>(color_space: ColorSpace, input: &mut Parser<'i, 't>, percentageReferences: [Option<f64>; 3], alreadyParsedFirstComponent: Option<f64>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut components = [0.0; 3];
+		for index in 0 .. 3
+		{
+			let component = match (index, alreadyParsedFirstComponent)
+			{
+				(0, Some(component)) => component,
+				_ => match percentageReferences[index]
+				{
+					None => Self::parse_hue(input)?,
+					Some(percentageReference) => Self::parse_number_or_percentage(input, percentageReference)?,
+				},
+			};
+			components[index] = component as f32;
+		}
+		
+		let alpha = if input.try(|input| input.expect_delim('/')).is_ok()
+		{
+			Self::parse_alpha(input)?
+		}
+		else
+		{
+			Self::Opaque
+		};
+		
+		Ok(Self::new(color_space, components, alpha))
+	}
+	
+	#[inline(always)]
+	fn parse_legacy_alpha<'i, 't>(input: &mut Parser<'i, 't>) -> Result<f32, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.try(|input| input.expect_comma()).is_ok()
+		{
+			Self::parse_alpha(input)
+		}
+		else
+		{
+			Ok(Self::Opaque)
+		}
+	}
+	
+	#[inline(always)]
+	fn parse_alpha<'i, 't>(input: &mut Parser<'i, 't>) -> Result<f32, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_number_or_percentage(input, 1.0).map(|alpha| alpha as f32)
+	}
+	
+	/// A number, a percentage (where `100%` is `percentageReference`) or `none` (zero).
+	#[inline(always)]
+	fn parse_number_or_percentage<'i, 't>(input: &mut Parser<'i, 't>, percentageReference: f64) -> Result<f64, ParseError<'i, CustomParseError<'i>>>
+	{
+		match *input.next()?
+		{
+			Token::Number { value, .. } => Ok(value as f64),
+			
+			Token::Percentage { unit_value, .. } => Ok(unit_value as f64 * percentageReference),
+			
+			Token::Ident(ref ident) if ident.eq_ignore_ascii_case("none") => Ok(0.0),
+			
+			ref unexpectedToken @ _ => CustomParseError::unexpectedToken(unexpectedToken),
+		}
+	}
+	
+	/// A hue, as a number of degrees, an angle or `none` (zero); normalized to be from 0 up to 360 degrees.
+	fn parse_hue<'i, 't>(input: &mut Parser<'i, 't>) -> Result<f64, ParseError<'i, CustomParseError<'i>>>
+	{
+		let degrees = match *input.next()?
+		{
+			Token::Number { value, .. } => value as f64,
+			
+			Token::Dimension { value, ref unit, .. } =>
+			{
+				let angle = value as f64;
+				match_ignore_ascii_case!
+				{
+					&*unit,
+					
+					"deg" => angle,
+					
+					"grad" => angle * 0.9,
+					
+					"rad" => angle.to_degrees(),
+					
+					"turn" => angle * 360.0,
+					
+					_ => return Err(ParseError::Custom(CustomParseError::CouldNotParseDimension(value, unit.clone()))),
+				}
+			}
+			
+			Token::Ident(ref ident) if ident.eq_ignore_ascii_case("none") => 0.0,
+			
+			ref unexpectedToken @ _ => return CustomParseError::unexpectedToken(unexpectedToken),
+		};
+		
+		Ok((degrees % 360.0 + 360.0) % 360.0)
+	}
+	
+	/// Clamps components to the ranges CSS Color 4 allows when parsing.
+	fn clamped(self) -> Self
+	{
+		#[inline(always)]
+		fn clamp(value: f32, minimum: f32, maximum: f32) -> f32
+		{
+			value.max(minimum).min(maximum)
+		}
+		
+		const Unbounded: f32 = ::std::f32::INFINITY;
+		
+		let c = self.components;
+		let components = match self.color_space
+		{
+			Srgb => [clamp(c[0], 0.0, 1.0), clamp(c[1], 0.0, 1.0), clamp(c[2], 0.0, 1.0)],
+			
+			Hsl | Hwb => [c[0], clamp(c[1], 0.0, 100.0), clamp(c[2], 0.0, 100.0)],
+			
+			Lab => [clamp(c[0], 0.0, 100.0), c[1], c[2]],
+			
+			Lch => [clamp(c[0], 0.0, 100.0), clamp(c[1], 0.0, Unbounded), c[2]],
+			
+			Oklab => [clamp(c[0], 0.0, 1.0), c[1], c[2]],
+			
+			Oklch => [clamp(c[0], 0.0, 1.0), clamp(c[1], 0.0, Unbounded), c[2]],
+			
+			_ => c,
+		};
+		
+		Self::new(self.color_space, components, clamp(self.alpha, 0.0, 1.0))
+	}
+	
+	#[inline(always)]
+	fn components_f64(&self) -> [f64; 3]
+	{
+		[self.components[0] as f64, self.components[1] as f64, self.components[2] as f64]
+	}
+	
+	#[inline(always)]
+	fn srgb(&self) -> [f64; 3]
+	{
+		self.color_space.convert(self.components_f64(), Srgb)
+	}
+	
+	/// If this color can be written exactly as a hex color, its 8-bit red, green, blue and alpha.
+	fn exact_rgba8(&self) -> Option<[u8; 4]>
+	{
+		const Tolerance: f64 = 1e-3;
+		
+		#[inline(always)]
+		fn exact(value: f64) -> Option<u8>
+		{
+			let scaled = value * 255.0;
+			let rounded = scaled.round();
+			if rounded >= 0.0 && rounded <= 255.0 && (scaled - rounded).abs() < Tolerance
+			{
+				Some(rounded as u8)
+			}
+			else
+			{
+				None
+			}
+		}
+		
+		let srgb = self.srgb();
+		Some([exact(srgb[0])?, exact(srgb[1])?, exact(srgb[2])?, exact(self.alpha as f64)?])
+	}
+	
+	/// The shortest of any named color (if opaque) and the hex color.
+	fn named_or_hex(rgba: [u8; 4]) -> String
+	{
+		let isShort = rgba.iter().all(|&byte| byte >> 4 == byte & 0x0F);
+		let isOpaque = rgba[3] == 255;
+		
+		let mut hex = String::with_capacity(9);
+		hex.push('#');
+		let bytes = if isOpaque
+		{
+			&rgba[.. 3]
+		}
+		else
+		{
+			&rgba[..]
+		};
+		for &byte in bytes
+		{
+			if isShort
+			{
+				hex.push_str(&format!("{:x}", byte & 0x0F));
+			}
+			else
+			{
+				hex.push_str(&format!("{:02x}", byte));
+			}
+		}
+		
+		if isOpaque
+		{
+			let shortestName = NamedColors.iter().filter(|&&(_, red, green, blue)| [red, green, blue] == rgba[.. 3]).map(|namedColor| namedColor.0).min_by_key(|name| name.len());
+			if let Some(name) = shortestName
+			{
+				if name.len() < hex.len()
+				{
+					return name.to_owned();
+				}
+			}
+		}
+		
+		hex
+	}
+	
+	/// Writes this color using the function for its color space; `rgb()` and `hsl()` use the legacy comma-separated syntax, which older browsers also support.
+	fn write_function<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		let isOpaque = self.alpha == Self::Opaque;
+		let c = self.components;
+		
+		match self.color_space
+		{
+			Srgb | Hsl =>
+			{
+				let (name, components, percentages) = if self.color_space == Srgb
+				{
+					("rgb", [c[0] * 255.0, c[1] * 255.0, c[2] * 255.0], [false, false, false])
+				}
+				else
+				{
+					("hsl", c, [false, true, true])
+				};
+				
+				dest.write_str(name)?;
+				if !isOpaque
+				{
+					dest.write_char('a')?;
+				}
+				dest.write_char('(')?;
+				for index in 0 .. 3
+				{
+					if index != 0
+					{
+						dest.write_char(',')?;
+					}
+					Self::write_number(dest, components[index])?;
+					if percentages[index]
+					{
+						dest.write_char('%')?;
+					}
+				}
+				if !isOpaque
+				{
+					dest.write_char(',')?;
+					Self::write_number(dest, self.alpha)?;
+				}
+				dest.write_char(')')
+			}
+			
+			_ =>
+			{
+				match self.color_space.predefined_name()
+				{
+					Some(name) =>
+					{
+						dest.write_str("color(")?;
+						dest.write_str(name)?;
+						dest.write_char(' ')?;
+					}
+					
+					None =>
+					{
+						dest.write_str(match self.color_space
+						{
+							Hwb => "hwb(",
+							Lab => "lab(",
+							Lch => "lch(",
+							Oklab => "oklab(",
+							_ => "oklch(",
+						})?;
+					}
+				}
+				
+				let percentages = self.color_space == Hwb;
+				for index in 0 .. 3
+				{
+					if index != 0
+					{
+						dest.write_char(' ')?;
+					}
+					Self::write_number(dest, c[index])?;
+					if percentages && index != 0
+					{
+						dest.write_char('%')?;
+					}
+				}
+				if !isOpaque
+				{
+					dest.write_char('/')?;
+					Self::write_number(dest, self.alpha)?;
+				}
+				dest.write_char(')')
+			}
+		}
+	}
+	
+	/// Writes a number rounded to four decimal places, which is more precise than any display, and without a leading zero.
+	#[inline(always)]
+	fn write_number<W: fmt::Write>(dest: &mut W, value: f32) -> fmt::Result
+	{
+		let rounded = (value * 10_000.0).round() / 10_000.0;
+		
+		// Avoid writing `-0`.
+		if rounded == 0.0
+		{
+			return dest.write_char('0');
+		}
+		
+		let number = rounded.to_css_string();
+		if number.starts_with("0.")
+		{
+			dest.write_str(&number[1..])
+		}
+		else if number.starts_with("-0.")
+		{
+			dest.write_char('-')?;
+			dest.write_str(&number[2..])
+		}
+		else
+		{
+			dest.write_str(&number)
+		}
+	}
+}
@@ -0,0 +1,131 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A `<color>` (https://drafts.csswg.org/css-color-4/#color-type).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Color
+{
+	/// `currentcolor`; the value of the `color` property.
+	CurrentColor,
+	
+	/// A system color, such as `Canvas`, chosen by the user agent.
+	System(SystemColor),
+	
+	/// A hex color, a named color, `transparent` or any of the color functions.
+	Absolute(AbsoluteColor),
+}
+
+impl ToCss for Color
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::Color::*;
+		
+		match *self
+		{
+			CurrentColor => dest.write_str("currentcolor"),
+			
+			System(ref systemColor) => systemColor.to_css(dest),
+			
+			Absolute(ref absoluteColor) => absoluteColor.to_css(dest),
+		}
+	}
+}
+
+impl Parse for Color
+{
+	fn parse<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::Color::*;
+		
+		let function = match *input.next()?
+		{
+			Token::Hash(ref value) | Token::IDHash(ref value) => return AbsoluteColor::parse_hex(value).map(Absolute),
+			
+			Token::Ident(ref ident) =>
+			{
+				if ident.eq_ignore_ascii_case("currentcolor")
+				{
+					return Ok(CurrentColor);
+				}
+				
+				if let Some(absoluteColor) = AbsoluteColor::from_name(ident)
+				{
+					return Ok(Absolute(absoluteColor));
+				}
+				
+				return SystemColor::from_ident(ident).map(System).map_err(|()| ParseError::Custom(CustomParseError::UnknownColorKeyword(ident.clone())));
+			}
+			
+			Token::Function(ref name) => name.clone(),
+			
+			ref unexpectedToken @ _ => return CustomParseError::unexpectedToken(unexpectedToken),
+		};
+		
+		input.parse_nested_block(|input| AbsoluteColor::parse_function(&function, input)).map(Absolute)
+	}
+}
+
+impl PropertyValue for Color
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"accent-color",
+		"background-color",
+		"border-block-end-color",
+		"border-block-start-color",
+		"border-bottom-color",
+		"border-inline-end-color",
+		"border-inline-start-color",
+		"border-left-color",
+		"border-right-color",
+		"border-top-color",
+		"caret-color",
+		"color",
+		"column-rule-color",
+		"flood-color",
+		"lighting-color",
+		"outline-color",
+		"stop-color",
+		"tap-highlight-color",
+		"text-decoration-color",
+		"text-emphasis-color",
+		"text-fill-color",
+		"text-stroke-color",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse(context, input)
+	}
+}
+
+impl Color
+{
+	/// `transparent`.
+	#[inline(always)]
+	pub fn transparent() -> Self
+	{
+		Color::Absolute(AbsoluteColor::transparent())
+	}
+	
+	/// Creates a new sRGB color from 8-bit red, green, blue and alpha, as a hex color would.
+	#[inline(always)]
+	pub fn from_rgba8(red: u8, green: u8, blue: u8, alpha: u8) -> Self
+	{
+		Color::Absolute(AbsoluteColor::from_rgba8(red, green, blue, alpha))
+	}
+	
+	/// The absolute color, unless this is `currentcolor` or a system color.
+	#[inline(always)]
+	pub fn absolute(&self) -> Option<&AbsoluteColor>
+	{
+		match *self
+		{
+			Color::Absolute(ref absoluteColor) => Some(absoluteColor),
+			_ => None,
+		}
+	}
+}
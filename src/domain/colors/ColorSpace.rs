@@ -0,0 +1,612 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A color space in which the three components of an `AbsoluteColor` are expressed.
+///
+/// Components use the reference ranges of CSS Color 4, so, for example, `Hsl` saturation and lightness run from 0 to 100 but `Srgb` red, green and blue run from 0 to 1.
+/// Hues are in degrees.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ColorSpace
+{
+	/// Hex colors, named colors, `rgb()` and `color(srgb ...)`; red, green and blue from 0 to 1.
+	Srgb,
+	
+	/// `hsl()`; hue, saturation from 0 to 100 and lightness from 0 to 100.
+	Hsl,
+	
+	/// `hwb()`; hue, whiteness from 0 to 100 and blackness from 0 to 100.
+	Hwb,
+	
+	/// `lab()`; CIE lightness from 0 to 100, a and b (nominally -125 to 125), relative to a D50 white point.
+	Lab,
+	
+	/// `lch()`; CIE lightness from 0 to 100, chroma (nominally 0 to 150) and hue, relative to a D50 white point.
+	Lch,
+	
+	/// `oklab()`; lightness from 0 to 1, a and b (nominally -0.4 to 0.4).
+	Oklab,
+	
+	/// `oklch()`; lightness from 0 to 1, chroma (nominally 0 to 0.4) and hue.
+	Oklch,
+	
+	/// `color(srgb-linear ...)`; linear-light red, green and blue from 0 to 1.
+	SrgbLinear,
+	
+	/// `color(display-p3 ...)`; red, green and blue from 0 to 1.
+	DisplayP3,
+	
+	/// `color(a98-rgb ...)`; red, green and blue from 0 to 1.
+	A98Rgb,
+	
+	/// `color(prophoto-rgb ...)`; red, green and blue from 0 to 1.
+	ProphotoRgb,
+	
+	/// `color(rec2020 ...)`; red, green and blue from 0 to 1.
+	Rec2020,
+	
+	/// `color(xyz-d50 ...)`; CIE XYZ relative to a D50 white point.
+	XyzD50,
+	
+	/// `color(xyz-d65 ...)` (or `color(xyz ...)`); CIE XYZ relative to a D65 white point.
+	XyzD65,
+}
+
+impl ColorSpace
+{
+	/// Whether colors in this color space can always be written as hex colors or with `rgb()`, `hsl()` or `hwb()`.
+	#[inline(always)]
+	pub fn is_srgb_compatible(self) -> bool
+	{
+		match self
+		{
+			Srgb | Hsl | Hwb => true,
+			_ => false,
+		}
+	}
+	
+	/// Which component, if any, is a hue.
+	#[inline(always)]
+	pub fn hue_component(self) -> Option<usize>
+	{
+		match self
+		{
+			Hsl | Hwb => Some(0),
+			Lch | Oklch => Some(2),
+			_ => None,
+		}
+	}
+	
+	/// The name used for this color space in the `color()` function, if it is one of the predefined color spaces.
+	#[inline(always)]
+	pub fn predefined_name(self) -> Option<&'static str>
+	{
+		let name = match self
+		{
+			Srgb => "srgb",
+			SrgbLinear => "srgb-linear",
+			DisplayP3 => "display-p3",
+			A98Rgb => "a98-rgb",
+			ProphotoRgb => "prophoto-rgb",
+			Rec2020 => "rec2020",
+			XyzD50 => "xyz-d50",
+			XyzD65 => "xyz-d65",
+			_ => return None,
+		};
+		Some(name)
+	}
+	
+	#[inline(always)]
+	pub(crate) fn from_predefined_name(name: &str) -> Option<Self>
+	{
+		match_ignore_ascii_case!
+		{
+			name,
+			
+			"srgb" => Some(Srgb),
+			
+			"srgb-linear" => Some(SrgbLinear),
+			
+			"display-p3" => Some(DisplayP3),
+			
+			"a98-rgb" => Some(A98Rgb),
+			
+			"prophoto-rgb" => Some(ProphotoRgb),
+			
+			"rec2020" => Some(Rec2020),
+			
+			"xyz" => Some(XyzD65),
+			
+			"xyz-d50" => Some(XyzD50),
+			
+			"xyz-d65" => Some(XyzD65),
+			
+			_ => None,
+		}
+	}
+	
+	/// Converts components in this color space to components in `to`.
+	pub fn convert(self, components: [f64; 3], to: ColorSpace) -> [f64; 3]
+	{
+		if self == to
+		{
+			return components;
+		}
+		
+		// Avoid a round trip through XYZ, and so rounding errors, between hex, `rgb()`, `hsl()` and `hwb()`.
+		if self.is_srgb_compatible() && to.is_srgb_compatible()
+		{
+			return to.from_srgb(self.to_srgb(components));
+		}
+		
+		to.from_xyz_d65(self.to_xyz_d65(components))
+	}
+	
+	/// Converts components in this color space to CIE XYZ relative to a D65 white point.
+	pub fn to_xyz_d65(self, components: [f64; 3]) -> [f64; 3]
+	{
+		match self
+		{
+			Srgb | Hsl | Hwb => Self::multiply(&LinearSrgbToXyzD65, Self::map(self.to_srgb(components), Self::srgb_to_linear)),
+			
+			SrgbLinear => Self::multiply(&LinearSrgbToXyzD65, components),
+			
+			DisplayP3 => Self::multiply(&LinearDisplayP3ToXyzD65, Self::map(components, Self::srgb_to_linear)),
+			
+			A98Rgb => Self::multiply(&LinearA98RgbToXyzD65, Self::map(components, Self::a98_rgb_to_linear)),
+			
+			ProphotoRgb => Self::multiply(&XyzD50ToXyzD65, Self::multiply(&LinearProphotoRgbToXyzD50, Self::map(components, Self::prophoto_rgb_to_linear))),
+			
+			Rec2020 => Self::multiply(&LinearRec2020ToXyzD65, Self::map(components, Self::rec2020_to_linear)),
+			
+			XyzD50 => Self::multiply(&XyzD50ToXyzD65, components),
+			
+			XyzD65 => components,
+			
+			Lab => Self::multiply(&XyzD50ToXyzD65, Self::lab_to_xyz_d50(components)),
+			
+			Lch => Lab.to_xyz_d65(Self::polar_to_rectangular(components)),
+			
+			Oklab =>
+			{
+				let lms = Self::multiply(&OklabToLms, components);
+				Self::multiply(&LmsToXyzD65, Self::map(lms, |value| value * value * value))
+			}
+			
+			Oklch => Oklab.to_xyz_d65(Self::polar_to_rectangular(components)),
+		}
+	}
+	
+	/// Converts CIE XYZ relative to a D65 white point to components in this color space.
+	pub fn from_xyz_d65(self, xyz: [f64; 3]) -> [f64; 3]
+	{
+		match self
+		{
+			Srgb | Hsl | Hwb => self.from_srgb(Self::map(Self::multiply(&XyzD65ToLinearSrgb, xyz), Self::linear_to_srgb)),
+			
+			SrgbLinear => Self::multiply(&XyzD65ToLinearSrgb, xyz),
+			
+			DisplayP3 => Self::map(Self::multiply(&XyzD65ToLinearDisplayP3, xyz), Self::linear_to_srgb),
+			
+			A98Rgb => Self::map(Self::multiply(&XyzD65ToLinearA98Rgb, xyz), Self::linear_to_a98_rgb),
+			
+			ProphotoRgb => Self::map(Self::multiply(&XyzD50ToLinearProphotoRgb, Self::multiply(&XyzD65ToXyzD50, xyz)), Self::linear_to_prophoto_rgb),
+			
+			Rec2020 => Self::map(Self::multiply(&XyzD65ToLinearRec2020, xyz), Self::linear_to_rec2020),
+			
+			XyzD50 => Self::multiply(&XyzD65ToXyzD50, xyz),
+			
+			XyzD65 => xyz,
+			
+			Lab => Self::xyz_d50_to_lab(Self::multiply(&XyzD65ToXyzD50, xyz)),
+			
+			Lch => Self::rectangular_to_polar(Lab.from_xyz_d65(xyz)),
+			
+			Oklab =>
+			{
+				let lms = Self::multiply(&XyzD65ToLms, xyz);
+				Self::multiply(&LmsToOklab, Self::map(lms, f64::cbrt))
+			}
+			
+			Oklch => Self::rectangular_to_polar(Oklab.from_xyz_d65(xyz)),
+		}
+	}
+	
+	/// https://drafts.csswg.org/css-color-4/#hsl-to-rgb and https://drafts.csswg.org/css-color-4/#hwb-to-rgb
+	fn to_srgb(self, components: [f64; 3]) -> [f64; 3]
+	{
+		match self
+		{
+			Hsl =>
+			{
+				let hue = components[0];
+				let saturation = components[1] / 100.0;
+				let lightness = components[2] / 100.0;
+				
+				let f = |n: f64|
+				{
+					let k = ((n + hue / 30.0) % 12.0 + 12.0) % 12.0;
+					let a = saturation * lightness.min(1.0 - lightness);
+					lightness - a * (k - 3.0).min(9.0 - k).min(1.0).max(-1.0)
+				};
+				[f(0.0), f(8.0), f(4.0)]
+			}
+			
+			Hwb =>
+			{
+				let whiteness = components[1] / 100.0;
+				let blackness = components[2] / 100.0;
+				
+				if whiteness + blackness >= 1.0
+				{
+					let gray = whiteness / (whiteness + blackness);
+					return [gray, gray, gray];
+				}
+				
+				let rgb = Hsl.to_srgb([components[0], 100.0, 50.0]);
+				Self::map(rgb, |value| value * (1.0 - whiteness - blackness) + whiteness)
+			}
+			
+			_ => components,
+		}
+	}
+	
+	/// https://drafts.csswg.org/css-color-4/#rgb-to-hsl and https://drafts.csswg.org/css-color-4/#rgb-to-hwb
+	fn from_srgb(self, rgb: [f64; 3]) -> [f64; 3]
+	{
+		let maximum = rgb[0].max(rgb[1]).max(rgb[2]);
+		let minimum = rgb[0].min(rgb[1]).min(rgb[2]);
+		let difference = maximum - minimum;
+		
+		let hue = if difference == 0.0
+		{
+			0.0
+		}
+		else if maximum == rgb[0]
+		{
+			(rgb[1] - rgb[2]) / difference + if rgb[1] < rgb[2] { 6.0 } else { 0.0 }
+		}
+		else if maximum == rgb[1]
+		{
+			(rgb[2] - rgb[0]) / difference + 2.0
+		}
+		else
+		{
+			(rgb[0] - rgb[1]) / difference + 4.0
+		} * 60.0;
+		
+		match self
+		{
+			Hsl =>
+			{
+				let lightness = (minimum + maximum) / 2.0;
+				let saturation = if lightness == 0.0 || lightness == 1.0
+				{
+					0.0
+				}
+				else
+				{
+					(maximum - lightness) / lightness.min(1.0 - lightness)
+				};
+				[hue, saturation * 100.0, lightness * 100.0]
+			}
+			
+			Hwb => [hue, minimum * 100.0, (1.0 - maximum) * 100.0],
+			
+			_ => rgb,
+		}
+	}
+	
+	#[inline(always)]
+	fn srgb_to_linear(value: f64) -> f64
+	{
+		let absolute = value.abs();
+		if absolute <= 0.04045
+		{
+			value / 12.92
+		}
+		else
+		{
+			value.signum() * ((absolute + 0.055) / 1.055).powf(2.4)
+		}
+	}
+	
+	#[inline(always)]
+	fn linear_to_srgb(value: f64) -> f64
+	{
+		let absolute = value.abs();
+		if absolute > 0.0031308
+		{
+			value.signum() * (1.055 * absolute.powf(1.0 / 2.4) - 0.055)
+		}
+		else
+		{
+			12.92 * value
+		}
+	}
+	
+	#[inline(always)]
+	fn a98_rgb_to_linear(value: f64) -> f64
+	{
+		value.signum() * value.abs().powf(563.0 / 256.0)
+	}
+	
+	#[inline(always)]
+	fn linear_to_a98_rgb(value: f64) -> f64
+	{
+		value.signum() * value.abs().powf(256.0 / 563.0)
+	}
+	
+	#[inline(always)]
+	fn prophoto_rgb_to_linear(value: f64) -> f64
+	{
+		let absolute = value.abs();
+		if absolute <= 16.0 / 512.0
+		{
+			value / 16.0
+		}
+		else
+		{
+			value.signum() * absolute.powf(1.8)
+		}
+	}
+	
+	#[inline(always)]
+	fn linear_to_prophoto_rgb(value: f64) -> f64
+	{
+		let absolute = value.abs();
+		if absolute >= 1.0 / 512.0
+		{
+			value.signum() * absolute.powf(1.0 / 1.8)
+		}
+		else
+		{
+			16.0 * value
+		}
+	}
+	
+	#[inline(always)]
+	fn rec2020_to_linear(value: f64) -> f64
+	{
+		let absolute = value.abs();
+		if absolute < Rec2020Beta * 4.5
+		{
+			value / 4.5
+		}
+		else
+		{
+			value.signum() * ((absolute + Rec2020Alpha - 1.0) / Rec2020Alpha).powf(1.0 / 0.45)
+		}
+	}
+	
+	#[inline(always)]
+	fn linear_to_rec2020(value: f64) -> f64
+	{
+		let absolute = value.abs();
+		if absolute > Rec2020Beta
+		{
+			value.signum() * (Rec2020Alpha * absolute.powf(0.45) - (Rec2020Alpha - 1.0))
+		}
+		else
+		{
+			4.5 * value
+		}
+	}
+	
+	/// https://drafts.csswg.org/css-color-4/#color-conversion-code
+	fn lab_to_xyz_d50(lab: [f64; 3]) -> [f64; 3]
+	{
+		let f1 = (lab[0] + 16.0) / 116.0;
+		let f0 = lab[1] / 500.0 + f1;
+		let f2 = f1 - lab[2] / 200.0;
+		
+		let inverse = |f: f64| if f * f * f > LabEpsilon
+		{
+			f * f * f
+		}
+		else
+		{
+			(116.0 * f - 16.0) / LabKappa
+		};
+		
+		let y = if lab[0] > LabKappa * LabEpsilon
+		{
+			f1 * f1 * f1
+		}
+		else
+		{
+			lab[0] / LabKappa
+		};
+		
+		[inverse(f0) * D50WhitePoint[0], y * D50WhitePoint[1], inverse(f2) * D50WhitePoint[2]]
+	}
+	
+	/// https://drafts.csswg.org/css-color-4/#color-conversion-code
+	fn xyz_d50_to_lab(xyz: [f64; 3]) -> [f64; 3]
+	{
+		let f = |value: f64| if value > LabEpsilon
+		{
+			value.cbrt()
+		}
+		else
+		{
+			(LabKappa * value + 16.0) / 116.0
+		};
+		
+		let f0 = f(xyz[0] / D50WhitePoint[0]);
+		let f1 = f(xyz[1] / D50WhitePoint[1]);
+		let f2 = f(xyz[2] / D50WhitePoint[2]);
+		
+		[116.0 * f1 - 16.0, 500.0 * (f0 - f1), 200.0 * (f1 - f2)]
+	}
+	
+	#[inline(always)]
+	fn polar_to_rectangular(polar: [f64; 3]) -> [f64; 3]
+	{
+		let hue = polar[2].to_radians();
+		[polar[0], polar[1] * hue.cos(), polar[1] * hue.sin()]
+	}
+	
+	/// An achromatic color's hue is powerless, so is zero.
+	#[inline(always)]
+	fn rectangular_to_polar(rectangular: [f64; 3]) -> [f64; 3]
+	{
+		const AchromaticChroma: f64 = 1e-9;
+		
+		let chroma = (rectangular[1] * rectangular[1] + rectangular[2] * rectangular[2]).sqrt();
+		let hue = if chroma <= AchromaticChroma
+		{
+			0.0
+		}
+		else
+		{
+			let hue = rectangular[2].atan2(rectangular[1]).to_degrees();
+			if hue < 0.0
+			{
+				hue + 360.0
+			}
+			else
+			{
+				hue
+			}
+		};
+		[rectangular[0], chroma, hue]
+	}
+	
+	#[inline(always)]
+	fn map<F: Fn(f64) -> f64>(components: [f64; 3], function: F) -> [f64; 3]
+	{
+		[function(components[0]), function(components[1]), function(components[2])]
+	}
+	
+	#[inline(always)]
+	fn multiply(matrix: &[[f64; 3]; 3], vector: [f64; 3]) -> [f64; 3]
+	{
+		let row = |row: &[f64; 3]| row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2];
+		[row(&matrix[0]), row(&matrix[1]), row(&matrix[2])]
+	}
+}
+
+const LabEpsilon: f64 = 216.0 / 24389.0;
+
+const LabKappa: f64 = 24389.0 / 27.0;
+
+const D50WhitePoint: [f64; 3] = [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
+
+const Rec2020Alpha: f64 = 1.09929682680944;
+
+const Rec2020Beta: f64 = 0.018053968510807;
+
+const LinearSrgbToXyzD65: [[f64; 3]; 3] =
+[
+	[0.41239079926595934, 0.357584339383878, 0.1804807884018343],
+	[0.21263900587151027, 0.715168678767756, 0.07219231536073371],
+	[0.01933081871559182, 0.11919477979462598, 0.9505321522496607],
+];
+
+const XyzD65ToLinearSrgb: [[f64; 3]; 3] =
+[
+	[3.2409699419045226, -1.537383177570094, -0.4986107602930034],
+	[-0.9692436362808796, 1.8759675015077202, 0.04155505740717559],
+	[0.05563007969699366, -0.20397695888897652, 1.0569715142428786],
+];
+
+const LinearDisplayP3ToXyzD65: [[f64; 3]; 3] =
+[
+	[0.4865709486482162, 0.26566769316909306, 0.1982172852343625],
+	[0.2289745640697488, 0.6917385218365064, 0.079286914093745],
+	[0.0, 0.04511338185890264, 1.043944368900976],
+];
+
+const XyzD65ToLinearDisplayP3: [[f64; 3]; 3] =
+[
+	[2.493496911941425, -0.9313836179191239, -0.40271078445071684],
+	[-0.8294889695615747, 1.7626640603183463, 0.023624685841943577],
+	[0.03584583024378447, -0.07617238926804182, 0.9568845240076872],
+];
+
+const LinearA98RgbToXyzD65: [[f64; 3]; 3] =
+[
+	[0.5766690429101305, 0.1855582379065463, 0.1882286462349947],
+	[0.29734497525053605, 0.6273635662554661, 0.07529145849399788],
+	[0.02703136138641234, 0.07068885253582723, 0.9913375368376388],
+];
+
+const XyzD65ToLinearA98Rgb: [[f64; 3]; 3] =
+[
+	[2.0415879038107465, -0.5650069742788596, -0.34473135077832956],
+	[-0.9692436362808795, 1.8759675015077202, 0.04155505740717557],
+	[0.013444280632031142, -0.11836239223101838, 1.0151749943912054],
+];
+
+const LinearProphotoRgbToXyzD50: [[f64; 3]; 3] =
+[
+	[0.7977604896723027, 0.13518583717574031, 0.0313493495815248],
+	[0.2880711282292934, 0.7118432178101014, 0.00008565396060525902],
+	[0.0, 0.0, 0.8251046025104601],
+];
+
+const XyzD50ToLinearProphotoRgb: [[f64; 3]; 3] =
+[
+	[1.3457989731028281, -0.25558010007997534, -0.05110628506753401],
+	[-0.5446224939028347, 1.5082327413132781, 0.02053603239147973],
+	[0.0, 0.0, 1.2119675456389454],
+];
+
+const LinearRec2020ToXyzD65: [[f64; 3]; 3] =
+[
+	[0.6369580483012914, 0.14461690358620832, 0.1688809751641721],
+	[0.2627002120112671, 0.6779980715188708, 0.05930171646986196],
+	[0.0, 0.028072693049087428, 1.060985057710791],
+];
+
+const XyzD65ToLinearRec2020: [[f64; 3]; 3] =
+[
+	[1.716651187971268, -0.355670783776392, -0.253366281373660],
+	[-0.666684351832489, 1.616481236634939, 0.0157685458139111],
+	[0.017639857445311, -0.042770613257809, 0.942103121235474],
+];
+
+/// Bradford chromatic adaptation.
+const XyzD65ToXyzD50: [[f64; 3]; 3] =
+[
+	[1.0479298208405488, 0.022946793341019088, -0.05019222954313557],
+	[0.029627815688159344, 0.990434484573249, -0.01707382502938514],
+	[-0.009243058152591178, 0.015055144896577895, 0.7518742899580008],
+];
+
+/// Bradford chromatic adaptation.
+const XyzD50ToXyzD65: [[f64; 3]; 3] =
+[
+	[0.9554734527042182, -0.023098536874261423, 0.0632593086610217],
+	[-0.028369706963208136, 1.0099954580058226, 0.021041398966943008],
+	[0.012314001688319899, -0.020507696433477912, 1.3303659366080753],
+];
+
+const XyzD65ToLms: [[f64; 3]; 3] =
+[
+	[0.8190224379967030, 0.3619062600528904, -0.1288737815209879],
+	[0.0329836539323885, 0.9292868615863434, 0.0361446663506424],
+	[0.0481771893596242, 0.2642395317527308, 0.6335478284694309],
+];
+
+const LmsToXyzD65: [[f64; 3]; 3] =
+[
+	[1.2268798758459243, -0.5578149944602171, 0.2813910456659647],
+	[-0.0405757452148008, 1.1122868032803170, -0.0717110580655164],
+	[-0.0763729366746601, -0.4214933324022432, 1.5869240198367816],
+];
+
+const LmsToOklab: [[f64; 3]; 3] =
+[
+	[0.2104542683093140, 0.7936177747023054, -0.0040720430116193],
+	[1.9779985324311684, -2.4285922420485799, 0.4505937096174110],
+	[0.0259040424655478, 0.7827717124575296, -0.8086757549230774],
+];
+
+const OklabToLms: [[f64; 3]; 3] =
+[
+	[1.0, 0.3963377773761749, 0.2158037573099136],
+	[1.0, -0.1055613458156586, -0.0638541728258133],
+	[1.0, -0.0894841775298119, -1.2914855480194092],
+];
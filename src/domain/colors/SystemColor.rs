@@ -0,0 +1,66 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+define_css_keyword_enum!
+(
+	SystemColor:
+	"accentcolor" => AccentColor,
+	"accentcolortext" => AccentColorText,
+	"activetext" => ActiveText,
+	"buttonborder" => ButtonBorder,
+	"buttonface" => ButtonFace,
+	"buttontext" => ButtonText,
+	"canvas" => Canvas,
+	"canvastext" => CanvasText,
+	"field" => Field,
+	"fieldtext" => FieldText,
+	"graytext" => GrayText,
+	"highlight" => Highlight,
+	"highlighttext" => HighlightText,
+	"linktext" => LinkText,
+	"mark" => Mark,
+	"marktext" => MarkText,
+	"selecteditem" => SelectedItem,
+	"selecteditemtext" => SelectedItemText,
+	"visitedtext" => VisitedText,
+	"activeborder" => ActiveBorder,
+	"activecaption" => ActiveCaption,
+	"appworkspace" => AppWorkspace,
+	"background" => Background,
+	"buttonhighlight" => ButtonHighlight,
+	"buttonshadow" => ButtonShadow,
+	"captiontext" => CaptionText,
+	"inactiveborder" => InactiveBorder,
+	"inactivecaption" => InactiveCaption,
+	"inactivecaptiontext" => InactiveCaptionText,
+	"infobackground" => InfoBackground,
+	"infotext" => InfoText,
+	"menu" => Menu,
+	"menutext" => MenuText,
+	"scrollbar" => Scrollbar,
+	"threeddarkshadow" => ThreeDDarkShadow,
+	"threedface" => ThreeDFace,
+	"threedhighlight" => ThreeDHighlight,
+	"threedlightshadow" => ThreeDLightShadow,
+	"threedshadow" => ThreeDShadow,
+	"window" => Window,
+	"windowframe" => WindowFrame,
+	"windowtext" => WindowText
+);
+
+impl SystemColor
+{
+	/// Deprecated system colors (https://drafts.csswg.org/css-color-4/#deprecated-system-colors) are still valid, but browsers map them to other system colors.
+	#[inline(always)]
+	pub fn is_deprecated(&self) -> bool
+	{
+		use self::SystemColor::*;
+		
+		match *self
+		{
+			AccentColor | AccentColorText | ActiveText | ButtonBorder | ButtonFace | ButtonText | Canvas | CanvasText | Field | FieldText | GrayText | Highlight | HighlightText | LinkText | Mark | MarkText | SelectedItem | SelectedItemText | VisitedText => false,
+			_ => true,
+		}
+	}
+}
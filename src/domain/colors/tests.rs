@@ -0,0 +1,64 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+
+
+fn absolute(css: &str) -> AbsoluteColor
+{
+	Color::parse_property_value_from_css(css).unwrap().absolute().unwrap().clone()
+}
+
+fn assert_components_near(color: &AbsoluteColor, expected: [f32; 3], tolerance: f32)
+{
+	for index in 0 .. 3
+	{
+		assert!((color.components[index] - expected[index]).abs() <= tolerance, "{:?} is not near {:?}", color.components, expected);
+	}
+}
+
+#[test]
+fn colors_convert_between_color_spaces()
+{
+	let red = absolute("red");
+	assert_components_near(&red.to_color_space(Lab), [54.2917, 80.8125, 69.8851], 0.01);
+	assert_components_near(&red.to_color_space(Oklch), [0.6280, 0.2577, 29.2339], 0.001);
+	assert_components_near(&red.to_color_space(Hsl), [0.0, 100.0, 50.0], 0.01);
+	assert_components_near(&absolute("white").to_color_space(Oklab), [1.0, 0.0, 0.0], 0.0001);
+	
+	assert_eq!(absolute("hsl(120 100% 50%)").to_rgba8(), [0, 255, 0, 255]);
+	assert_eq!(absolute("hwb(240 0% 0% / 50%)").to_rgba8(), [0, 0, 255, 128]);
+}
+
+#[test]
+fn colors_round_trip_through_every_color_space()
+{
+	let color = AbsoluteColor::from_rgba8(10, 200, 30, 255);
+	for &colorSpace in &[Srgb, Hsl, Hwb, Lab, Lch, Oklab, Oklch, SrgbLinear, DisplayP3, A98Rgb, ProphotoRgb, Rec2020, XyzD50, XyzD65]
+	{
+		assert_eq!(color.to_color_space(colorSpace).to_rgba8(), [10, 200, 30, 255], "{:?}", colorSpace);
+	}
+}
+
+#[test]
+fn colors_outside_the_srgb_gamut_are_clipped()
+{
+	let p3Red = absolute("color(display-p3 1 0 0)");
+	assert!(!p3Red.is_in_srgb_gamut());
+	assert_eq!(p3Red.to_rgba8(), [255, 0, 0, 255]);
+	assert!(absolute("color(display-p3 0.5 0.5 0.5)").is_in_srgb_gamut());
+}
+
+#[test]
+fn contrast_ratios_follow_wcag()
+{
+	let black = absolute("black");
+	let white = absolute("#fff");
+	assert_eq!(black.relative_luminance(), 0.0);
+	assert!((white.relative_luminance() - 1.0).abs() < 1e-6);
+	assert!((black.contrast_ratio(&white) - 21.0).abs() < 1e-4);
+	assert!((white.contrast_ratio(&black) - 21.0).abs() < 1e-4);
+	assert_eq!(white.contrast_ratio(&white), 1.0);
+	assert!((absolute("#777").contrast_ratio(&white) - 4.48).abs() < 0.01);
+}
@@ -0,0 +1,17 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A definition of a custom property (`--*`) by a property declaration; see `CustomPropertyGraph`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomPropertyDefinition
+{
+	/// The custom property's name, including the leading `--`.
+	pub name: Atom,
+	
+	/// The names of the custom properties the definition's value refers to in `var()` functions, including those in fallbacks.
+	pub references: BTreeSet<Atom>,
+	
+	/// Where the property declaration was in the source CSS, if source spans were recorded when parsing.
+	pub source_span: Option<SourceSpan>,
+}
@@ -0,0 +1,180 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The graph of the custom properties (`--*`) defined by property declarations in a stylesheet, including those in nested rules, and of their uses in `var()` functions; see `Stylesheet::custom_property_graph()`.
+///
+/// A stylesheet does not know which elements its rules match, so all the definitions of a custom property are treated as one.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CustomPropertyGraph
+{
+	/// The definitions, in the order they were found.
+	pub definitions: Vec<CustomPropertyDefinition>,
+	
+	/// The uses, in the order they were found; a use in the fallback of a `var()` function is found after that of the function.
+	pub uses: Vec<CustomPropertyUse>,
+}
+
+impl CustomPropertyGraph
+{
+	/// Is this custom property defined?
+	#[inline(always)]
+	pub fn is_defined(&self, name: &str) -> bool
+	{
+		self.definitions.iter().any(|definition| &definition.name[..] == name)
+	}
+	
+	/// Is this custom property used?
+	#[inline(always)]
+	pub fn is_used(&self, name: &str) -> bool
+	{
+		self.uses.iter().any(|customPropertyUse| &customPropertyUse.name[..] == name)
+	}
+	
+	/// The defined custom properties, and, for each, the defined custom properties which any of its definitions refer to.
+	pub fn dependencies(&self) -> BTreeMap<&Atom, BTreeSet<&Atom>>
+	{
+		let mut dependencies: BTreeMap<&Atom, BTreeSet<&Atom>> = BTreeMap::new();
+		for definition in self.definitions.iter()
+		{
+			dependencies.entry(&definition.name).or_insert_with(BTreeSet::new);
+		}
+		
+		for definition in self.definitions.iter()
+		{
+			let defined: Vec<&Atom> = definition.references.iter().filter(|reference| self.is_defined(reference)).collect();
+			dependencies.get_mut(&definition.name).unwrap().extend(defined);
+		}
+		
+		dependencies
+	}
+	
+	/// The custom properties which depend on themselves, directly or through others; such dependency cycles make them invalid at computed-value time: https://drafts.csswg.org/css-variables/#cycles
+	/// Each cycle is returned once, as the names of the custom properties in it in sorted order; the cycles are also sorted.
+	pub fn cycles(&self) -> Vec<Vec<Atom>>
+	{
+		let dependencies = self.dependencies();
+		
+		let reachable: BTreeMap<&Atom, BTreeSet<&Atom>> = dependencies.keys().map(|&name| (name, Self::reachable_from(name, &dependencies))).collect();
+		
+		let mut cycles = Vec::new();
+		let mut inACycle = BTreeSet::new();
+		for (&name, reachableFromName) in reachable.iter()
+		{
+			if inACycle.contains(name) || !reachableFromName.contains(name)
+			{
+				continue;
+			}
+			
+			let cycle: Vec<Atom> = reachableFromName.iter().filter(|&&other| reachable[other].contains(name)).map(|&other| other.clone()).collect();
+			inACycle.extend(cycle.iter().cloned());
+			cycles.push(cycle);
+		}
+		cycles
+	}
+	
+	/// The uses, in `var()` functions without a fallback, of custom properties which are not defined; such uses make the property declarations using them invalid at computed-value time.
+	/// Returns them in the order they were found.
+	#[inline(always)]
+	pub fn undefined_uses_without_fallbacks(&self) -> Vec<&CustomPropertyUse>
+	{
+		self.uses.iter().filter(|customPropertyUse| !customPropertyUse.has_fallback && !self.is_defined(&customPropertyUse.name)).collect()
+	}
+	
+	/// The definitions of custom properties which are never used.
+	/// Returns them in the order they were found; a custom property defined more than once is returned once for each definition.
+	#[inline(always)]
+	pub fn unused_definitions(&self) -> Vec<&CustomPropertyDefinition>
+	{
+		self.definitions.iter().filter(|definition| !self.is_used(&definition.name)).collect()
+	}
+	
+	pub(crate) fn new(cssRules: &CssRules) -> Self
+	{
+		let mut graph = Self::default();
+		graph.add_css_rules(cssRules);
+		graph
+	}
+	
+	fn add_css_rules(&mut self, cssRules: &CssRules)
+	{
+		use self::CssRule::*;
+		
+		for cssRule in cssRules.0.iter()
+		{
+			match *cssRule
+			{
+				Style(ref rule) => self.add_property_declarations(&rule.property_declarations),
+				
+				Keyframes(ref rule) => for keyframe in rule.keyframes.iter()
+				{
+					self.add_property_declarations(&keyframe.property_declarations)
+				},
+				
+				Page(ref rule) => self.add_property_declarations(&rule.property_declarations),
+				
+				Document(ref rule) => self.add_css_rules(&rule.rules),
+				
+				Media(ref rule) => self.add_css_rules(&rule.rules),
+				
+				Supports(ref rule) => self.add_css_rules(&rule.rules),
+				
+				_ => (),
+			}
+		}
+	}
+	
+	fn add_property_declarations<I: HasImportance>(&mut self, propertyDeclarations: &PropertyDeclarations<I>)
+	{
+		for propertyDeclaration in propertyDeclarations.0.iter()
+		{
+			if propertyDeclaration.hasACustomPropertyName()
+			{
+				self.definitions.push
+				(
+					CustomPropertyDefinition
+					{
+						name: propertyDeclaration.name.clone(),
+						references: propertyDeclaration.references.clone(),
+						source_span: propertyDeclaration.source_span,
+					}
+				);
+			}
+			
+			if let UnparsedPropertyValue::SpecifiedValue(ref specifiedValue) = propertyDeclaration.value
+			{
+				let uses = &mut self.uses;
+				specifiedValue.walk_component_values(&mut |componentValue|
+				{
+					if let Some((name, fallback)) = componentValue.var_function_arguments()
+					{
+						uses.push
+						(
+							CustomPropertyUse
+							{
+								name: Atom::from(name),
+								has_fallback: fallback.is_some(),
+								property_name: propertyDeclaration.name.clone(),
+								source_span: propertyDeclaration.source_span,
+							}
+						);
+					}
+				});
+			}
+		}
+	}
+	
+	fn reachable_from<'a>(name: &'a Atom, dependencies: &BTreeMap<&'a Atom, BTreeSet<&'a Atom>>) -> BTreeSet<&'a Atom>
+	{
+		let mut reachable = BTreeSet::new();
+		let mut toVisit: Vec<&Atom> = dependencies[name].iter().cloned().collect();
+		while let Some(next) = toVisit.pop()
+		{
+			if reachable.insert(next)
+			{
+				toVisit.extend(dependencies[next].iter().cloned());
+			}
+		}
+		reachable
+	}
+}
@@ -0,0 +1,20 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A use of a custom property in a `var()` function in a property declaration's value; see `CustomPropertyGraph`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomPropertyUse
+{
+	/// The name of the custom property used, including the leading `--`.
+	pub name: Atom,
+	
+	/// Does the `var()` function have a fallback?
+	pub has_fallback: bool,
+	
+	/// The name of the property whose declaration uses the custom property; this may itself be a custom property.
+	pub property_name: Atom,
+	
+	/// Where the property declaration was in the source CSS, if source spans were recorded when parsing.
+	pub source_span: Option<SourceSpan>,
+}
@@ -0,0 +1,58 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `box-shadow` property: https://drafts.csswg.org/css-backgrounds-3/#box-shadow
+/// An empty list is `none`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BoxShadowList(pub Vec<Shadow>);
+
+impl ToCss for BoxShadowList
+{
+	#[inline(always)]
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		write_shadows(&self.0, dest)
+	}
+}
+
+impl PropertyValue for BoxShadowList
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"box-shadow",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		parse_shadows(context, input, true).map(BoxShadowList)
+	}
+}
+
+impl BoxShadowList
+{
+	/// Whether this is `none`.
+	#[inline(always)]
+	pub fn is_none(&self) -> bool
+	{
+		self.0.is_empty()
+	}
+	
+	/// Whether any shadow uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		self.0.iter().any(Shadow::is_var)
+	}
+	
+	/// Calls `rewrite` with the color of every shadow which specifies one, so that it can be changed in place.
+	#[inline(always)]
+	pub fn rewrite_colors<Rewrite: FnMut(&mut Color)>(&mut self, rewrite: &mut Rewrite)
+	{
+		for shadow in self.0.iter_mut()
+		{
+			shadow.rewrite_colors(rewrite);
+		}
+	}
+}
@@ -0,0 +1,175 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A filter function of the `filter` and `backdrop-filter` properties: https://drafts.fxtf.org/filter-effects-1/#filter-functions
+/// An omitted argument is parsed as its default value, such as `1` for `brightness()`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterFunction
+{
+	/// `blur(radius)`
+	blur(CalculablePropertyValue<LengthUnit<CssUnsignedNumber>>),
+	
+	/// `brightness(amount)`
+	brightness(CalculablePropertyValue<NumberOrPercentageUnit<CssUnsignedNumber>>),
+	
+	/// `contrast(amount)`
+	contrast(CalculablePropertyValue<NumberOrPercentageUnit<CssUnsignedNumber>>),
+	
+	/// `drop-shadow(shadow)`; the shadow never has a spread distance or `inset`.
+	drop_shadow(Shadow),
+	
+	/// `grayscale(amount)`
+	grayscale(CalculablePropertyValue<NumberOrPercentageUnit<CssUnsignedNumber>>),
+	
+	/// `hue-rotate(angle)`
+	hue_rotate(CalculablePropertyValue<AngleUnit<CssSignedNumber>>),
+	
+	/// `invert(amount)`
+	invert(CalculablePropertyValue<NumberOrPercentageUnit<CssUnsignedNumber>>),
+	
+	/// `opacity(amount)`
+	opacity(CalculablePropertyValue<NumberOrPercentageUnit<CssUnsignedNumber>>),
+	
+	/// `saturate(amount)`
+	saturate(CalculablePropertyValue<NumberOrPercentageUnit<CssUnsignedNumber>>),
+	
+	/// `sepia(amount)`
+	sepia(CalculablePropertyValue<NumberOrPercentageUnit<CssUnsignedNumber>>),
+	
+	/// `url(reference)` to an SVG filter element.
+	url(SpecifiedUrl),
+}
+
+impl ToCss for FilterFunction
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::FilterFunction::*;
+		
+		#[inline(always)]
+		fn write<W: fmt::Write, T: ToCss>(dest: &mut W, name: &str, argument: &T) -> fmt::Result
+		{
+			dest.write_str(name)?;
+			dest.write_char('(')?;
+			argument.to_css(dest)?;
+			dest.write_char(')')
+		}
+		
+		match *self
+		{
+			blur(ref radius) => write(dest, "blur", radius),
+			
+			brightness(ref amount) => write(dest, "brightness", amount),
+			
+			contrast(ref amount) => write(dest, "contrast", amount),
+			
+			drop_shadow(ref shadow) => write(dest, "drop-shadow", shadow),
+			
+			grayscale(ref amount) => write(dest, "grayscale", amount),
+			
+			hue_rotate(ref angle) => write(dest, "hue-rotate", angle),
+			
+			invert(ref amount) => write(dest, "invert", amount),
+			
+			opacity(ref amount) => write(dest, "opacity", amount),
+			
+			saturate(ref amount) => write(dest, "saturate", amount),
+			
+			sepia(ref amount) => write(dest, "sepia", amount),
+			
+			url(ref reference) => reference.to_css(dest),
+		}
+	}
+}
+
+impl FilterFunction
+{
+	/// Whether an argument uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		use self::FilterFunction::*;
+		
+		match *self
+		{
+			blur(ref radius) => radius.is_var(),
+			
+			brightness(ref amount) | contrast(ref amount) | grayscale(ref amount) | invert(ref amount) | opacity(ref amount) | saturate(ref amount) | sepia(ref amount) => amount.is_var(),
+			
+			drop_shadow(ref shadow) => shadow.is_var(),
+			
+			hue_rotate(ref angle) => angle.is_var(),
+			
+			url(_) => false,
+		}
+	}
+	
+	pub(crate) fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::FilterFunction::*;
+		
+		if let Ok(reference) = input.try(|input| SpecifiedUrl::parse(context, input))
+		{
+			return Ok(url(reference));
+		}
+		
+		let name = input.expect_function()?.clone();
+		input.parse_nested_block(|input|
+		{
+			#[inline(always)]
+			fn amount<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<CalculablePropertyValue<NumberOrPercentageUnit<CssUnsignedNumber>>, ParseError<'i, CustomParseError<'i>>>
+			{
+				if input.is_exhausted()
+				{
+					Ok(CalculablePropertyValue::Constant(NumberOrPercentageUnit::IsNumber(CssUnsignedNumber::One)))
+				}
+				else
+				{
+					NumberOrPercentageUnit::parse_one_outside_calc_function(context, input)
+				}
+			}
+			
+			match_ignore_ascii_case!
+			{
+				&*name,
+				
+				"blur" => if input.is_exhausted()
+				{
+					Ok(blur(CalculablePropertyValue::Constant(LengthUnit::default())))
+				}
+				else
+				{
+					Ok(blur(LengthUnit::parse_one_outside_calc_function(context, input)?))
+				},
+				
+				"brightness" => Ok(brightness(amount(context, input)?)),
+				
+				"contrast" => Ok(contrast(amount(context, input)?)),
+				
+				"drop-shadow" => Ok(drop_shadow(Shadow::parse(context, input, false)?)),
+				
+				"grayscale" => Ok(grayscale(amount(context, input)?)),
+				
+				"hue-rotate" => if input.is_exhausted()
+				{
+					Ok(hue_rotate(CalculablePropertyValue::Constant(AngleUnit::deg(CssSignedNumber::Zero))))
+				}
+				else
+				{
+					Ok(hue_rotate(AngleUnit::parse_one_outside_calc_function(context, input)?))
+				},
+				
+				"invert" => Ok(invert(amount(context, input)?)),
+				
+				"opacity" => Ok(opacity(amount(context, input)?)),
+				
+				"saturate" => Ok(saturate(amount(context, input)?)),
+				
+				"sepia" => Ok(sepia(amount(context, input)?)),
+				
+				_ => Err(ParseError::Custom(CustomParseError::UnknownFilterFunction(name.clone()))),
+			}
+		})
+	}
+}
@@ -0,0 +1,88 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `filter` and `backdrop-filter` properties: https://drafts.fxtf.org/filter-effects-1/#FilterProperty
+/// An empty list is `none`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FilterList(pub Vec<FilterFunction>);
+
+impl ToCss for FilterList
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.is_none()
+		{
+			return dest.write_str("none");
+		}
+		
+		let mut afterFirst = false;
+		for filterFunction in self.0.iter()
+		{
+			if afterFirst
+			{
+				dest.write_char(' ')?;
+			}
+			else
+			{
+				afterFirst = true;
+			}
+			filterFunction.to_css(dest)?;
+		}
+		Ok(())
+	}
+}
+
+impl PropertyValue for FilterList
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"backdrop-filter",
+		"filter",
+	];
+	
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			return Ok(FilterList::default());
+		}
+		
+		let mut filterFunctions = vec![FilterFunction::parse(context, input)?];
+		while !input.is_exhausted()
+		{
+			filterFunctions.push(FilterFunction::parse(context, input)?);
+		}
+		Ok(FilterList(filterFunctions))
+	}
+}
+
+impl FilterList
+{
+	/// Whether this is `none`.
+	#[inline(always)]
+	pub fn is_none(&self) -> bool
+	{
+		self.0.is_empty()
+	}
+	
+	/// Whether any filter function uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		self.0.iter().any(FilterFunction::is_var)
+	}
+	
+	/// Calls `rewrite` with the color of every `drop-shadow()` which specifies one, so that it can be changed in place.
+	#[inline(always)]
+	pub fn rewrite_colors<Rewrite: FnMut(&mut Color)>(&mut self, rewrite: &mut Rewrite)
+	{
+		for filterFunction in self.0.iter_mut()
+		{
+			if let FilterFunction::drop_shadow(ref mut shadow) = *filterFunction
+			{
+				shadow.rewrite_colors(rewrite);
+			}
+		}
+	}
+}
@@ -0,0 +1,191 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A shadow of the `box-shadow` and `text-shadow` properties or of the `drop-shadow()` filter function: https://drafts.csswg.org/css-backgrounds-3/#shadow-syntax
+/// Only `box-shadow` permits a spread distance and `inset`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Shadow
+{
+	/// The color; `currentcolor` if `None`.
+	pub color: Option<Color>,
+	
+	/// The horizontal offset; positive values are to the right.
+	pub horizontal_offset: CalculablePropertyValue<LengthUnit<CssSignedNumber>>,
+	
+	/// The vertical offset; positive values are downwards.
+	pub vertical_offset: CalculablePropertyValue<LengthUnit<CssSignedNumber>>,
+	
+	/// The blur radius; zero if omitted.
+	pub blur_radius: CalculablePropertyValue<LengthUnit<CssUnsignedNumber>>,
+	
+	/// The spread distance; zero if omitted.
+	pub spread_distance: CalculablePropertyValue<LengthUnit<CssSignedNumber>>,
+	
+	/// Whether this is an inner shadow, `inset`.
+	pub inset: bool,
+}
+
+/// Writes `inset`, the lengths and the color; a blur radius and spread distance of zero are omitted, and other lengths of zero are written without a unit.
+impl ToCss for Shadow
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.inset
+		{
+			dest.write_str("inset ")?;
+		}
+		
+		Self::write_length(&self.horizontal_offset, dest)?;
+		dest.write_char(' ')?;
+		Self::write_length(&self.vertical_offset, dest)?;
+		
+		let hasSpreadDistance = !Self::is_absolute_zero(&self.spread_distance);
+		if hasSpreadDistance || !Self::is_absolute_zero(&self.blur_radius)
+		{
+			dest.write_char(' ')?;
+			Self::write_length(&self.blur_radius, dest)?;
+		}
+		if hasSpreadDistance
+		{
+			dest.write_char(' ')?;
+			self.spread_distance.to_css(dest)?;
+		}
+		
+		if let Some(ref color) = self.color
+		{
+			dest.write_char(' ')?;
+			color.to_css(dest)?;
+		}
+		Ok(())
+	}
+}
+
+impl Shadow
+{
+	/// Whether any length uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		self.horizontal_offset.is_var() || self.vertical_offset.is_var() || self.blur_radius.is_var() || self.spread_distance.is_var()
+	}
+	
+	/// Calls `rewrite` with the color, if it is specified, so that it can be changed in place.
+	#[inline(always)]
+	pub fn rewrite_colors<Rewrite: FnMut(&mut Color)>(&mut self, rewrite: &mut Rewrite)
+	{
+		if let Some(ref mut color) = self.color
+		{
+			rewrite(color);
+		}
+	}
+	
+	/// Parses `<color>? && [ <length>{2} <length [0,∞]>? <length>? ] && inset?`; the spread distance and `inset` are only parsed if `isBoxShadow` is true.
+	pub(crate) fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>, isBoxShadow: bool) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut color = None;
+		let mut lengths = None;
+		let mut inset = false;
+		loop
+		{
+			if lengths.is_none()
+			{
+				if let Ok(horizontal_offset) = input.try(|input| LengthUnit::parse_one_outside_calc_function(context, input))
+				{
+					let vertical_offset = LengthUnit::parse_one_outside_calc_function(context, input)?;
+					let blur_radius = match input.try(|input| LengthUnit::parse_one_outside_calc_function(context, input))
+					{
+						Ok(blur_radius) => blur_radius,
+						
+						// A negative blur radius makes the shadow invalid; it must not be taken to be the spread distance.
+						Err(_) => if input.try(|input| <LengthUnit<CssSignedNumber> as Unit>::parse_one_outside_calc_function(context, input)).is_ok()
+						{
+							return Err(ParseError::Custom(CustomParseError::ShadowBlurRadiusMayNotBeNegative))
+						}
+						else
+						{
+							Self::zero()
+						},
+					};
+					let spread_distance = if isBoxShadow
+					{
+						input.try(|input| LengthUnit::parse_one_outside_calc_function(context, input)).unwrap_or_else(|_| Self::zero())
+					}
+					else
+					{
+						Self::zero()
+					};
+					lengths = Some((horizontal_offset, vertical_offset, blur_radius, spread_distance));
+					continue;
+				}
+			}
+			
+			if color.is_none()
+			{
+				if let Ok(value) = input.try(|input| Color::parse(context, input))
+				{
+					color = Some(value);
+					continue;
+				}
+			}
+			
+			if isBoxShadow && !inset
+			{
+				if input.try(|input| input.expect_ident_matching("inset")).is_ok()
+				{
+					inset = true;
+					continue;
+				}
+			}
+			
+			break;
+		}
+		
+		match lengths
+		{
+			None => Err(ParseError::Custom(CustomParseError::ShadowMustHaveHorizontalAndVerticalOffsets)),
+			
+			Some((horizontal_offset, vertical_offset, blur_radius, spread_distance)) => Ok
+			(
+				Self
+				{
+					color,
+					horizontal_offset,
+					vertical_offset,
+					blur_radius,
+					spread_distance,
+					inset,
+				}
+			),
+		}
+	}
+	
+	#[inline(always)]
+	fn write_length<Number: CssNumber, W: fmt::Write>(length: &CalculablePropertyValue<LengthUnit<Number>>, dest: &mut W) -> fmt::Result
+	{
+		if Self::is_absolute_zero(length)
+		{
+			dest.write_char('0')
+		}
+		else
+		{
+			length.to_css(dest)
+		}
+	}
+	
+	#[inline(always)]
+	fn zero<Number: CssNumber>() -> CalculablePropertyValue<LengthUnit<Number>>
+	{
+		CalculablePropertyValue::Constant(LengthUnit::default())
+	}
+	
+	#[inline(always)]
+	fn is_absolute_zero<Number: CssNumber>(length: &CalculablePropertyValue<LengthUnit<Number>>) -> bool
+	{
+		match *length
+		{
+			CalculablePropertyValue::Constant(ref length) => length.is_absolute_zero(),
+			_ => false,
+		}
+	}
+}
@@ -0,0 +1,14 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Parses `none` or a comma-separated list of shadows.
+pub(crate) fn parse_shadows<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>, isBoxShadow: bool) -> Result<Vec<Shadow>, ParseError<'i, CustomParseError<'i>>>
+{
+	if input.try(|input| input.expect_ident_matching("none")).is_ok()
+	{
+		return Ok(Vec::new());
+	}
+	
+	input.parse_comma_separated(|input| Shadow::parse(context, input, isBoxShadow))
+}
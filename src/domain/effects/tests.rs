@@ -0,0 +1,26 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+
+
+#[test]
+fn shadows_with_a_negative_blur_radius_are_invalid()
+{
+	match BoxShadowList::parse_property_value_from_css("1px 1px -2px")
+	{
+		Err(ParseError::Custom(CustomParseError::ShadowBlurRadiusMayNotBeNegative)) => (),
+		result @ _ => panic!("{:?}", result.map(|value| value.to_css_string())),
+	}
+	assert!(TextShadowList::parse_property_value_from_css("1px 1px -2px").is_err());
+	assert!(FilterList::parse_property_value_from_css("drop-shadow(1px 1px -2px)").is_err());
+}
+
+#[test]
+fn shadows_serialize_in_their_shortest_form()
+{
+	assert_eq!(BoxShadowList::parse_property_value_from_css("1px 1px 0 -2px red").unwrap().to_css_string(), "1px 1px 0 -2px red");
+	assert_eq!(BoxShadowList::parse_property_value_from_css("red inset 0px 1px 0px 0px").unwrap().to_css_string(), "inset 0 1px red");
+	assert_eq!(TextShadowList::parse_property_value_from_css("1px 2px 3px").unwrap().to_css_string(), "1px 2px 3px");
+}
@@ -0,0 +1,107 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Evaluates the argument of a trigonometric function, which is an angle, inside a math function for another unit.
+/// Angles are never relative to fonts, viewports or percentages; `attr()` values are looked up with the property default of a zero angle.
+/// The conversions are trait objects rather than a type parameter because trigonometric functions may be nested, eg `sin(sin(1deg) * 1rad)`, and a type parameter would be instantiated as `AngleArgumentConversion<AngleArgumentConversion<...>>` without end.
+pub(crate) struct AngleArgumentConversion<'a, U: 'a + Unit>
+{
+	attribute_conversion: &'a dyn AttributeConversion<U>,
+	
+	css_variable_conversion: &'a dyn CssVariableConversion,
+}
+
+impl<'a, U: Unit> FontRelativeLengthConversion<U::Number> for AngleArgumentConversion<'a, U>
+{
+	#[inline(always)]
+	fn em(&self) -> U::Number
+	{
+		U::Number::Zero
+	}
+	
+	#[inline(always)]
+	fn ex(&self) -> U::Number
+	{
+		U::Number::Zero
+	}
+	
+	#[inline(always)]
+	fn ch(&self) -> U::Number
+	{
+		U::Number::Zero
+	}
+	
+	#[inline(always)]
+	fn rem(&self) -> U::Number
+	{
+		U::Number::Zero
+	}
+}
+
+impl<'a, U: Unit> ViewportPercentageLengthConversion<U::Number> for AngleArgumentConversion<'a, U>
+{
+	#[inline(always)]
+	fn vw(&self) -> U::Number
+	{
+		U::Number::Zero
+	}
+	
+	#[inline(always)]
+	fn vh(&self) -> U::Number
+	{
+		U::Number::Zero
+	}
+	
+	#[inline(always)]
+	fn vmin(&self) -> U::Number
+	{
+		U::Number::Zero
+	}
+	
+	#[inline(always)]
+	fn vmax(&self) -> U::Number
+	{
+		U::Number::Zero
+	}
+}
+
+impl<'a, U: Unit> PercentageConversion<U::Number> for AngleArgumentConversion<'a, U>
+{
+	#[inline(always)]
+	fn one_hundred_percent_in_absolute_units(&self) -> U::Number
+	{
+		U::Number::Zero
+	}
+}
+
+impl<'a, U: Unit> AttributeConversion<AngleUnit<U::Number>> for AngleArgumentConversion<'a, U>
+{
+	#[inline(always)]
+	fn attributeValue(&self, attribute_lower_case_name: &str) -> (Option<&str>, AngleUnit<U::Number>)
+	{
+		(self.attribute_conversion.attributeValue(attribute_lower_case_name).0, AngleUnit::default())
+	}
+}
+
+impl<'a, U: Unit> CssVariableConversion for AngleArgumentConversion<'a, U>
+{
+	#[inline(always)]
+	fn cssVariableValue(&self, css_variable_name_without_leading_double_dash: &str) -> Option<&str>
+	{
+		self.css_variable_conversion.cssVariableValue(css_variable_name_without_leading_double_dash)
+	}
+}
+
+impl<'a, U: Unit> AngleArgumentConversion<'a, U>
+{
+	#[inline(always)]
+	pub(crate) fn new<Conversion: 'a + AttributeConversion<U> + CssVariableConversion>(conversion: &'a Conversion) -> Self
+	{
+		Self
+		{
+			attribute_conversion: conversion,
+			css_variable_conversion: conversion,
+		}
+	}
+}
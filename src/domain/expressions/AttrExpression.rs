@@ -105,9 +105,13 @@ impl AttrExpression
 			}
 			else
 			{
-				let type_or_unit = if let Some(type_or_unit) = input.try(|input| TypeOrUnit::parse(input)).ok()
+				let type_or_unit = if let Ok(ident) = input.try(|input| input.expect_ident_cloned())
 				{
-					type_or_unit
+					match TypeOrUnit::from_ident(&ident)
+					{
+						Ok(type_or_unit) => type_or_unit,
+						Err(()) => return Err(ParseError::Custom(CustomParseError::UnknownAttrTypeOrUnit(ident))),
+					}
 				}
 				else
 				{
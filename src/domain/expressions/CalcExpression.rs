@@ -9,6 +9,8 @@ pub enum CalcExpression<U: Unit>
 	
 	Number(U::Number),
 	
+	MathConstant(MathConstant),
+	
 	Parentheses(Box<CalcExpression<U>>),
 	
 	Addition(Box<CalcExpression<U>>, Box<CalcExpression<U>>),
@@ -41,6 +43,8 @@ impl<U: Unit> ToCss for CalcExpression<U>
 			
 			Number(ref number) => number.to_css(dest),
 			
+			MathConstant(ref constant) => constant.to_css(dest),
+			
 			Parentheses(ref calcFunctionBody) =>
 			{
 				dest.write_char('(')?;
@@ -98,6 +102,8 @@ impl<U: Unit> Expression<U> for CalcExpression<U>
 			
 			Number(number) => Some(number),
 			
+			MathConstant(ref constant) => U::Number::new(constant.value()).ok(),
+			
 			Parentheses(ref subExpression) => subExpression.evaluate(conversion),
 			
 			Addition(ref lhsSubExpression, ref rhsSubExpression) => match (lhsSubExpression.evaluate(conversion), rhsSubExpression.evaluate(conversion))
@@ -130,7 +136,7 @@ impl<U: Unit> Expression<U> for CalcExpression<U>
 impl<U: Unit> CalcExpression<U>
 {
 	/// Parse a top-level `calc` expression, with all nested sub-expressions.
-	/// DOES NOT simplify expressions. This is because simplification is harder than it ought to be:-
+	/// DOES NOT simplify expressions (use `simplify()` afterwards). This is because simplification is harder than it ought to be:-
 	/// * Percentages can be treated as multiples of 'x', eg 50% => 0.5x, BUT
 	/// * Zero percentages have to be preserved, so detecting 'divide by zero' at parse time isn't easy
 	/// * Calc expressions additionally have other unknown quantities when dealing with units:-
@@ -146,7 +152,7 @@ impl<U: Unit> CalcExpression<U>
 	#[inline(always)]
 	pub(crate) fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
 	{
-		input.parse_nested_block(|input| Self::parse_sum(context, input))
+		context.parse_nested_calc_expression(|| input.parse_nested_block(|input| Self::parse_sum(context, input)))
 	}
 	
 	/// Parse a `calc` expression, and all the sum that may follow, and stop as soon as a non-sum expression is found.
@@ -162,9 +168,9 @@ impl<U: Unit> CalcExpression<U>
 		loop
 		{
 			let stateToResetParseToIfNotSum = input.state();
-			match *input.next_including_whitespace()?
+			match input.next_including_whitespace()
 			{
-				WhiteSpace(_) =>
+				Ok(&WhiteSpace(_)) =>
 				{
 					// a trailing whitespace
 					if input.is_exhausted()
@@ -220,14 +226,14 @@ impl<U: Unit> CalcExpression<U>
 		loop
 		{
 			let stateToResetParseToIfNotProduct = input.state();
-			match *input.next()?
+			match input.next()
 			{
-				Delim('*') =>
+				Ok(&Delim('*')) =>
 				{
 					currentProduct = Multiplication(Box::new(currentProduct), Box::new(Self::parse_one(context, input)?));
 				}
 				
-				Delim('/') =>
+				Ok(&Delim('/')) =>
 				{
 					currentProduct = Division(Box::new(currentProduct), Box::new(Self::parse_one(context, input)?));
 				}
@@ -245,6 +251,11 @@ impl<U: Unit> CalcExpression<U>
 	
 	fn parse_one<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
 	{
+		if let Ok(constant) = input.try(|input| MathConstant::parse(input))
+		{
+			return Ok(CalcExpression::MathConstant(constant));
+		}
+		
 		let either = U::parse_one_inside_calc_function(context, input)?;
 		if either.is_left()
 		{
@@ -256,9 +267,120 @@ impl<U: Unit> CalcExpression<U>
 		}
 	}
 	
+	/// Evaluates this expression as the argument of a math function; unlike `evaluate()`, a constant such as `infinity` can be an argument, eg of `clamp()`.
+	#[inline(always)]
+	pub(crate) fn evaluate_argument<Conversion: FontRelativeLengthConversion<U::Number> + ViewportPercentageLengthConversion<U::Number> + PercentageConversion<U::Number> + AttributeConversion<U> + CssVariableConversion>(&self, conversion: &Conversion) -> Option<f32>
+	{
+		match *self
+		{
+			CalcExpression::MathConstant(ref constant) => Some(constant.value()),
+			_ => self.evaluate(conversion).map(|number| number.as_f32()),
+		}
+	}
+	
 	#[inline(always)]
 	pub(crate) fn parse_parentheses<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Either<CalculablePropertyValue<U>, CalcExpression<U>>, ParseError<'i, CustomParseError<'i>>>
 	{
 		Ok(Right(CalcExpression::Parentheses(Box::new(CalcExpression::parse(context, input)?))))
 	}
 }
+
+impl<U: Unit + Clone + PartialEq + Add<<U as Unit>::Number, Output = U> + Sub<<U as Unit>::Number, Output = U>> CalcExpression<U>
+{
+	/// Simplifies this expression following https://drafts.csswg.org/css-values-4/#calc-simplification, eg `10px + 2em - 5px + 0%` is simplified to `0% + 2em + 5px`.
+	///
+	/// * Like terms are combined, with dimensions converted to their canonical unit (eg `px` for `AbsoluteLength`)
+	/// * Terms are sorted: numbers, then percentages, then dimensions ordered by their unit, then everything else in the order it was written
+	/// * Terms which are zero, such as `0%`, are kept, as they determine the type of the result
+	/// * Number-only sub-expressions are folded, and products and quotients with numbers are distributed over sums
+	/// * Nested `calc()` functions are inlined and redundant parentheses are removed
+	/// * Everything else, such as `var()`, `attr()` and products of dimensions, is kept as it is
+	///
+	/// If the simplified expression can not be represented (eg because it starts with a negative term and numbers must be unsigned) this expression is returned unchanged.
+	#[inline(always)]
+	pub fn simplify(&self) -> Self
+	{
+		CalcSumTerm::to_expression(&self.to_sum()).unwrap_or_else(|| self.clone())
+	}
+	
+	/// The terms of the sum this expression simplifies to, with like terms combined.
+	pub(crate) fn to_sum(&self) -> Vec<CalcSumTerm<U>>
+	{
+		use self::CalcExpression::*;
+		
+		let terms = match *self
+		{
+			CalculablePropertyValue(ref calculable) => return calculable.to_sum(),
+			
+			Number(number) => vec![CalcSumTerm::Number(number.as_f32())],
+			
+			MathConstant(ref constant) =>
+			{
+				let value = constant.value();
+				if value.is_finite()
+				{
+					vec![CalcSumTerm::Number(value)]
+				}
+				else
+				{
+					vec![CalcSumTerm::Other(1.0, self.clone())]
+				}
+			}
+			
+			Parentheses(ref subExpression) => return subExpression.to_sum(),
+			
+			Addition(ref lhsSubExpression, ref rhsSubExpression) =>
+			{
+				let mut terms = lhsSubExpression.to_sum();
+				terms.extend(rhsSubExpression.to_sum());
+				terms
+			}
+			
+			Subtraction(ref lhsSubExpression, ref rhsSubExpression) =>
+			{
+				let mut terms = lhsSubExpression.to_sum();
+				terms.extend(rhsSubExpression.to_sum().into_iter().map(|term| term.scale(-1.0)));
+				terms
+			}
+			
+			Multiplication(ref lhsSubExpression, ref rhsSubExpression) =>
+			{
+				let lhs = lhsSubExpression.to_sum();
+				let rhs = rhsSubExpression.to_sum();
+				match (CalcSumTerm::single_number(&lhs), CalcSumTerm::single_number(&rhs))
+				{
+					(_, Some(factor)) => lhs.into_iter().map(|term| term.scale(factor)).collect(),
+					(Some(factor), None) => rhs.into_iter().map(|term| term.scale(factor)).collect(),
+					(None, None) => self.other(CalcSumTerm::to_operand(&lhs, false).and_then(|lhs| CalcSumTerm::to_operand(&rhs, false).map(|rhs| Multiplication(Box::new(lhs), Box::new(rhs))))),
+				}
+			}
+			
+			Division(ref lhsSubExpression, ref rhsSubExpression) =>
+			{
+				let lhs = lhsSubExpression.to_sum();
+				let rhs = rhsSubExpression.to_sum();
+				match CalcSumTerm::single_number(&rhs)
+				{
+					Some(divisor) if divisor != 0.0 => lhs.into_iter().map(|term| term.scale(1.0 / divisor)).collect(),
+					_ => self.other(CalcSumTerm::to_operand(&lhs, false).and_then(|lhs| CalcSumTerm::to_operand(&rhs, true).map(|rhs| Division(Box::new(lhs), Box::new(rhs))))),
+				}
+			}
+		};
+		
+		CalcSumTerm::combine(terms)
+	}
+	
+	/// Whether this expression simplifies to just a number, such as `pi / 2`.
+	#[inline(always)]
+	pub(crate) fn is_number(&self) -> bool
+	{
+		CalcSumTerm::single_number(&self.to_sum()).is_some()
+	}
+	
+	/// A term which can not be combined with others; if the simplified expression can not be represented, this expression is used unchanged.
+	#[inline(always)]
+	fn other(&self, simplified: Option<Self>) -> Vec<CalcSumTerm<U>>
+	{
+		vec![CalcSumTerm::Other(1.0, simplified.unwrap_or_else(|| self.clone()))]
+	}
+}
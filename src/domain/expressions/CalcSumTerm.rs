@@ -0,0 +1,239 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A term of the sum a `calc()` expression is simplified to, with its coefficient: https://drafts.csswg.org/css-values-4/#calc-simplification
+#[derive(Debug, Clone)]
+pub(crate) enum CalcSumTerm<U: Unit>
+{
+	Number(f32),
+	
+	Percentage(f32),
+	
+	/// A dimension in its canonical unit (eg `px` for `AbsoluteLength`s), with a value of zero in that unit to identify it.
+	Dimension(f32, U),
+	
+	/// Anything which can not be combined with other terms, such as a `var()` or a product of two dimensions.
+	Other(f32, CalcExpression<U>),
+}
+
+impl<U: Unit + Clone + PartialEq + Add<<U as Unit>::Number, Output = U> + Sub<<U as Unit>::Number, Output = U>> CalcSumTerm<U>
+{
+	#[inline(always)]
+	pub(crate) fn coefficient(&self) -> f32
+	{
+		use self::CalcSumTerm::*;
+		
+		match *self
+		{
+			Number(coefficient) | Percentage(coefficient) | Dimension(coefficient, _) | Other(coefficient, _) => coefficient,
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn scale(self, factor: f32) -> Self
+	{
+		use self::CalcSumTerm::*;
+		
+		match self
+		{
+			Number(coefficient) => Number(coefficient * factor),
+			Percentage(coefficient) => Percentage(coefficient * factor),
+			Dimension(coefficient, zero) => Dimension(coefficient * factor, zero),
+			Other(coefficient, expression) => Other(coefficient * factor, expression),
+		}
+	}
+	
+	/// A constant as a term; values which are just numbers are treated as numbers.
+	#[inline(always)]
+	pub(crate) fn from_constant(constant: &U) -> Self
+	{
+		let canonical = constant.clone().to_canonical_dimension();
+		let coefficient = canonical.to_f32();
+		
+		if U::HasDimension
+		{
+			let zero = canonical.clone() - canonical.to_CssNumber();
+			CalcSumTerm::Dimension(coefficient, zero)
+		}
+		else
+		{
+			CalcSumTerm::Number(coefficient)
+		}
+	}
+	
+	/// The number, if these terms are just a number.
+	#[inline(always)]
+	pub(crate) fn single_number(terms: &[Self]) -> Option<f32>
+	{
+		if terms.len() != 1
+		{
+			return None;
+		}
+		
+		match terms[0]
+		{
+			CalcSumTerm::Number(number) => Some(number),
+			_ => None,
+		}
+	}
+	
+	/// Combines like terms and sorts them as the specification requires: numbers, then percentages, then dimensions ordered by their unit (ASCII case-insensitively), then everything else in the order it first occurs.
+	/// Terms which are zero are kept, as they still determine the type of the sum (eg `0%`).
+	pub(crate) fn combine(terms: Vec<Self>) -> Vec<Self>
+	{
+		let mut combined: Vec<Self> = Vec::with_capacity(terms.len());
+		for term in terms
+		{
+			let likeTerm = combined.iter_mut().find(|combinedTerm| combinedTerm.is_like(&term));
+			match likeTerm
+			{
+				Some(likeTerm) => likeTerm.add_coefficient(term.coefficient()),
+				None => combined.push(term),
+			}
+		}
+		
+		combined.sort_by(|term, otherTerm| term.sort_key().cmp(&otherTerm.sort_key()));
+		combined
+	}
+	
+	/// The expression for a sum of terms, or `None` if a term can not be represented (eg a negative first term when numbers must be unsigned).
+	pub(crate) fn to_expression(terms: &[Self]) -> Option<CalcExpression<U>>
+	{
+		let (first, remainder) = terms.split_first()?;
+		
+		let mut sum = first.to_signed_expression()?;
+		for term in remainder.iter()
+		{
+			let magnitude = term.to_magnitude_expression()?;
+			sum = if term.coefficient() < 0.0
+			{
+				CalcExpression::Subtraction(Box::new(sum), Box::new(magnitude))
+			}
+			else
+			{
+				CalcExpression::Addition(Box::new(sum), Box::new(magnitude))
+			};
+		}
+		Some(sum)
+	}
+	
+	/// The expression for a sum of terms as an operand of a multiplication or division, with parentheses if needed.
+	pub(crate) fn to_operand(terms: &[Self], isDivisor: bool) -> Option<CalcExpression<U>>
+	{
+		use self::CalcExpression::*;
+		
+		let expression = Self::to_expression(terms)?;
+		let needsParentheses = match expression
+		{
+			Addition(..) | Subtraction(..) => true,
+			Multiplication(..) | Division(..) => isDivisor,
+			_ => false,
+		};
+		
+		if needsParentheses
+		{
+			Some(Parentheses(Box::new(expression)))
+		}
+		else
+		{
+			Some(expression)
+		}
+	}
+	
+	/// The value for a single remaining term, if it is a constant or a percentage.
+	pub(crate) fn to_calculable_property_value(&self) -> Option<CalculablePropertyValue<U>>
+	{
+		use self::CalcSumTerm::*;
+		
+		match *self
+		{
+			Number(coefficient) if !U::HasDimension => Some(CalculablePropertyValue::Constant(U::default() + Self::number(coefficient)?)),
+			Percentage(coefficient) => Some(CalculablePropertyValue::Percentage(PercentageUnit(Self::number(coefficient)?))),
+			Dimension(coefficient, ref zero) => Some(CalculablePropertyValue::Constant(zero.clone() + Self::number(coefficient)?)),
+			_ => None,
+		}
+	}
+	
+	#[inline(always)]
+	fn is_like(&self, other: &Self) -> bool
+	{
+		use self::CalcSumTerm::*;
+		
+		match (self, other)
+		{
+			(&Number(_), &Number(_)) => true,
+			(&Percentage(_), &Percentage(_)) => true,
+			(&Dimension(_, ref zero), &Dimension(_, ref otherZero)) => zero == otherZero,
+			_ => false,
+		}
+	}
+	
+	/// Sorting is stable, so terms with equal keys, such as `var()`s, stay in the order they first occur.
+	#[inline(always)]
+	fn sort_key(&self) -> (u8, String)
+	{
+		use self::CalcSumTerm::*;
+		
+		match *self
+		{
+			Number(_) => (0, String::new()),
+			Percentage(_) => (1, String::new()),
+			Dimension(_, ref zero) => (2, zero.to_css_string().trim_left_matches(|character: char| character.is_ascii_digit() || character == '.' || character == '-').to_ascii_lowercase()),
+			Other(..) => (3, String::new()),
+		}
+	}
+	
+	#[inline(always)]
+	fn add_coefficient(&mut self, value: f32)
+	{
+		use self::CalcSumTerm::*;
+		
+		match *self
+		{
+			Number(ref mut coefficient) | Percentage(ref mut coefficient) | Dimension(ref mut coefficient, _) | Other(ref mut coefficient, _) => *coefficient += value,
+		}
+	}
+	
+	#[inline(always)]
+	fn to_signed_expression(&self) -> Option<CalcExpression<U>>
+	{
+		match *self
+		{
+			CalcSumTerm::Other(coefficient, ref expression) if coefficient < 0.0 => Some(CalcExpression::Multiplication(Box::new(CalcExpression::Number(Self::number(coefficient)?)), Box::new(expression.clone()))),
+			CalcSumTerm::Other(..) => self.to_magnitude_expression(),
+			_ => self.to_expression_with_coefficient(self.coefficient()),
+		}
+	}
+	
+	#[inline(always)]
+	fn to_magnitude_expression(&self) -> Option<CalcExpression<U>>
+	{
+		match *self
+		{
+			CalcSumTerm::Other(coefficient, ref expression) if coefficient.abs() == 1.0 => Some(expression.clone()),
+			CalcSumTerm::Other(coefficient, ref expression) => Some(CalcExpression::Multiplication(Box::new(CalcExpression::Number(Self::number(coefficient.abs())?)), Box::new(expression.clone()))),
+			_ => self.to_expression_with_coefficient(self.coefficient().abs()),
+		}
+	}
+	
+	#[inline(always)]
+	fn to_expression_with_coefficient(&self, coefficient: f32) -> Option<CalcExpression<U>>
+	{
+		use self::CalcSumTerm::*;
+		
+		match *self
+		{
+			Number(_) => Some(CalcExpression::Number(Self::number(coefficient)?)),
+			Percentage(_) => Some(CalcExpression::CalculablePropertyValue(CalculablePropertyValue::Percentage(PercentageUnit(Self::number(coefficient)?)))),
+			Dimension(_, ref zero) => Some(CalcExpression::CalculablePropertyValue(CalculablePropertyValue::Constant(zero.clone() + Self::number(coefficient)?))),
+			Other(..) => None,
+		}
+	}
+	
+	#[inline(always)]
+	fn number(value: f32) -> Option<U::Number>
+	{
+		U::Number::new(value).ok()
+	}
+}
@@ -14,6 +14,8 @@ pub enum CalculablePropertyValue<U: Unit>
 	Attr(AttrFunction),
 	
 	Var(VarFunction),
+	
+	Math(MathFunction<U>),
 }
 
 impl<U: Unit> Default for CalculablePropertyValue<U>
@@ -42,6 +44,8 @@ impl<U: Unit> ToCss for CalculablePropertyValue<U>
 			Attr(ref function) => function.to_css(dest),
 			
 			Var(ref function) => function.to_css(dest),
+			
+			Math(ref function) => function.to_css(dest),
 		}
 	}
 }
@@ -58,7 +62,7 @@ impl<U: Unit> Expression<U> for CalculablePropertyValue<U>
 		
 		match *self
 		{
-			Constant(ref constant) => Some(constant.to_CssNumber()),
+			Constant(ref constant) => Some(constant.to_canonical_dimension_value(conversion)),
 			
 			Percentage(ref percentage) => Some(percentage.to_absolute_value(conversion)),
 			
@@ -67,6 +71,69 @@ impl<U: Unit> Expression<U> for CalculablePropertyValue<U>
 			Attr(ref function) => function.evaluate(conversion),
 			
 			Var(ref function) => function.evaluate(conversion),
+			
+			Math(ref function) => function.evaluate(conversion),
+		}
+	}
+}
+
+impl<U: Unit> CalculablePropertyValue<U>
+{
+	/// Whether this value is a `var()`, which can not be evaluated until custom properties have been substituted and might be substituted by more than one value.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		match *self
+		{
+			CalculablePropertyValue::Var(_) => true,
+			_ => false,
+		}
+	}
+}
+
+impl<U: Unit + Clone + PartialEq + Add<<U as Unit>::Number, Output = U> + Sub<<U as Unit>::Number, Output = U>> CalculablePropertyValue<U>
+{
+	/// Simplifies a `calc()` function as for `CalcExpression::simplify()`; if only a constant or a percentage remains, it is returned instead of the function, eg `calc(1in - 6px)` is simplified to `90px`.
+	/// Other values are returned unchanged.
+	pub fn simplify(&self) -> Self
+	{
+		match *self
+		{
+			Calc(ref function) =>
+			{
+				let terms = function.0.to_sum();
+				
+				if terms.len() == 1
+				{
+					if let Some(value) = terms[0].to_calculable_property_value()
+					{
+						return value;
+					}
+				}
+				
+				match CalcSumTerm::to_expression(&terms)
+				{
+					Some(expression) => Calc(CalcFunction(Rc::new(expression))),
+					None => self.clone(),
+				}
+			}
+			
+			_ => self.clone(),
+		}
+	}
+	
+	/// The terms of the sum this value simplifies to inside a `calc()` expression.
+	pub(crate) fn to_sum(&self) -> Vec<CalcSumTerm<U>>
+	{
+		match *self
+		{
+			Constant(ref constant) => vec![CalcSumTerm::from_constant(constant)],
+			
+			Percentage(ref percentage) => vec![CalcSumTerm::Percentage(percentage.0.as_f32())],
+			
+			Calc(ref function) => function.0.to_sum(),
+			
+			Attr(_) | Var(_) | Math(_) => vec![CalcSumTerm::Other(1.0, CalcExpression::CalculablePropertyValue(self.clone()))],
 		}
 	}
 }
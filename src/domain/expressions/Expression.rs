@@ -5,6 +5,7 @@
 pub trait Expression<U: Unit>
 {
 	/// Evaluate the Expression by returning the numeric value of the canonical dimension
+	/// Constants are converted to the canonical dimension, too, so `1in` evaluates to `96` (pixels), `100ms` to `0.1` (seconds) and `1turn` to `360` (degrees)
 	/// Division by zero is handled by returning the maximum possible f32 value
 	/// Subtractions for UnsignedCssNumber that are negative are handled by returning 0.0
 	#[inline(always)]
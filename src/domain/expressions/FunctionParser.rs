@@ -9,6 +9,7 @@ pub(crate) enum FunctionParser
 	calc,
 	var,
 	parentheses,
+	math(MathFunctionName),
 }
 
 impl FunctionParser
@@ -26,7 +27,7 @@ impl FunctionParser
 			
 			"var" => Ok(var),
 			
-			_ => Err(ParseError::Custom(CustomParseError::UnknownFunctionInValueExpression(name.to_owned())))
+			_ => MathFunctionName::from_ident(name).map(math).map_err(|()| ParseError::Custom(CustomParseError::UnknownFunctionInValueExpression(name.to_owned())))
 		}
 	}
 	
@@ -41,6 +42,8 @@ impl FunctionParser
 			
 			var => Ok(Var(VarFunction(Rc::new(VarExpression::parse(context, input)?)))),
 			
+			math(name) => Ok(Math(MathFunction(Rc::new(MathExpression::parse(name, context, input)?)))),
+			
 			_ => panic!("Should not be called in this context"),
 		}
 	}
@@ -50,12 +53,14 @@ impl FunctionParser
 	{
 		match *self
 		{
-			attr => Ok(Left(Calc(CalcFunction(Rc::new(CalcExpression::parse(context, input)?))))),
+			attr => Ok(Left(Attr(AttrFunction(Rc::new(AttrExpression::parse(context, input)?))))),
 			
-			calc => Ok(Left(Attr(AttrFunction(Rc::new(AttrExpression::parse(context, input)?))))),
+			calc => Ok(Left(Calc(CalcFunction(Rc::new(CalcExpression::parse(context, input)?))))),
 			
 			var => Ok(Left(Var(VarFunction(Rc::new(VarExpression::parse(context, input)?))))),
 			
+			math(name) => Ok(Left(Math(MathFunction(Rc::new(MathExpression::parse(name, context, input)?))))),
+			
 			parentheses => CalcExpression::parse_parentheses(context, input),
 		}
 	}
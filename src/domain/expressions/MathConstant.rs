@@ -0,0 +1,79 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A numeric constant in a math function or `calc()` expression: https://drafts.csswg.org/css-values-4/#calc-constants
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum MathConstant
+{
+	e,
+	
+	pi,
+	
+	infinity,
+	
+	negative_infinity,
+	
+	NaN,
+}
+
+impl ToCss for MathConstant
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::MathConstant::*;
+		
+		let value = match *self
+		{
+			e => "e",
+			pi => "pi",
+			infinity => "infinity",
+			negative_infinity => "-infinity",
+			NaN => "NaN",
+		};
+		dest.write_str(value)
+	}
+}
+
+impl MathConstant
+{
+	/// The value of this constant; infinite and NaN values can not be represented by a `CssNumber`, so expressions using them can not be evaluated unless, for example, they are clamped.
+	#[inline(always)]
+	pub fn value(&self) -> f32
+	{
+		use self::MathConstant::*;
+		
+		match *self
+		{
+			e => ::std::f32::consts::E,
+			pi => ::std::f32::consts::PI,
+			infinity => ::std::f32::INFINITY,
+			negative_infinity => ::std::f32::NEG_INFINITY,
+			NaN => ::std::f32::NAN,
+		}
+	}
+	
+	#[inline(always)]
+	pub(crate) fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::MathConstant::*;
+		
+		let ident = input.expect_ident()?;
+		match_ignore_ascii_case!
+		{
+			&*ident,
+			
+			"e" => Ok(e),
+			
+			"pi" => Ok(pi),
+			
+			"infinity" => Ok(infinity),
+			
+			"-infinity" => Ok(negative_infinity),
+			
+			"nan" => Ok(NaN),
+			
+			_ => Err(ParseError::Basic(BasicParseError::UnexpectedToken(Token::Ident(ident.clone())))),
+		}
+	}
+}
@@ -0,0 +1,354 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A math function other than `calc()`: https://drafts.csswg.org/css-values-4/#math
+///
+/// Arguments are parsed as for `calc()`, so, as there, unit-less and unit-having quantities are mixed leniently.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum MathExpression<U: Unit>
+{
+	/// `min(A#)`.
+	Min(Vec<CalcExpression<U>>),
+	
+	/// `max(A#)`.
+	Max(Vec<CalcExpression<U>>),
+	
+	/// `clamp(MIN, VAL, MAX)`; `MIN` wins if it is greater than `MAX`.
+	Clamp(CalcExpression<U>, CalcExpression<U>, CalcExpression<U>),
+	
+	/// `round(<rounding-strategy>?, A, B?)`; if `B` is absent, it is `1`.
+	Round(RoundingStrategy, CalcExpression<U>, Option<CalcExpression<U>>),
+	
+	/// `mod(A, B)`; the result has the sign of `B`.
+	Mod(CalcExpression<U>, CalcExpression<U>),
+	
+	/// `rem(A, B)`; the result has the sign of `A`.
+	Rem(CalcExpression<U>, CalcExpression<U>),
+	
+	/// `abs(A)`.
+	Abs(CalcExpression<U>),
+	
+	/// `sign(A)`, which is `-1`, `0` or `1`.
+	Sign(CalcExpression<U>),
+	
+	/// `sin(A)`, where `A` is an angle or a number of radians.
+	Sin(CalcExpression<AngleUnit<U::Number>>),
+	
+	/// `cos(A)`, where `A` is an angle or a number of radians.
+	Cos(CalcExpression<AngleUnit<U::Number>>),
+	
+	/// `tan(A)`, where `A` is an angle or a number of radians.
+	Tan(CalcExpression<AngleUnit<U::Number>>),
+	
+	/// `pow(A, B)`.
+	Pow(CalcExpression<U>, CalcExpression<U>),
+	
+	/// `sqrt(A)`.
+	Sqrt(CalcExpression<U>),
+	
+	/// `hypot(A#)`.
+	Hypot(Vec<CalcExpression<U>>),
+	
+	/// `log(A, B?)`; if `B` is absent, the natural logarithm.
+	Log(CalcExpression<U>, Option<CalcExpression<U>>),
+	
+	/// `exp(A)`.
+	Exp(CalcExpression<U>),
+}
+
+impl<U: Unit> ToCss for MathExpression<U>
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::MathExpression::*;
+		
+		match *self
+		{
+			Min(ref arguments) => Self::write_function("min", arguments.iter(), dest),
+			
+			Max(ref arguments) => Self::write_function("max", arguments.iter(), dest),
+			
+			Clamp(ref minimum, ref value, ref maximum) => Self::write_function("clamp", [minimum, value, maximum].iter().cloned(), dest),
+			
+			Round(roundingStrategy, ref value, ref step) =>
+			{
+				dest.write_str("round(")?;
+				if roundingStrategy != RoundingStrategy::default()
+				{
+					roundingStrategy.to_css(dest)?;
+					dest.write_str(", ")?;
+				}
+				value.to_css(dest)?;
+				if let Some(ref step) = *step
+				{
+					dest.write_str(", ")?;
+					step.to_css(dest)?;
+				}
+				dest.write_char(')')
+			}
+			
+			Mod(ref dividend, ref divisor) => Self::write_function("mod", [dividend, divisor].iter().cloned(), dest),
+			
+			Rem(ref dividend, ref divisor) => Self::write_function("rem", [dividend, divisor].iter().cloned(), dest),
+			
+			Abs(ref value) => Self::write_function("abs", Some(value).into_iter(), dest),
+			
+			Sign(ref value) => Self::write_function("sign", Some(value).into_iter(), dest),
+			
+			Sin(ref angle) => Self::write_function("sin", Some(angle).into_iter(), dest),
+			
+			Cos(ref angle) => Self::write_function("cos", Some(angle).into_iter(), dest),
+			
+			Tan(ref angle) => Self::write_function("tan", Some(angle).into_iter(), dest),
+			
+			Pow(ref base, ref exponent) => Self::write_function("pow", [base, exponent].iter().cloned(), dest),
+			
+			Sqrt(ref value) => Self::write_function("sqrt", Some(value).into_iter(), dest),
+			
+			Hypot(ref arguments) => Self::write_function("hypot", arguments.iter(), dest),
+			
+			Log(ref value, None) => Self::write_function("log", Some(value).into_iter(), dest),
+			
+			Log(ref value, Some(ref base)) => Self::write_function("log", [value, base].iter().cloned(), dest),
+			
+			Exp(ref value) => Self::write_function("exp", Some(value).into_iter(), dest),
+		}
+	}
+}
+
+impl<U: Unit> Expression<U> for MathExpression<U>
+{
+	/// Evaluate the MathExpression by returning the numeric value of the canonical dimension
+	/// Results which are not finite (eg from `mod()` by zero or `sqrt(-1)`) are handled by returning None
+	#[inline(always)]
+	fn evaluate<Conversion: FontRelativeLengthConversion<U::Number> + ViewportPercentageLengthConversion<U::Number> + PercentageConversion<U::Number> + AttributeConversion<U> + CssVariableConversion>(&self, conversion: &Conversion) -> Option<U::Number>
+	{
+		use self::MathExpression::*;
+		
+		let value = |expression: &CalcExpression<U>| expression.evaluate_argument(conversion);
+		
+		let values = |arguments: &Vec<CalcExpression<U>>| arguments.iter().map(&value).collect::<Option<Vec<f32>>>();
+		
+		let result = match *self
+		{
+			Min(ref arguments) => values(arguments)?.into_iter().fold(::std::f32::INFINITY, f32::min),
+			
+			Max(ref arguments) => values(arguments)?.into_iter().fold(::std::f32::NEG_INFINITY, f32::max),
+			
+			Clamp(ref minimum, ref value_, ref maximum) => value(minimum)?.max(value(value_)?.min(value(maximum)?)),
+			
+			Round(roundingStrategy, ref value_, ref step) =>
+			{
+				let step = match *step
+				{
+					None => 1.0,
+					Some(ref step) => value(step)?,
+				};
+				roundingStrategy.round(value(value_)?, step)
+			}
+			
+			Mod(ref dividend, ref divisor) =>
+			{
+				let (dividend, divisor) = (value(dividend)?, value(divisor)?);
+				dividend - divisor * (dividend / divisor).floor()
+			}
+			
+			Rem(ref dividend, ref divisor) => value(dividend)? % value(divisor)?,
+			
+			Abs(ref value_) => value(value_)?.abs(),
+			
+			Sign(ref value_) =>
+			{
+				let value_ = value(value_)?;
+				if value_ == 0.0
+				{
+					0.0
+				}
+				else
+				{
+					value_.signum()
+				}
+			}
+			
+			Sin(ref angle) => Self::radians(angle, conversion)?.sin(),
+			
+			Cos(ref angle) => Self::radians(angle, conversion)?.cos(),
+			
+			Tan(ref angle) => Self::radians(angle, conversion)?.tan(),
+			
+			Pow(ref base, ref exponent) => value(base)?.powf(value(exponent)?),
+			
+			Sqrt(ref value_) => value(value_)?.sqrt(),
+			
+			Hypot(ref arguments) => values(arguments)?.into_iter().map(|value| value * value).sum::<f32>().sqrt(),
+			
+			Log(ref value_, None) => value(value_)?.ln(),
+			
+			Log(ref value_, Some(ref base)) => value(value_)?.log(value(base)?),
+			
+			Exp(ref value_) => value(value_)?.exp(),
+		};
+		
+		U::Number::new(result).ok()
+	}
+}
+
+impl<U: Unit> MathExpression<U>
+{
+	/// Parse the arguments of a math function, other than `calc()`, with this name.
+	#[inline(always)]
+	pub(crate) fn parse<'i, 't>(name: MathFunctionName, context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::MathExpression::*;
+		use self::MathFunctionName::*;
+		
+		context.parse_nested_calc_expression(|| input.parse_nested_block(|input|
+		{
+			let expression = match name
+			{
+				min => Min(Self::parse_arguments(context, input)?),
+				
+				max => Max(Self::parse_arguments(context, input)?),
+				
+				clamp =>
+				{
+					let minimum = Self::parse_argument(context, input)?;
+					input.expect_comma()?;
+					let value = Self::parse_argument(context, input)?;
+					input.expect_comma()?;
+					Clamp(minimum, value, Self::parse_argument(context, input)?)
+				}
+				
+				round =>
+				{
+					let roundingStrategy = match input.try(|input| RoundingStrategy::parse(input))
+					{
+						Ok(roundingStrategy) =>
+						{
+							input.expect_comma()?;
+							roundingStrategy
+						}
+						Err(_) => RoundingStrategy::default(),
+					};
+					let value = Self::parse_argument(context, input)?;
+					Round(roundingStrategy, value, Self::parse_optional_argument(context, input)?)
+				}
+				
+				mod_ =>
+				{
+					let dividend = Self::parse_argument(context, input)?;
+					input.expect_comma()?;
+					Mod(dividend, Self::parse_argument(context, input)?)
+				}
+				
+				rem =>
+				{
+					let dividend = Self::parse_argument(context, input)?;
+					input.expect_comma()?;
+					Rem(dividend, Self::parse_argument(context, input)?)
+				}
+				
+				abs => Abs(Self::parse_argument(context, input)?),
+				
+				sign => Sign(Self::parse_argument(context, input)?),
+				
+				sin => Sin(CalcExpression::parse_sum(context, input)?),
+				
+				cos => Cos(CalcExpression::parse_sum(context, input)?),
+				
+				tan => Tan(CalcExpression::parse_sum(context, input)?),
+				
+				pow =>
+				{
+					let base = Self::parse_argument(context, input)?;
+					input.expect_comma()?;
+					Pow(base, Self::parse_argument(context, input)?)
+				}
+				
+				sqrt => Sqrt(Self::parse_argument(context, input)?),
+				
+				hypot => Hypot(Self::parse_arguments(context, input)?),
+				
+				log =>
+				{
+					let value = Self::parse_argument(context, input)?;
+					Log(value, Self::parse_optional_argument(context, input)?)
+				}
+				
+				exp => Exp(Self::parse_argument(context, input)?),
+			};
+			Ok(expression)
+		}))
+	}
+	
+	#[inline(always)]
+	fn parse_argument<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<CalcExpression<U>, ParseError<'i, CustomParseError<'i>>>
+	{
+		input.parse_until_before(Delimiter::Comma, |input| CalcExpression::parse_sum(context, input))
+	}
+	
+	#[inline(always)]
+	fn parse_optional_argument<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Option<CalcExpression<U>>, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.try(|input| input.expect_comma()).is_ok()
+		{
+			Ok(Some(Self::parse_argument(context, input)?))
+		}
+		else
+		{
+			Ok(None)
+		}
+	}
+	
+	#[inline(always)]
+	fn parse_arguments<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Vec<CalcExpression<U>>, ParseError<'i, CustomParseError<'i>>>
+	{
+		input.parse_comma_separated(|input| CalcExpression::parse_sum(context, input))
+	}
+	
+	/// A number, rather than an angle, is a number of radians; angles are evaluated in degrees.
+	/// A `var()` argument is resolved through `CssVariableConversion`, so `sin(var(--x))` is evaluated in radians if `--x` is a number.
+	#[inline(always)]
+	fn radians<Conversion: AttributeConversion<U> + CssVariableConversion>(angle: &CalcExpression<AngleUnit<U::Number>>, conversion: &Conversion) -> Option<f32>
+	{
+		if let CalcExpression::CalculablePropertyValue(CalculablePropertyValue::Var(VarFunction(ref expression))) = *angle
+		{
+			if let Some(number) = expression.value_css(conversion).and_then(|value_css| CssSignedNumber::from_raw_css_for_var_expression_evaluation(value_css, expression.is_not_in_page_rule))
+			{
+				return Some(number.as_f32());
+			}
+		}
+		
+		let value = angle.evaluate_argument(&AngleArgumentConversion::new(conversion))?;
+		
+		if angle.is_number()
+		{
+			Some(value)
+		}
+		else
+		{
+			Some(value.to_radians())
+		}
+	}
+	
+	fn write_function<'a, W: fmt::Write, A: 'a + ToCss, I: Iterator<Item = &'a A>>(name: &str, arguments: I, dest: &mut W) -> fmt::Result
+	{
+		dest.write_str(name)?;
+		dest.write_char('(')?;
+		let mut afterFirst = false;
+		for argument in arguments
+		{
+			if afterFirst
+			{
+				dest.write_str(", ")?;
+			}
+			else
+			{
+				afterFirst = true;
+			}
+			argument.to_css(dest)?;
+		}
+		dest.write_char(')')
+	}
+}
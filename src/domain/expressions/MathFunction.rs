@@ -0,0 +1,26 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct MathFunction<U: Unit>(pub Rc<MathExpression<U>>);
+
+impl<U: Unit> ToCss for MathFunction<U>
+{
+	#[inline(always)]
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		self.0.to_css(dest)
+	}
+}
+
+impl<U: Unit> Expression<U> for MathFunction<U>
+{
+	/// Evaluate the MathFunction by returning the numeric value of the canonical dimension
+	/// Results which are not finite (eg from division by zero or `pow(-1, 0.5)`) are handled by returning None
+	#[inline(always)]
+	fn evaluate<Conversion: FontRelativeLengthConversion<U::Number> + ViewportPercentageLengthConversion<U::Number> + PercentageConversion<U::Number> + AttributeConversion<U> + CssVariableConversion>(&self, conversion: &Conversion) -> Option<U::Number>
+	{
+		self.0.evaluate(conversion)
+	}
+}
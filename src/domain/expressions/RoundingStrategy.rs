@@ -0,0 +1,44 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+define_css_keyword_enum!
+{
+	RoundingStrategy:
+	"nearest" => nearest,
+	"up" => up,
+	"down" => down,
+	"to-zero" => to_zero,
+}
+
+impl Default for RoundingStrategy
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		RoundingStrategy::nearest
+	}
+}
+
+impl RoundingStrategy
+{
+	/// Rounds `value` to a multiple of `step`; a `step` of zero gives NaN.
+	/// The sign of `step` is ignored, as `up` is always towards positive infinity and `down` towards negative infinity: https://drafts.csswg.org/css-values-4/#round-func
+	#[inline(always)]
+	pub fn round(&self, value: f32, step: f32) -> f32
+	{
+		use self::RoundingStrategy::*;
+		
+		let step = step.abs();
+		let multiple = value / step;
+		let rounded = match *self
+		{
+			// Halfway values are rounded up, towards positive infinity.
+			nearest => (multiple + 0.5).floor(),
+			up => multiple.ceil(),
+			down => multiple.floor(),
+			to_zero => multiple.trunc(),
+		};
+		rounded * step
+	}
+}
@@ -5,7 +5,7 @@
 #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct VarExpression
 {
-	pub custom_property_lower_case_name_without_double_dash: String,
+	pub custom_property_name_without_double_dash: String,
 
 	pub default_value_css: Option<String>,
 
@@ -17,7 +17,7 @@ impl ToCss for VarExpression
 	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
 	{
 		dest.write_str("--")?;
-		serialize_identifier(&self.custom_property_lower_case_name_without_double_dash, dest)?;
+		serialize_identifier(&self.custom_property_name_without_double_dash, dest)?;
 		
 		if let Some(ref default_value) = self.default_value_css
 		{
@@ -36,7 +36,7 @@ impl<U: Unit> Expression<U> for VarExpression
 	#[inline(always)]
 	fn evaluate<Conversion: FontRelativeLengthConversion<U::Number> + ViewportPercentageLengthConversion<U::Number> + PercentageConversion<U::Number> + AttributeConversion<U> + CssVariableConversion>(&self, conversion: &Conversion) -> Option<U::Number>
 	{
-		match conversion.cssVariableValue(&self.custom_property_lower_case_name_without_double_dash)
+		match conversion.cssVariableValue(&self.custom_property_name_without_double_dash)
 		{
 			Some(value_css) => U::from_raw_css_for_var_expression_evaluation(value_css, self.is_not_in_page_rule).map(|unit| unit.to_CssNumber()),
 			None => if let Some(ref value_css) = self.default_value_css
@@ -53,21 +53,30 @@ impl<U: Unit> Expression<U> for VarExpression
 
 impl VarExpression
 {
+	/// The raw CSS this expression resolves to: the value of the custom property if defined, otherwise the default value, if any.
+	#[inline(always)]
+	pub(crate) fn value_css<'a, Conversion: CssVariableConversion>(&'a self, conversion: &'a Conversion) -> Option<&'a str>
+	{
+		match conversion.cssVariableValue(&self.custom_property_name_without_double_dash)
+		{
+			Some(value_css) => Some(value_css),
+			None => self.default_value_css.as_ref().map(|value_css| value_css.as_str()),
+		}
+	}
+	
 	#[inline(always)]
 	pub(crate) fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
 	{
 		input.parse_nested_block(|input|
 		{
-			let custom_property_lower_case_name_without_double_dash =
+			let custom_property_name_without_double_dash =
 			{
 				let identifier = input.expect_ident()?;
 				if !identifier.starts_with("--")
 				{
 					return Err(ParseError::Custom(CustomParseError::CssVariablesInVarExpressionsMustStartWithTwoDashes(identifier.clone())))
 				}
-				let mut custom_property_lower_case_name_without_double_dash = (&identifier[2..]).to_owned();
-				custom_property_lower_case_name_without_double_dash.make_ascii_lowercase();
-				custom_property_lower_case_name_without_double_dash
+				(&identifier[2..]).to_owned()
 			};
 			
 			let startPosition = input.position();
@@ -108,7 +117,7 @@ impl VarExpression
 			(
 				Self
 				{
-					custom_property_lower_case_name_without_double_dash,
+					custom_property_name_without_double_dash,
 					default_value_css,
 					is_not_in_page_rule: context.isNotInPageRule(),
 				}
@@ -10,15 +10,28 @@ use ::either::Either;
 use ::either::Either::*;
 use ::std::rc::Rc;
 use ::std::ascii::AsciiExt;
+use ::std::ops::Add;
+use ::std::ops::Sub;
 
 
+#[cfg(test)]
+mod tests;
+
+
+include!("AngleArgumentConversion.rs");
 include!("AttrExpression.rs");
 include!("AttrFunction.rs");
 include!("CalcExpression.rs");
 include!("CalcFunction.rs");
+include!("CalcSumTerm.rs");
 include!("CalculablePropertyValue.rs");
 include!("Expression.rs");
 include!("FunctionParser.rs");
+include!("MathConstant.rs");
+include!("MathExpression.rs");
+include!("MathFunction.rs");
+include!("MathFunctionName.rs");
+include!("RoundingStrategy.rs");
 include!("TypeOrUnit.rs");
 include!("VarExpression.rs");
 include!("VarFunction.rs");
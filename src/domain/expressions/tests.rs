@@ -0,0 +1,117 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+use super::domain::boxModel::LengthOrPercentageOrAuto;
+use super::domain::numbers::CssSignedNumber;
+use super::domain::properties::PropertyValue;
+
+
+fn simplify(css: &str) -> String
+{
+	match LengthOrPercentageOrAuto::<CssSignedNumber>::parse_property_value_from_css(css).unwrap()
+	{
+		LengthOrPercentageOrAuto::value(value) => value.simplify().to_css_string(),
+		LengthOrPercentageOrAuto::auto => panic!("Not a value"),
+	}
+}
+
+#[test]
+fn simplifying_combines_like_terms_and_sorts_them_by_type_and_unit()
+{
+	assert_eq!(simplify("calc(10px + 2em - 5px + 0%)"), "calc(0% + 2em + 5px)");
+	assert_eq!(simplify("calc(2em + 10% + 1em - 10%)"), "calc(0% + 3em)");
+	assert_eq!(simplify("calc(1vw + 1px + 1EM)"), "calc(1em + 1px + 1vw)");
+	assert_eq!(simplify("calc(var(--b) + 1px + var(--a) + 2% + 2px)"), "calc(2% + 3px + var(--b) + var(--a))");
+}
+
+#[test]
+fn simplifying_converts_to_canonical_units()
+{
+	assert_eq!(simplify("calc(1in - 6px)"), "90px");
+	assert_eq!(simplify("calc(calc(1cm + 1mm) * 0)"), "0px");
+}
+
+fn evaluate(css: &str) -> Option<f32>
+{
+	evaluate_with_conversion(css, &SimplisticExampleOfConversion::default())
+}
+
+fn evaluate_with_conversion(css: &str, conversion: &SimplisticExampleOfConversion<LengthOrPercentageUnit<CssSignedNumber>>) -> Option<f32>
+{
+	match LengthOrPercentageOrAuto::<CssSignedNumber>::parse_property_value_from_css(css).unwrap()
+	{
+		LengthOrPercentageOrAuto::value(value) => value.evaluate(conversion).map(|number| number.as_f32()),
+		LengthOrPercentageOrAuto::auto => panic!("Not a value"),
+	}
+}
+
+#[test]
+fn clamp_prefers_the_minimum_when_it_exceeds_the_maximum()
+{
+	assert_eq!(evaluate("clamp(1px, 5px, 3px)"), Some(3.0));
+	assert_eq!(evaluate("clamp(1px, -5px, 3px)"), Some(1.0));
+	assert_eq!(evaluate("clamp(4px, 2px, 3px)"), Some(4.0));
+}
+
+#[test]
+fn round_uses_the_sign_of_the_value_not_the_step()
+{
+	assert_eq!(evaluate("round(2.5px, 1px)"), Some(3.0));
+	assert_eq!(evaluate("round(-2.5px, 1px)"), Some(-2.0));
+	assert_eq!(evaluate("round(up, -2.5px, 1px)"), Some(-2.0));
+	assert_eq!(evaluate("round(down, -2.5px, 1px)"), Some(-3.0));
+	assert_eq!(evaluate("round(to-zero, -2.5px, 1px)"), Some(-2.0));
+	assert_eq!(evaluate("round(up, 2.5px, -1px)"), Some(3.0));
+	assert_eq!(evaluate("round(down, 2.5px, -1px)"), Some(2.0));
+	assert_eq!(evaluate("round(7px, -5px)"), Some(5.0));
+	assert_eq!(evaluate("round(7px, 0px)"), None);
+}
+
+#[test]
+fn mod_takes_the_sign_of_the_divisor_and_rem_of_the_dividend()
+{
+	assert_eq!(evaluate("mod(5px, 3px)"), Some(2.0));
+	assert_eq!(evaluate("mod(-5px, 3px)"), Some(1.0));
+	assert_eq!(evaluate("mod(5px, -3px)"), Some(-1.0));
+	assert_eq!(evaluate("mod(-5px, -3px)"), Some(-2.0));
+	assert_eq!(evaluate("rem(5px, 3px)"), Some(2.0));
+	assert_eq!(evaluate("rem(-5px, 3px)"), Some(-2.0));
+	assert_eq!(evaluate("rem(5px, -3px)"), Some(2.0));
+	assert_eq!(evaluate("rem(-5px, -3px)"), Some(-2.0));
+	assert_eq!(evaluate("mod(5px, 0px)"), None);
+	assert_eq!(evaluate("rem(5px, 0px)"), None);
+}
+
+#[test]
+fn trigonometric_functions_take_numbers_as_radians_and_may_be_nested()
+{
+	assert_eq!(evaluate("calc(2px * sin(90deg))"), Some(2.0));
+	assert_eq!(evaluate("calc(2px * cos(0))"), Some(2.0));
+	assert_eq!(evaluate("calc(2px * sin(sin(90deg) * 90deg))"), Some(2.0));
+}
+
+#[test]
+fn trigonometric_functions_take_var_numbers_as_radians()
+{
+	let mut conversion = SimplisticExampleOfConversion::default();
+	conversion.cssVariablesWithNamesWithoutLeadingDoubleDashToRawCss.insert("half-turn".to_owned(), "3.1415927".to_owned());
+	conversion.cssVariablesWithNamesWithoutLeadingDoubleDashToRawCss.insert("quarter-turn".to_owned(), "90deg".to_owned());
+	
+	assert_eq!(evaluate_with_conversion("calc(2px * cos(var(--half-turn)))", &conversion), Some(-2.0));
+	assert_eq!(evaluate_with_conversion("calc(2px * sin(var(--quarter-turn)))", &conversion), Some(2.0));
+}
+
+#[test]
+fn var_looks_up_custom_properties_by_their_exact_name()
+{
+	let mut conversion = SimplisticExampleOfConversion::default();
+	conversion.cssVariablesWithNamesWithoutLeadingDoubleDashToRawCss.insert("Main-Gap".to_owned(), "2px".to_owned());
+	conversion.cssVariablesWithNamesWithoutLeadingDoubleDashToRawCss.insert("main-gap".to_owned(), "5px".to_owned());
+	
+	assert_eq!(evaluate_with_conversion("var(--Main-Gap)", &conversion), Some(2.0));
+	assert_eq!(evaluate_with_conversion("calc(var(--main-gap) * 2)", &conversion), Some(10.0));
+	assert_eq!(evaluate_with_conversion("var(--MAIN-GAP)", &conversion), None);
+	assert_eq!(simplify("calc(var(--Main-Gap) + 1px + 2px)"), "calc(3px + var(--Main-Gap))");
+}
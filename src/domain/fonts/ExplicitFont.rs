@@ -0,0 +1,173 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `font` shorthand property when it is not a system font.
+/// See https://drafts.csswg.org/css-fonts-4/#font-prop
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ExplicitFont
+{
+	/// The value of the `font-style` property.
+	pub font_style: FontStyle,
+	
+	/// The value of the `font-variant-caps` property; only `normal` or `small-caps`.
+	pub font_variant_caps: FontVariantCaps,
+	
+	/// The value of the `font-weight` property.
+	pub font_weight: FontWeightValue,
+	
+	/// The value of the `font-stretch` property.
+	pub font_stretch: FontStretch,
+	
+	/// The value of the `font-size` property.
+	pub font_size: FontSize,
+	
+	/// The value of the `line-height` property.
+	pub line_height: LineHeight,
+	
+	/// The value of the `font-family` property.
+	pub font_family: FontFamilyList,
+}
+
+impl ToCss for ExplicitFont
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		#[inline(always)]
+		fn keyword<W: fmt::Write, T: ToCss>(dest: &mut W, isNormal: bool, value: &T) -> fmt::Result
+		{
+			if isNormal
+			{
+				return Ok(());
+			}
+			
+			value.to_css(dest)?;
+			dest.write_char(' ')
+		}
+		
+		keyword(dest, self.font_style == FontStyle::normal, &self.font_style)?;
+		keyword(dest, self.font_variant_caps == FontVariantCaps::normal, &self.font_variant_caps)?;
+		keyword(dest, self.font_weight == FontWeightValue::default(), &self.font_weight)?;
+		keyword(dest, self.font_stretch == FontStretch::normal, &self.font_stretch)?;
+		
+		self.font_size.to_css(dest)?;
+		
+		if self.line_height != LineHeight::normal
+		{
+			dest.write_char('/')?;
+			self.line_height.to_css(dest)?;
+		}
+		
+		dest.write_char(' ')?;
+		self.font_family.to_css(dest)
+	}
+}
+
+impl ExplicitFont
+{
+	/// A font of the given size and family; all the other longhand properties have their initial values.
+	#[inline(always)]
+	pub fn new(font_size: FontSize, font_family: FontFamilyList) -> Self
+	{
+		Self
+		{
+			font_style: FontStyle::normal,
+			font_variant_caps: FontVariantCaps::normal,
+			font_weight: FontWeightValue::default(),
+			font_stretch: FontStretch::normal,
+			font_size,
+			line_height: LineHeight::normal,
+			font_family,
+		}
+	}
+	
+	/// Whether the font size or line height is a `var()`, which might be substituted by more than one value.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		self.font_size.is_var() || self.line_height.is_var()
+	}
+	
+	/// Parses `[ <'font-style'> || small-caps || <'font-weight'> || <'font-stretch'> ]? <'font-size'> [ / <'line-height'> ]? <'font-family'>`.
+	pub(crate) fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut font_style = None;
+		let mut font_variant_caps = None;
+		let mut font_weight = None;
+		let mut font_stretch = None;
+		
+		// `normal` can be any of these, so is counted but otherwise ignored.
+		for _ in 0..4
+		{
+			if input.try(|input| input.expect_ident_matching("normal")).is_ok()
+			{
+				continue;
+			}
+			
+			if font_style.is_none()
+			{
+				if let Ok(value) = input.try(|input| FontStyle::parse(input))
+				{
+					font_style = Some(value);
+					continue;
+				}
+			}
+			
+			if font_variant_caps.is_none()
+			{
+				if input.try(|input| input.expect_ident_matching("small-caps")).is_ok()
+				{
+					font_variant_caps = Some(FontVariantCaps::small_caps);
+					continue;
+				}
+			}
+			
+			if font_weight.is_none()
+			{
+				if let Ok(value) = input.try(|input| FontWeightValue::parse_property_value(context, input))
+				{
+					font_weight = Some(value);
+					continue;
+				}
+			}
+			
+			if font_stretch.is_none()
+			{
+				if let Ok(value) = input.try(|input| FontStretch::parse(input))
+				{
+					font_stretch = Some(value);
+					continue;
+				}
+			}
+			
+			break;
+		}
+		
+		let font_size = FontSize::parse_property_value(context, input)?;
+		
+		let line_height = if input.try(|input| input.expect_delim('/')).is_ok()
+		{
+			LineHeight::parse_property_value(context, input)?
+		}
+		else
+		{
+			LineHeight::normal
+		};
+		
+		let font_family = FontFamilyList::parse_property_value(context, input)?;
+		
+		Ok
+		(
+			Self
+			{
+				font_style: font_style.unwrap_or(FontStyle::normal),
+				font_variant_caps: font_variant_caps.unwrap_or(FontVariantCaps::normal),
+				font_weight: font_weight.unwrap_or_default(),
+				font_stretch: font_stretch.unwrap_or(FontStretch::normal),
+				font_size,
+				line_height,
+				font_family,
+			}
+		)
+	}
+}
@@ -0,0 +1,171 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `font` shorthand property.
+/// Besides the longhand properties it sets, it resets all the other font properties (such as `font-variant-ligatures` and `font-feature-settings`) to their initial values.
+/// See https://drafts.csswg.org/css-fonts-4/#font-prop
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Font
+{
+	/// A system font, such as `caption`, chosen by the user agent.
+	System(SystemFont),
+	
+	/// A font size and family, optionally with a style, weight, stretch, small capitals and a line height.
+	Explicit(ExplicitFont),
+}
+
+impl ToCss for Font
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::Font::*;
+		
+		match *self
+		{
+			System(ref systemFont) => systemFont.to_css(dest),
+			
+			Explicit(ref explicitFont) => explicitFont.to_css(dest),
+		}
+	}
+}
+
+impl PropertyValue for Font
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"font",
+	];
+	
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::Font::*;
+		
+		if let Ok(systemFont) = input.try(|input| SystemFont::parse(input))
+		{
+			return Ok(System(systemFont));
+		}
+		
+		ExplicitFont::parse(context, input).map(Explicit)
+	}
+}
+
+impl Shorthand for Font
+{
+	#[inline(always)]
+	fn longhand_property_names(_shorthand_property_name: &str) -> &'static [&'static str]
+	{
+		Self::LonghandPropertyNames
+	}
+	
+	#[inline(always)]
+	fn is_related_property(shorthand_property_name: &str, property_name: &str) -> bool
+	{
+		is_related_font_property(shorthand_property_name, property_name)
+	}
+	
+	/// Returns `None` for system fonts, as the values of their longhand properties are only known to the user agent.
+	fn expand(&self) -> Option<Vec<SpecifiedValue>>
+	{
+		let explicitFont = match *self
+		{
+			Font::System(_) => return None,
+			Font::Explicit(ref explicitFont) => explicitFont,
+		};
+		
+		if explicitFont.is_var()
+		{
+			return None;
+		}
+		
+		let mut longhands = Vec::with_capacity(Self::LonghandPropertyNames.len());
+		longhands.push(SpecifiedValue::from_typed_value(&explicitFont.font_style).ok()?);
+		longhands.push(SpecifiedValue::from_typed_value(&explicitFont.font_variant_caps).ok()?);
+		longhands.push(SpecifiedValue::from_typed_value(&explicitFont.font_weight).ok()?);
+		longhands.push(SpecifiedValue::from_typed_value(&explicitFont.font_stretch).ok()?);
+		longhands.push(SpecifiedValue::from_typed_value(&explicitFont.font_size).ok()?);
+		longhands.push(SpecifiedValue::from_typed_value(&explicitFont.line_height).ok()?);
+		longhands.push(SpecifiedValue::from_typed_value(&explicitFont.font_family).ok()?);
+		for initialValue in Self::ResetLonghandInitialValues.iter()
+		{
+			longhands.push(SpecifiedValue::from_valid_css((*initialValue).to_owned()).ok()?);
+		}
+		Some(longhands)
+	}
+	
+	fn fold(longhands: &[&SpecifiedValue]) -> Option<Self>
+	{
+		if longhands.len() != Self::LonghandPropertyNames.len()
+		{
+			return None;
+		}
+		
+		let explicitFont = ExplicitFont
+		{
+			font_style: FontStyle::parse_property_value_from_css(longhands[0].original_css()).ok()?,
+			font_variant_caps: FontVariantCaps::parse_property_value_from_css(longhands[1].original_css()).ok()?,
+			font_weight: FontWeightValue::parse_property_value_from_css(longhands[2].original_css()).ok()?,
+			font_stretch: FontStretch::parse_property_value_from_css(longhands[3].original_css()).ok()?,
+			font_size: FontSize::parse_property_value_from_css(longhands[4].original_css()).ok()?,
+			line_height: LineHeight::parse_property_value_from_css(longhands[5].original_css()).ok()?,
+			font_family: FontFamilyList::parse_property_value_from_css(longhands[6].original_css()).ok()?,
+		};
+		
+		if explicitFont.is_var() || (explicitFont.font_variant_caps != FontVariantCaps::normal && explicitFont.font_variant_caps != FontVariantCaps::small_caps)
+		{
+			return None;
+		}
+		
+		let resetLonghands = &longhands[(Self::LonghandPropertyNames.len() - Self::ResetLonghandInitialValues.len())..];
+		if resetLonghands.iter().zip(Self::ResetLonghandInitialValues.iter()).any(|(longhand, initialValue)| !longhand.original_css().trim().eq_ignore_ascii_case(initialValue))
+		{
+			return None;
+		}
+		
+		Some(Font::Explicit(explicitFont))
+	}
+}
+
+impl Font
+{
+	/// The longhand properties set by the `font` shorthand property, followed by those it resets (in the same order as `ResetLonghandInitialValues`).
+	pub const LonghandPropertyNames: &'static [&'static str] = &
+	[
+		"font-style",
+		"font-variant-caps",
+		"font-weight",
+		"font-stretch",
+		"font-size",
+		"line-height",
+		"font-family",
+		"font-size-adjust",
+		"font-kerning",
+		"font-language-override",
+		"font-feature-settings",
+		"font-optical-sizing",
+		"font-variation-settings",
+		"font-variant-alternates",
+		"font-variant-east-asian",
+		"font-variant-emoji",
+		"font-variant-ligatures",
+		"font-variant-numeric",
+		"font-variant-position",
+	];
+	
+	/// The initial values of the longhand properties which the `font` shorthand property resets.
+	pub const ResetLonghandInitialValues: &'static [&'static str] = &
+	[
+		"none",
+		"auto",
+		"normal",
+		"normal",
+		"auto",
+		"normal",
+		"normal",
+		"normal",
+		"normal",
+		"normal",
+		"normal",
+		"normal",
+	];
+}
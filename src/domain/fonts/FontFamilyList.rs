@@ -0,0 +1,68 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `font-family` property; a prioritized list of family names and generic families.
+/// See https://drafts.csswg.org/css-fonts-3/#font-family-prop
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct FontFamilyList(pub Vec<FontFamily>);
+
+impl ToCss for FontFamilyList
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		let mut afterFirst = false;
+		for fontFamily in self.0.iter()
+		{
+			if afterFirst
+			{
+				dest.write_char(',')?;
+			}
+			else
+			{
+				afterFirst = true;
+			}
+			fontFamily.to_css(dest)?;
+		}
+		Ok(())
+	}
+}
+
+impl PropertyValue for FontFamilyList
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"font-family",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		input.parse_comma_separated(|input| FontFamily::parse(input)).map(FontFamilyList)
+	}
+}
+
+impl FontFamilyList
+{
+	/// The names of the font families which are not generic, in order of priority.
+	#[inline(always)]
+	pub fn family_names(&self) -> Vec<&FamilyName>
+	{
+		self.0.iter().filter_map(|fontFamily| match *fontFamily
+		{
+			FontFamily::FamilyName(ref familyName) => Some(familyName),
+			FontFamily::Generic(_) => None,
+		}).collect()
+	}
+	
+	/// The generic font family which is the last resort, if any.
+	#[inline(always)]
+	pub fn generic(&self) -> Option<GenericFontFamilyName>
+	{
+		self.0.iter().filter_map(|fontFamily| match *fontFamily
+		{
+			FontFamily::FamilyName(_) => None,
+			FontFamily::Generic(generic) => Some(generic),
+		}).next()
+	}
+}
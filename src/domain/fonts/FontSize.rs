@@ -0,0 +1,129 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `font-size` property.
+/// See https://drafts.csswg.org/css-fonts-4/#font-size-prop
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[allow(missing_docs)]
+pub enum FontSize
+{
+	xx_small,
+	x_small,
+	small,
+	medium,
+	large,
+	x_large,
+	xx_large,
+	xxx_large,
+	
+	/// Larger than the parent element's font size.
+	larger,
+	
+	/// Smaller than the parent element's font size.
+	smaller,
+	
+	/// invariant or calculated non-negative length or non-negative percentage (of the parent element's font size)
+	value(CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>),
+}
+
+impl Default for FontSize
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		FontSize::medium
+	}
+}
+
+impl ToCss for FontSize
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::FontSize::*;
+		
+		match *self
+		{
+			xx_small => dest.write_str("xx-small"),
+			
+			x_small => dest.write_str("x-small"),
+			
+			small => dest.write_str("small"),
+			
+			medium => dest.write_str("medium"),
+			
+			large => dest.write_str("large"),
+			
+			x_large => dest.write_str("x-large"),
+			
+			xx_large => dest.write_str("xx-large"),
+			
+			xxx_large => dest.write_str("xxx-large"),
+			
+			larger => dest.write_str("larger"),
+			
+			smaller => dest.write_str("smaller"),
+			
+			value(ref numeric_value) => numeric_value.to_css(dest),
+		}
+	}
+}
+
+impl PropertyValue for FontSize
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"font-size",
+	];
+	
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::FontSize::*;
+		
+		if let Ok(ident) = input.try(|i| i.expect_ident_cloned())
+		{
+			return match_ignore_ascii_case!
+			{
+				&*ident,
+				
+				"xx-small" => Ok(xx_small),
+				
+				"x-small" => Ok(x_small),
+				
+				"small" => Ok(small),
+				
+				"medium" => Ok(medium),
+				
+				"large" => Ok(large),
+				
+				"x-large" => Ok(x_large),
+				
+				"xx-large" => Ok(xx_large),
+				
+				"xxx-large" => Ok(xxx_large),
+				
+				"larger" => Ok(larger),
+				
+				"smaller" => Ok(smaller),
+				
+				_ => Err(ParseError::Basic(BasicParseError::UnexpectedToken(Token::Ident(ident)))),
+			}
+		}
+		
+		Ok(value(LengthOrPercentageUnit::parse_one_outside_calc_function(context, input)?))
+	}
+}
+
+impl FontSize
+{
+	/// Whether this value is a `var()`, which might be substituted by more than one value.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		match *self
+		{
+			FontSize::value(ref numeric_value) => numeric_value.is_var(),
+			_ => false,
+		}
+	}
+}
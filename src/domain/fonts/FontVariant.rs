@@ -0,0 +1,235 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `font-variant` shorthand property.
+/// See https://drafts.csswg.org/css-fonts-4/#font-variant-prop
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct FontVariant
+{
+	/// The value of the `font-variant-ligatures` property.
+	pub font_variant_ligatures: FontVariantLigatures,
+	
+	/// The value of the `font-variant-caps` property.
+	pub font_variant_caps: FontVariantCaps,
+	
+	/// The value of the `font-variant-alternates` property.
+	pub font_variant_alternates: FontVariantAlternates,
+	
+	/// The value of the `font-variant-numeric` property.
+	pub font_variant_numeric: FontVariantNumeric,
+	
+	/// The value of the `font-variant-east-asian` property.
+	pub font_variant_east_asian: FontVariantEastAsian,
+	
+	/// The value of the `font-variant-position` property.
+	pub font_variant_position: FontVariantPosition,
+	
+	/// The value of the `font-variant-emoji` property.
+	pub font_variant_emoji: FontVariantEmoji,
+}
+
+impl ToCss for FontVariant
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.font_variant_ligatures.none && self.is_normal_except_ligatures()
+		{
+			return dest.write_str("none");
+		}
+		
+		if self.font_variant_ligatures.is_normal() && self.is_normal_except_ligatures()
+		{
+			return dest.write_str("normal");
+		}
+		
+		#[inline(always)]
+		fn keyword<W: fmt::Write, T: ToCss>(afterFirst: &mut bool, dest: &mut W, isNormal: bool, value: &T) -> fmt::Result
+		{
+			if isNormal
+			{
+				return Ok(());
+			}
+			
+			if *afterFirst
+			{
+				dest.write_char(' ')?;
+			}
+			else
+			{
+				*afterFirst = true;
+			}
+			value.to_css(dest)
+		}
+		
+		let mut afterFirst = false;
+		self.font_variant_ligatures.write_keywords(&mut afterFirst, dest)?;
+		keyword(&mut afterFirst, dest, self.font_variant_caps == FontVariantCaps::normal, &self.font_variant_caps)?;
+		self.font_variant_alternates.write_keywords(&mut afterFirst, dest)?;
+		self.font_variant_numeric.write_keywords(&mut afterFirst, dest)?;
+		self.font_variant_east_asian.write_keywords(&mut afterFirst, dest)?;
+		keyword(&mut afterFirst, dest, self.font_variant_position == FontVariantPosition::normal, &self.font_variant_position)?;
+		keyword(&mut afterFirst, dest, self.font_variant_emoji == FontVariantEmoji::normal, &self.font_variant_emoji)
+	}
+}
+
+impl PropertyValue for FontVariant
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"font-variant",
+	];
+	
+	fn parse_property_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.try(|input| input.expect_ident_matching("normal")).is_ok()
+		{
+			return Ok(Self::default());
+		}
+		
+		if input.try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			return Ok
+			(
+				Self
+				{
+					font_variant_ligatures: FontVariantLigatures::none(),
+					.. Self::default()
+				}
+			);
+		}
+		
+		let mut fontVariant = Self::default();
+		fontVariant.parse_keyword_or_function(input)?;
+		while input.try(|input| fontVariant.parse_keyword_or_function(input)).is_ok()
+		{
+		}
+		Ok(fontVariant)
+	}
+}
+
+impl Shorthand for FontVariant
+{
+	#[inline(always)]
+	fn longhand_property_names(_shorthand_property_name: &str) -> &'static [&'static str]
+	{
+		&[
+			"font-variant-ligatures",
+			"font-variant-caps",
+			"font-variant-alternates",
+			"font-variant-numeric",
+			"font-variant-east-asian",
+			"font-variant-position",
+			"font-variant-emoji",
+		]
+	}
+	
+	#[inline(always)]
+	fn is_related_property(shorthand_property_name: &str, property_name: &str) -> bool
+	{
+		is_related_font_property(shorthand_property_name, property_name)
+	}
+	
+	#[inline(always)]
+	fn expand(&self) -> Option<Vec<SpecifiedValue>>
+	{
+		Some
+		(
+			vec!
+			[
+				SpecifiedValue::from_typed_value(&self.font_variant_ligatures).ok()?,
+				SpecifiedValue::from_typed_value(&self.font_variant_caps).ok()?,
+				SpecifiedValue::from_typed_value(&self.font_variant_alternates).ok()?,
+				SpecifiedValue::from_typed_value(&self.font_variant_numeric).ok()?,
+				SpecifiedValue::from_typed_value(&self.font_variant_east_asian).ok()?,
+				SpecifiedValue::from_typed_value(&self.font_variant_position).ok()?,
+				SpecifiedValue::from_typed_value(&self.font_variant_emoji).ok()?,
+			]
+		)
+	}
+	
+	fn fold(longhands: &[&SpecifiedValue]) -> Option<Self>
+	{
+		if longhands.len() != 7
+		{
+			return None;
+		}
+		
+		let fontVariant = Self
+		{
+			font_variant_ligatures: FontVariantLigatures::parse_property_value_from_css(longhands[0].original_css()).ok()?,
+			font_variant_caps: FontVariantCaps::parse_property_value_from_css(longhands[1].original_css()).ok()?,
+			font_variant_alternates: FontVariantAlternates::parse_property_value_from_css(longhands[2].original_css()).ok()?,
+			font_variant_numeric: FontVariantNumeric::parse_property_value_from_css(longhands[3].original_css()).ok()?,
+			font_variant_east_asian: FontVariantEastAsian::parse_property_value_from_css(longhands[4].original_css()).ok()?,
+			font_variant_position: FontVariantPosition::parse_property_value_from_css(longhands[5].original_css()).ok()?,
+			font_variant_emoji: FontVariantEmoji::parse_property_value_from_css(longhands[6].original_css()).ok()?,
+		};
+		
+		// `none` can only disable ligatures if every other longhand is `normal`.
+		if fontVariant.font_variant_ligatures.none && !fontVariant.is_normal_except_ligatures()
+		{
+			return None;
+		}
+		
+		Some(fontVariant)
+	}
+}
+
+impl FontVariant
+{
+	#[inline(always)]
+	fn is_normal_except_ligatures(&self) -> bool
+	{
+		self.font_variant_caps == FontVariantCaps::normal && self.font_variant_alternates.is_normal() && self.font_variant_numeric.is_normal() && self.font_variant_east_asian.is_normal() && self.font_variant_position == FontVariantPosition::normal && self.font_variant_emoji == FontVariantEmoji::normal
+	}
+	
+	fn parse_keyword_or_function<'i, 't>(&mut self, input: &mut Parser<'i, 't>) -> Result<(), ParseError<'i, CustomParseError<'i>>>
+	{
+		let ident = match input.next()?.clone()
+		{
+			Token::Ident(ident) => ident,
+			
+			Token::Function(name) => return input.parse_nested_block(|input| self.font_variant_alternates.parse_function(name, input)),
+			
+			unexpectedToken @ _ => return CustomParseError::unexpectedToken(&unexpectedToken),
+		};
+		
+		if self.font_variant_ligatures.accept_keyword(&ident) || self.font_variant_alternates.accept_keyword(&ident) || self.font_variant_numeric.accept_keyword(&ident) || self.font_variant_east_asian.accept_keyword(&ident)
+		{
+			return Ok(());
+		}
+		
+		if !ident.eq_ignore_ascii_case("normal")
+		{
+			if let Ok(caps) = FontVariantCaps::from_ident(&ident)
+			{
+				if self.font_variant_caps == FontVariantCaps::normal
+				{
+					self.font_variant_caps = caps;
+					return Ok(());
+				}
+			}
+			
+			if let Ok(position) = FontVariantPosition::from_ident(&ident)
+			{
+				if self.font_variant_position == FontVariantPosition::normal
+				{
+					self.font_variant_position = position;
+					return Ok(());
+				}
+			}
+			
+			if let Ok(fontVariantEmoji) = FontVariantEmoji::from_ident(&ident)
+			{
+				if self.font_variant_emoji == FontVariantEmoji::normal
+				{
+					self.font_variant_emoji = fontVariantEmoji;
+					return Ok(());
+				}
+			}
+		}
+		
+		Err(ParseError::Custom(CustomParseError::UnknownOrRepeatedFontVariantKeyword(ident)))
+	}
+}
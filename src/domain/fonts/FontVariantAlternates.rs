@@ -0,0 +1,225 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `font-variant-alternates` property; `normal` if nothing is specified.
+/// The functions refer to feature values defined by `@font-feature-values` rules.
+/// See https://drafts.csswg.org/css-fonts-4/#font-variant-alternates-prop
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct FontVariantAlternates
+{
+	/// `stylistic()`.
+	pub stylistic: Option<CustomIdent>,
+	
+	/// `historical-forms`.
+	pub historical_forms: bool,
+	
+	/// `styleset()`; empty if not specified.
+	pub styleset: Vec<CustomIdent>,
+	
+	/// `character-variant()`; empty if not specified.
+	pub character_variant: Vec<CustomIdent>,
+	
+	/// `swash()`.
+	pub swash: Option<CustomIdent>,
+	
+	/// `ornaments()`.
+	pub ornaments: Option<CustomIdent>,
+	
+	/// `annotation()`.
+	pub annotation: Option<CustomIdent>,
+}
+
+impl ToCss for FontVariantAlternates
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.is_normal()
+		{
+			return dest.write_str("normal");
+		}
+		
+		let mut afterFirst = false;
+		self.write_keywords(&mut afterFirst, dest)
+	}
+}
+
+impl PropertyValue for FontVariantAlternates
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"font-variant-alternates",
+	];
+	
+	fn parse_property_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.try(|input| input.expect_ident_matching("normal")).is_ok()
+		{
+			return Ok(Self::default());
+		}
+		
+		let mut alternates = Self::default();
+		alternates.parse_keyword_or_function(input)?;
+		while input.try(|input| alternates.parse_keyword_or_function(input)).is_ok()
+		{
+		}
+		Ok(alternates)
+	}
+}
+
+impl FontVariantAlternates
+{
+	/// Whether this is `normal`.
+	#[inline(always)]
+	pub fn is_normal(&self) -> bool
+	{
+		*self == Self::default()
+	}
+	
+	pub(crate) fn write_keywords<W: fmt::Write>(&self, afterFirst: &mut bool, dest: &mut W) -> fmt::Result
+	{
+		#[inline(always)]
+		fn separate<W: fmt::Write>(afterFirst: &mut bool, dest: &mut W) -> fmt::Result
+		{
+			if *afterFirst
+			{
+				dest.write_char(' ')
+			}
+			else
+			{
+				*afterFirst = true;
+				Ok(())
+			}
+		}
+		
+		#[inline(always)]
+		fn function<W: fmt::Write>(afterFirst: &mut bool, dest: &mut W, name: &str, featureValueNames: &[CustomIdent]) -> fmt::Result
+		{
+			if featureValueNames.is_empty()
+			{
+				return Ok(());
+			}
+			
+			separate(afterFirst, dest)?;
+			dest.write_str(name)?;
+			dest.write_char('(')?;
+			let mut afterFirstFeatureValueName = false;
+			for featureValueName in featureValueNames.iter()
+			{
+				if afterFirstFeatureValueName
+				{
+					dest.write_char(',')?;
+				}
+				else
+				{
+					afterFirstFeatureValueName = true;
+				}
+				featureValueName.to_css(dest)?;
+			}
+			dest.write_char(')')
+		}
+		
+		function(afterFirst, dest, "stylistic", self.stylistic.as_ref().map(|name| ::std::slice::from_ref(name)).unwrap_or(&[]))?;
+		
+		if self.historical_forms
+		{
+			separate(afterFirst, dest)?;
+			dest.write_str("historical-forms")?;
+		}
+		
+		function(afterFirst, dest, "styleset", &self.styleset)?;
+		function(afterFirst, dest, "character-variant", &self.character_variant)?;
+		function(afterFirst, dest, "swash", self.swash.as_ref().map(|name| ::std::slice::from_ref(name)).unwrap_or(&[]))?;
+		function(afterFirst, dest, "ornaments", self.ornaments.as_ref().map(|name| ::std::slice::from_ref(name)).unwrap_or(&[]))?;
+		function(afterFirst, dest, "annotation", self.annotation.as_ref().map(|name| ::std::slice::from_ref(name)).unwrap_or(&[]))
+	}
+	
+	/// Parses `historical-forms` or one of the functions; it is an error if either is unknown or has already been specified.
+	pub(crate) fn parse_keyword_or_function<'i, 't>(&mut self, input: &mut Parser<'i, 't>) -> Result<(), ParseError<'i, CustomParseError<'i>>>
+	{
+		match input.next()?.clone()
+		{
+			Token::Ident(ident) =>
+			{
+				if self.accept_keyword(&ident)
+				{
+					Ok(())
+				}
+				else
+				{
+					Err(ParseError::Custom(CustomParseError::UnknownOrRepeatedFontVariantKeyword(ident)))
+				}
+			}
+			
+			Token::Function(name) => input.parse_nested_block(|input| self.parse_function(name, input)),
+			
+			unexpectedToken @ _ => CustomParseError::unexpectedToken(&unexpectedToken),
+		}
+	}
+	
+	/// Returns false if the keyword is not `historical-forms` or has already been specified.
+	pub(crate) fn accept_keyword(&mut self, ident: &str) -> bool
+	{
+		if ident.eq_ignore_ascii_case("historical-forms") && !self.historical_forms
+		{
+			self.historical_forms = true;
+			true
+		}
+		else
+		{
+			false
+		}
+	}
+	
+	/// Parses the arguments of a function.
+	pub(crate) fn parse_function<'i, 't>(&mut self, name: CowRcStr<'i>, input: &mut Parser<'i, 't>) -> Result<(), ParseError<'i, CustomParseError<'i>>>
+	{
+		#[inline(always)]
+		fn parse_feature_value_name<'i, 't>(input: &mut Parser<'i, 't>) -> Result<CustomIdent, ParseError<'i, CustomParseError<'i>>>
+		{
+			let ident = input.expect_ident_cloned()?;
+			CustomIdent::from_ident(&ident, &[])
+		}
+		
+		#[inline(always)]
+		fn single<'i, 't>(name: CowRcStr<'i>, featureValueName: &mut Option<CustomIdent>, input: &mut Parser<'i, 't>) -> Result<(), ParseError<'i, CustomParseError<'i>>>
+		{
+			if featureValueName.is_some()
+			{
+				return Err(ParseError::Custom(CustomParseError::UnknownOrRepeatedFontVariantAlternatesFunction(name)));
+			}
+			*featureValueName = Some(parse_feature_value_name(input)?);
+			Ok(())
+		}
+		
+		#[inline(always)]
+		fn list<'i, 't>(name: CowRcStr<'i>, featureValueNames: &mut Vec<CustomIdent>, input: &mut Parser<'i, 't>) -> Result<(), ParseError<'i, CustomParseError<'i>>>
+		{
+			if !featureValueNames.is_empty()
+			{
+				return Err(ParseError::Custom(CustomParseError::UnknownOrRepeatedFontVariantAlternatesFunction(name)));
+			}
+			*featureValueNames = input.parse_comma_separated(parse_feature_value_name)?;
+			Ok(())
+		}
+		
+		match_ignore_ascii_case!
+		{
+			&*name,
+			
+			"stylistic" => single(name.clone(), &mut self.stylistic, input),
+			
+			"styleset" => list(name.clone(), &mut self.styleset, input),
+			
+			"character-variant" => list(name.clone(), &mut self.character_variant, input),
+			
+			"swash" => single(name.clone(), &mut self.swash, input),
+			
+			"ornaments" => single(name.clone(), &mut self.ornaments, input),
+			
+			"annotation" => single(name.clone(), &mut self.annotation, input),
+			
+			_ => Err(ParseError::Custom(CustomParseError::UnknownOrRepeatedFontVariantAlternatesFunction(name.clone()))),
+		}
+	}
+}
@@ -0,0 +1,38 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+define_css_keyword_enum!
+{
+	FontVariantCaps:
+	"normal" => normal,
+	"small-caps" => small_caps,
+	"all-small-caps" => all_small_caps,
+	"petite-caps" => petite_caps,
+	"all-petite-caps" => all_petite_caps,
+	"unicase" => unicase,
+	"titling-caps" => titling_caps,
+}
+
+impl Default for FontVariantCaps
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		FontVariantCaps::normal
+	}
+}
+
+impl PropertyValue for FontVariantCaps
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"font-variant-caps",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		FontVariantCaps::parse(input)
+	}
+}
@@ -0,0 +1,153 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `font-variant-east-asian` property; `normal` if nothing is specified.
+/// See https://drafts.csswg.org/css-fonts-4/#font-variant-east-asian-prop
+#[derive(Debug, Default, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct FontVariantEastAsian
+{
+	/// `jis78`, `jis83`, `jis90`, `jis04`, `simplified` or `traditional`.
+	pub variant: Option<FontVariantEastAsianVariant>,
+	
+	/// `full-width` or `proportional-width`.
+	pub width: Option<FontVariantEastAsianWidth>,
+	
+	/// `ruby`.
+	pub ruby: bool,
+}
+
+impl ToCss for FontVariantEastAsian
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.is_normal()
+		{
+			return dest.write_str("normal");
+		}
+		
+		let mut afterFirst = false;
+		self.write_keywords(&mut afterFirst, dest)
+	}
+}
+
+impl PropertyValue for FontVariantEastAsian
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"font-variant-east-asian",
+	];
+	
+	fn parse_property_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.try(|input| input.expect_ident_matching("normal")).is_ok()
+		{
+			return Ok(Self::default());
+		}
+		
+		let mut eastAsian = Self::default();
+		eastAsian.parse_keyword(input)?;
+		while input.try(|input| eastAsian.parse_keyword(input)).is_ok()
+		{
+		}
+		Ok(eastAsian)
+	}
+}
+
+impl FontVariantEastAsian
+{
+	/// Whether this is `normal`.
+	#[inline(always)]
+	pub fn is_normal(&self) -> bool
+	{
+		*self == Self::default()
+	}
+	
+	#[inline(always)]
+	pub(crate) fn write_keywords<W: fmt::Write>(&self, afterFirst: &mut bool, dest: &mut W) -> fmt::Result
+	{
+		#[inline(always)]
+		fn separate<W: fmt::Write>(afterFirst: &mut bool, dest: &mut W) -> fmt::Result
+		{
+			if *afterFirst
+			{
+				dest.write_char(' ')
+			}
+			else
+			{
+				*afterFirst = true;
+				Ok(())
+			}
+		}
+		
+		if let Some(variant) = self.variant
+		{
+			separate(afterFirst, dest)?;
+			variant.to_css(dest)?;
+		}
+		
+		if let Some(width) = self.width
+		{
+			separate(afterFirst, dest)?;
+			width.to_css(dest)?;
+		}
+		
+		if self.ruby
+		{
+			separate(afterFirst, dest)?;
+			dest.write_str("ruby")?;
+		}
+		
+		Ok(())
+	}
+	
+	/// Parses one keyword other than `normal`; it is an error if the keyword is unknown or its setting has already been specified.
+	pub(crate) fn parse_keyword<'i, 't>(&mut self, input: &mut Parser<'i, 't>) -> Result<(), ParseError<'i, CustomParseError<'i>>>
+	{
+		let ident = input.expect_ident_cloned()?;
+		if self.accept_keyword(&ident)
+		{
+			Ok(())
+		}
+		else
+		{
+			Err(ParseError::Custom(CustomParseError::UnknownOrRepeatedFontVariantKeyword(ident)))
+		}
+	}
+	
+	/// Returns false if the keyword is unknown or its setting has already been specified.
+	pub(crate) fn accept_keyword(&mut self, ident: &str) -> bool
+	{
+		if let Ok(variant) = FontVariantEastAsianVariant::from_ident(ident)
+		{
+			if self.variant.is_some()
+			{
+				return false;
+			}
+			self.variant = Some(variant);
+			return true;
+		}
+		
+		if let Ok(width) = FontVariantEastAsianWidth::from_ident(ident)
+		{
+			if self.width.is_some()
+			{
+				return false;
+			}
+			self.width = Some(width);
+			return true;
+		}
+		
+		if ident.eq_ignore_ascii_case("ruby")
+		{
+			if self.ruby
+			{
+				return false;
+			}
+			self.ruby = true;
+			return true;
+		}
+		
+		false
+	}
+}
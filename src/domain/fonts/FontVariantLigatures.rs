@@ -0,0 +1,170 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `font-variant-ligatures` property.
+/// Each of the `Option<bool>` fields is `None` if not specified, `Some(true)` if enabled (eg `common-ligatures`) and `Some(false)` if disabled (eg `no-common-ligatures`).
+/// See https://drafts.csswg.org/css-fonts-4/#font-variant-ligatures-prop
+#[derive(Debug, Default, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct FontVariantLigatures
+{
+	/// `none`; disables all ligatures and contextual forms. If true, all other fields are `None`.
+	pub none: bool,
+	
+	/// `common-ligatures` or `no-common-ligatures`.
+	pub common_ligatures: Option<bool>,
+	
+	/// `discretionary-ligatures` or `no-discretionary-ligatures`.
+	pub discretionary_ligatures: Option<bool>,
+	
+	/// `historical-ligatures` or `no-historical-ligatures`.
+	pub historical_ligatures: Option<bool>,
+	
+	/// `contextual` or `no-contextual`.
+	pub contextual: Option<bool>,
+}
+
+impl ToCss for FontVariantLigatures
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.none
+		{
+			return dest.write_str("none");
+		}
+		
+		if self.is_normal()
+		{
+			return dest.write_str("normal");
+		}
+		
+		let mut afterFirst = false;
+		self.write_keywords(&mut afterFirst, dest)
+	}
+}
+
+impl PropertyValue for FontVariantLigatures
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"font-variant-ligatures",
+	];
+	
+	fn parse_property_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.try(|input| input.expect_ident_matching("normal")).is_ok()
+		{
+			return Ok(Self::default());
+		}
+		
+		if input.try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			return Ok(Self::none());
+		}
+		
+		let mut ligatures = Self::default();
+		ligatures.parse_keyword(input)?;
+		while input.try(|input| ligatures.parse_keyword(input)).is_ok()
+		{
+		}
+		Ok(ligatures)
+	}
+}
+
+impl FontVariantLigatures
+{
+	/// `none`.
+	#[inline(always)]
+	pub fn none() -> Self
+	{
+		Self
+		{
+			none: true,
+			.. Self::default()
+		}
+	}
+	
+	/// Whether this is `normal`.
+	#[inline(always)]
+	pub fn is_normal(&self) -> bool
+	{
+		*self == Self::default()
+	}
+	
+	#[inline(always)]
+	pub(crate) fn write_keywords<W: fmt::Write>(&self, afterFirst: &mut bool, dest: &mut W) -> fmt::Result
+	{
+		let keywords =
+		[
+			(self.common_ligatures, "common-ligatures", "no-common-ligatures"),
+			(self.discretionary_ligatures, "discretionary-ligatures", "no-discretionary-ligatures"),
+			(self.historical_ligatures, "historical-ligatures", "no-historical-ligatures"),
+			(self.contextual, "contextual", "no-contextual"),
+		];
+		
+		for &(setting, enabled, disabled) in keywords.iter()
+		{
+			if let Some(isEnabled) = setting
+			{
+				if *afterFirst
+				{
+					dest.write_char(' ')?;
+				}
+				else
+				{
+					*afterFirst = true;
+				}
+				dest.write_str(if isEnabled { enabled } else { disabled })?;
+			}
+		}
+		Ok(())
+	}
+	
+	/// Parses one keyword other than `normal` and `none`; it is an error if the keyword is unknown or its setting has already been specified.
+	pub(crate) fn parse_keyword<'i, 't>(&mut self, input: &mut Parser<'i, 't>) -> Result<(), ParseError<'i, CustomParseError<'i>>>
+	{
+		let ident = input.expect_ident_cloned()?;
+		if self.accept_keyword(&ident)
+		{
+			Ok(())
+		}
+		else
+		{
+			Err(ParseError::Custom(CustomParseError::UnknownOrRepeatedFontVariantKeyword(ident)))
+		}
+	}
+	
+	/// Returns false if the keyword is unknown or its setting has already been specified.
+	pub(crate) fn accept_keyword(&mut self, ident: &str) -> bool
+	{
+		let (setting, isEnabled) = match_ignore_ascii_case!
+		{
+			ident,
+			
+			"common-ligatures" => (&mut self.common_ligatures, true),
+			
+			"no-common-ligatures" => (&mut self.common_ligatures, false),
+			
+			"discretionary-ligatures" => (&mut self.discretionary_ligatures, true),
+			
+			"no-discretionary-ligatures" => (&mut self.discretionary_ligatures, false),
+			
+			"historical-ligatures" => (&mut self.historical_ligatures, true),
+			
+			"no-historical-ligatures" => (&mut self.historical_ligatures, false),
+			
+			"contextual" => (&mut self.contextual, true),
+			
+			"no-contextual" => (&mut self.contextual, false),
+			
+			_ => return false,
+		};
+		
+		if setting.is_some()
+		{
+			return false;
+		}
+		*setting = Some(isEnabled);
+		true
+	}
+}
@@ -0,0 +1,191 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `font-variant-numeric` property; `normal` if nothing is specified.
+/// See https://drafts.csswg.org/css-fonts-4/#font-variant-numeric-prop
+#[derive(Debug, Default, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct FontVariantNumeric
+{
+	/// `lining-nums` or `oldstyle-nums`.
+	pub figure: Option<FontVariantNumericFigure>,
+	
+	/// `proportional-nums` or `tabular-nums`.
+	pub spacing: Option<FontVariantNumericSpacing>,
+	
+	/// `diagonal-fractions` or `stacked-fractions`.
+	pub fraction: Option<FontVariantNumericFraction>,
+	
+	/// `ordinal`.
+	pub ordinal: bool,
+	
+	/// `slashed-zero`.
+	pub slashed_zero: bool,
+}
+
+impl ToCss for FontVariantNumeric
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.is_normal()
+		{
+			return dest.write_str("normal");
+		}
+		
+		let mut afterFirst = false;
+		self.write_keywords(&mut afterFirst, dest)
+	}
+}
+
+impl PropertyValue for FontVariantNumeric
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"font-variant-numeric",
+	];
+	
+	fn parse_property_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.try(|input| input.expect_ident_matching("normal")).is_ok()
+		{
+			return Ok(Self::default());
+		}
+		
+		let mut numeric = Self::default();
+		numeric.parse_keyword(input)?;
+		while input.try(|input| numeric.parse_keyword(input)).is_ok()
+		{
+		}
+		Ok(numeric)
+	}
+}
+
+impl FontVariantNumeric
+{
+	/// Whether this is `normal`.
+	#[inline(always)]
+	pub fn is_normal(&self) -> bool
+	{
+		*self == Self::default()
+	}
+	
+	#[inline(always)]
+	pub(crate) fn write_keywords<W: fmt::Write>(&self, afterFirst: &mut bool, dest: &mut W) -> fmt::Result
+	{
+		#[inline(always)]
+		fn separate<W: fmt::Write>(afterFirst: &mut bool, dest: &mut W) -> fmt::Result
+		{
+			if *afterFirst
+			{
+				dest.write_char(' ')
+			}
+			else
+			{
+				*afterFirst = true;
+				Ok(())
+			}
+		}
+		
+		if let Some(figure) = self.figure
+		{
+			separate(afterFirst, dest)?;
+			figure.to_css(dest)?;
+		}
+		
+		if let Some(spacing) = self.spacing
+		{
+			separate(afterFirst, dest)?;
+			spacing.to_css(dest)?;
+		}
+		
+		if let Some(fraction) = self.fraction
+		{
+			separate(afterFirst, dest)?;
+			fraction.to_css(dest)?;
+		}
+		
+		if self.ordinal
+		{
+			separate(afterFirst, dest)?;
+			dest.write_str("ordinal")?;
+		}
+		
+		if self.slashed_zero
+		{
+			separate(afterFirst, dest)?;
+			dest.write_str("slashed-zero")?;
+		}
+		
+		Ok(())
+	}
+	
+	/// Parses one keyword other than `normal`; it is an error if the keyword is unknown or its setting has already been specified.
+	pub(crate) fn parse_keyword<'i, 't>(&mut self, input: &mut Parser<'i, 't>) -> Result<(), ParseError<'i, CustomParseError<'i>>>
+	{
+		let ident = input.expect_ident_cloned()?;
+		if self.accept_keyword(&ident)
+		{
+			Ok(())
+		}
+		else
+		{
+			Err(ParseError::Custom(CustomParseError::UnknownOrRepeatedFontVariantKeyword(ident)))
+		}
+	}
+	
+	/// Returns false if the keyword is unknown or its setting has already been specified.
+	pub(crate) fn accept_keyword(&mut self, ident: &str) -> bool
+	{
+		if let Ok(figure) = FontVariantNumericFigure::from_ident(ident)
+		{
+			if self.figure.is_some()
+			{
+				return false;
+			}
+			self.figure = Some(figure);
+			return true;
+		}
+		
+		if let Ok(spacing) = FontVariantNumericSpacing::from_ident(ident)
+		{
+			if self.spacing.is_some()
+			{
+				return false;
+			}
+			self.spacing = Some(spacing);
+			return true;
+		}
+		
+		if let Ok(fraction) = FontVariantNumericFraction::from_ident(ident)
+		{
+			if self.fraction.is_some()
+			{
+				return false;
+			}
+			self.fraction = Some(fraction);
+			return true;
+		}
+		
+		if ident.eq_ignore_ascii_case("ordinal")
+		{
+			if self.ordinal
+			{
+				return false;
+			}
+			self.ordinal = true;
+			return true;
+		}
+		
+		if ident.eq_ignore_ascii_case("slashed-zero")
+		{
+			if self.slashed_zero
+			{
+				return false;
+			}
+			self.slashed_zero = true;
+			return true;
+		}
+		
+		false
+	}
+}
@@ -0,0 +1,34 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+define_css_keyword_enum!
+{
+	FontVariantPosition:
+	"normal" => normal,
+	"sub" => sub,
+	"super" => super_,
+}
+
+impl Default for FontVariantPosition
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		FontVariantPosition::normal
+	}
+}
+
+impl PropertyValue for FontVariantPosition
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"font-variant-position",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		FontVariantPosition::parse(input)
+	}
+}
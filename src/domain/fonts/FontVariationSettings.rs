@@ -0,0 +1,102 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `font-variation-settings` property; a map of OpenType font variation axis tags to values, which is empty for `normal`.
+/// See https://drafts.csswg.org/css-fonts-4/#font-variation-settings-def
+#[derive(Debug, Default, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct FontVariationSettings(pub BTreeMap<String, CssSignedNumber>);
+
+impl ToCss for FontVariationSettings
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.0.is_empty()
+		{
+			return serialize_identifier("normal", dest);
+		}
+		
+		let mut afterFirst = false;
+		for (axisTag, value) in self.0.iter()
+		{
+			if afterFirst
+			{
+				dest.write_char(',')?;
+			}
+			else
+			{
+				afterFirst = true;
+			}
+			serialize_string(axisTag, dest)?;
+			dest.write_char(' ')?;
+			value.to_css(dest)?;
+		}
+		Ok(())
+	}
+}
+
+impl PropertyValue for FontVariationSettings
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"font-variation-settings",
+	];
+	
+	fn parse_property_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.try(|input| input.expect_ident_matching("normal")).is_ok()
+		{
+			return Ok(Self::default());
+		}
+		
+		let mut settings = BTreeMap::new();
+		for (axisTag, value) in input.parse_comma_separated(Self::parse_setting)?
+		{
+			settings.insert(axisTag, value);
+		}
+		Ok(FontVariationSettings(settings))
+	}
+}
+
+impl FontVariationSettings
+{
+	/// The value of a variation axis, if specified.
+	#[inline(always)]
+	pub fn setting(&self, axisTag: &str) -> Option<CssSignedNumber>
+	{
+		self.0.get(axisTag).map(|reference| *reference)
+	}
+	
+	/// Whether this is `normal`.
+	#[inline(always)]
+	pub fn isNormal(&self) -> bool
+	{
+		self.0.is_empty()
+	}
+	
+	fn parse_setting<'i, 't>(input: &mut Parser<'i, 't>) -> Result<(String, CssSignedNumber), ParseError<'i, CustomParseError<'i>>>
+	{
+		let axisTag =
+		{
+			let axisTag = input.expect_string()?;
+			if axisTag.len() != 4
+			{
+				return Err(ParseError::Custom(CustomParseError::FontVariationSettingAxisTagMustBeFourCharacters(axisTag.clone())))
+			}
+			
+			for character in axisTag.chars()
+			{
+				if character < '\x20' || character > '\x7E'
+				{
+					return Err(ParseError::Custom(CustomParseError::FontVariationSettingAxisTagMustBePrintableAscii(axisTag.clone())))
+				}
+			}
+			
+			axisTag.as_ref().to_owned()
+		};
+		
+		let value = input.expect_number()?;
+		let value = CssSignedNumber::new(value).map_err(|cssNumberConversionError| ParseError::Custom(CustomParseError::CouldNotParseCssSignedNumber(cssNumberConversionError, value)))?;
+		Ok((axisTag, value))
+	}
+}
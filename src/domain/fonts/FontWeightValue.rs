@@ -0,0 +1,148 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `font-weight` property.
+/// See https://drafts.csswg.org/css-fonts-4/#font-weight-prop
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum FontWeightValue
+{
+	/// A number from 1 to 1000 inclusive, such as `450`; `normal` is 400 and `bold` is 700.
+	Absolute(CssUnsignedNumber),
+	
+	/// `bolder`; relative to the inherited weight.
+	Bolder,
+	
+	/// `lighter`; relative to the inherited weight.
+	Lighter,
+}
+
+impl Default for FontWeightValue
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		FontWeightValue::Absolute(Self::normal())
+	}
+}
+
+impl ToCss for FontWeightValue
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::FontWeightValue::*;
+		
+		match *self
+		{
+			Absolute(ref weight) => weight.to_css(dest),
+			
+			Bolder => dest.write_str("bolder"),
+			
+			Lighter => dest.write_str("lighter"),
+		}
+	}
+}
+
+impl PropertyValue for FontWeightValue
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"font-weight",
+	];
+	
+	fn parse_property_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::FontWeightValue::*;
+		
+		if let Ok(weight) = input.try(|input| input.expect_number())
+		{
+			if weight < 1. || weight > 1000.
+			{
+				return Err(ParseError::Custom(CustomParseError::FontWeightMustBeANumberFrom1To1000(weight)));
+			}
+			return CssUnsignedNumber::new(weight).map(Absolute).map_err(|cssNumberConversionError| ParseError::Custom(CustomParseError::CouldNotParseCssUnsignedNumber(cssNumberConversionError, weight)));
+		}
+		
+		let ident = input.expect_ident()?;
+		match_ignore_ascii_case!
+		{
+			&ident,
+			
+			"normal" => Ok(Absolute(Self::normal())),
+			
+			"bold" => Ok(Absolute(Self::bold())),
+			
+			"bolder" => Ok(Bolder),
+			
+			"lighter" => Ok(Lighter),
+			
+			_ => Err(ParseError::Basic(BasicParseError::UnexpectedToken(Token::Ident(ident.clone())))),
+		}
+	}
+}
+
+impl FontWeightValue
+{
+	/// The weight of `normal`, 400.
+	#[inline(always)]
+	pub fn normal() -> CssUnsignedNumber
+	{
+		CssUnsignedNumber::from(400u16)
+	}
+	
+	/// The weight of `bold`, 700.
+	#[inline(always)]
+	pub fn bold() -> CssUnsignedNumber
+	{
+		CssUnsignedNumber::from(700u16)
+	}
+	
+	/// Resolves this weight against the weight of the parent element, using the table for `bolder` and `lighter` in CSS Fonts Level 4.
+	#[inline(always)]
+	pub fn resolve(&self, parentFontWeight: CssUnsignedNumber) -> CssUnsignedNumber
+	{
+		use self::FontWeightValue::*;
+		
+		let parent = parentFontWeight.as_f32();
+		let weight = |weight: u16| CssUnsignedNumber::from(weight);
+		
+		match *self
+		{
+			Absolute(fontWeight) => fontWeight,
+			
+			Bolder => if parent < 350.
+			{
+				weight(400)
+			}
+			else if parent < 550.
+			{
+				weight(700)
+			}
+			else if parent < 900.
+			{
+				weight(900)
+			}
+			else
+			{
+				parentFontWeight
+			},
+			
+			Lighter => if parent < 100.
+			{
+				parentFontWeight
+			}
+			else if parent < 550.
+			{
+				weight(100)
+			}
+			else if parent < 750.
+			{
+				weight(400)
+			}
+			else
+			{
+				weight(700)
+			},
+		}
+	}
+}
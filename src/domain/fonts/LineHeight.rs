@@ -0,0 +1,83 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `line-height` property.
+/// See https://drafts.csswg.org/css2/visudet.html#propdef-line-height
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum LineHeight
+{
+	/// Chosen by the user agent from the font's metrics
+	normal,
+	
+	/// A multiple of the element's font size
+	number(CssUnsignedNumber),
+	
+	/// invariant or calculated non-negative length or non-negative percentage (of the element's font size)
+	value(CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>),
+}
+
+impl Default for LineHeight
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		LineHeight::normal
+	}
+}
+
+impl ToCss for LineHeight
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::LineHeight::*;
+		
+		match *self
+		{
+			normal => dest.write_str("normal"),
+			
+			number(ref multiple) => multiple.to_css(dest),
+			
+			value(ref numeric_value) => numeric_value.to_css(dest),
+		}
+	}
+}
+
+impl PropertyValue for LineHeight
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"line-height",
+	];
+	
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::LineHeight::*;
+		
+		if input.try(|i| i.expect_ident_matching("normal")).is_ok()
+		{
+			return Ok(normal);
+		}
+		
+		if let Ok(multiple) = input.try(|i| i.expect_number())
+		{
+			return CssUnsignedNumber::new(multiple).map(number).map_err(|cssNumberConversionError| ParseError::Custom(CustomParseError::CouldNotParseCssUnsignedNumber(cssNumberConversionError, multiple)));
+		}
+		
+		Ok(value(LengthOrPercentageUnit::parse_one_outside_calc_function(context, input)?))
+	}
+}
+
+impl LineHeight
+{
+	/// Whether this value is a `var()`, which might be substituted by more than one value.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		match *self
+		{
+			LineHeight::value(ref numeric_value) => numeric_value.is_var(),
+			_ => false,
+		}
+	}
+}
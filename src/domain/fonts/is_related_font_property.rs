@@ -0,0 +1,22 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Whether a property sets any of the same values as the `font` or `font-variant` shorthand property; for example, `font-variant-caps` is related to both, and `font` is related to `font-variant`.
+pub(crate) fn is_related_font_property(shorthand_property_name: &str, property_name: &str) -> bool
+{
+	#[inline(always)]
+	fn starts_with_ignoring_ascii_case(value: &str, prefix: &str) -> bool
+	{
+		value.len() >= prefix.len() && value.is_char_boundary(prefix.len()) && value[..prefix.len()].eq_ignore_ascii_case(prefix)
+	}
+	
+	if shorthand_property_name.eq_ignore_ascii_case("font-variant")
+	{
+		property_name.eq_ignore_ascii_case("font") || starts_with_ignoring_ascii_case(property_name, "font-variant")
+	}
+	else
+	{
+		property_name.eq_ignore_ascii_case("line-height") || starts_with_ignoring_ascii_case(property_name, "font")
+	}
+}
@@ -0,0 +1,36 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+use super::properties::PropertyValue;
+use super::properties::Shorthand;
+use super::properties::SpecifiedValue;
+use ::std::collections::BTreeMap;
+
+#[cfg(test)]
+mod tests;
+
+
+include!("ExplicitFont.rs");
+include!("Font.rs");
+include!("FontFamilyList.rs");
+include!("FontSize.rs");
+include!("FontVariant.rs");
+include!("FontVariantAlternates.rs");
+include!("FontVariantCaps.rs");
+include!("FontVariantEastAsian.rs");
+include!("FontVariantEastAsianVariant.rs");
+include!("FontVariantEastAsianWidth.rs");
+include!("FontVariantEmoji.rs");
+include!("FontVariantLigatures.rs");
+include!("FontVariantNumeric.rs");
+include!("FontVariantNumericFigure.rs");
+include!("FontVariantNumericFraction.rs");
+include!("FontVariantNumericSpacing.rs");
+include!("FontVariantPosition.rs");
+include!("FontVariationSettings.rs");
+include!("FontWeightValue.rs");
+include!("is_related_font_property.rs");
+include!("LineHeight.rs");
+include!("SystemFont.rs");
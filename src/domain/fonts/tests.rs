@@ -0,0 +1,88 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+use super::properties::Importance;
+use super::properties::PropertyDeclarations;
+
+
+fn rewritten<Rewrite: FnOnce(&mut PropertyDeclarations<Importance>)>(css: &str, rewrite: Rewrite) -> String
+{
+	let mut stylesheet = Stylesheet::parse(css).ok().unwrap();
+	match stylesheet.rules.0[0]
+	{
+		CssRule::Style(ref mut styleRule) => rewrite(&mut styleRule.property_declarations),
+		_ => panic!("not a style rule"),
+	}
+	stylesheet.to_css_string(false)
+}
+
+fn font_weight(css: &str) -> String
+{
+	FontWeightValue::parse_property_value_from_css(css).unwrap().to_css_string()
+}
+
+#[test]
+fn font_weights_are_numbers_from_1_to_1000()
+{
+	assert_eq!(font_weight("450"), "450");
+	assert_eq!(font_weight("1"), "1");
+	assert_eq!(font_weight("1000"), "1000");
+	assert_eq!(font_weight("123.5"), "123.5");
+	assert_eq!(font_weight("normal"), "400");
+	assert_eq!(font_weight("BOLD"), "700");
+	assert_eq!(font_weight("bolder"), "bolder");
+	
+	for css in ["0", "0.5", "1000.5", "-100"].iter()
+	{
+		match FontWeightValue::parse_property_value_from_css(css)
+		{
+			Err(ParseError::Custom(CustomParseError::FontWeightMustBeANumberFrom1To1000(_))) => (),
+			result => panic!("{}: {:?}", css, result),
+		}
+	}
+	assert!(FontWeightValue::parse_property_value_from_css("heavy").is_err());
+	assert!(FontWeightValue::parse_property_value_from_css("400px").is_err());
+}
+
+#[test]
+fn relative_font_weights_are_resolved()
+{
+	let resolve = |css: &str, parent: u16| FontWeightValue::parse_property_value_from_css(css).unwrap().resolve(CssUnsignedNumber::from(parent)).to_css_string();
+	
+	assert_eq!(resolve("450", 100), "450");
+	assert_eq!(resolve("bolder", 300), "400");
+	assert_eq!(resolve("bolder", 450), "700");
+	assert_eq!(resolve("bolder", 600), "900");
+	assert_eq!(resolve("bolder", 950), "950");
+	assert_eq!(resolve("lighter", 50), "50");
+	assert_eq!(resolve("lighter", 450), "100");
+	assert_eq!(resolve("lighter", 700), "400");
+	assert_eq!(resolve("lighter", 800), "700");
+}
+
+#[test]
+fn font_shorthand_is_expanded()
+{
+	let expanded = |css| rewritten(css, PropertyDeclarations::expand_font_shorthands);
+	
+	assert_eq!(expanded("a{font:italic 450 12px/1.5 serif}"), "a{font-style:italic;font-variant-caps:normal;font-weight:450;font-stretch:normal;font-size:12px;line-height:1.5;font-family:serif;font-size-adjust:none;font-kerning:auto;font-language-override:normal;font-feature-settings:normal;font-optical-sizing:auto;font-variation-settings:normal;font-variant-alternates:normal;font-variant-east-asian:normal;font-variant-emoji:normal;font-variant-ligatures:normal;font-variant-numeric:normal;font-variant-position:normal}");
+	
+	let inherited = Font::LonghandPropertyNames.iter().map(|name| format!("{}:inherit!important", name)).collect::<Vec<_>>().join(";");
+	assert_eq!(expanded("a{font:inherit!important}"), format!("a{{{}}}", inherited));
+	assert_eq!(expanded("a{font:caption}"), "a{font:caption}");
+	assert_eq!(expanded("a{font:12px var(--family)}"), "a{font:12px var(--family)}");
+}
+
+#[test]
+fn font_longhands_are_folded()
+{
+	let folded = |css| rewritten(css, PropertyDeclarations::fold_font_longhands);
+	let roundTripped = |css| rewritten(&rewritten(css, PropertyDeclarations::expand_font_shorthands), PropertyDeclarations::fold_font_longhands);
+	
+	assert_eq!(roundTripped("a{font:italic 450 12px/1.5 serif}"), "a{font:italic 450 12px/1.5 serif}");
+	assert_eq!(roundTripped("a{font:small-caps bold 1em sans-serif!important}"), "a{font:small-caps 700 1em sans-serif!important}");
+	assert_eq!(roundTripped("a{font:unset}"), "a{font:unset}");
+	assert_eq!(folded("a{font-style:italic;font-weight:450}"), "a{font-style:italic;font-weight:450}");
+}
@@ -0,0 +1,140 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `content` property: https://drafts.csswg.org/css-content-3/#content-property
+#[derive(Debug, Clone, PartialEq)]
+pub enum Content
+{
+	/// `normal`
+	normal,
+	
+	/// `none`
+	none,
+	
+	/// A list of items, such as `open-quote counter(chapter) ". "`, and their alternative text (written after `/`), which is empty if there is none.
+	items
+	{
+		items: Vec<ContentItem>,
+		alternative_text: Vec<ContentItem>,
+	},
+}
+
+impl Default for Content
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Content::normal
+	}
+}
+
+impl ToCss for Content
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::Content::*;
+		
+		#[inline(always)]
+		fn write_items<W: fmt::Write>(items: &[ContentItem], dest: &mut W) -> fmt::Result
+		{
+			let mut afterFirst = false;
+			for item in items.iter()
+			{
+				if afterFirst
+				{
+					dest.write_char(' ')?;
+				}
+				else
+				{
+					afterFirst = true;
+				}
+				item.to_css(dest)?;
+			}
+			Ok(())
+		}
+		
+		match *self
+		{
+			normal => dest.write_str("normal"),
+			
+			none => dest.write_str("none"),
+			
+			items { ref items, ref alternative_text } =>
+			{
+				write_items(items, dest)?;
+				if !alternative_text.is_empty()
+				{
+					dest.write_char('/')?;
+					write_items(alternative_text, dest)?;
+				}
+				Ok(())
+			}
+		}
+	}
+}
+
+impl PropertyValue for Content
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"content",
+	];
+	
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if let Ok(ident) = input.try(|input| -> Result<_, ParseError<'i, CustomParseError<'i>>>
+		{
+			let ident = input.expect_ident_cloned()?;
+			match_ignore_ascii_case!
+			{
+				&*ident,
+				
+				"normal" => Ok(Content::normal),
+				
+				"none" => Ok(Content::none),
+				
+				_ => Err(ParseError::Basic(BasicParseError::UnexpectedToken(Token::Ident(ident.clone())))),
+			}
+		})
+		{
+			return Ok(ident);
+		}
+		
+		let items = Self::parse_items(context, input, false)?;
+		let alternative_text = if input.try(|input| input.expect_delim('/')).is_ok()
+		{
+			Self::parse_items(context, input, true)?
+		}
+		else
+		{
+			Vec::new()
+		};
+		Ok(Content::items { items, alternative_text })
+	}
+}
+
+impl Content
+{
+	/// The counter styles used by `counter()` and `counters()`, other than `none`, in the order they are used.
+	#[inline(always)]
+	pub fn counter_styles(&self) -> Vec<&CounterStyleIdent>
+	{
+		match *self
+		{
+			Content::items { ref items, ref alternative_text } => items.iter().chain(alternative_text.iter()).filter_map(ContentItem::counter_style).collect(),
+			_ => Vec::new(),
+		}
+	}
+	
+	#[inline(always)]
+	fn parse_items<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>, isAlternativeText: bool) -> Result<Vec<ContentItem>, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut items = vec![ContentItem::parse(context, input, isAlternativeText)?];
+		while let Ok(item) = input.try(|input| ContentItem::parse(context, input, isAlternativeText))
+		{
+			items.push(item);
+		}
+		Ok(items)
+	}
+}
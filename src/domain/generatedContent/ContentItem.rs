@@ -0,0 +1,194 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// An item of the value of the `content` property: https://drafts.csswg.org/css-content-3/#content-property
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContentItem
+{
+	/// A string.
+	string(String),
+	
+	/// An image, such as `url()` or a gradient.
+	image(Image),
+	
+	/// `attr()`; the type, if any, is always `string` or `url`.
+	attr(AttrExpression),
+	
+	/// `counter(name, style)`; a style of `None` is `none`, which hides the counter.
+	counter(CustomIdent, Option<CounterStyleIdent>),
+	
+	/// `counters(name, separator, style)`; a style of `None` is `none`, which hides the counters.
+	counters(CustomIdent, String, Option<CounterStyleIdent>),
+	
+	/// `open-quote`, `close-quote`, `no-open-quote` or `no-close-quote`.
+	quote(QuoteKeyword),
+}
+
+impl ToCss for ContentItem
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::ContentItem::*;
+		
+		#[inline(always)]
+		fn write_style<W: fmt::Write>(style: &Option<CounterStyleIdent>, dest: &mut W) -> fmt::Result
+		{
+			match *style
+			{
+				Some(CounterStyleIdent::decimal) => Ok(()),
+				
+				Some(ref style) =>
+				{
+					dest.write_char(',')?;
+					style.to_css(dest)
+				}
+				
+				None => dest.write_str(",none"),
+			}
+		}
+		
+		match *self
+		{
+			string(ref value) => serialize_string(value, dest),
+			
+			image(ref value) => value.to_css(dest),
+			
+			attr(ref attrExpression) =>
+			{
+				dest.write_str("attr(")?;
+				attrExpression.to_css(dest)?;
+				dest.write_char(')')
+			}
+			
+			counter(ref name, ref style) =>
+			{
+				dest.write_str("counter(")?;
+				name.to_css(dest)?;
+				write_style(style, dest)?;
+				dest.write_char(')')
+			}
+			
+			counters(ref name, ref separator, ref style) =>
+			{
+				dest.write_str("counters(")?;
+				name.to_css(dest)?;
+				dest.write_char(',')?;
+				serialize_string(separator, dest)?;
+				write_style(style, dest)?;
+				dest.write_char(')')
+			}
+			
+			quote(quoteKeyword) => quoteKeyword.to_css(dest),
+		}
+	}
+}
+
+impl ContentItem
+{
+	/// The counter style used by `counter()` or `counters()`, unless it is `none`.
+	#[inline(always)]
+	pub fn counter_style(&self) -> Option<&CounterStyleIdent>
+	{
+		use self::ContentItem::*;
+		
+		match *self
+		{
+			counter(_, ref style) | counters(_, _, ref style) => style.as_ref(),
+			_ => None,
+		}
+	}
+	
+	/// Parses an item; `isAlternativeText` restricts the items to strings, `attr()`, `counter()` and `counters()`.
+	pub(crate) fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>, isAlternativeText: bool) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::ContentItem::*;
+		
+		if let Ok(value) = input.try(|input| input.expect_string_cloned())
+		{
+			return Ok(string(value.as_ref().to_owned()));
+		}
+		
+		if !isAlternativeText
+		{
+			if let Ok(quoteKeyword) = input.try(QuoteKeyword::parse)
+			{
+				return Ok(quote(quoteKeyword));
+			}
+		}
+		
+		if let Ok(name) = input.try(|input|
+		{
+			let name = input.expect_function()?.clone();
+			if name.eq_ignore_ascii_case("attr") || name.eq_ignore_ascii_case("counter") || name.eq_ignore_ascii_case("counters")
+			{
+				Ok(name)
+			}
+			else
+			{
+				Err(BasicParseError::UnexpectedToken(Token::Function(name)))
+			}
+		})
+		{
+			if name.eq_ignore_ascii_case("attr")
+			{
+				let attrExpression = AttrExpression::parse(context, input)?;
+				return match attrExpression.type_or_unit
+				{
+					TypeOrUnit::string | TypeOrUnit::url => Ok(attr(attrExpression)),
+					typeOrUnit @ _ => Err(ParseError::Custom(CustomParseError::AttrTypeOrUnitIsNotAllowedInContent(typeOrUnit))),
+				};
+			}
+			
+			let isCounters = name.eq_ignore_ascii_case("counters");
+			return input.parse_nested_block(|input|
+			{
+				let ident = input.expect_ident_cloned()?;
+				let name = CustomIdent::from_ident(&ident, &["none"])?;
+				
+				let separator = if isCounters
+				{
+					input.expect_comma()?;
+					Some(input.expect_string()?.as_ref().to_owned())
+				}
+				else
+				{
+					None
+				};
+				
+				let style = if input.try(|input| input.expect_comma()).is_ok()
+				{
+					if input.try(|input| input.expect_ident_matching("none")).is_ok()
+					{
+						None
+					}
+					else
+					{
+						Some(CounterStyleIdent::parse(input)?)
+					}
+				}
+				else
+				{
+					Some(CounterStyleIdent::decimal)
+				};
+				
+				Ok
+				(
+					match separator
+					{
+						None => counter(name, style),
+						Some(separator) => counters(name, separator, style),
+					}
+				)
+			});
+		}
+		
+		if isAlternativeText
+		{
+			let token = input.next()?.clone();
+			return Err(ParseError::Basic(BasicParseError::UnexpectedToken(token)));
+		}
+		
+		Ok(image(Image::parse(context, input)?))
+	}
+}
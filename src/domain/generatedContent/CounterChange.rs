@@ -0,0 +1,84 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A counter and its value in `counter-reset`, `counter-increment` or `counter-set`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CounterChange
+{
+	/// The counter's name.
+	pub name: CustomIdent,
+	
+	/// The value to reset, increment or set the counter by or to; `None` uses the property's default (0 for `counter-reset` and `counter-set`, 1 for `counter-increment`).
+	pub value: Option<i32>,
+	
+	/// `reversed(name)`; only allowed in `counter-reset`.
+	pub reversed: bool,
+}
+
+impl ToCss for CounterChange
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.reversed
+		{
+			dest.write_str("reversed(")?;
+			self.name.to_css(dest)?;
+			dest.write_char(')')?;
+		}
+		else
+		{
+			self.name.to_css(dest)?;
+		}
+		
+		if let Some(value) = self.value
+		{
+			dest.write_char(' ')?;
+			value.to_css(dest)?;
+		}
+		Ok(())
+	}
+}
+
+impl CounterChange
+{
+	#[inline(always)]
+	pub(crate) fn parse<'i, 't>(input: &mut Parser<'i, 't>, allowReversed: bool) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		const ExcludedNames: &'static [&'static str] = &["none"];
+		
+		let (name, reversed) = match input.next()?.clone()
+		{
+			Token::Ident(ref ident) => (CustomIdent::from_ident(ident, ExcludedNames)?, false),
+			
+			Token::Function(ref name) if name.eq_ignore_ascii_case("reversed") =>
+			{
+				if !allowReversed
+				{
+					return Err(ParseError::Custom(CustomParseError::ReversedCounterIsOnlyAllowedInCounterReset));
+				}
+				
+				let name = input.parse_nested_block(|input|
+				{
+					let ident = input.expect_ident_cloned()?;
+					CustomIdent::from_ident(&ident, ExcludedNames)
+				})?;
+				(name, true)
+			}
+			
+			token @ _ => return Err(ParseError::Basic(BasicParseError::UnexpectedToken(token))),
+		};
+		
+		let value = input.try(|input| input.expect_integer()).ok();
+		
+		Ok
+		(
+			Self
+			{
+				name,
+				value,
+				reversed,
+			}
+		)
+	}
+}
@@ -0,0 +1,40 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `counter-reset` property, which resets counters; reversed() is allowed; empty is `none`.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct CounterReset(pub Vec<CounterChange>);
+
+impl ToCss for CounterReset
+{
+	#[inline(always)]
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		write_counter_changes(&self.0, dest)
+	}
+}
+
+impl PropertyValue for CounterReset
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"counter-reset",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Ok(CounterReset(parse_counter_changes(input, true)?))
+	}
+}
+
+impl CounterReset
+{
+	/// Is this `none`?
+	#[inline(always)]
+	pub fn is_none(&self) -> bool
+	{
+		self.0.is_empty()
+	}
+}
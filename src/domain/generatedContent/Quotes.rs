@@ -0,0 +1,101 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `quotes` property: https://drafts.csswg.org/css-content-3/#quotes-property
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Quotes
+{
+	/// `none`; `open-quote` and `close-quote` produce no quotation marks.
+	none,
+	
+	/// `auto`; the quotation marks are appropriate for the content language.
+	auto,
+	
+	/// Pairs of open and close quotation marks, outermost first.
+	pairs(Vec<(String, String)>),
+}
+
+impl Default for Quotes
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Quotes::auto
+	}
+}
+
+impl ToCss for Quotes
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::Quotes::*;
+		
+		match *self
+		{
+			none => dest.write_str("none"),
+			
+			auto => dest.write_str("auto"),
+			
+			pairs(ref quotes) =>
+			{
+				let mut afterFirst = false;
+				for &(ref open, ref close) in quotes.iter()
+				{
+					if afterFirst
+					{
+						dest.write_char(' ')?;
+					}
+					else
+					{
+						afterFirst = true;
+					}
+					serialize_string(open, dest)?;
+					dest.write_char(' ')?;
+					serialize_string(close, dest)?;
+				}
+				Ok(())
+			}
+		}
+	}
+}
+
+impl PropertyValue for Quotes
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"quotes",
+	];
+	
+	fn parse_property_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::Quotes::*;
+		
+		if input.try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			return Ok(none);
+		}
+		
+		if input.try(|input| input.expect_ident_matching("auto")).is_ok()
+		{
+			return Ok(auto);
+		}
+		
+		let mut quotes = Vec::new();
+		loop
+		{
+			let open = input.expect_string()?.as_ref().to_owned();
+			let close = match input.try(|input| input.expect_string_cloned())
+			{
+				Ok(close) => close.as_ref().to_owned(),
+				Err(_) => return Err(ParseError::Custom(CustomParseError::QuotesMustBePairsOfStrings)),
+			};
+			quotes.push((open, close));
+			
+			if input.is_exhausted()
+			{
+				return Ok(pairs(quotes));
+			}
+		}
+	}
+}
@@ -0,0 +1,14 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A counter style used by `counter()` or `counters()` in a `content` property declaration for which there is no `@counter-style` rule and which is not predefined; see `Stylesheet::undeclared_counter_styles()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UndeclaredCounterStyle
+{
+	/// The counter style.
+	pub name: CounterStyleIdent,
+	
+	/// Where the property declaration using the counter style was in the source CSS, if source spans were recorded when parsing.
+	pub source_span: Option<SourceSpan>,
+}
@@ -0,0 +1,23 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+use super::properties::PropertyValue;
+
+#[cfg(test)]
+mod tests;
+
+
+include!("Content.rs");
+include!("ContentItem.rs");
+include!("CounterChange.rs");
+include!("CounterIncrement.rs");
+include!("CounterReset.rs");
+include!("CounterSet.rs");
+include!("parse_counter_changes.rs");
+include!("QuoteKeyword.rs");
+include!("Quotes.rs");
+include!("UndeclaredCounterStyle.rs");
+include!("undeclared_counter_styles.rs");
+include!("write_counter_changes.rs");
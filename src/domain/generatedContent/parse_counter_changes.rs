@@ -0,0 +1,19 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+#[inline(always)]
+fn parse_counter_changes<'i, 't>(input: &mut Parser<'i, 't>, allowReversed: bool) -> Result<Vec<CounterChange>, ParseError<'i, CustomParseError<'i>>>
+{
+	if input.try(|input| input.expect_ident_matching("none")).is_ok()
+	{
+		return Ok(Vec::new());
+	}
+	
+	let mut counterChanges = vec![CounterChange::parse(input, allowReversed)?];
+	while !input.is_exhausted()
+	{
+		counterChanges.push(CounterChange::parse(input, allowReversed)?);
+	}
+	Ok(counterChanges)
+}
@@ -0,0 +1,52 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+
+
+fn undeclared_counter_style_names(css: &str) -> Vec<String>
+{
+	let stylesheet = Stylesheet::parse(css).ok().unwrap();
+	stylesheet.undeclared_counter_styles().iter().map(|undeclaredCounterStyle| undeclaredCounterStyle.name.to_css_string()).collect()
+}
+
+#[test]
+fn predefined_and_declared_counter_styles_are_not_undeclared()
+{
+	assert_eq!(undeclared_counter_style_names("a::before{content:counter(item, decimal) counters(item, '.', lower-roman) counter(item)}"), Vec::<String>::new());
+	assert_eq!(undeclared_counter_style_names("@counter-style thumbs{system:cyclic;symbols:'👍'}a::before{content:counter(item, thumbs)}"), Vec::<String>::new());
+	assert_eq!(undeclared_counter_style_names("a::before{content:counter(item, thumbs)}@media print{@counter-style thumbs{system:cyclic;symbols:'👍'}}"), Vec::<String>::new());
+	assert_eq!(undeclared_counter_style_names("a::before{content:counter(item, none)}"), Vec::<String>::new());
+}
+
+#[test]
+fn undeclared_counter_styles_are_found_in_order_in_nested_rules()
+{
+	let css = "a::before{content:counter(item, thumbs) counters(item, '.', stars)}@media print{@supports (display:grid){b::after{content:'x' counter(item, thumbs)}}}";
+	assert_eq!(undeclared_counter_style_names(css), vec!["thumbs", "stars", "thumbs"]);
+}
+
+#[test]
+fn undeclared_counter_styles_have_the_source_span_of_their_declaration()
+{
+	let options = StylesheetParseOptions
+	{
+		record_source_spans: true,
+		..StylesheetParseOptions::default()
+	};
+	let (stylesheet, _) = Stylesheet::parse_with_options("a{color:red;content:counter(item, stars)}", &options).ok().unwrap();
+	let undeclaredCounterStyles = stylesheet.undeclared_counter_styles();
+	
+	assert_eq!(undeclaredCounterStyles.len(), 1);
+	let sourceSpan = undeclaredCounterStyles[0].source_span.unwrap();
+	assert_eq!(sourceSpan.start.byte_offset, 12);
+	assert_eq!(sourceSpan.end.byte_offset, 40);
+}
+
+#[test]
+fn counter_styles_in_unparsable_values_are_not_checked()
+{
+	assert_eq!(undeclared_counter_style_names("a::before{content:counter(item, var(--style))}"), Vec::<String>::new());
+	assert_eq!(undeclared_counter_style_names("a::before{content:var(--x) counter(item, stars)}"), Vec::<String>::new());
+}
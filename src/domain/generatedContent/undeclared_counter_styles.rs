@@ -0,0 +1,53 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Finds the counter styles used by `counter()` and `counters()` in `content` property declarations in style rules, including those nested in `@media`, `@supports` and `@document` rules, which are not predefined (such as `decimal`) and for which there is no `@counter-style` rule anywhere in `cssRules`.
+/// Counter styles in values which can not be parsed or use `var()` are not checked.
+pub(crate) fn undeclared_counter_styles(cssRules: &CssRules) -> Vec<UndeclaredCounterStyle>
+{
+	fn collect<'a>(cssRules: &'a CssRules, declaredCounterStyles: &mut HashSet<&'a CounterStyleIdent>, usedCounterStyles: &mut Vec<UndeclaredCounterStyle>)
+	{
+		use self::CssRule::*;
+		
+		for cssRule in cssRules.0.iter()
+		{
+			match *cssRule
+			{
+				CounterStyle(ref rule) =>
+				{
+					declaredCounterStyles.insert(&rule.name);
+				}
+				
+				Style(ref rule) => for propertyDeclaration in rule.property_declarations.0.iter()
+				{
+					if let Some(Ok(content)) = propertyDeclaration.typed_value::<Content>()
+					{
+						for counterStyle in content.counter_styles()
+						{
+							if let CounterStyleIdent::Custom(_) = *counterStyle
+							{
+								usedCounterStyles.push(UndeclaredCounterStyle { name: counterStyle.clone(), source_span: propertyDeclaration.source_span });
+							}
+						}
+					}
+				},
+				
+				Document(ref rule) => collect(&rule.rules, declaredCounterStyles, usedCounterStyles),
+				
+				Media(ref rule) => collect(&rule.rules, declaredCounterStyles, usedCounterStyles),
+				
+				Supports(ref rule) => collect(&rule.rules, declaredCounterStyles, usedCounterStyles),
+				
+				_ => (),
+			}
+		}
+	}
+	
+	let mut declaredCounterStyles = HashSet::new();
+	let mut usedCounterStyles = Vec::new();
+	collect(cssRules, &mut declaredCounterStyles, &mut usedCounterStyles);
+	
+	usedCounterStyles.retain(|usedCounterStyle| !declaredCounterStyles.contains(&usedCounterStyle.name));
+	usedCounterStyles
+}
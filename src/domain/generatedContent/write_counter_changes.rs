@@ -0,0 +1,27 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+#[inline(always)]
+fn write_counter_changes<W: fmt::Write>(counterChanges: &[CounterChange], dest: &mut W) -> fmt::Result
+{
+	if counterChanges.is_empty()
+	{
+		return dest.write_str("none");
+	}
+	
+	let mut afterFirst = false;
+	for counterChange in counterChanges.iter()
+	{
+		if afterFirst
+		{
+			dest.write_char(' ')?;
+		}
+		else
+		{
+			afterFirst = true;
+		}
+		counterChange.to_css(dest)?;
+	}
+	Ok(())
+}
@@ -0,0 +1,94 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A grammar compiled from CSS Value Definition Syntax (https://drafts.csswg.org/css-values-4/#value-defs), such as `[ <length> | auto ]{1,4}` or `<color>#`, which validates property values without writing bespoke code for each property.
+///
+/// Non-terminal data types, such as `<line-style>`, and references to property values, such as `<'border-top-width'>`, are resolved when compiling using the bundled tables of standard grammars.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grammar
+{
+	/// The compiled grammar.
+	pub root: GrammarNode,
+}
+
+impl ToCss for Grammar
+{
+	#[inline(always)]
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		self.root.to_css(dest)
+	}
+}
+
+impl Grammar
+{
+	/// Compiles a grammar.
+	#[inline(always)]
+	pub fn compile<'i>(grammar: &'i str) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Ok
+		(
+			Self
+			{
+				root: GrammarNode::compile(grammar, &mut Vec::new())?,
+			}
+		)
+	}
+	
+	/// Compiles the grammar of a property, named without any vendor prefix, from the bundled table of standard property grammars.
+	/// Properties which are not in the table, including all custom properties, are an error.
+	#[inline(always)]
+	pub fn for_property<'i>(name: &'i str) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let lowerCaseName = name.to_ascii_lowercase();
+		match property_grammar(&lowerCaseName)
+		{
+			None => Err(ParseError::Custom(CustomParseError::UnknownPropertyForGrammar(CowRcStr::from(name)))),
+			Some(grammar) => Ok
+			(
+				Self
+				{
+					root: GrammarNode::compile(grammar, &mut vec![lowerCaseName])?,
+				}
+			),
+		}
+	}
+	
+	/// Matches component values, ignoring whitespace, against this grammar.
+	/// If they do not match, the error is for the furthest component value reached, with what was expected there.
+	/// Data types, such as `<length>`, are parsed using `ParseLimits::for_untrusted_input()`; see `match_component_values_with_limits()`.
+	#[inline(always)]
+	pub fn match_component_values<'i>(&self, componentValues: &[ComponentValue]) -> Result<GrammarMatch, ParseError<'i, CustomParseError<'i>>>
+	{
+		self.match_component_values_with_limits(componentValues, ParseLimits::for_untrusted_input())
+	}
+	
+	/// As `match_component_values()`, but parses data types using the given `limits`.
+	#[inline(always)]
+	pub fn match_component_values_with_limits<'i>(&self, componentValues: &[ComponentValue], limits: ParseLimits) -> Result<GrammarMatch, ParseError<'i, CustomParseError<'i>>>
+	{
+		let level = GrammarLevel::new(componentValues, 0);
+		let mut matcher = GrammarMatcher::new(limits);
+		match matcher.match_entirely(&self.root, &level)
+		{
+			Some(grammarMatch) => Ok(grammarMatch),
+			None => Err(matcher.into_error()),
+		}
+	}
+	
+	/// Matches a specified value against this grammar.
+	/// Values which use `var()` can not be matched until the custom properties have been substituted, so are errors.
+	#[inline(always)]
+	pub fn match_specified_value<'a>(&self, specifiedValue: &'a SpecifiedValue) -> Result<GrammarMatch, ParseError<'a, CustomParseError<'a>>>
+	{
+		self.match_component_values(specifiedValue.component_values())
+	}
+	
+	/// As `match_specified_value()`, but parses data types using the given `limits`.
+	#[inline(always)]
+	pub fn match_specified_value_with_limits<'a>(&self, specifiedValue: &'a SpecifiedValue, limits: ParseLimits) -> Result<GrammarMatch, ParseError<'a, CustomParseError<'a>>>
+	{
+		self.match_component_values_with_limits(specifiedValue.component_values(), limits)
+	}
+}
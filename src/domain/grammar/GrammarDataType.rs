@@ -0,0 +1,98 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+define_css_keyword_enum!
+{
+	GrammarDataType:
+	"angle" => angle,
+	"color" => color,
+	"custom-ident" => custom_ident,
+	"dashed-ident" => dashed_ident,
+	"flex" => flex,
+	"ident" => ident,
+	"image" => image,
+	"integer" => integer,
+	"length" => length,
+	"length-percentage" => length_percentage,
+	"number" => number,
+	"percentage" => percentage,
+	"resolution" => resolution,
+	"string" => string,
+	"time" => time,
+	"url" => url,
+}
+
+impl GrammarDataType
+{
+	/// Does a single component value, such as `10px` or `calc(1em + 2px)`, match this data type?
+	/// Ranges, such as the `[0,∞]` in `<length [0,∞]>`, are checked by `GrammarNode`.
+	/// Parses using `ParseLimits::for_untrusted_input()`; see `matches_with_limits()`.
+	#[inline(always)]
+	pub fn matches(&self, componentValue: &ComponentValue) -> bool
+	{
+		self.matches_with_limits(componentValue, ParseLimits::for_untrusted_input())
+	}
+	
+	/// As `matches()`, but parses using the given `limits`.
+	pub fn matches_with_limits(&self, componentValue: &ComponentValue, limits: ParseLimits) -> bool
+	{
+		use self::GrammarDataType::*;
+		
+		match *componentValue
+		{
+			ComponentValue::preserved_token(ref token) => match (*self, token)
+			{
+				(string, &Token::QuotedString(_)) => return true,
+				
+				(ident, &Token::Ident(_)) => return true,
+				
+				(custom_ident, &Token::Ident(ref value)) => return CustomIdent::from_ident(value, &[]).is_ok(),
+				
+				(dashed_ident, &Token::Ident(ref value)) => return value.starts_with("--"),
+				
+				(integer, &Token::Number { int_value, .. }) => return int_value.is_some(),
+				
+				(flex, &Token::Dimension { value, ref unit, .. }) => return value >= 0. && unit.eq_ignore_ascii_case("fr"),
+				
+				(string, _) | (ident, _) | (custom_ident, _) | (dashed_ident, _) | (integer, _) | (flex, _) => return false,
+				
+				_ => (),
+			},
+			
+			ComponentValue::function(..) => match *self
+			{
+				string | ident | custom_ident | dashed_ident | integer | flex => return false,
+				_ => (),
+			},
+			
+			_ => return false,
+		}
+		
+		match *self
+		{
+			angle => Self::parses(componentValue, limits, <AngleUnit<CssSignedNumber> as Unit>::parse_one_outside_calc_function),
+			color => Self::parses(componentValue, limits, Color::parse),
+			image => Self::parses(componentValue, limits, Image::parse),
+			length => Self::parses(componentValue, limits, <LengthUnit<CssSignedNumber> as Unit>::parse_one_outside_calc_function),
+			length_percentage => Self::parses(componentValue, limits, <LengthOrPercentageUnit<CssSignedNumber> as Unit>::parse_one_outside_calc_function),
+			number => Self::parses(componentValue, limits, <CssSignedNumber as Unit>::parse_one_outside_calc_function),
+			percentage => Self::parses(componentValue, limits, <PercentageUnit<CssSignedNumber> as Unit>::parse_one_outside_calc_function),
+			resolution => Self::parses(componentValue, limits, <ResolutionUnit<CssSignedNumber> as Unit>::parse_one_outside_calc_function),
+			time => Self::parses(componentValue, limits, <TimeUnit<CssSignedNumber> as Unit>::parse_one_outside_calc_function),
+			url => Self::parses(componentValue, limits, SpecifiedUrl::parse),
+			string | ident | custom_ident | dashed_ident | integer | flex => false,
+		}
+	}
+	
+	#[inline(always)]
+	fn parses<R, P: for<'i, 't> Fn(&ParserContext, &mut Parser<'i, 't>) -> Result<R, ParseError<'i, CustomParseError<'i>>>>(componentValue: &ComponentValue, limits: ParseLimits, parser: P) -> bool
+	{
+		let css = componentValue.to_css_string();
+		let context = ParserContext::new_for_property_value(limits);
+		let mut parserInput = ParserInput::new(&css);
+		let mut input = Parser::new(&mut parserInput);
+		let result = input.parse_entirely(|input| parser(&context, input));
+		result.is_ok()
+	}
+}
@@ -0,0 +1,64 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The component values, excluding whitespace, of a property value or of a function's arguments, with the indices which order all of the component values in a property value (including those nested in functions and blocks).
+#[derive(Debug)]
+struct GrammarLevel<'a>
+{
+	componentValues: Vec<(&'a ComponentValue, usize)>,
+	endIndex: usize,
+}
+
+impl<'a> GrammarLevel<'a>
+{
+	#[inline(always)]
+	fn new(componentValues: &'a [ComponentValue], firstIndex: usize) -> Self
+	{
+		let mut index = firstIndex;
+		let mut nonWhitespaceComponentValues = Vec::with_capacity(componentValues.len());
+		for componentValue in componentValues.iter()
+		{
+			if !componentValue.is_whitespace()
+			{
+				nonWhitespaceComponentValues.push((componentValue, index));
+			}
+			index += 1 + componentValue.number_of_descendants();
+		}
+		
+		Self
+		{
+			componentValues: nonWhitespaceComponentValues,
+			endIndex: index,
+		}
+	}
+	
+	#[inline(always)]
+	fn len(&self) -> usize
+	{
+		self.componentValues.len()
+	}
+	
+	#[inline(always)]
+	fn get(&self, position: usize) -> Option<&'a ComponentValue>
+	{
+		self.componentValues.get(position).map(|&(componentValue, _)| componentValue)
+	}
+	
+	#[inline(always)]
+	fn index(&self, position: usize) -> usize
+	{
+		self.componentValues[position].1
+	}
+	
+	/// Ranks order failures by how far into a property value they occurred; the end of a function's arguments ranks after its last argument but before the component value following the function.
+	#[inline(always)]
+	fn rank(&self, position: usize) -> usize
+	{
+		match self.componentValues.get(position)
+		{
+			Some(&(_, index)) => 2 * index + 1,
+			None => 2 * self.endIndex,
+		}
+	}
+}
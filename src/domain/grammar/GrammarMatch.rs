@@ -0,0 +1,80 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A value which matched a `Grammar`, as a tree mirroring the grammar's components.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GrammarMatch
+{
+	/// A keyword, in ASCII lower case.
+	keyword(String),
+	
+	/// A literal, such as `/`.
+	literal(char),
+	
+	/// A component value which matched a basic data type.
+	data_type(GrammarDataType, ComponentValue),
+	
+	/// A value which matched a non-terminal data type, such as `<line-style>`, or a property's value, such as `<'border-top-width'>`.
+	non_terminal(String, Box<GrammarMatch>),
+	
+	/// A function and its matched arguments.
+	function(String, Box<GrammarMatch>),
+	
+	/// Components which matched a juxtaposition, `&&` or `||`, in the order they occurred; components which did not occur are absent.
+	sequence(Vec<GrammarMatch>),
+	
+	/// The repetitions of a multiplied component, without any separating commas; `?` which did not occur is empty.
+	repetitions(Vec<GrammarMatch>),
+}
+
+impl GrammarMatch
+{
+	/// Finds the matches of non-terminal data types or property values with this name, at any depth, in the order they occurred.
+	pub fn non_terminals(&self, lowerCaseName: &str) -> Vec<&GrammarMatch>
+	{
+		let mut found = Vec::new();
+		self.collect_non_terminals(lowerCaseName, &mut found);
+		found
+	}
+	
+	/// The component values which matched basic data types, at any depth, in the order they occurred.
+	pub fn data_types(&self) -> Vec<(GrammarDataType, &ComponentValue)>
+	{
+		use self::GrammarMatch::*;
+		
+		match *self
+		{
+			keyword(_) | literal(_) => Vec::new(),
+			data_type(dataType, ref componentValue) => vec![(dataType, componentValue)],
+			non_terminal(_, ref grammarMatch) | function(_, ref grammarMatch) => grammarMatch.data_types(),
+			sequence(ref grammarMatches) | repetitions(ref grammarMatches) => grammarMatches.iter().flat_map(GrammarMatch::data_types).collect(),
+		}
+	}
+	
+	fn collect_non_terminals<'a>(&'a self, lowerCaseName: &str, found: &mut Vec<&'a GrammarMatch>)
+	{
+		use self::GrammarMatch::*;
+		
+		match *self
+		{
+			keyword(_) | literal(_) | data_type(..) => (),
+			
+			non_terminal(ref name, ref grammarMatch) =>
+			{
+				if name == lowerCaseName
+				{
+					found.push(grammarMatch);
+				}
+				grammarMatch.collect_non_terminals(lowerCaseName, found);
+			}
+			
+			function(_, ref grammarMatch) => grammarMatch.collect_non_terminals(lowerCaseName, found),
+			
+			sequence(ref grammarMatches) | repetitions(ref grammarMatches) => for grammarMatch in grammarMatches.iter()
+			{
+				grammarMatch.collect_non_terminals(lowerCaseName, found);
+			},
+		}
+	}
+}
@@ -0,0 +1,339 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Matches component values against a `GrammarNode` by backtracking; at each position, every way a node can match is kept, but only the first for each end position.
+/// Remembers the furthest position at which matching failed, and what was expected there, to report a precise error.
+#[derive(Debug)]
+struct GrammarMatcher<'a>
+{
+	furthestRank: Option<usize>,
+	furthestComponentValue: Option<&'a ComponentValue>,
+	expected: Vec<String>,
+	limits: ParseLimits,
+}
+
+impl<'a> GrammarMatcher<'a>
+{
+	#[inline(always)]
+	fn new(limits: ParseLimits) -> Self
+	{
+		Self
+		{
+			furthestRank: None,
+			furthestComponentValue: None,
+			expected: Vec::new(),
+			limits,
+		}
+	}
+	
+	#[inline(always)]
+	fn into_error<'i>(self) -> ParseError<'i, CustomParseError<'i>>
+	{
+		match self.furthestComponentValue
+		{
+			Some(componentValue) => ParseError::Custom(CustomParseError::UnexpectedTokenForGrammar(componentValue.first_token(), self.expected)),
+			None => ParseError::Custom(CustomParseError::UnexpectedEndOfInputForGrammar(self.expected)),
+		}
+	}
+	
+	fn match_entirely(&mut self, node: &GrammarNode, level: &GrammarLevel<'a>) -> Option<GrammarMatch>
+	{
+		let mut furthestEnd = 0;
+		for (end, grammarMatch) in self.matches(node, level, 0)
+		{
+			if end == level.len()
+			{
+				return Some(grammarMatch);
+			}
+			furthestEnd = max(furthestEnd, end);
+		}
+		
+		self.failed(level, furthestEnd, None);
+		None
+	}
+	
+	fn matches(&mut self, node: &GrammarNode, level: &GrammarLevel<'a>, position: usize) -> Vec<(usize, GrammarMatch)>
+	{
+		use self::GrammarNode::*;
+		
+		match *node
+		{
+			keyword(ref name) => match level.get(position)
+			{
+				Some(&ComponentValue::preserved_token(Token::Ident(ref value))) if value.eq_ignore_ascii_case(name) => vec![(position + 1, GrammarMatch::keyword(name.clone()))],
+				_ =>
+				{
+					self.failed(level, position, Some(node));
+					Vec::new()
+				}
+			},
+			
+			literal(character) =>
+			{
+				let isMatch = match level.get(position)
+				{
+					Some(&ComponentValue::preserved_token(ref token)) => match *token
+					{
+						Token::Comma => character == ',',
+						Token::Colon => character == ':',
+						Token::Delim(delimiter) => character == delimiter,
+						_ => false,
+					},
+					_ => false,
+				};
+				
+				if isMatch
+				{
+					vec![(position + 1, GrammarMatch::literal(character))]
+				}
+				else
+				{
+					self.failed(level, position, Some(node));
+					Vec::new()
+				}
+			}
+			
+			data_type(dataType, minimum, maximum) => match level.get(position)
+			{
+				Some(componentValue) if dataType.matches_with_limits(componentValue, self.limits) && Self::is_within_range(componentValue, minimum, maximum) => vec![(position + 1, GrammarMatch::data_type(dataType, componentValue.clone()))],
+				_ =>
+				{
+					self.failed(level, position, Some(node));
+					Vec::new()
+				}
+			},
+			
+			non_terminal(ref name, _, ref grammar) => self.matches(grammar, level, position).into_iter().map(|(end, grammarMatch)| (end, GrammarMatch::non_terminal(name.clone(), Box::new(grammarMatch)))).collect(),
+			
+			function(ref name, ref arguments) => match level.get(position)
+			{
+				Some(&ComponentValue::function(ref functionName, ref componentValues)) if functionName.eq_ignore_ascii_case(name) =>
+				{
+					let argumentsLevel = GrammarLevel::new(componentValues, level.index(position) + 1);
+					match self.match_entirely(arguments, &argumentsLevel)
+					{
+						Some(grammarMatch) => vec![(position + 1, GrammarMatch::function(name.clone(), Box::new(grammarMatch)))],
+						None => Vec::new(),
+					}
+				}
+				_ =>
+				{
+					self.failed(level, position, Some(node));
+					Vec::new()
+				}
+			},
+			
+			group(ref grammar, mustNotBeEmpty) =>
+			{
+				let mut matches = self.matches(grammar, level, position);
+				if mustNotBeEmpty
+				{
+					matches.retain(|&(end, _)| end != position);
+				}
+				matches
+			}
+			
+			juxtaposition(ref nodes) =>
+			{
+				let mut states = vec![(position, Vec::new())];
+				for node in nodes.iter()
+				{
+					let mut nextStates = Vec::new();
+					for (statePosition, grammarMatches) in states
+					{
+						for (end, grammarMatch) in self.matches(node, level, statePosition)
+						{
+							let mut grammarMatches = grammarMatches.clone();
+							grammarMatches.push(grammarMatch);
+							Self::add(&mut nextStates, end, grammarMatches);
+						}
+					}
+					states = nextStates;
+				}
+				states.into_iter().map(|(end, grammarMatches)| (end, GrammarMatch::sequence(grammarMatches))).collect()
+			}
+			
+			all_in_any_order(ref nodes) => self.matches_in_any_order(nodes, level, position, true),
+			
+			one_or_more_in_any_order(ref nodes) => self.matches_in_any_order(nodes, level, position, false),
+			
+			exactly_one(ref nodes) =>
+			{
+				let mut matches = Vec::new();
+				for node in nodes.iter()
+				{
+					for (end, grammarMatch) in self.matches(node, level, position)
+					{
+						Self::add(&mut matches, end, grammarMatch);
+					}
+				}
+				matches
+			}
+			
+			multiplied(ref grammar, multiplier) => self.matches_multiplied(grammar, multiplier, level, position),
+		}
+	}
+	
+	fn matches_in_any_order(&mut self, nodes: &[GrammarNode], level: &GrammarLevel<'a>, position: usize, mustMatchAll: bool) -> Vec<(usize, GrammarMatch)>
+	{
+		let allMatched = (1u64 << nodes.len()) - 1;
+		
+		let mut matches = Vec::new();
+		let mut states: Vec<(usize, u64, Vec<GrammarMatch>)> = vec![(position, 0, Vec::new())];
+		while !states.is_empty()
+		{
+			let mut nextStates: Vec<(usize, u64, Vec<GrammarMatch>)> = Vec::new();
+			for (statePosition, matched, grammarMatches) in states
+			{
+				for (index, node) in nodes.iter().enumerate()
+				{
+					let bit = 1u64 << index;
+					if matched & bit != 0
+					{
+						continue;
+					}
+					
+					for (end, grammarMatch) in self.matches(node, level, statePosition)
+					{
+						let nowMatched = matched | bit;
+						if !nextStates.iter().any(|&(nextEnd, nextMatched, _)| nextEnd == end && nextMatched == nowMatched)
+						{
+							let mut grammarMatches = grammarMatches.clone();
+							grammarMatches.push(grammarMatch);
+							nextStates.push((end, nowMatched, grammarMatches));
+						}
+					}
+				}
+			}
+			
+			for &(end, matched, ref grammarMatches) in nextStates.iter()
+			{
+				if !mustMatchAll || matched == allMatched
+				{
+					Self::add(&mut matches, end, GrammarMatch::sequence(grammarMatches.clone()));
+				}
+			}
+			states = nextStates;
+		}
+		matches
+	}
+	
+	fn matches_multiplied(&mut self, node: &GrammarNode, multiplier: GrammarMultiplier, level: &GrammarLevel<'a>, position: usize) -> Vec<(usize, GrammarMatch)>
+	{
+		let mut matches = Vec::new();
+		if multiplier.is_satisfied_by(0)
+		{
+			matches.push((position, GrammarMatch::repetitions(Vec::new())));
+		}
+		
+		let mut count = 0;
+		let mut states = vec![(position, Vec::new())];
+		while !states.is_empty() && multiplier.allows_more_than(count)
+		{
+			count += 1;
+			
+			let mut nextStates = Vec::new();
+			for (statePosition, grammarMatches) in states
+			{
+				let start = if multiplier.comma_separated && count > 1
+				{
+					match level.get(statePosition)
+					{
+						Some(&ComponentValue::preserved_token(Token::Comma)) => statePosition + 1,
+						_ =>
+						{
+							self.failed(level, statePosition, Some(&GrammarNode::literal(',')));
+							continue;
+						}
+					}
+				}
+				else
+				{
+					statePosition
+				};
+				
+				for (end, grammarMatch) in self.matches(node, level, start)
+				{
+					// Repetitions which match nothing are only needed to reach the minimum.
+					if end == statePosition && multiplier.is_satisfied_by(count - 1)
+					{
+						continue;
+					}
+					
+					let mut grammarMatches = grammarMatches.clone();
+					grammarMatches.push(grammarMatch);
+					Self::add(&mut nextStates, end, grammarMatches);
+				}
+			}
+			
+			if multiplier.is_satisfied_by(count)
+			{
+				for &(end, ref grammarMatches) in nextStates.iter()
+				{
+					Self::add(&mut matches, end, GrammarMatch::repetitions(grammarMatches.clone()));
+				}
+			}
+			states = nextStates;
+		}
+		matches
+	}
+	
+	#[inline(always)]
+	fn failed(&mut self, level: &GrammarLevel<'a>, position: usize, expected: Option<&GrammarNode>)
+	{
+		let rank = level.rank(position);
+		let isFurther = match self.furthestRank
+		{
+			None => true,
+			Some(furthestRank) => rank > furthestRank,
+		};
+		
+		if isFurther
+		{
+			self.furthestRank = Some(rank);
+			self.furthestComponentValue = level.get(position);
+			self.expected.clear();
+		}
+		
+		if self.furthestRank == Some(rank)
+		{
+			if let Some(node) = expected
+			{
+				let expected = node.to_css_string();
+				if !self.expected.contains(&expected)
+				{
+					self.expected.push(expected);
+				}
+			}
+		}
+	}
+	
+	#[inline(always)]
+	fn add<T>(matches: &mut Vec<(usize, T)>, end: usize, value: T)
+	{
+		if !matches.iter().any(|&(existingEnd, _)| existingEnd == end)
+		{
+			matches.push((end, value));
+		}
+	}
+	
+	/// Dimensions are compared in their canonical unit (see `GrammarNode::data_type`); those without a fixed ratio to it, such as `em`, can only be compared with a bound of zero.
+	#[inline(always)]
+	fn is_within_range(componentValue: &ComponentValue, minimum: Option<f32>, maximum: Option<f32>) -> bool
+	{
+		let value = match *componentValue
+		{
+			ComponentValue::preserved_token(Token::Number { value, .. }) => value,
+			ComponentValue::preserved_token(Token::Percentage { unit_value, .. }) => unit_value * 100.,
+			ComponentValue::preserved_token(Token::Dimension { value, ref unit, .. }) => match canonical_dimension_value(value, unit)
+			{
+				Some(canonical) => canonical,
+				None => return minimum.map(|minimum| !(minimum >= 0. && value < 0.)).unwrap_or(true) && maximum.map(|maximum| !(maximum <= 0. && value > 0.)).unwrap_or(true),
+			},
+			_ => return true,
+		};
+		
+		minimum.map(|minimum| value >= minimum).unwrap_or(true) && maximum.map(|maximum| value <= maximum).unwrap_or(true)
+	}
+}
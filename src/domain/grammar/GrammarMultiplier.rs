@@ -0,0 +1,128 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// How many times a component of a grammar is repeated, such as the `{1,4}` in `[ <length> | auto ]{1,4}` or the `#` in `<color>#`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct GrammarMultiplier
+{
+	/// The minimum number of repetitions.
+	pub minimum: usize,
+	
+	/// The maximum number of repetitions; `None` is unbounded.
+	pub maximum: Option<usize>,
+	
+	/// Repetitions are separated by commas (`#`).
+	pub comma_separated: bool,
+}
+
+impl ToCss for GrammarMultiplier
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.comma_separated
+		{
+			dest.write_char('#')?;
+			if self.minimum == 1 && self.maximum.is_none()
+			{
+				return Ok(());
+			}
+		}
+		else
+		{
+			match (self.minimum, self.maximum)
+			{
+				(0, None) => return dest.write_char('*'),
+				(1, None) => return dest.write_char('+'),
+				(0, Some(1)) => return dest.write_char('?'),
+				_ => (),
+			}
+		}
+		
+		dest.write_char('{')?;
+		write!(dest, "{}", self.minimum)?;
+		match self.maximum
+		{
+			Some(maximum) if maximum == self.minimum => (),
+			Some(maximum) =>
+			{
+				dest.write_char(',')?;
+				write!(dest, "{}", maximum)?;
+			}
+			None => dest.write_char(',')?,
+		}
+		dest.write_char('}')
+	}
+}
+
+impl GrammarMultiplier
+{
+	/// `*`
+	pub const ZeroOrMore: Self = Self { minimum: 0, maximum: None, comma_separated: false };
+	
+	/// `+`
+	pub const OneOrMore: Self = Self { minimum: 1, maximum: None, comma_separated: false };
+	
+	/// `?`
+	pub const Optional: Self = Self { minimum: 0, maximum: Some(1), comma_separated: false };
+	
+	/// `#`
+	pub const OneOrMoreCommaSeparated: Self = Self { minimum: 1, maximum: None, comma_separated: true };
+	
+	/// Does `count` repetitions satisfy this multiplier's minimum?
+	#[inline(always)]
+	pub fn is_satisfied_by(&self, count: usize) -> bool
+	{
+		count >= self.minimum
+	}
+	
+	/// Are more than `count` repetitions allowed?
+	#[inline(always)]
+	pub fn allows_more_than(&self, count: usize) -> bool
+	{
+		match self.maximum
+		{
+			None => true,
+			Some(maximum) => count < maximum,
+		}
+	}
+	
+	/// Parses the contents of a `{A}`, `{A,}` or `{A,B}` range.
+	pub(crate) fn parse_range<'i, 't>(input: &mut Parser<'i, 't>, comma_separated: bool) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		input.parse_nested_block(|input|
+		{
+			let minimum = input.expect_integer()?;
+			let maximum = if input.try(|input| input.expect_comma()).is_ok()
+			{
+				if input.is_exhausted()
+				{
+					None
+				}
+				else
+				{
+					Some(input.expect_integer()?)
+				}
+			}
+			else
+			{
+				Some(minimum)
+			};
+			
+			if minimum < 0 || maximum.map(|maximum| maximum < minimum || maximum == 0).unwrap_or(false)
+			{
+				return Err(ParseError::Custom(CustomParseError::GrammarMultiplierRangeIsInvalid(minimum, maximum)));
+			}
+			
+			Ok
+			(
+				Self
+				{
+					minimum: minimum as usize,
+					maximum: maximum.map(|maximum| maximum as usize),
+					comma_separated,
+				}
+			)
+		})
+	}
+}
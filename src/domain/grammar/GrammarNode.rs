@@ -0,0 +1,434 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A compiled component of a grammar written in CSS Value Definition Syntax: https://drafts.csswg.org/css-values-4/#value-defs
+#[derive(Debug, Clone, PartialEq)]
+pub enum GrammarNode
+{
+	/// A keyword, such as `auto`, which matches an identifier ignoring ASCII case.
+	keyword(String),
+	
+	/// A literal, such as `,` or `/`.
+	literal(char),
+	
+	/// A basic data type, such as `<length>`, and its range, such as the `[0,∞]` in `<length [0,∞]>`, with any bound in a unit converted to its canonical unit (eg `deg`); `None` is unbounded.
+	data_type(GrammarDataType, Option<f32>, Option<f32>),
+	
+	/// A non-terminal data type, such as `<line-style>`, or, if `true`, a property's value, such as `<'border-top-width'>`, with its grammar.
+	non_terminal(String, bool, Box<GrammarNode>),
+	
+	/// A function, such as `fit-content( <length-percentage> )`.
+	function(String, Box<GrammarNode>),
+	
+	/// A bracketed group, `[ ... ]`; if `true`, the group must match at least one value (`[ ... ]!`).
+	group(Box<GrammarNode>, bool),
+	
+	/// Components which must all occur, in order.
+	juxtaposition(Vec<GrammarNode>),
+	
+	/// Components which must all occur, in any order (`&&`).
+	all_in_any_order(Vec<GrammarNode>),
+	
+	/// Components of which one or more must occur, in any order (`||`).
+	one_or_more_in_any_order(Vec<GrammarNode>),
+	
+	/// Components of which exactly one must occur (`|`).
+	exactly_one(Vec<GrammarNode>),
+	
+	/// A repeated component, such as `<length>{1,4}`.
+	multiplied(Box<GrammarNode>, GrammarMultiplier),
+}
+
+impl ToCss for GrammarNode
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::GrammarNode::*;
+		
+		#[inline(always)]
+		fn write_list<W: fmt::Write>(nodes: &[GrammarNode], separator: &str, dest: &mut W) -> fmt::Result
+		{
+			let mut afterFirst = false;
+			for node in nodes.iter()
+			{
+				if afterFirst
+				{
+					dest.write_str(separator)?;
+				}
+				else
+				{
+					afterFirst = true;
+				}
+				node.to_css(dest)?;
+			}
+			Ok(())
+		}
+		
+		#[inline(always)]
+		fn write_bound<W: fmt::Write>(bound: Option<f32>, infinity: &str, dest: &mut W) -> fmt::Result
+		{
+			match bound
+			{
+				None => dest.write_str(infinity),
+				Some(bound) => bound.to_css(dest),
+			}
+		}
+		
+		match *self
+		{
+			keyword(ref name) => serialize_identifier(name, dest),
+			
+			literal(character) => dest.write_char(character),
+			
+			data_type(dataType, minimum, maximum) =>
+			{
+				dest.write_char('<')?;
+				dataType.to_css(dest)?;
+				if minimum.is_some() || maximum.is_some()
+				{
+					dest.write_str(" [")?;
+					write_bound(minimum, "-∞", dest)?;
+					dest.write_char(',')?;
+					write_bound(maximum, "∞", dest)?;
+					dest.write_char(']')?;
+				}
+				dest.write_char('>')
+			}
+			
+			non_terminal(ref name, isProperty, _) =>
+			{
+				dest.write_char('<')?;
+				if isProperty
+				{
+					dest.write_char('\'')?;
+				}
+				dest.write_str(name)?;
+				if isProperty
+				{
+					dest.write_char('\'')?;
+				}
+				dest.write_char('>')
+			}
+			
+			function(ref name, ref arguments) =>
+			{
+				serialize_identifier(name, dest)?;
+				dest.write_str("( ")?;
+				arguments.to_css(dest)?;
+				dest.write_str(" )")
+			}
+			
+			group(ref node, mustNotBeEmpty) =>
+			{
+				dest.write_str("[ ")?;
+				node.to_css(dest)?;
+				dest.write_str(" ]")?;
+				if mustNotBeEmpty
+				{
+					dest.write_char('!')?;
+				}
+				Ok(())
+			}
+			
+			juxtaposition(ref nodes) => write_list(nodes, " ", dest),
+			
+			all_in_any_order(ref nodes) => write_list(nodes, " && ", dest),
+			
+			one_or_more_in_any_order(ref nodes) => write_list(nodes, " || ", dest),
+			
+			exactly_one(ref nodes) => write_list(nodes, " | ", dest),
+			
+			multiplied(ref node, multiplier) =>
+			{
+				node.to_css(dest)?;
+				multiplier.to_css(dest)
+			}
+		}
+	}
+}
+
+impl GrammarNode
+{
+	/// Compiles a grammar; `namesBeingCompiled` are the non-terminals whose grammars are being compiled, used to reject recursive definitions.
+	pub(crate) fn compile<'i>(grammar: &'i str, namesBeingCompiled: &mut Vec<String>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut parserInput = ParserInput::new(grammar);
+		let mut input = Parser::new(&mut parserInput);
+		input.parse_entirely(|input| Self::parse_exactly_one(input, namesBeingCompiled))
+	}
+	
+	fn parse_exactly_one<'i, 't>(input: &mut Parser<'i, 't>, namesBeingCompiled: &mut Vec<String>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut nodes = vec![Self::parse_one_or_more_in_any_order(input, namesBeingCompiled)?];
+		while input.try(|input| input.expect_delim('|')).is_ok()
+		{
+			nodes.push(Self::parse_one_or_more_in_any_order(input, namesBeingCompiled)?);
+		}
+		Ok(Self::combine(nodes, GrammarNode::exactly_one))
+	}
+	
+	fn parse_one_or_more_in_any_order<'i, 't>(input: &mut Parser<'i, 't>, namesBeingCompiled: &mut Vec<String>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut nodes = vec![Self::parse_all_in_any_order(input, namesBeingCompiled)?];
+		while input.try(|input| -> Result<_, ParseError<'i, CustomParseError<'i>>>
+		{
+			match *input.next()?
+			{
+				Token::Column => Ok(()),
+				ref token @ _ => CustomParseError::unexpectedToken(token),
+			}
+		}).is_ok()
+		{
+			nodes.push(Self::parse_all_in_any_order(input, namesBeingCompiled)?);
+		}
+		Self::check_number_of_components_in_any_order(&nodes)?;
+		Ok(Self::combine(nodes, GrammarNode::one_or_more_in_any_order))
+	}
+	
+	fn parse_all_in_any_order<'i, 't>(input: &mut Parser<'i, 't>, namesBeingCompiled: &mut Vec<String>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut nodes = vec![Self::parse_juxtaposition(input, namesBeingCompiled)?];
+		while input.try(|input|
+		{
+			input.expect_delim('&')?;
+			input.expect_delim('&')
+		}).is_ok()
+		{
+			nodes.push(Self::parse_juxtaposition(input, namesBeingCompiled)?);
+		}
+		Self::check_number_of_components_in_any_order(&nodes)?;
+		Ok(Self::combine(nodes, GrammarNode::all_in_any_order))
+	}
+	
+	/// The components of `&&` and `||` are tracked as bits of a `u64` when matching.
+	#[inline(always)]
+	fn check_number_of_components_in_any_order<'i>(nodes: &[GrammarNode]) -> Result<(), ParseError<'i, CustomParseError<'i>>>
+	{
+		const MaximumNumberOfComponentsInAnyOrder: usize = 63;
+		
+		if nodes.len() > MaximumNumberOfComponentsInAnyOrder
+		{
+			Err(ParseError::Custom(CustomParseError::GrammarHasTooManyComponentsInAnyOrder(nodes.len())))
+		}
+		else
+		{
+			Ok(())
+		}
+	}
+	
+	fn parse_juxtaposition<'i, 't>(input: &mut Parser<'i, 't>, namesBeingCompiled: &mut Vec<String>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut nodes = vec![Self::parse_multiplied(input, namesBeingCompiled)?];
+		loop
+		{
+			let state = input.state();
+			let isEndOfJuxtaposition = match input.next()
+			{
+				Err(_) | Ok(&Token::Delim('|')) | Ok(&Token::Delim('&')) | Ok(&Token::Column) => true,
+				Ok(_) => false,
+			};
+			input.reset(&state);
+			
+			if isEndOfJuxtaposition
+			{
+				return Ok(Self::combine(nodes, GrammarNode::juxtaposition));
+			}
+			nodes.push(Self::parse_multiplied(input, namesBeingCompiled)?);
+		}
+	}
+	
+	fn parse_multiplied<'i, 't>(input: &mut Parser<'i, 't>, namesBeingCompiled: &mut Vec<String>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::GrammarNode::*;
+		
+		let mut node = Self::parse_component(input, namesBeingCompiled)?;
+		loop
+		{
+			let state = input.state();
+			let token = match input.next()
+			{
+				Ok(token) => token.clone(),
+				Err(_) => return Ok(node),
+			};
+			
+			let multiplier = match token
+			{
+				Token::Delim('*') => GrammarMultiplier::ZeroOrMore,
+				
+				Token::Delim('+') => GrammarMultiplier::OneOrMore,
+				
+				Token::Delim('?') => GrammarMultiplier::Optional,
+				
+				Token::Delim('#') => if input.try(|input| input.expect_curly_bracket_block()).is_ok()
+				{
+					GrammarMultiplier::parse_range(input, true)?
+				}
+				else
+				{
+					GrammarMultiplier::OneOrMoreCommaSeparated
+				},
+				
+				Token::CurlyBracketBlock => GrammarMultiplier::parse_range(input, false)?,
+				
+				Token::Delim('!') => match node
+				{
+					group(_, ref mut mustNotBeEmpty) if !*mustNotBeEmpty =>
+					{
+						*mustNotBeEmpty = true;
+						continue;
+					}
+					_ => return CustomParseError::unexpectedToken(&token),
+				},
+				
+				_ =>
+				{
+					input.reset(&state);
+					return Ok(node);
+				}
+			};
+			node = multiplied(Box::new(node), multiplier);
+		}
+	}
+	
+	fn parse_component<'i, 't>(input: &mut Parser<'i, 't>, namesBeingCompiled: &mut Vec<String>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::GrammarNode::*;
+		
+		let token = input.next()?.clone();
+		match token
+		{
+			Token::Ident(ref name) => Ok(keyword(name.to_ascii_lowercase())),
+			
+			Token::Comma => Ok(literal(',')),
+			
+			Token::Colon => Ok(literal(':')),
+			
+			Token::Delim('/') => Ok(literal('/')),
+			
+			Token::QuotedString(ref value) if value.chars().count() == 1 => Ok(literal(value.chars().next().unwrap())),
+			
+			Token::Delim('<') =>
+			{
+				let node = Self::parse_data_type(input, namesBeingCompiled)?;
+				input.expect_delim('>')?;
+				Ok(node)
+			}
+			
+			Token::Function(ref name) =>
+			{
+				let arguments = input.parse_nested_block(|input| Self::parse_exactly_one(input, namesBeingCompiled))?;
+				Ok(function(name.to_ascii_lowercase(), Box::new(arguments)))
+			}
+			
+			Token::SquareBracketBlock =>
+			{
+				let node = input.parse_nested_block(|input| Self::parse_exactly_one(input, namesBeingCompiled))?;
+				Ok(group(Box::new(node), false))
+			}
+			
+			_ => CustomParseError::unexpectedToken(&token),
+		}
+	}
+	
+	fn parse_data_type<'i, 't>(input: &mut Parser<'i, 't>, namesBeingCompiled: &mut Vec<String>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::GrammarNode::*;
+		
+		let token = input.next()?.clone();
+		match token
+		{
+			Token::Ident(ref name) =>
+			{
+				if let Ok(dataType) = GrammarDataType::from_ident(name)
+				{
+					return if input.try(|input| input.expect_square_bracket_block()).is_ok()
+					{
+						let (minimum, maximum) = input.parse_nested_block(|input|
+						{
+							let minimum = Self::parse_range_bound(input, "-∞")?;
+							input.expect_comma()?;
+							let maximum = Self::parse_range_bound(input, "∞")?;
+							Ok((minimum, maximum))
+						})?;
+						
+						if let (Some(minimum), Some(maximum)) = (minimum, maximum)
+						{
+							if maximum < minimum
+							{
+								return Err(ParseError::Custom(CustomParseError::GrammarDataTypeRangeIsInvalid(minimum, maximum)));
+							}
+						}
+						Ok(data_type(dataType, minimum, maximum))
+					}
+					else
+					{
+						Ok(data_type(dataType, None, None))
+					};
+				}
+				
+				let lowerCaseName = name.to_ascii_lowercase();
+				match data_type_grammar(&lowerCaseName)
+				{
+					None => Err(ParseError::Custom(CustomParseError::UnknownGrammarDataType(name.clone()))),
+					Some(grammar) => Self::compile_non_terminal(name, lowerCaseName, false, grammar, namesBeingCompiled),
+				}
+			}
+			
+			Token::QuotedString(ref name) =>
+			{
+				let lowerCaseName = name.to_ascii_lowercase();
+				match property_grammar(&lowerCaseName)
+				{
+					None => Err(ParseError::Custom(CustomParseError::UnknownPropertyForGrammar(name.clone()))),
+					Some(grammar) => Self::compile_non_terminal(name, lowerCaseName, true, grammar, namesBeingCompiled),
+				}
+			}
+			
+			_ => CustomParseError::unexpectedToken(&token),
+		}
+	}
+	
+	fn compile_non_terminal<'i>(name: &CowRcStr<'i>, lowerCaseName: String, isProperty: bool, grammar: &'static str, namesBeingCompiled: &mut Vec<String>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if namesBeingCompiled.contains(&lowerCaseName)
+		{
+			return Err(ParseError::Custom(CustomParseError::GrammarDataTypeIsRecursive(name.clone())));
+		}
+		
+		namesBeingCompiled.push(lowerCaseName.clone());
+		let node = Self::compile(grammar, namesBeingCompiled)?;
+		namesBeingCompiled.pop();
+		
+		Ok(GrammarNode::non_terminal(lowerCaseName, isProperty, Box::new(node)))
+	}
+	
+	fn parse_range_bound<'i, 't>(input: &mut Parser<'i, 't>, infinity: &str) -> Result<Option<f32>, ParseError<'i, CustomParseError<'i>>>
+	{
+		let token = input.next()?.clone();
+		match token
+		{
+			Token::Number { value, .. } => Ok(Some(value)),
+			Token::Dimension { value, ref unit, .. } => match canonical_dimension_value(value, unit)
+			{
+				None => Err(ParseError::Custom(CustomParseError::GrammarDataTypeRangeBoundHasARelativeUnit(unit.clone()))),
+				canonical @ Some(_) => Ok(canonical),
+			},
+			Token::Ident(ref value) if value.as_ref() == infinity => Ok(None),
+			_ => CustomParseError::unexpectedToken(&token),
+		}
+	}
+	
+	#[inline(always)]
+	fn combine<Constructor: FnOnce(Vec<GrammarNode>) -> GrammarNode>(mut nodes: Vec<GrammarNode>, constructor: Constructor) -> Self
+	{
+		if nodes.len() == 1
+		{
+			nodes.pop().unwrap()
+		}
+		else
+		{
+			constructor(nodes)
+		}
+	}
+}
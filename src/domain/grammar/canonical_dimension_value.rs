@@ -0,0 +1,43 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Converts a dimension to its canonical unit (`deg`, `s`, `Hz`, `dppx` or, for absolute lengths, `px`), so that range bounds and values written in different units can be compared.
+/// Returns `None` for units without a fixed ratio to their canonical unit, such as `em` and `vw`, and for unknown units.
+pub(crate) fn canonical_dimension_value(value: f32, unit: &str) -> Option<f32>
+{
+	const PixelsPerInch: f32 = 96.0;
+	
+	let ratio = match_ignore_ascii_case!
+	{
+		unit,
+		
+		"px" => 1.0,
+		"in" => PixelsPerInch,
+		"cm" => PixelsPerInch / 2.54,
+		"mm" => PixelsPerInch / 25.4,
+		"q" => PixelsPerInch / 101.6,
+		"pt" => PixelsPerInch / 72.0,
+		"pc" => PixelsPerInch / 6.0,
+		
+		"deg" => 1.0,
+		"grad" => 360.0 / 400.0,
+		"rad" => 180.0 / ::std::f32::consts::PI,
+		"turn" => 360.0,
+		
+		"s" => 1.0,
+		"ms" => 1.0 / 1000.0,
+		
+		"hz" => 1.0,
+		"khz" => 1000.0,
+		
+		"dppx" => 1.0,
+		"x" => 1.0,
+		"dpi" => 1.0 / PixelsPerInch,
+		"dpcm" => 2.54 / PixelsPerInch,
+		
+		_ => return None
+	};
+	
+	Some(value * ratio)
+}
@@ -0,0 +1,56 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The grammar, in CSS Value Definition Syntax, of a non-terminal data type, such as `line-style`, from the bundled table of standard data types.
+pub fn data_type_grammar(lowerCaseName: &str) -> Option<&'static str>
+{
+	static DataTypeGrammars: phf::Map<&'static str, &'static str> = phf_map!
+	{
+		"absolute-size" => "xx-small | x-small | small | medium | large | x-large | xx-large | xxx-large",
+		"alpha-value" => "<number> | <percentage>",
+		"animateable-feature" => "scroll-position | contents | <custom-ident>",
+		"attachment" => "scroll | fixed | local",
+		"bg-image" => "<image> | none",
+		"bg-layer" => "<bg-image> || <bg-position> [ / <bg-size> ]? || <repeat-style> || <attachment> || <box> || <box>",
+		"bg-position" => "<position>",
+		"bg-size" => "[ <length-percentage [0,∞]> | auto ]{1,2} | cover | contain",
+		"box" => "border-box | padding-box | content-box",
+		"content-distribution" => "space-between | space-around | space-evenly | stretch",
+		"content-position" => "center | start | end | flex-start | flex-end",
+		"counter-name" => "<custom-ident>",
+		"counter-style" => "<custom-ident>",
+		"display-box" => "contents | none",
+		"display-inside" => "flow | flow-root | table | flex | grid | ruby",
+		"display-internal" => "table-row-group | table-header-group | table-footer-group | table-row | table-cell | table-column-group | table-column | table-caption | ruby-base | ruby-text | ruby-base-container | ruby-text-container",
+		"display-legacy" => "inline-block | inline-table | inline-flex | inline-grid",
+		"display-listitem" => "<display-outside>? && [ flow | flow-root ]? && list-item",
+		"display-outside" => "block | inline | run-in",
+		"easing-function" => "linear | ease | ease-in | ease-out | ease-in-out | step-start | step-end | cubic-bezier( <number [0,1]> , <number> , <number [0,1]> , <number> ) | steps( <integer [1,∞]> [ , <step-position> ]? )",
+		"family-name" => "<string> | <custom-ident>+",
+		"final-bg-layer" => "<bg-image> || <bg-position> [ / <bg-size> ]? || <repeat-style> || <attachment> || <box> || <box> || <'background-color'>",
+		"generic-family" => "serif | sans-serif | cursive | fantasy | monospace | system-ui | math | emoji | fangsong | ui-serif | ui-sans-serif | ui-monospace | ui-rounded",
+		"keyframes-name" => "<custom-ident> | <string>",
+		"line-style" => "none | hidden | dotted | dashed | solid | double | groove | ridge | inset | outset",
+		"line-width" => "<length [0,∞]> | thin | medium | thick",
+		"overflow-position" => "unsafe | safe",
+		"position" => "[ left | center | right | top | bottom | <length-percentage> ] | [ left | center | right | <length-percentage> ] [ top | center | bottom | <length-percentage> ] | [ center | [ left | right ] <length-percentage>? ] && [ center | [ top | bottom ] <length-percentage>? ]",
+		"ratio" => "<number [0,∞]> [ / <number [0,∞]> ]?",
+		"relative-size" => "larger | smaller",
+		"repeat-style" => "repeat-x | repeat-y | [ repeat | space | round | no-repeat ]{1,2}",
+		"reversed-counter-name" => "reversed( <counter-name> )",
+		"self-position" => "center | start | end | self-start | self-end | flex-start | flex-end",
+		"shadow" => "<color>? && [ <length>{2} <length [0,∞]>? <length>? ] && inset?",
+		"shadow-t" => "[ <length>{2} <length [0,∞]>? ] && <color>?",
+		"single-animation" => "<time [0s,∞]> || <easing-function> || <time> || <single-animation-iteration-count> || <single-animation-direction> || <single-animation-fill-mode> || <single-animation-play-state> || [ none | <keyframes-name> ]",
+		"single-animation-direction" => "normal | reverse | alternate | alternate-reverse",
+		"single-animation-fill-mode" => "none | forwards | backwards | both",
+		"single-animation-iteration-count" => "infinite | <number [0,∞]>",
+		"single-animation-play-state" => "running | paused",
+		"single-transition" => "[ none | <single-transition-property> ] || <time [0s,∞]> || <easing-function> || <time>",
+		"single-transition-property" => "all | <custom-ident>",
+		"step-position" => "jump-start | jump-end | jump-none | jump-both | start | end",
+	};
+	
+	DataTypeGrammars.get(lowerCaseName).map(|grammar| *grammar)
+}
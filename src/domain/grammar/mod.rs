@@ -0,0 +1,25 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+use super::colors::Color;
+use super::properties::ComponentValue;
+use super::properties::SpecifiedValue;
+use ::std::cmp::max;
+
+
+#[cfg(test)]
+mod tests;
+
+
+include!("canonical_dimension_value.rs");
+include!("data_type_grammar.rs");
+include!("Grammar.rs");
+include!("GrammarDataType.rs");
+include!("GrammarLevel.rs");
+include!("GrammarMatch.rs");
+include!("GrammarMatcher.rs");
+include!("GrammarMultiplier.rs");
+include!("GrammarNode.rs");
+include!("property_grammar.rs");
@@ -0,0 +1,155 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The grammar, in CSS Value Definition Syntax, of a standard property, from the bundled table of property grammars.
+pub fn property_grammar(lowerCaseName: &str) -> Option<&'static str>
+{
+	static PropertyGrammars: phf::Map<&'static str, &'static str> = phf_map!
+	{
+		"align-content" => "normal | <content-distribution> | <overflow-position>? <content-position> | baseline",
+		"align-items" => "normal | stretch | baseline | <overflow-position>? <self-position>",
+		"align-self" => "auto | normal | stretch | baseline | <overflow-position>? <self-position>",
+		"animation" => "<single-animation>#",
+		"animation-delay" => "<time>#",
+		"animation-direction" => "<single-animation-direction>#",
+		"animation-duration" => "<time [0s,∞]>#",
+		"animation-fill-mode" => "<single-animation-fill-mode>#",
+		"animation-iteration-count" => "<single-animation-iteration-count>#",
+		"animation-name" => "[ none | <keyframes-name> ]#",
+		"animation-play-state" => "<single-animation-play-state>#",
+		"animation-timing-function" => "<easing-function>#",
+		"aspect-ratio" => "auto || <ratio>",
+		"background" => "[ <bg-layer> , ]* <final-bg-layer>",
+		"background-attachment" => "<attachment>#",
+		"background-clip" => "<box>#",
+		"background-color" => "<color>",
+		"background-image" => "<bg-image>#",
+		"background-origin" => "<box>#",
+		"background-position" => "<bg-position>#",
+		"background-repeat" => "<repeat-style>#",
+		"background-size" => "<bg-size>#",
+		"border" => "<line-width> || <line-style> || <color>",
+		"border-bottom" => "<line-width> || <line-style> || <color>",
+		"border-bottom-color" => "<color>",
+		"border-bottom-left-radius" => "<length-percentage [0,∞]>{1,2}",
+		"border-bottom-right-radius" => "<length-percentage [0,∞]>{1,2}",
+		"border-bottom-style" => "<line-style>",
+		"border-bottom-width" => "<line-width>",
+		"border-collapse" => "separate | collapse",
+		"border-color" => "<color>{1,4}",
+		"border-left" => "<line-width> || <line-style> || <color>",
+		"border-left-color" => "<color>",
+		"border-left-style" => "<line-style>",
+		"border-left-width" => "<line-width>",
+		"border-radius" => "<length-percentage [0,∞]>{1,4} [ / <length-percentage [0,∞]>{1,4} ]?",
+		"border-right" => "<line-width> || <line-style> || <color>",
+		"border-right-color" => "<color>",
+		"border-right-style" => "<line-style>",
+		"border-right-width" => "<line-width>",
+		"border-spacing" => "<length [0,∞]>{1,2}",
+		"border-style" => "<line-style>{1,4}",
+		"border-top" => "<line-width> || <line-style> || <color>",
+		"border-top-color" => "<color>",
+		"border-top-left-radius" => "<length-percentage [0,∞]>{1,2}",
+		"border-top-right-radius" => "<length-percentage [0,∞]>{1,2}",
+		"border-top-style" => "<line-style>",
+		"border-top-width" => "<line-width>",
+		"border-width" => "<line-width>{1,4}",
+		"bottom" => "auto | <length-percentage>",
+		"box-shadow" => "none | <shadow>#",
+		"box-sizing" => "content-box | border-box",
+		"caption-side" => "top | bottom",
+		"clear" => "inline-start | inline-end | block-start | block-end | left | right | top | bottom | both | none",
+		"color" => "<color>",
+		"column-gap" => "normal | <length-percentage [0,∞]>",
+		"counter-increment" => "[ <counter-name> <integer>? ]+ | none",
+		"counter-reset" => "[ <counter-name> <integer>? | <reversed-counter-name> <integer>? ]+ | none",
+		"counter-set" => "[ <counter-name> <integer>? ]+ | none",
+		"cursor" => "[ <url> [ <number> <number> ]? , ]* [ auto | default | none | context-menu | help | pointer | progress | wait | cell | crosshair | text | vertical-text | alias | copy | move | no-drop | not-allowed | grab | grabbing | e-resize | n-resize | ne-resize | nw-resize | s-resize | se-resize | sw-resize | w-resize | ew-resize | ns-resize | nesw-resize | nwse-resize | col-resize | row-resize | all-scroll | zoom-in | zoom-out ]",
+		"direction" => "ltr | rtl",
+		"display" => "[ <display-outside> || <display-inside> ] | <display-listitem> | <display-internal> | <display-box> | <display-legacy>",
+		"empty-cells" => "show | hide",
+		"flex" => "none | [ <'flex-grow'> <'flex-shrink'>? || <'flex-basis'> ]",
+		"flex-basis" => "content | <'width'>",
+		"flex-direction" => "row | row-reverse | column | column-reverse",
+		"flex-flow" => "<'flex-direction'> || <'flex-wrap'>",
+		"flex-grow" => "<number [0,∞]>",
+		"flex-shrink" => "<number [0,∞]>",
+		"flex-wrap" => "nowrap | wrap | wrap-reverse",
+		"float" => "block-start | block-end | inline-start | inline-end | left | right | top | bottom | none",
+		"font-family" => "[ <family-name> | <generic-family> ]#",
+		"font-size" => "<absolute-size> | <relative-size> | <length-percentage [0,∞]> | math",
+		"font-style" => "normal | italic | oblique <angle [-90deg,90deg]>?",
+		"font-variant-caps" => "normal | small-caps | all-small-caps | petite-caps | all-petite-caps | unicase | titling-caps",
+		"font-weight" => "normal | bold | bolder | lighter | <number [1,1000]>",
+		"gap" => "<'row-gap'> <'column-gap'>?",
+		"height" => "auto | <length-percentage [0,∞]> | min-content | max-content | fit-content( <length-percentage [0,∞]> )",
+		"justify-content" => "normal | <content-distribution> | <overflow-position>? [ <content-position> | left | right ]",
+		"left" => "auto | <length-percentage>",
+		"letter-spacing" => "normal | <length>",
+		"line-height" => "normal | <number [0,∞]> | <length-percentage [0,∞]>",
+		"list-style" => "<'list-style-position'> || <'list-style-image'> || <'list-style-type'>",
+		"list-style-image" => "<image> | none",
+		"list-style-position" => "inside | outside",
+		"list-style-type" => "<counter-style> | <string> | none",
+		"margin" => "<'margin-top'>{1,4}",
+		"margin-bottom" => "<length-percentage> | auto",
+		"margin-left" => "<length-percentage> | auto",
+		"margin-right" => "<length-percentage> | auto",
+		"margin-top" => "<length-percentage> | auto",
+		"max-height" => "none | <length-percentage [0,∞]> | min-content | max-content | fit-content( <length-percentage [0,∞]> )",
+		"max-width" => "none | <length-percentage [0,∞]> | min-content | max-content | fit-content( <length-percentage [0,∞]> )",
+		"min-height" => "auto | <length-percentage [0,∞]> | min-content | max-content | fit-content( <length-percentage [0,∞]> )",
+		"min-width" => "auto | <length-percentage [0,∞]> | min-content | max-content | fit-content( <length-percentage [0,∞]> )",
+		"object-fit" => "fill | contain | cover | none | scale-down",
+		"object-position" => "<position>",
+		"opacity" => "<alpha-value>",
+		"order" => "<integer>",
+		"outline" => "<'outline-color'> || <'outline-style'> || <'outline-width'>",
+		"outline-color" => "<color> | invert",
+		"outline-offset" => "<length>",
+		"outline-style" => "auto | <line-style>",
+		"outline-width" => "<line-width>",
+		"overflow" => "[ visible | hidden | clip | scroll | auto ]{1,2}",
+		"overflow-x" => "visible | hidden | clip | scroll | auto",
+		"overflow-y" => "visible | hidden | clip | scroll | auto",
+		"padding" => "<'padding-top'>{1,4}",
+		"padding-bottom" => "<length-percentage [0,∞]>",
+		"padding-left" => "<length-percentage [0,∞]>",
+		"padding-right" => "<length-percentage [0,∞]>",
+		"padding-top" => "<length-percentage [0,∞]>",
+		"pointer-events" => "auto | none | visiblePainted | visibleFill | visibleStroke | visible | painted | fill | stroke | all",
+		"position" => "static | relative | absolute | sticky | fixed",
+		"quotes" => "none | auto | [ <string> <string> ]+",
+		"resize" => "none | both | horizontal | vertical | block | inline",
+		"right" => "auto | <length-percentage>",
+		"row-gap" => "normal | <length-percentage [0,∞]>",
+		"table-layout" => "auto | fixed",
+		"text-align" => "start | end | left | right | center | justify | match-parent | justify-all",
+		"text-decoration-color" => "<color>",
+		"text-decoration-line" => "none | [ underline || overline || line-through || blink ]",
+		"text-decoration-style" => "solid | double | dotted | dashed | wavy",
+		"text-indent" => "<length-percentage> && hanging? && each-line?",
+		"text-overflow" => "clip | ellipsis",
+		"text-shadow" => "none | <shadow-t>#",
+		"text-transform" => "none | [ capitalize | uppercase | lowercase ] || full-width || full-size-kana",
+		"top" => "auto | <length-percentage>",
+		"transition" => "<single-transition>#",
+		"transition-delay" => "<time>#",
+		"transition-duration" => "<time [0s,∞]>#",
+		"transition-property" => "none | <single-transition-property>#",
+		"transition-timing-function" => "<easing-function>#",
+		"user-select" => "auto | text | none | contain | all",
+		"vertical-align" => "baseline | sub | super | text-top | text-bottom | middle | top | bottom | <length-percentage>",
+		"visibility" => "visible | hidden | collapse",
+		"white-space" => "normal | pre | nowrap | pre-wrap | break-spaces | pre-line",
+		"width" => "auto | <length-percentage [0,∞]> | min-content | max-content | fit-content( <length-percentage [0,∞]> )",
+		"will-change" => "auto | <animateable-feature>#",
+		"word-break" => "normal | keep-all | break-all | break-word",
+		"word-spacing" => "normal | <length>",
+		"z-index" => "auto | <integer>",
+	};
+	
+	PropertyGrammars.get(lowerCaseName).map(|grammar| *grammar)
+}
@@ -0,0 +1,96 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+
+
+fn matches(grammar: &str, css: &str) -> bool
+{
+	Grammar::compile(grammar).unwrap().match_component_values(&ComponentValue::parse_css(css).unwrap()).is_ok()
+}
+
+#[test]
+fn grammars_match_in_any_order_and_with_multipliers()
+{
+	assert!(matches("<length>{1,2} && [ solid | dashed ]", "solid 1px 2px"));
+	assert!(matches("a || b || c", "c a"));
+	assert!(!matches("a || b || c", "c c"));
+	assert!(matches("<length>#", "1px, 2px, 3px"));
+	assert!(!matches("<length>#", "1px, , 3px"));
+	assert!(matches("fit-content( <length-percentage> )", "fit-content(50%)"));
+}
+
+#[test]
+fn grammar_errors_are_for_the_furthest_component_value_reached()
+{
+	let grammar = Grammar::compile("a b [ c | d ]").unwrap();
+	
+	match grammar.match_component_values(&ComponentValue::parse_css("a b e").unwrap())
+	{
+		Err(ParseError::Custom(CustomParseError::UnexpectedTokenForGrammar(Token::Ident(ref name), ref expected))) =>
+		{
+			assert_eq!(name.as_ref(), "e");
+			assert_eq!(expected, &vec!["c".to_owned(), "d".to_owned()]);
+		}
+		result @ _ => panic!("{:?}", result),
+	}
+	
+	match grammar.match_component_values(&ComponentValue::parse_css("a").unwrap())
+	{
+		Err(ParseError::Custom(CustomParseError::UnexpectedEndOfInputForGrammar(ref expected))) => assert_eq!(expected, &vec!["b".to_owned()]),
+		result @ _ => panic!("{:?}", result),
+	}
+}
+
+#[test]
+fn grammar_ranges_are_compared_in_canonical_units()
+{
+	assert!(matches("<angle [-90deg,90deg]>", "1rad"));
+	assert!(matches("<angle [-90deg,90deg]>", "100grad"));
+	assert!(!matches("<angle [-90deg,90deg]>", "2rad"));
+	assert!(!matches("<angle [-90deg,90deg]>", "-0.3turn"));
+	
+	assert!(matches("<time [0s,1s]>", "500ms"));
+	assert!(!matches("<time [0s,1s]>", "1500ms"));
+	assert!(matches("<time [0ms,1000ms]>", "1s"));
+	
+	assert!(matches("<length [0,1in]>", "96px"));
+	assert!(!matches("<length [0,1in]>", "97px"));
+	assert!(matches("<length [0,∞]>", "2em"));
+	assert!(!matches("<length [0,∞]>", "-2em"));
+	
+	match Grammar::compile("<length [0,10em]>")
+	{
+		Err(ParseError::Custom(CustomParseError::GrammarDataTypeRangeBoundHasARelativeUnit(ref unit))) => assert_eq!(unit.as_ref(), "em"),
+		result @ _ => panic!("{:?}", result),
+	}
+}
+
+#[test]
+fn grammars_with_too_many_components_in_any_order_are_rejected()
+{
+	let grammar = |count: usize, separator: &str| (0 .. count).map(|index| format!("a{}", index)).collect::<Vec<_>>().join(separator);
+	
+	assert!(Grammar::compile(&grammar(63, " || ")).is_ok());
+	
+	match Grammar::compile(&grammar(64, " || "))
+	{
+		Err(ParseError::Custom(CustomParseError::GrammarHasTooManyComponentsInAnyOrder(64))) => (),
+		result @ _ => panic!("{:?}", result.is_ok()),
+	}
+	
+	match Grammar::compile(&grammar(70, " && "))
+	{
+		Err(ParseError::Custom(CustomParseError::GrammarHasTooManyComponentsInAnyOrder(70))) => (),
+		result @ _ => panic!("{:?}", result.is_ok()),
+	}
+}
+
+#[test]
+fn bundled_property_grammars_compile()
+{
+	assert!(Grammar::for_property("transition").is_ok());
+	assert!(Grammar::for_property("BORDER-TOP").unwrap().match_component_values(&ComponentValue::parse_css("1px solid red").unwrap()).is_ok());
+	assert!(Grammar::for_property("--custom").is_err());
+}
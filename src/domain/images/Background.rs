@@ -0,0 +1,194 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The `background` shorthand property: https://drafts.csswg.org/css-backgrounds-3/#background
+#[derive(Debug, Clone, PartialEq)]
+pub struct Background
+{
+	/// The layers, topmost first; there is always at least one.
+	pub layers: Vec<BackgroundLayer>,
+	
+	/// The background color, beneath all of the layers.
+	pub color: Color,
+}
+
+impl Default for Background
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			layers: vec![BackgroundLayer::default()],
+			color: Color::transparent(),
+		}
+	}
+}
+
+impl ToCss for Background
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		let finalLayerIndex = self.layers.len() - 1;
+		for (index, layer) in self.layers.iter().enumerate()
+		{
+			if index == finalLayerIndex
+			{
+				layer.to_css_with_color(Some(&self.color), dest)?;
+			}
+			else
+			{
+				layer.to_css_with_color(None, dest)?;
+				dest.write_char(',')?;
+			}
+		}
+		Ok(())
+	}
+}
+
+impl PropertyValue for Background
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"background",
+	];
+	
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let layersAndColors = input.parse_comma_separated(|input| BackgroundLayer::parse(context, input))?;
+		
+		let finalLayerIndex = layersAndColors.len() - 1;
+		let mut color = Color::transparent();
+		let mut layers = Vec::with_capacity(layersAndColors.len());
+		for (index, (layer, layerColor)) in layersAndColors.into_iter().enumerate()
+		{
+			if let Some(layerColor) = layerColor
+			{
+				if index != finalLayerIndex
+				{
+					return Err(ParseError::Custom(CustomParseError::BackgroundColorMustBeInTheFinalLayer));
+				}
+				color = layerColor;
+			}
+			layers.push(layer);
+		}
+		
+		Ok(Self { layers, color })
+	}
+}
+
+impl Shorthand for Background
+{
+	#[inline(always)]
+	fn longhand_property_names(_shorthand_property_name: &str) -> &'static [&'static str]
+	{
+		Self::LonghandPropertyNames
+	}
+	
+	#[inline(always)]
+	fn is_related_property(_shorthand_property_name: &str, property_name: &str) -> bool
+	{
+		const Prefix: &'static str = "background";
+		
+		property_name.len() >= Prefix.len() && property_name.is_char_boundary(Prefix.len()) && property_name[..Prefix.len()].eq_ignore_ascii_case(Prefix)
+	}
+	
+	fn expand(&self) -> Option<Vec<SpecifiedValue>>
+	{
+		if self.is_var()
+		{
+			return None;
+		}
+		
+		let layers = &self.layers;
+		Some
+		(
+			vec!
+			[
+				SpecifiedValue::from_typed_value(&Layers(layers.iter().map(|layer| layer.image.clone()).collect())).ok()?,
+				SpecifiedValue::from_typed_value(&Layers(layers.iter().map(|layer| layer.position.clone()).collect())).ok()?,
+				SpecifiedValue::from_typed_value(&Layers(layers.iter().map(|layer| layer.size.clone()).collect())).ok()?,
+				SpecifiedValue::from_typed_value(&Layers(layers.iter().map(|layer| layer.repeat).collect())).ok()?,
+				SpecifiedValue::from_typed_value(&Layers(layers.iter().map(|layer| layer.attachment).collect())).ok()?,
+				SpecifiedValue::from_typed_value(&Layers(layers.iter().map(|layer| layer.origin).collect())).ok()?,
+				SpecifiedValue::from_typed_value(&Layers(layers.iter().map(|layer| layer.clip).collect())).ok()?,
+				SpecifiedValue::from_typed_value(&self.color).ok()?,
+			]
+		)
+	}
+	
+	/// Only folds longhand properties which all have one value for each layer of `background-image`.
+	fn fold(longhands: &[&SpecifiedValue]) -> Option<Self>
+	{
+		if longhands.len() != Self::LonghandPropertyNames.len()
+		{
+			return None;
+		}
+		
+		let images = Layers::<ImageOrNone>::parse_property_value_from_css(longhands[0].original_css()).ok()?.0;
+		let positions = Layers::<Position>::parse_property_value_from_css(longhands[1].original_css()).ok()?.0;
+		let sizes = Layers::<BackgroundSize>::parse_property_value_from_css(longhands[2].original_css()).ok()?.0;
+		let repeats = Layers::<BackgroundRepeat>::parse_property_value_from_css(longhands[3].original_css()).ok()?.0;
+		let attachments = Layers::<BackgroundAttachment>::parse_property_value_from_css(longhands[4].original_css()).ok()?.0;
+		let origins = Layers::<BackgroundBox>::parse_property_value_from_css(longhands[5].original_css()).ok()?.0;
+		let clips = Layers::<BackgroundBox>::parse_property_value_from_css(longhands[6].original_css()).ok()?.0;
+		let color = Color::parse_property_value_from_css(longhands[7].original_css()).ok()?;
+		
+		let numberOfLayers = images.len();
+		if [positions.len(), sizes.len(), repeats.len(), attachments.len(), origins.len(), clips.len()].iter().any(|length| *length != numberOfLayers)
+		{
+			return None;
+		}
+		
+		let mut layers = Vec::with_capacity(numberOfLayers);
+		let mut iterator = images.into_iter().zip(positions).zip(sizes).zip(repeats).zip(attachments).zip(origins).zip(clips);
+		while let Some((((((((image, position), size), repeat), attachment), origin), clip))) = iterator.next()
+		{
+			layers.push(BackgroundLayer { image, position, size, repeat, attachment, origin, clip });
+		}
+		
+		let background = Self { layers, color };
+		if background.is_var()
+		{
+			None
+		}
+		else
+		{
+			Some(background)
+		}
+	}
+}
+
+impl Background
+{
+	/// The longhand properties set by the `background` shorthand property.
+	pub const LonghandPropertyNames: &'static [&'static str] = &
+	[
+		"background-image",
+		"background-position",
+		"background-size",
+		"background-repeat",
+		"background-attachment",
+		"background-origin",
+		"background-clip",
+		"background-color",
+	];
+	
+	/// Whether any layer uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		self.layers.iter().any(BackgroundLayer::is_var)
+	}
+	
+	/// Calls `rewrite` with every URL in every layer, so that it can be changed in place.
+	#[inline(always)]
+	pub fn rewrite_urls<Rewrite: FnMut(&mut SpecifiedUrl)>(&mut self, rewrite: &mut Rewrite)
+	{
+		for layer in self.layers.iter_mut()
+		{
+			layer.image.rewrite_urls(rewrite);
+		}
+	}
+}
@@ -0,0 +1,246 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// One layer of the `background` shorthand property.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackgroundLayer
+{
+	/// The value of `background-image` for this layer.
+	pub image: ImageOrNone,
+	
+	/// The value of `background-position` for this layer.
+	pub position: Position,
+	
+	/// The value of `background-size` for this layer.
+	pub size: BackgroundSize,
+	
+	/// The value of `background-repeat` for this layer.
+	pub repeat: BackgroundRepeat,
+	
+	/// The value of `background-attachment` for this layer.
+	pub attachment: BackgroundAttachment,
+	
+	/// The value of `background-origin` for this layer.
+	pub origin: BackgroundBox,
+	
+	/// The value of `background-clip` for this layer.
+	pub clip: BackgroundBox,
+}
+
+impl Default for BackgroundLayer
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			image: ImageOrNone::default(),
+			position: Position::default(),
+			size: BackgroundSize::default(),
+			repeat: BackgroundRepeat::default(),
+			attachment: BackgroundAttachment::default(),
+			origin: BackgroundBox::padding_box,
+			clip: BackgroundBox::border_box,
+		}
+	}
+}
+
+impl BackgroundLayer
+{
+	/// Whether the image, position or size uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		self.image.is_var() || self.position.is_var() || self.size.is_var()
+	}
+	
+	/// Writes the values which are not initial values; `color` is only present for the final layer.
+	pub(crate) fn to_css_with_color<W: fmt::Write>(&self, color: Option<&Color>, dest: &mut W) -> fmt::Result
+	{
+		let initial = Self::default();
+		let mut afterFirst = false;
+		
+		if self.image != initial.image
+		{
+			self.image.to_css(dest)?;
+			afterFirst = true;
+		}
+		
+		if self.position != initial.position || self.size != initial.size
+		{
+			if afterFirst
+			{
+				dest.write_char(' ')?;
+			}
+			self.position.to_css(dest)?;
+			if self.size != initial.size
+			{
+				dest.write_char('/')?;
+				self.size.to_css(dest)?;
+			}
+			afterFirst = true;
+		}
+		
+		if self.repeat != initial.repeat
+		{
+			if afterFirst
+			{
+				dest.write_char(' ')?;
+			}
+			self.repeat.to_css(dest)?;
+			afterFirst = true;
+		}
+		
+		if self.attachment != initial.attachment
+		{
+			if afterFirst
+			{
+				dest.write_char(' ')?;
+			}
+			self.attachment.to_css(dest)?;
+			afterFirst = true;
+		}
+		
+		// A single box is used for both `background-origin` and `background-clip`.
+		if self.origin != initial.origin || self.clip != initial.clip
+		{
+			if afterFirst
+			{
+				dest.write_char(' ')?;
+			}
+			self.origin.to_css(dest)?;
+			if self.clip != self.origin
+			{
+				dest.write_char(' ')?;
+				self.clip.to_css(dest)?;
+			}
+			afterFirst = true;
+		}
+		
+		if let Some(color) = color
+		{
+			if *color != Color::transparent()
+			{
+				if afterFirst
+				{
+					dest.write_char(' ')?;
+				}
+				color.to_css(dest)?;
+				afterFirst = true;
+			}
+		}
+		
+		if !afterFirst
+		{
+			dest.write_str("none")?;
+		}
+		
+		Ok(())
+	}
+	
+	/// Parses a layer, in which each value can occur at most once and in any order; a color is only permitted in the final layer, which the caller checks.
+	pub(crate) fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<(Self, Option<Color>), ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut image = None;
+		let mut positionAndSize = None;
+		let mut repeat = None;
+		let mut attachment = None;
+		let mut boxes = Vec::with_capacity(2);
+		let mut color = None;
+		
+		loop
+		{
+			if image.is_none()
+			{
+				if let Ok(value) = input.try(|input| ImageOrNone::parse(context, input))
+				{
+					image = Some(value);
+					continue;
+				}
+			}
+			
+			if positionAndSize.is_none()
+			{
+				if let Ok(position) = input.try(|input| Position::parse(context, input))
+				{
+					let size = if input.try(|input| input.expect_delim('/')).is_ok()
+					{
+						BackgroundSize::parse(context, input)?
+					}
+					else
+					{
+						BackgroundSize::default()
+					};
+					positionAndSize = Some((position, size));
+					continue;
+				}
+			}
+			
+			if repeat.is_none()
+			{
+				if let Ok(value) = input.try(|input| BackgroundRepeat::parse(context, input))
+				{
+					repeat = Some(value);
+					continue;
+				}
+			}
+			
+			if attachment.is_none()
+			{
+				if let Ok(value) = input.try(BackgroundAttachment::parse)
+				{
+					attachment = Some(value);
+					continue;
+				}
+			}
+			
+			if boxes.len() < 2
+			{
+				if let Ok(value) = input.try(BackgroundBox::parse)
+				{
+					boxes.push(value);
+					continue;
+				}
+			}
+			
+			if color.is_none()
+			{
+				if let Ok(value) = input.try(|input| Color::parse(context, input))
+				{
+					color = Some(value);
+					continue;
+				}
+			}
+			
+			break;
+		}
+		
+		if image.is_none() && positionAndSize.is_none() && repeat.is_none() && attachment.is_none() && boxes.is_empty() && color.is_none()
+		{
+			let unexpectedToken = input.next()?.clone();
+			return CustomParseError::unexpectedToken(&unexpectedToken);
+		}
+		
+		let initial = Self::default();
+		let (position, size) = positionAndSize.unwrap_or((initial.position, initial.size));
+		let (origin, clip) = match boxes.len()
+		{
+			0 => (initial.origin, initial.clip),
+			1 => (boxes[0], boxes[0]),
+			_ => (boxes[0], boxes[1]),
+		};
+		
+		let layer = Self
+		{
+			image: image.unwrap_or(initial.image),
+			position,
+			size,
+			repeat: repeat.unwrap_or(initial.repeat),
+			attachment: attachment.unwrap_or(initial.attachment),
+			origin,
+			clip,
+		};
+		Ok((layer, color))
+	}
+}
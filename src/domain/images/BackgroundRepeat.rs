@@ -0,0 +1,62 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// How a background image is repeated horizontally and vertically.
+#[derive(Debug, Default, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct BackgroundRepeat
+{
+	/// How the image is repeated horizontally.
+	pub horizontal: RepeatStyle,
+	
+	/// How the image is repeated vertically.
+	pub vertical: RepeatStyle,
+}
+
+impl ToCss for BackgroundRepeat
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::RepeatStyle::*;
+		
+		match (self.horizontal, self.vertical)
+		{
+			(repeat, no_repeat) => dest.write_str("repeat-x"),
+			
+			(no_repeat, repeat) => dest.write_str("repeat-y"),
+			
+			(horizontal, vertical) => if horizontal == vertical
+			{
+				horizontal.to_css(dest)
+			}
+			else
+			{
+				horizontal.to_css(dest)?;
+				dest.write_char(' ')?;
+				vertical.to_css(dest)
+			},
+		}
+	}
+}
+
+impl BackgroundRepeat
+{
+	pub(crate) fn parse<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::RepeatStyle::*;
+		
+		if input.try(|input| input.expect_ident_matching("repeat-x")).is_ok()
+		{
+			return Ok(Self { horizontal: repeat, vertical: no_repeat });
+		}
+		
+		if input.try(|input| input.expect_ident_matching("repeat-y")).is_ok()
+		{
+			return Ok(Self { horizontal: no_repeat, vertical: repeat });
+		}
+		
+		let horizontal = RepeatStyle::parse(input)?;
+		let vertical = input.try(RepeatStyle::parse).unwrap_or(horizontal);
+		Ok(Self { horizontal, vertical })
+	}
+}
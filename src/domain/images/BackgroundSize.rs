@@ -0,0 +1,84 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The size of a background image.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum BackgroundSize
+{
+	/// Scale the image to be as large as possible while fitting inside the background positioning area.
+	contain,
+	
+	/// Scale the image to be as small as possible while covering the background positioning area.
+	cover,
+	
+	/// A width and a height; a missing height is `auto`.
+	explicit(LengthOrPercentageOrAuto<CssUnsignedNumber>, LengthOrPercentageOrAuto<CssUnsignedNumber>),
+}
+
+/// `auto`.
+impl Default for BackgroundSize
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		BackgroundSize::explicit(LengthOrPercentageOrAuto::auto, LengthOrPercentageOrAuto::auto)
+	}
+}
+
+impl ToCss for BackgroundSize
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::BackgroundSize::*;
+		
+		match *self
+		{
+			contain => dest.write_str("contain"),
+			
+			cover => dest.write_str("cover"),
+			
+			explicit(ref width, LengthOrPercentageOrAuto::auto) => width.to_css(dest),
+			
+			explicit(ref width, ref height) =>
+			{
+				width.to_css(dest)?;
+				dest.write_char(' ')?;
+				height.to_css(dest)
+			}
+		}
+	}
+}
+
+impl BackgroundSize
+{
+	/// Whether the width or height uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		match *self
+		{
+			BackgroundSize::explicit(ref width, ref height) => width.is_var() || height.is_var(),
+			_ => false,
+		}
+	}
+	
+	pub(crate) fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::BackgroundSize::*;
+		
+		if input.try(|input| input.expect_ident_matching("contain")).is_ok()
+		{
+			return Ok(contain);
+		}
+		
+		if input.try(|input| input.expect_ident_matching("cover")).is_ok()
+		{
+			return Ok(cover);
+		}
+		
+		let width = LengthOrPercentageOrAuto::parse(context, input)?;
+		let height = input.try(|input| LengthOrPercentageOrAuto::parse(context, input)).unwrap_or(LengthOrPercentageOrAuto::auto);
+		Ok(explicit(width, height))
+	}
+}
@@ -0,0 +1,39 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A color stop in a gradient; `Position` is a length-percentage for linear and radial gradients and an angle-percentage for conic gradients.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorStop<Position>
+{
+	/// The color.
+	pub color: Color,
+	
+	/// Where the color starts, if specified.
+	pub position: Option<Position>,
+	
+	/// Where the color ends, if specified; there can only be an end if there is a start.
+	pub second_position: Option<Position>,
+}
+
+impl<Position: ToCss> ToCss for ColorStop<Position>
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		self.color.to_css(dest)?;
+		
+		if let Some(ref position) = self.position
+		{
+			dest.write_char(' ')?;
+			position.to_css(dest)?;
+			
+			if let Some(ref secondPosition) = self.second_position
+			{
+				dest.write_char(' ')?;
+				secondPosition.to_css(dest)?;
+			}
+		}
+		
+		Ok(())
+	}
+}
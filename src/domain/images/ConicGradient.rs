@@ -0,0 +1,119 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A `conic-gradient()` or `repeating-conic-gradient()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConicGradient
+{
+	/// Whether this is a `repeating-conic-gradient()`.
+	pub repeating: bool,
+	
+	/// The angle the gradient starts from, with `0deg` pointing up and positive angles turning clockwise.
+	pub from: CalculablePropertyValue<AngleUnit<CssSignedNumber>>,
+	
+	/// The center of the gradient.
+	pub position: Position,
+	
+	/// The color stops and transition hints, positioned by angles or percentages of a full turn; there are always at least two color stops.
+	pub items: Vec<GradientItem<CalculablePropertyValue<AngleUnit<CssSignedNumber>>>>,
+}
+
+impl ToCss for ConicGradient
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.repeating
+		{
+			dest.write_str("repeating-")?;
+		}
+		dest.write_str("conic-gradient(")?;
+		
+		let mut afterFirst = false;
+		
+		if self.from != CalculablePropertyValue::default()
+		{
+			dest.write_str("from ")?;
+			self.from.to_css(dest)?;
+			afterFirst = true;
+		}
+		
+		if self.position != Position::center()
+		{
+			if afterFirst
+			{
+				dest.write_char(' ')?;
+			}
+			dest.write_str("at ")?;
+			self.position.to_css(dest)?;
+			afterFirst = true;
+		}
+		
+		if afterFirst
+		{
+			dest.write_char(',')?;
+		}
+		
+		GradientItem::write_list(&self.items, dest)?;
+		
+		dest.write_char(')')
+	}
+}
+
+impl ConicGradient
+{
+	/// Whether any angle or position uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		self.from.is_var() || self.position.is_var() || self.items.iter().any(|item| item.is_var(CalculablePropertyValue::is_var))
+	}
+	
+	/// Parses the arguments of the function.
+	pub(crate) fn parse_arguments<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>, repeating: bool) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let from = if input.try(|input| input.expect_ident_matching("from")).is_ok()
+		{
+			Some(AngleUnit::parse_one_outside_calc_function(context, input)?)
+		}
+		else
+		{
+			None
+		};
+		
+		let position = if input.try(|input| input.expect_ident_matching("at")).is_ok()
+		{
+			Some(Position::parse(context, input)?)
+		}
+		else
+		{
+			None
+		};
+		
+		if from.is_some() || position.is_some()
+		{
+			input.expect_comma()?;
+		}
+		
+		Ok
+		(
+			Self
+			{
+				repeating,
+				from: from.unwrap_or_default(),
+				position: position.unwrap_or_else(Position::center),
+				items: GradientItem::parse_list(context, input, Self::parse_angle_or_percentage)?,
+			}
+		)
+	}
+	
+	fn parse_angle_or_percentage<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<CalculablePropertyValue<AngleUnit<CssSignedNumber>>, ParseError<'i, CustomParseError<'i>>>
+	{
+		if let Ok(unit_value) = input.try(|input| input.expect_percentage())
+		{
+			return PercentageUnit::parse_percentage(unit_value).map(CalculablePropertyValue::Percentage);
+		}
+		
+		AngleUnit::parse_one_outside_calc_function(context, input)
+	}
+}
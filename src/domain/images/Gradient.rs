@@ -0,0 +1,91 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A gradient image: https://drafts.csswg.org/css-images-4/#gradients
+#[derive(Debug, Clone, PartialEq)]
+pub enum Gradient
+{
+	/// `linear-gradient()` or `repeating-linear-gradient()`.
+	Linear(LinearGradient),
+	
+	/// `radial-gradient()` or `repeating-radial-gradient()`.
+	Radial(RadialGradient),
+	
+	/// `conic-gradient()` or `repeating-conic-gradient()`.
+	Conic(ConicGradient),
+}
+
+impl ToCss for Gradient
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::Gradient::*;
+		
+		match *self
+		{
+			Linear(ref linearGradient) => linearGradient.to_css(dest),
+			
+			Radial(ref radialGradient) => radialGradient.to_css(dest),
+			
+			Conic(ref conicGradient) => conicGradient.to_css(dest),
+		}
+	}
+}
+
+impl Gradient
+{
+	/// Whether this is a repeating gradient.
+	#[inline(always)]
+	pub fn is_repeating(&self) -> bool
+	{
+		use self::Gradient::*;
+		
+		match *self
+		{
+			Linear(ref linearGradient) => linearGradient.repeating,
+			Radial(ref radialGradient) => radialGradient.repeating,
+			Conic(ref conicGradient) => conicGradient.repeating,
+		}
+	}
+	
+	/// Whether any angle, length, percentage or position uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		use self::Gradient::*;
+		
+		match *self
+		{
+			Linear(ref linearGradient) => linearGradient.is_var(),
+			Radial(ref radialGradient) => radialGradient.is_var(),
+			Conic(ref conicGradient) => conicGradient.is_var(),
+		}
+	}
+	
+	/// Parses the arguments of the gradient function `name`; returns `None` if `name` is not a gradient function.
+	pub(crate) fn parse_arguments<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>, name: &str) -> Option<Result<Self, ParseError<'i, CustomParseError<'i>>>>
+	{
+		use self::Gradient::*;
+		
+		let result = match_ignore_ascii_case!
+		{
+			name,
+			
+			"linear-gradient" => LinearGradient::parse_arguments(context, input, false).map(Linear),
+			
+			"repeating-linear-gradient" => LinearGradient::parse_arguments(context, input, true).map(Linear),
+			
+			"radial-gradient" => RadialGradient::parse_arguments(context, input, false).map(Radial),
+			
+			"repeating-radial-gradient" => RadialGradient::parse_arguments(context, input, true).map(Radial),
+			
+			"conic-gradient" => ConicGradient::parse_arguments(context, input, false).map(Conic),
+			
+			"repeating-conic-gradient" => ConicGradient::parse_arguments(context, input, true).map(Conic),
+			
+			_ => return None,
+		};
+		Some(result)
+	}
+}
@@ -0,0 +1,123 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// An item in the list of color stops of a gradient.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GradientItem<Position>
+{
+	/// A color stop.
+	ColorStop(ColorStop<Position>),
+	
+	/// A color interpolation hint, between two color stops.
+	TransitionHint(Position),
+}
+
+impl<Position: ToCss> ToCss for GradientItem<Position>
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::GradientItem::*;
+		
+		match *self
+		{
+			ColorStop(ref colorStop) => colorStop.to_css(dest),
+			
+			TransitionHint(ref position) => position.to_css(dest),
+		}
+	}
+}
+
+impl<Position> GradientItem<Position>
+{
+	/// The color, unless this is a transition hint.
+	#[inline(always)]
+	pub fn color(&self) -> Option<&Color>
+	{
+		match *self
+		{
+			GradientItem::ColorStop(ref colorStop) => Some(&colorStop.color),
+			GradientItem::TransitionHint(_) => None,
+		}
+	}
+	
+	/// Whether the position of this item uses a `var()`; `isVar` checks one position.
+	#[inline(always)]
+	pub(crate) fn is_var<IsVar: Fn(&Position) -> bool>(&self, isVar: IsVar) -> bool
+	{
+		match *self
+		{
+			GradientItem::ColorStop(ref colorStop) => colorStop.position.as_ref().map_or(false, &isVar) || colorStop.second_position.as_ref().map_or(false, &isVar),
+			GradientItem::TransitionHint(ref position) => isVar(position),
+		}
+	}
+	
+	/// Parses the comma-separated list of color stops and transition hints that ends a gradient function, using `parsePosition` for each position.
+	pub(crate) fn parse_list<'i, 't, ParsePosition: for<'tt> Fn(&ParserContext, &mut Parser<'i, 'tt>) -> Result<Position, ParseError<'i, CustomParseError<'i>>>>(context: &ParserContext, input: &mut Parser<'i, 't>, parsePosition: ParsePosition) -> Result<Vec<Self>, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::GradientItem::*;
+		
+		let items = input.parse_comma_separated(|input|
+		{
+			if let Ok(position) = input.try(|input| parsePosition(context, input))
+			{
+				return Ok(TransitionHint(position));
+			}
+			
+			let color = Color::parse(context, input)?;
+			let position = input.try(|input| parsePosition(context, input)).ok();
+			let second_position = if position.is_some()
+			{
+				input.try(|input| parsePosition(context, input)).ok()
+			}
+			else
+			{
+				None
+			};
+			
+			Ok(ColorStop(self::ColorStop { color, position, second_position }))
+		})?;
+		
+		if items.iter().filter(|item| item.color().is_some()).count() < 2
+		{
+			return Err(ParseError::Custom(CustomParseError::GradientMustHaveAtLeastTwoColorStops));
+		}
+		
+		let mut previousWasTransitionHint = true;
+		for item in items.iter()
+		{
+			let isTransitionHint = item.color().is_none();
+			if isTransitionHint && previousWasTransitionHint
+			{
+				return Err(ParseError::Custom(CustomParseError::GradientTransitionHintMustBeBetweenTwoColorStops));
+			}
+			previousWasTransitionHint = isTransitionHint;
+		}
+		if previousWasTransitionHint
+		{
+			return Err(ParseError::Custom(CustomParseError::GradientTransitionHintMustBeBetweenTwoColorStops));
+		}
+		
+		Ok(items)
+	}
+	
+	/// Writes the comma-separated list of color stops and transition hints.
+	#[inline(always)]
+	pub(crate) fn write_list<W: fmt::Write>(items: &[Self], dest: &mut W) -> fmt::Result where Position: ToCss
+	{
+		let mut afterFirst = false;
+		for item in items.iter()
+		{
+			if afterFirst
+			{
+				dest.write_char(',')?;
+			}
+			else
+			{
+				afterFirst = true;
+			}
+			item.to_css(dest)?;
+		}
+		Ok(())
+	}
+}
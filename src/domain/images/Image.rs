@@ -0,0 +1,115 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// An `<image>`: https://drafts.csswg.org/css-images-4/#image-values
+#[derive(Debug, Clone, PartialEq)]
+pub enum Image
+{
+	/// `url()`.
+	Url(SpecifiedUrl),
+	
+	/// A gradient function.
+	Gradient(Gradient),
+	
+	/// `image-set()`.
+	ImageSet(ImageSet),
+}
+
+impl ToCss for Image
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::Image::*;
+		
+		match *self
+		{
+			Url(ref url) => url.to_css(dest),
+			
+			Gradient(ref gradient) => gradient.to_css(dest),
+			
+			ImageSet(ref imageSet) => imageSet.to_css(dest),
+		}
+	}
+}
+
+impl Image
+{
+	/// Whether a gradient in this image uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		use self::Image::*;
+		
+		match *self
+		{
+			Url(_) => false,
+			Gradient(ref gradient) => gradient.is_var(),
+			ImageSet(ref imageSet) => imageSet.candidates.iter().any(|candidate| candidate.image.is_var()),
+		}
+	}
+	
+	/// Calls `rewrite` with every URL in this image, including those of all the candidates of an `image-set()`, so that it can be changed in place.
+	pub fn rewrite_urls<Rewrite: FnMut(&mut SpecifiedUrl)>(&mut self, rewrite: &mut Rewrite)
+	{
+		use self::Image::*;
+		
+		match *self
+		{
+			Url(ref mut url) => rewrite(url),
+			Gradient(_) => (),
+			ImageSet(ref mut imageSet) => for candidate in imageSet.candidates.iter_mut()
+			{
+				candidate.image.rewrite_urls(rewrite);
+			},
+		}
+	}
+	
+	pub(crate) fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_allowing_image_set(context, input, true)
+	}
+	
+	/// Parses an image which is not an `image-set()`, such as a candidate of an `image-set()`.
+	#[inline(always)]
+	pub(crate) fn parse_not_image_set<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_allowing_image_set(context, input, false)
+	}
+	
+	fn parse_allowing_image_set<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>, allowImageSet: bool) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::Image::*;
+		
+		if let Ok(url) = input.try(|input| SpecifiedUrl::parse(context, input))
+		{
+			return Ok(Url(url));
+		}
+		
+		let name = input.expect_function()?.clone();
+		input.parse_nested_block(|input|
+		{
+			if let Some(gradient) = self::Gradient::parse_arguments(context, input, &name)
+			{
+				return gradient.map(Gradient);
+			}
+			
+			let isImageSet = name.eq_ignore_ascii_case("image-set") || name.eq_ignore_ascii_case("-webkit-image-set");
+			if isImageSet && !allowImageSet
+			{
+				return Err(ParseError::Custom(CustomParseError::ImageSetCanNotBeNestedInImageSet));
+			}
+			
+			match_ignore_ascii_case!
+			{
+				&*name,
+				
+				"image-set" => self::ImageSet::parse_arguments(context, input, None).map(ImageSet),
+				
+				"-webkit-image-set" => self::ImageSet::parse_arguments(context, input, Some(VendorPrefix::webkit)).map(ImageSet),
+				
+				_ => Err(ParseError::Custom(CustomParseError::UnknownImageFunction(name.clone()))),
+			}
+		})
+	}
+}
@@ -0,0 +1,87 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// `none` or an `<image>`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImageOrNone
+{
+	/// No image.
+	none,
+	
+	/// An image.
+	image(Image),
+}
+
+impl Default for ImageOrNone
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		ImageOrNone::none
+	}
+}
+
+impl ToCss for ImageOrNone
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::ImageOrNone::*;
+		
+		match *self
+		{
+			none => dest.write_str("none"),
+			
+			image(ref value) => value.to_css(dest),
+		}
+	}
+}
+
+impl PropertyValue for ImageOrNone
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"border-image-source",
+		"list-style-image",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse(context, input)
+	}
+}
+
+impl ImageOrNone
+{
+	/// Whether a gradient in the image uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		match *self
+		{
+			ImageOrNone::none => false,
+			ImageOrNone::image(ref image) => image.is_var(),
+		}
+	}
+	
+	/// Calls `rewrite` with every URL in the image, so that it can be changed in place.
+	#[inline(always)]
+	pub fn rewrite_urls<Rewrite: FnMut(&mut SpecifiedUrl)>(&mut self, rewrite: &mut Rewrite)
+	{
+		if let ImageOrNone::image(ref mut image) = *self
+		{
+			image.rewrite_urls(rewrite);
+		}
+	}
+	
+	pub(crate) fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			return Ok(ImageOrNone::none);
+		}
+		
+		Image::parse(context, input).map(ImageOrNone::image)
+	}
+}
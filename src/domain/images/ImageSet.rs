@@ -0,0 +1,107 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// An `image-set()`, from which the user agent picks the most appropriate image for the device's resolution: https://drafts.csswg.org/css-images-4/#image-set-notation
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageSet
+{
+	/// `Some(VendorPrefix::webkit)` for `-webkit-image-set()`.
+	pub vendor_prefix: Option<VendorPrefix>,
+	
+	/// The candidate images, in the order specified.
+	pub candidates: Vec<ImageSetCandidate>,
+}
+
+impl ToCss for ImageSet
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if let Some(ref vendorPrefix) = self.vendor_prefix
+		{
+			vendorPrefix.to_css(dest)?;
+		}
+		dest.write_str("image-set(")?;
+		
+		let mut afterFirst = false;
+		for candidate in self.candidates.iter()
+		{
+			if afterFirst
+			{
+				dest.write_char(',')?;
+			}
+			else
+			{
+				afterFirst = true;
+			}
+			candidate.to_css(dest)?;
+		}
+		
+		dest.write_char(')')
+	}
+}
+
+impl HasVendorPrefix for ImageSet
+{
+	#[inline(always)]
+	fn isNotVendorPrefixed(&self) -> bool
+	{
+		self.vendor_prefix.is_none()
+	}
+}
+
+impl ImageSet
+{
+	/// Picks the candidate a user agent would for a device pixel ratio (`1.0` is `1x` or `96dpi`), ignoring candidates with a `type()` for which `is_supported_type` returns false.
+	///
+	/// This is the candidate with the lowest resolution that is at least `device_pixel_ratio` or, if there is none, the one with the highest resolution; the first specified wins a tie.
+	pub fn select_candidate<IsSupportedType: Fn(&str) -> bool>(&self, device_pixel_ratio: f32, is_supported_type: IsSupportedType) -> Option<&ImageSetCandidate>
+	{
+		let mut selected: Option<(&ImageSetCandidate, f32)> = None;
+		
+		for candidate in self.candidates.iter()
+		{
+			if let Some(ref mime_type) = candidate.mime_type
+			{
+				if !is_supported_type(mime_type)
+				{
+					continue;
+				}
+			}
+			
+			let dppx = candidate.resolution.to_dppx().as_f32();
+			let isBetter = match selected
+			{
+				None => true,
+				Some((_, selectedDppx)) => if selectedDppx >= device_pixel_ratio
+				{
+					dppx >= device_pixel_ratio && dppx < selectedDppx
+				}
+				else
+				{
+					dppx > selectedDppx
+				},
+			};
+			
+			if isBetter
+			{
+				selected = Some((candidate, dppx));
+			}
+		}
+		
+		selected.map(|(candidate, _)| candidate)
+	}
+	
+	/// Parses the arguments of the function.
+	pub(crate) fn parse_arguments<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>, vendor_prefix: Option<VendorPrefix>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Ok
+		(
+			Self
+			{
+				vendor_prefix,
+				candidates: input.parse_comma_separated(|input| ImageSetCandidate::parse(context, input))?,
+			}
+		)
+	}
+}
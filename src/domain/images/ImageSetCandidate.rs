@@ -0,0 +1,91 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A candidate image in an `image-set()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageSetCandidate
+{
+	/// The image; a candidate specified as a string is a `Image::Url`.
+	pub image: Image,
+	
+	/// The resolution of the image; `1x` if not specified.
+	pub resolution: ResolutionUnit<CssUnsignedNumber>,
+	
+	/// The MIME type of the image from `type()`, if specified.
+	pub mime_type: Option<String>,
+}
+
+impl ToCss for ImageSetCandidate
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		self.image.to_css(dest)?;
+		
+		if self.resolution != ResolutionUnit::default()
+		{
+			dest.write_char(' ')?;
+			self.resolution.to_css(dest)?;
+		}
+		
+		if let Some(ref mime_type) = self.mime_type
+		{
+			dest.write_str(" type(")?;
+			serialize_string(mime_type, dest)?;
+			dest.write_char(')')?;
+		}
+		
+		Ok(())
+	}
+}
+
+impl ImageSetCandidate
+{
+	pub(crate) fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let image = match input.try(|input| input.expect_string_cloned())
+		{
+			Ok(url) => Image::Url(SpecifiedUrl(url.as_ref().to_owned())),
+			Err(_) => Image::parse_not_image_set(context, input)?,
+		};
+		
+		let mut resolution = None;
+		let mut mime_type = None;
+		loop
+		{
+			if resolution.is_none()
+			{
+				if let Ok(value) = input.try(|input| ResolutionUnit::parse_one_outside_calc_function(context, input))
+				{
+					match value
+					{
+						CalculablePropertyValue::Constant(value) => resolution = Some(value),
+						_ => return Err(ParseError::Custom(CustomParseError::ImageSetResolutionMustBeAConstant)),
+					}
+					continue;
+				}
+			}
+			
+			if mime_type.is_none()
+			{
+				if input.try(|input| input.expect_function_matching("type")).is_ok()
+				{
+					mime_type = Some(input.parse_nested_block(|input| Ok(input.expect_string()?.as_ref().to_owned()))?);
+					continue;
+				}
+			}
+			
+			break;
+		}
+		
+		Ok
+		(
+			Self
+			{
+				image,
+				resolution: resolution.unwrap_or_default(),
+				mime_type,
+			}
+		)
+	}
+}
@@ -0,0 +1,139 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A comma-separated list of values, one for each background (or mask) layer.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Layers<T>(pub Vec<T>);
+
+impl<T: ToCss> ToCss for Layers<T>
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		let mut afterFirst = false;
+		for layer in self.0.iter()
+		{
+			if afterFirst
+			{
+				dest.write_char(',')?;
+			}
+			else
+			{
+				afterFirst = true;
+			}
+			layer.to_css(dest)?;
+		}
+		Ok(())
+	}
+}
+
+impl PropertyValue for Layers<ImageOrNone>
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"background-image",
+		"mask-image",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_with(context, input, ImageOrNone::parse)
+	}
+}
+
+impl PropertyValue for Layers<Position>
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"background-position",
+		"mask-position",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_with(context, input, Position::parse)
+	}
+}
+
+impl PropertyValue for Layers<BackgroundSize>
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"background-size",
+		"mask-size",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_with(context, input, BackgroundSize::parse)
+	}
+}
+
+impl PropertyValue for Layers<BackgroundRepeat>
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"background-repeat",
+		"mask-repeat",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_with(context, input, BackgroundRepeat::parse)
+	}
+}
+
+impl PropertyValue for Layers<BackgroundAttachment>
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"background-attachment",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_with(context, input, |_context, input| BackgroundAttachment::parse(input))
+	}
+}
+
+impl PropertyValue for Layers<BackgroundBox>
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"background-clip",
+		"background-origin",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_with(context, input, |_context, input| BackgroundBox::parse(input))
+	}
+}
+
+impl Layers<ImageOrNone>
+{
+	/// Calls `rewrite` with every URL in every layer, so that it can be changed in place.
+	#[inline(always)]
+	pub fn rewrite_urls<Rewrite: FnMut(&mut SpecifiedUrl)>(&mut self, rewrite: &mut Rewrite)
+	{
+		for layer in self.0.iter_mut()
+		{
+			layer.rewrite_urls(rewrite);
+		}
+	}
+}
+
+impl<T> Layers<T>
+{
+	#[inline(always)]
+	fn parse_with<'i, 't, Parse: for<'tt> Fn(&ParserContext, &mut Parser<'i, 'tt>) -> Result<T, ParseError<'i, CustomParseError<'i>>>>(context: &ParserContext, input: &mut Parser<'i, 't>, parse: Parse) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		input.parse_comma_separated(|input| parse(context, input)).map(Layers)
+	}
+}
@@ -0,0 +1,79 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A `linear-gradient()` or `repeating-linear-gradient()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearGradient
+{
+	/// Whether this is a `repeating-linear-gradient()`.
+	pub repeating: bool,
+	
+	/// The direction of the gradient line.
+	pub direction: LinearGradientDirection,
+	
+	/// The color stops and transition hints; there are always at least two color stops.
+	pub items: Vec<GradientItem<CalculablePropertyValue<LengthOrPercentageUnit<CssSignedNumber>>>>,
+}
+
+impl ToCss for LinearGradient
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.repeating
+		{
+			dest.write_str("repeating-")?;
+		}
+		dest.write_str("linear-gradient(")?;
+		
+		if self.direction != LinearGradientDirection::default()
+		{
+			self.direction.to_css(dest)?;
+			dest.write_char(',')?;
+		}
+		
+		GradientItem::write_list(&self.items, dest)?;
+		
+		dest.write_char(')')
+	}
+}
+
+impl LinearGradient
+{
+	/// Whether any angle or position uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		let directionIsVar = match self.direction
+		{
+			LinearGradientDirection::Angle(ref angle) => angle.is_var(),
+			LinearGradientDirection::To(..) => false,
+		};
+		
+		directionIsVar || self.items.iter().any(|item| item.is_var(CalculablePropertyValue::is_var))
+	}
+	
+	/// Parses the arguments of the function.
+	pub(crate) fn parse_arguments<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>, repeating: bool) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let direction = match input.try(|input| LinearGradientDirection::parse(context, input))
+		{
+			Ok(direction) =>
+			{
+				input.expect_comma()?;
+				direction
+			}
+			Err(_) => LinearGradientDirection::default(),
+		};
+		
+		Ok
+		(
+			Self
+			{
+				repeating,
+				direction,
+				items: GradientItem::parse_list(context, input, LengthOrPercentageUnit::parse_one_outside_calc_function)?,
+			}
+		)
+	}
+}
@@ -0,0 +1,74 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The direction of a linear gradient.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum LinearGradientDirection
+{
+	/// An angle, with `0deg` pointing up and positive angles turning clockwise.
+	Angle(CalculablePropertyValue<AngleUnit<CssSignedNumber>>),
+	
+	/// `to` a side or a corner; at least one of the horizontal and vertical sides is present.
+	To(Option<HorizontalPositionKeyword>, Option<VerticalPositionKeyword>),
+}
+
+/// `to bottom`.
+impl Default for LinearGradientDirection
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		LinearGradientDirection::To(None, Some(VerticalPositionKeyword::bottom))
+	}
+}
+
+impl ToCss for LinearGradientDirection
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::LinearGradientDirection::*;
+		
+		match *self
+		{
+			Angle(ref angle) => angle.to_css(dest),
+			
+			To(horizontal, vertical) =>
+			{
+				dest.write_str("to")?;
+				if let Some(horizontal) = horizontal
+				{
+					dest.write_char(' ')?;
+					horizontal.to_css(dest)?;
+				}
+				if let Some(vertical) = vertical
+				{
+					dest.write_char(' ')?;
+					vertical.to_css(dest)?;
+				}
+				Ok(())
+			}
+		}
+	}
+}
+
+impl LinearGradientDirection
+{
+	pub(crate) fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::LinearGradientDirection::*;
+		
+		if input.try(|input| input.expect_ident_matching("to")).is_err()
+		{
+			return AngleUnit::parse_one_outside_calc_function(context, input).map(Angle);
+		}
+		
+		if let Ok(horizontal) = input.try(HorizontalPositionKeyword::parse)
+		{
+			return Ok(To(Some(horizontal), input.try(VerticalPositionKeyword::parse).ok()));
+		}
+		
+		let vertical = VerticalPositionKeyword::parse(input)?;
+		Ok(To(input.try(HorizontalPositionKeyword::parse).ok(), Some(vertical)))
+	}
+}
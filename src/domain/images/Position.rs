@@ -0,0 +1,253 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A `<position>`, as used by `background-position` and by gradients: https://drafts.csswg.org/css-values-4/#position
+///
+/// All of the one, two, three and four value syntaxes are parsed; the shortest of the one, two and four value syntaxes is serialized.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct Position
+{
+	/// The horizontal component.
+	pub horizontal: PositionComponent<HorizontalPositionKeyword>,
+	
+	/// The vertical component.
+	pub vertical: PositionComponent<VerticalPositionKeyword>,
+}
+
+/// `0% 0%`, the initial value of `background-position`.
+impl Default for Position
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		let zeroPercent = CalculablePropertyValue::Constant(LengthOrPercentageUnit::IsPercentage(PercentageUnit(CssSignedNumber::Zero)));
+		
+		Self
+		{
+			horizontal: PositionComponent::value(zeroPercent.clone()),
+			vertical: PositionComponent::value(zeroPercent),
+		}
+	}
+}
+
+impl ToCss for Position
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::PositionComponent::*;
+		
+		if self.horizontal.has_offset_from_side() || self.vertical.has_offset_from_side()
+		{
+			self.horizontal.to_css_with_offset_from_side("left", dest)?;
+			dest.write_char(' ')?;
+			return self.vertical.to_css_with_offset_from_side("top", dest);
+		}
+		
+		match (&self.horizontal, &self.vertical)
+		{
+			(horizontal, &center) => horizontal.to_css(dest),
+			
+			(&center, vertical @ &side(_, None)) => vertical.to_css(dest),
+			
+			(horizontal, vertical) =>
+			{
+				horizontal.to_css(dest)?;
+				dest.write_char(' ')?;
+				vertical.to_css(dest)
+			}
+		}
+	}
+}
+
+impl PropertyValue for Position
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"object-position",
+		"perspective-origin",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse(context, input)
+	}
+}
+
+impl Position
+{
+	/// `center`, the position used by gradients when none is specified.
+	#[inline(always)]
+	pub fn center() -> Self
+	{
+		Self
+		{
+			horizontal: PositionComponent::center,
+			vertical: PositionComponent::center,
+		}
+	}
+	
+	/// Whether either component uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		self.horizontal.is_var() || self.vertical.is_var()
+	}
+	
+	pub(crate) fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_at_most(context, input, 4)
+	}
+	
+	/// Parses a position of at most `maximumNumberOfValues` values; `transform-origin`, for instance, only permits the one and two value syntaxes.
+	pub(crate) fn parse_at_most<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>, maximumNumberOfValues: usize) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		enum Part
+		{
+			Horizontal(HorizontalPositionKeyword),
+			Vertical(VerticalPositionKeyword),
+			Center,
+			Offset(CalculablePropertyValue<LengthOrPercentageUnit<CssSignedNumber>>),
+		}
+		
+		fn parse_part<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Part, ParseError<'i, CustomParseError<'i>>>
+		{
+			if let Ok(ident) = input.try(|input| input.expect_ident_cloned())
+			{
+				if ident.eq_ignore_ascii_case("center")
+				{
+					return Ok(Part::Center);
+				}
+				if let Ok(horizontal) = HorizontalPositionKeyword::from_ident(&ident)
+				{
+					return Ok(Part::Horizontal(horizontal));
+				}
+				if let Ok(vertical) = VerticalPositionKeyword::from_ident(&ident)
+				{
+					return Ok(Part::Vertical(vertical));
+				}
+				return Err(ParseError::Basic(BasicParseError::UnexpectedToken(Token::Ident(ident))));
+			}
+			
+			LengthOrPercentageUnit::parse_one_outside_calc_function(context, input).map(Part::Offset)
+		}
+		
+		let mut parts = Vec::with_capacity(4);
+		while parts.len() < maximumNumberOfValues
+		{
+			match input.try(|input| parse_part(context, input))
+			{
+				Ok(part) => parts.push(part),
+				Err(error) => if parts.is_empty()
+				{
+					return Err(error);
+				}
+				else
+				{
+					break
+				},
+			}
+		}
+		
+		let invalid = || Err(ParseError::Custom(CustomParseError::InvalidPosition));
+		
+		if parts.len() <= 2
+		{
+			let second = if parts.len() == 2
+			{
+				parts.pop()
+			}
+			else
+			{
+				None
+			};
+			let first = parts.pop().unwrap();
+			
+			let (horizontal, vertical) = match (first, second)
+			{
+				(Part::Horizontal(horizontal), None) => (PositionComponent::side(horizontal, None), PositionComponent::center),
+				(Part::Vertical(vertical), None) => (PositionComponent::center, PositionComponent::side(vertical, None)),
+				(Part::Center, None) => (PositionComponent::center, PositionComponent::center),
+				(Part::Offset(offset), None) => (PositionComponent::value(offset), PositionComponent::center),
+				
+				(Part::Vertical(vertical), Some(Part::Horizontal(horizontal))) => (PositionComponent::side(horizontal, None), PositionComponent::side(vertical, None)),
+				(Part::Vertical(vertical), Some(Part::Center)) => (PositionComponent::center, PositionComponent::side(vertical, None)),
+				(Part::Center, Some(Part::Horizontal(horizontal))) => (PositionComponent::side(horizontal, None), PositionComponent::center),
+				
+				(Part::Vertical(_), Some(_)) | (_, Some(Part::Horizontal(_))) => return invalid(),
+				
+				(first, Some(second)) =>
+				{
+					let horizontal = match first
+					{
+						Part::Horizontal(horizontal) => PositionComponent::side(horizontal, None),
+						Part::Center => PositionComponent::center,
+						Part::Offset(offset) => PositionComponent::value(offset),
+						Part::Vertical(_) => unreachable!(),
+					};
+					let vertical = match second
+					{
+						Part::Vertical(vertical) => PositionComponent::side(vertical, None),
+						Part::Center => PositionComponent::center,
+						Part::Offset(offset) => PositionComponent::value(offset),
+						Part::Horizontal(_) => unreachable!(),
+					};
+					(horizontal, vertical)
+				}
+			};
+			return Ok(Self { horizontal, vertical });
+		}
+		
+		// The three and four value syntaxes are two keywords, each optionally followed by an offset (but not after `center`).
+		let mut horizontal = None;
+		let mut vertical = None;
+		let mut centers = 0;
+		let mut parts = parts.into_iter().peekable();
+		while let Some(part) = parts.next()
+		{
+			let offset = match parts.peek()
+			{
+				Some(&Part::Offset(_)) => match parts.next()
+				{
+					Some(Part::Offset(offset)) => Some(offset),
+					_ => unreachable!(),
+				},
+				_ => None,
+			};
+			
+			match (part, offset)
+			{
+				(Part::Horizontal(keyword), offset) => if horizontal.is_none()
+				{
+					horizontal = Some(PositionComponent::side(keyword, offset));
+				}
+				else
+				{
+					return invalid();
+				},
+				
+				(Part::Vertical(keyword), offset) => if vertical.is_none()
+				{
+					vertical = Some(PositionComponent::side(keyword, offset));
+				}
+				else
+				{
+					return invalid();
+				},
+				
+				(Part::Center, None) => centers += 1,
+				
+				_ => return invalid(),
+			}
+		}
+		
+		match (horizontal, vertical, centers)
+		{
+			(Some(horizontal), Some(vertical), 0) => Ok(Self { horizontal, vertical }),
+			(Some(horizontal), None, 1) => Ok(Self { horizontal, vertical: PositionComponent::center }),
+			(None, Some(vertical), 1) => Ok(Self { horizontal: PositionComponent::center, vertical }),
+			_ => invalid(),
+		}
+	}
+}
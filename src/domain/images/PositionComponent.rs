@@ -0,0 +1,98 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// One axis of a `<position>`.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum PositionComponent<Side>
+{
+	/// `center`; the same as an offset of `50%`.
+	center,
+	
+	/// An offset from the left or top edge.
+	value(CalculablePropertyValue<LengthOrPercentageUnit<CssSignedNumber>>),
+	
+	/// An offset from a side; no offset is the same as an offset of `0%`.
+	side(Side, Option<CalculablePropertyValue<LengthOrPercentageUnit<CssSignedNumber>>>),
+}
+
+impl<Side: ToCss> ToCss for PositionComponent<Side>
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::PositionComponent::*;
+		
+		match *self
+		{
+			center => dest.write_str("center"),
+			
+			value(ref offset) => offset.to_css(dest),
+			
+			side(ref keyword, None) => keyword.to_css(dest),
+			
+			side(ref keyword, Some(ref offset)) =>
+			{
+				keyword.to_css(dest)?;
+				dest.write_char(' ')?;
+				offset.to_css(dest)
+			}
+		}
+	}
+}
+
+impl<Side> PositionComponent<Side>
+{
+	/// Whether this component uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		use self::PositionComponent::*;
+		
+		match *self
+		{
+			center => false,
+			value(ref offset) => offset.is_var(),
+			side(_, Some(ref offset)) => offset.is_var(),
+			side(_, None) => false,
+		}
+	}
+	
+	#[inline(always)]
+	fn has_offset_from_side(&self) -> bool
+	{
+		match *self
+		{
+			PositionComponent::side(_, Some(_)) => true,
+			_ => false,
+		}
+	}
+}
+
+impl<Side: ToCss> PositionComponent<Side>
+{
+	/// Writes this component as a side and an offset, as the four value syntax requires; `startSide` is `left` or `top`.
+	fn to_css_with_offset_from_side<W: fmt::Write>(&self, startSide: &str, dest: &mut W) -> fmt::Result
+	{
+		use self::PositionComponent::*;
+		
+		match *self
+		{
+			center => write!(dest, "{} 50%", startSide),
+			
+			value(ref offset) =>
+			{
+				dest.write_str(startSide)?;
+				dest.write_char(' ')?;
+				offset.to_css(dest)
+			}
+			
+			side(ref keyword, None) =>
+			{
+				keyword.to_css(dest)?;
+				dest.write_str(" 0%")
+			}
+			
+			side(_, Some(_)) => self.to_css(dest),
+		}
+	}
+}
@@ -0,0 +1,136 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A `radial-gradient()` or `repeating-radial-gradient()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RadialGradient
+{
+	/// Whether this is a `repeating-radial-gradient()`.
+	pub repeating: bool,
+	
+	/// The ending shape; a `circle` always has a `radius` or an `extent` size and an `ellipse` always has `radii` or an `extent` size.
+	pub shape: RadialGradientShape,
+	
+	/// The size of the ending shape.
+	pub size: RadialGradientSize,
+	
+	/// The center of the ending shape.
+	pub position: Position,
+	
+	/// The color stops and transition hints; there are always at least two color stops.
+	pub items: Vec<GradientItem<CalculablePropertyValue<LengthOrPercentageUnit<CssSignedNumber>>>>,
+}
+
+impl ToCss for RadialGradient
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.repeating
+		{
+			dest.write_str("repeating-")?;
+		}
+		dest.write_str("radial-gradient(")?;
+		
+		let isExtent = match self.size
+		{
+			RadialGradientSize::extent(_) => true,
+			_ => false,
+		};
+		
+		let mut afterFirst = false;
+		
+		// An ellipse is the default shape and a circle is implied by a single radius.
+		if self.shape == RadialGradientShape::circle && isExtent
+		{
+			self.shape.to_css(dest)?;
+			afterFirst = true;
+		}
+		
+		if self.size != RadialGradientSize::default()
+		{
+			if afterFirst
+			{
+				dest.write_char(' ')?;
+			}
+			self.size.to_css(dest)?;
+			afterFirst = true;
+		}
+		
+		if self.position != Position::center()
+		{
+			if afterFirst
+			{
+				dest.write_char(' ')?;
+			}
+			dest.write_str("at ")?;
+			self.position.to_css(dest)?;
+			afterFirst = true;
+		}
+		
+		if afterFirst
+		{
+			dest.write_char(',')?;
+		}
+		
+		GradientItem::write_list(&self.items, dest)?;
+		
+		dest.write_char(')')
+	}
+}
+
+impl RadialGradient
+{
+	/// Whether any radius or position uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		self.size.is_var() || self.position.is_var() || self.items.iter().any(|item| item.is_var(CalculablePropertyValue::is_var))
+	}
+	
+	/// Parses the arguments of the function.
+	pub(crate) fn parse_arguments<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>, repeating: bool) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut shape = input.try(RadialGradientShape::parse).ok();
+		let size = input.try(|input| RadialGradientSize::parse(context, input)).ok();
+		if shape.is_none()
+		{
+			shape = input.try(RadialGradientShape::parse).ok();
+		}
+		
+		let position = if input.try(|input| input.expect_ident_matching("at")).is_ok()
+		{
+			Some(Position::parse(context, input)?)
+		}
+		else
+		{
+			None
+		};
+		
+		if shape.is_some() || size.is_some() || position.is_some()
+		{
+			input.expect_comma()?;
+		}
+		
+		let size = size.unwrap_or_default();
+		let shape = match (shape, &size)
+		{
+			(None, &RadialGradientSize::radius(_)) => RadialGradientShape::circle,
+			(None, _) => RadialGradientShape::ellipse,
+			(Some(RadialGradientShape::circle), &RadialGradientSize::radii(..)) | (Some(RadialGradientShape::ellipse), &RadialGradientSize::radius(_)) => return Err(ParseError::Custom(CustomParseError::InvalidRadialGradientSize)),
+			(Some(shape), _) => shape,
+		};
+		
+		Ok
+		(
+			Self
+			{
+				repeating,
+				shape,
+				size,
+				position: position.unwrap_or_else(Position::center),
+				items: GradientItem::parse_list(context, input, LengthOrPercentageUnit::parse_one_outside_calc_function)?,
+			}
+		)
+	}
+}
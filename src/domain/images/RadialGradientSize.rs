@@ -0,0 +1,88 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The size of the ending shape of a radial gradient.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum RadialGradientSize
+{
+	/// Sized to meet the closest or farthest side or corner of the gradient box.
+	extent(RadialGradientExtent),
+	
+	/// The radius of a circle.
+	radius(CalculablePropertyValue<LengthUnit<CssUnsignedNumber>>),
+	
+	/// The horizontal and vertical radii of an ellipse.
+	radii(CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>, CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>),
+}
+
+impl Default for RadialGradientSize
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		RadialGradientSize::extent(RadialGradientExtent::default())
+	}
+}
+
+impl ToCss for RadialGradientSize
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::RadialGradientSize::*;
+		
+		match *self
+		{
+			extent(keyword) => keyword.to_css(dest),
+			
+			radius(ref value) => value.to_css(dest),
+			
+			radii(ref horizontal, ref vertical) =>
+			{
+				horizontal.to_css(dest)?;
+				dest.write_char(' ')?;
+				vertical.to_css(dest)
+			}
+		}
+	}
+}
+
+impl RadialGradientSize
+{
+	/// Whether a radius uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		use self::RadialGradientSize::*;
+		
+		match *self
+		{
+			extent(_) => false,
+			radius(ref value) => value.is_var(),
+			radii(ref horizontal, ref vertical) => horizontal.is_var() || vertical.is_var(),
+		}
+	}
+	
+	pub(crate) fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::RadialGradientSize::*;
+		
+		if let Ok(keyword) = input.try(RadialGradientExtent::parse)
+		{
+			return Ok(extent(keyword));
+		}
+		
+		let bothRadii = input.try(|input|
+		{
+			let horizontal = LengthOrPercentageUnit::parse_one_outside_calc_function(context, input)?;
+			let vertical = LengthOrPercentageUnit::parse_one_outside_calc_function(context, input)?;
+			Ok(radii(horizontal, vertical))
+		});
+		if bothRadii.is_ok()
+		{
+			return bothRadii;
+		}
+		
+		LengthUnit::parse_one_outside_calc_function(context, input).map(radius)
+	}
+}
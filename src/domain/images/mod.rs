@@ -0,0 +1,40 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+use super::colors::Color;
+use super::properties::PropertyValue;
+use super::properties::Shorthand;
+use super::properties::SpecifiedValue;
+
+#[cfg(test)]
+mod tests;
+
+
+include!("Background.rs");
+include!("BackgroundAttachment.rs");
+include!("BackgroundBox.rs");
+include!("BackgroundLayer.rs");
+include!("BackgroundRepeat.rs");
+include!("BackgroundSize.rs");
+include!("ColorStop.rs");
+include!("ConicGradient.rs");
+include!("Gradient.rs");
+include!("GradientItem.rs");
+include!("HorizontalPositionKeyword.rs");
+include!("Image.rs");
+include!("ImageOrNone.rs");
+include!("ImageSet.rs");
+include!("ImageSetCandidate.rs");
+include!("Layers.rs");
+include!("LinearGradient.rs");
+include!("LinearGradientDirection.rs");
+include!("Position.rs");
+include!("PositionComponent.rs");
+include!("RadialGradient.rs");
+include!("RadialGradientExtent.rs");
+include!("RadialGradientShape.rs");
+include!("RadialGradientSize.rs");
+include!("RepeatStyle.rs");
+include!("VerticalPositionKeyword.rs");
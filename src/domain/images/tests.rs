@@ -0,0 +1,67 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+
+
+fn image_set(css: &str) -> ImageSet
+{
+	match ImageOrNone::parse_property_value_from_css(css).unwrap()
+	{
+		ImageOrNone::image(Image::ImageSet(imageSet)) => imageSet,
+		imageOrNone => panic!("{}: {:?}", css, imageOrNone),
+	}
+}
+
+fn selected_image(imageSet: &ImageSet, device_pixel_ratio: f32) -> Option<String>
+{
+	imageSet.select_candidate(device_pixel_ratio, |mime_type| mime_type != "image/jxl").map(|candidate| candidate.image.to_css_string())
+}
+
+#[test]
+fn image_sets_are_parsed()
+{
+	let imageSet = image_set(r#"image-set("a.png" 1x, url(b.png) 2dppx type("image/png"), linear-gradient(red, blue) 192dpi)"#);
+	assert_eq!(imageSet.vendor_prefix, None);
+	assert_eq!(imageSet.candidates.len(), 3);
+	assert_eq!(imageSet.candidates[0].image, Image::Url(SpecifiedUrl("a.png".to_owned())));
+	assert_eq!(imageSet.candidates[1].mime_type, Some("image/png".to_owned()));
+	assert_eq!(imageSet.candidates[2].resolution.to_dppx().as_f32(), 2.);
+	
+	assert_eq!(image_set(r#"-webkit-image-set(url(a.png) 1x)"#).vendor_prefix, Some(VendorPrefix::webkit));
+	assert!(ImageOrNone::parse_property_value_from_css(r#"image-set("a.png" calc(1x + 1x))"#).is_err());
+}
+
+#[test]
+fn image_sets_can_not_be_nested()
+{
+	for css in [r#"image-set(image-set("a.png" 1x) 2x)"#, r#"image-set("a.png" 1x, -webkit-image-set("b.png" 1x) 2x)"#, r#"-webkit-image-set(IMAGE-SET("a.png" 1x))"#].iter()
+	{
+		match ImageOrNone::parse_property_value_from_css(css)
+		{
+			Err(ParseError::Custom(CustomParseError::ImageSetCanNotBeNestedInImageSet)) => (),
+			result => panic!("{}: {:?}", css, result),
+		}
+	}
+}
+
+#[test]
+fn image_set_candidates_are_selected_for_the_device_pixel_ratio()
+{
+	let imageSet = image_set(r#"image-set("2x.png" 2x, "1x.png" 1x, "3x.png" 3x, "1x-again.png" 1x)"#);
+	assert_eq!(selected_image(&imageSet, 0.5), Some("url(1x.png)".to_owned()));
+	assert_eq!(selected_image(&imageSet, 1.), Some("url(1x.png)".to_owned()));
+	assert_eq!(selected_image(&imageSet, 1.5), Some("url(2x.png)".to_owned()));
+	assert_eq!(selected_image(&imageSet, 4.), Some("url(3x.png)".to_owned()));
+}
+
+#[test]
+fn image_set_candidates_of_unsupported_types_are_not_selected()
+{
+	let imageSet = image_set(r#"image-set("a.jxl" 1x type("image/jxl"), "a.png" 1x type("image/png"), "b.jxl" 2x type("image/jxl"))"#);
+	assert_eq!(selected_image(&imageSet, 2.), Some("url(a.png)".to_owned()));
+	
+	let imageSet = image_set(r#"image-set("a.jxl" type("image/jxl"))"#);
+	assert_eq!(selected_image(&imageSet, 1.), None);
+}
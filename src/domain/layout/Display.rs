@@ -0,0 +1,248 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `display` property: https://drafts.csswg.org/css-display-3/#the-display-properties
+/// The legacy keywords `inline-block`, `inline-table`, `inline-flex` and `inline-grid` and the single keywords such as `flex` are parsed into their two keyword equivalents; the shortest equivalent is always serialized.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Display
+{
+	/// `none`
+	none,
+	
+	/// `contents`
+	contents,
+	
+	/// An internal table or ruby display type, such as `table-cell`.
+	internal(DisplayInternal),
+	
+	/// An outer and inner display type, such as `inline flex` (`inline-flex`); `list_item` is only ever `true` for an inner display type of `flow` or `flow-root`.
+	outside_inside
+	{
+		outside: DisplayOutside,
+		inside: DisplayInside,
+		list_item: bool,
+	},
+}
+
+impl Default for Display
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Display::outside_inside
+		{
+			outside: DisplayOutside::inline,
+			inside: DisplayInside::flow,
+			list_item: false,
+		}
+	}
+}
+
+impl ToCss for Display
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::Display::*;
+		use self::DisplayInside::*;
+		use self::DisplayOutside::*;
+		
+		match *self
+		{
+			none => dest.write_str("none"),
+			
+			contents => dest.write_str("contents"),
+			
+			internal(internalDisplayType) => internalDisplayType.to_css(dest),
+			
+			outside_inside { outside, inside, list_item: true } =>
+			{
+				if outside != block
+				{
+					outside.to_css(dest)?;
+					dest.write_char(' ')?;
+				}
+				if inside != flow
+				{
+					inside.to_css(dest)?;
+					dest.write_char(' ')?;
+				}
+				dest.write_str("list-item")
+			}
+			
+			outside_inside { outside: block, inside: flow, .. } => dest.write_str("block"),
+			
+			outside_inside { outside: inline, inside: flow, .. } => dest.write_str("inline"),
+			
+			outside_inside { outside: run_in, inside: flow, .. } => dest.write_str("run-in"),
+			
+			outside_inside { outside: inline, inside: ruby, .. } => dest.write_str("ruby"),
+			
+			outside_inside { outside: inline, inside: flow_root, .. } => dest.write_str("inline-block"),
+			
+			outside_inside { outside: inline, inside: table, .. } => dest.write_str("inline-table"),
+			
+			outside_inside { outside: inline, inside: flex, .. } => dest.write_str("inline-flex"),
+			
+			outside_inside { outside: inline, inside: grid, .. } => dest.write_str("inline-grid"),
+			
+			outside_inside { outside: block, inside, .. } => inside.to_css(dest),
+			
+			outside_inside { outside, inside, .. } =>
+			{
+				outside.to_css(dest)?;
+				dest.write_char(' ')?;
+				inside.to_css(dest)
+			}
+		}
+	}
+}
+
+impl PropertyValue for Display
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"display",
+	];
+	
+	fn parse_property_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::Display::*;
+		use self::DisplayInside::*;
+		use self::DisplayOutside::*;
+		
+		if let Ok(value) = input.try(|input| -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+		{
+			let ident = input.expect_ident_cloned()?;
+			match_ignore_ascii_case!
+			{
+				&*ident,
+				
+				"none" => Ok(none),
+				
+				"contents" => Ok(contents),
+				
+				"inline-block" => Ok(outside_inside { outside: inline, inside: flow_root, list_item: false }),
+				
+				"inline-table" => Ok(outside_inside { outside: inline, inside: table, list_item: false }),
+				
+				"inline-flex" => Ok(outside_inside { outside: inline, inside: flex, list_item: false }),
+				
+				"inline-grid" => Ok(outside_inside { outside: inline, inside: grid, list_item: false }),
+				
+				_ => DisplayInternal::from_ident(&ident).map(internal).map_err(|()| ParseError::Basic(BasicParseError::UnexpectedToken(Token::Ident(ident.clone())))),
+			}
+		})
+		{
+			return Ok(value);
+		}
+		
+		let mut outsideKeyword = None;
+		let mut insideKeyword = None;
+		let mut list_item = false;
+		loop
+		{
+			if outsideKeyword.is_none()
+			{
+				if let Ok(value) = input.try(DisplayOutside::parse)
+				{
+					outsideKeyword = Some(value);
+					continue;
+				}
+			}
+			
+			if insideKeyword.is_none()
+			{
+				if let Ok(value) = input.try(DisplayInside::parse)
+				{
+					insideKeyword = Some(value);
+					continue;
+				}
+			}
+			
+			if !list_item
+			{
+				if input.try(|input| input.expect_ident_matching("list-item")).is_ok()
+				{
+					list_item = true;
+					continue;
+				}
+			}
+			
+			break;
+		}
+		
+		if outsideKeyword.is_none() && insideKeyword.is_none() && !list_item
+		{
+			let token = input.next()?.clone();
+			return Err(ParseError::Basic(BasicParseError::UnexpectedToken(token)));
+		}
+		
+		let inside = insideKeyword.unwrap_or(flow);
+		if list_item && inside != flow && inside != flow_root
+		{
+			return Err(ParseError::Custom(CustomParseError::DisplayListItemMustBeFlowOrFlowRoot(inside)));
+		}
+		
+		let outside = match outsideKeyword
+		{
+			Some(outside) => outside,
+			None => if inside == ruby
+			{
+				inline
+			}
+			else
+			{
+				block
+			},
+		};
+		
+		Ok(outside_inside { outside, inside, list_item })
+	}
+}
+
+impl Display
+{
+	/// Whether the element generates no boxes at all (`none`); an element with `contents` does not generate a box but its children do.
+	#[inline(always)]
+	pub fn is_none(&self) -> bool
+	{
+		*self == Display::none
+	}
+	
+	/// Whether the element establishes a flex container (`flex` or `inline-flex`).
+	#[inline(always)]
+	pub fn is_flex_container(&self) -> bool
+	{
+		self.inside() == Some(DisplayInside::flex)
+	}
+	
+	/// Whether the element establishes a grid container (`grid` or `inline-grid`).
+	#[inline(always)]
+	pub fn is_grid_container(&self) -> bool
+	{
+		self.inside() == Some(DisplayInside::grid)
+	}
+	
+	/// The inner display type, if any.
+	#[inline(always)]
+	pub fn inside(&self) -> Option<DisplayInside>
+	{
+		match *self
+		{
+			Display::outside_inside { inside, .. } => Some(inside),
+			_ => None,
+		}
+	}
+	
+	/// The outer display type, if any.
+	#[inline(always)]
+	pub fn outside(&self) -> Option<DisplayOutside>
+	{
+		match *self
+		{
+			Display::outside_inside { outside, .. } => Some(outside),
+			_ => None,
+		}
+	}
+}
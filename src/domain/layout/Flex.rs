@@ -0,0 +1,213 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The `flex` shorthand property: https://drafts.csswg.org/css-flexbox-1/#flex-property
+#[derive(Debug, Clone, PartialEq)]
+pub struct Flex
+{
+	/// The value of `flex-grow`.
+	pub grow: CalculablePropertyValue<CssUnsignedNumber>,
+	
+	/// The value of `flex-shrink`.
+	pub shrink: CalculablePropertyValue<CssUnsignedNumber>,
+	
+	/// The value of `flex-basis`; `0%` if omitted after a flex factor.
+	pub basis: FlexBasis,
+}
+
+impl Default for Flex
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			grow: CalculablePropertyValue::Constant(CssUnsignedNumber::Zero),
+			shrink: CalculablePropertyValue::Constant(CssUnsignedNumber::One),
+			basis: FlexBasis::default(),
+		}
+	}
+}
+
+/// Writes `none`, or the shortest of the forms `<grow> <shrink>?`, `<basis>` and `<grow> <shrink>? <basis>`.
+impl ToCss for Flex
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if *self == Self::none()
+		{
+			return dest.write_str("none");
+		}
+		
+		let one = CalculablePropertyValue::Constant(CssUnsignedNumber::One);
+		if self.basis != FlexBasis::zero() && self.grow == one && self.shrink == one
+		{
+			return self.basis.to_css(dest);
+		}
+		
+		self.grow.to_css(dest)?;
+		if self.shrink != one
+		{
+			dest.write_char(' ')?;
+			self.shrink.to_css(dest)?;
+		}
+		if self.basis != FlexBasis::zero()
+		{
+			dest.write_char(' ')?;
+			self.basis.to_css(dest)?;
+		}
+		Ok(())
+	}
+}
+
+impl PropertyValue for Flex
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"flex",
+	];
+	
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			return Ok(Self::none());
+		}
+		
+		let mut grow = None;
+		let mut shrink = None;
+		let mut basis = None;
+		loop
+		{
+			// A unitless zero that is not already preceded by two flex factors is a flex factor, so these are parsed before the basis.
+			if grow.is_none()
+			{
+				if let Ok(value) = input.try(|input| CssUnsignedNumber::parse_one_outside_calc_function(context, input))
+				{
+					grow = Some(value);
+					shrink = input.try(|input| CssUnsignedNumber::parse_one_outside_calc_function(context, input)).ok();
+					continue;
+				}
+			}
+			
+			if basis.is_none()
+			{
+				if let Ok(value) = input.try(|input| FlexBasis::parse_property_value(context, input))
+				{
+					basis = Some(value);
+					continue;
+				}
+			}
+			
+			break;
+		}
+		
+		let one = CalculablePropertyValue::Constant(CssUnsignedNumber::One);
+		match (grow, basis)
+		{
+			(None, None) =>
+			{
+				let token = input.next()?.clone();
+				Err(ParseError::Basic(BasicParseError::UnexpectedToken(token)))
+			}
+			
+			(None, Some(basis)) => Ok(Self { grow: one.clone(), shrink: one, basis }),
+			
+			(Some(grow), basis) => Ok
+			(
+				Self
+				{
+					grow,
+					shrink: shrink.unwrap_or(one),
+					basis: basis.unwrap_or_else(FlexBasis::zero),
+				}
+			),
+		}
+	}
+}
+
+impl Shorthand for Flex
+{
+	#[inline(always)]
+	fn longhand_property_names(_shorthand_property_name: &str) -> &'static [&'static str]
+	{
+		&["flex-grow", "flex-shrink", "flex-basis"]
+	}
+	
+	#[inline(always)]
+	fn is_related_property(_shorthand_property_name: &str, _property_name: &str) -> bool
+	{
+		false
+	}
+	
+	fn expand(&self) -> Option<Vec<SpecifiedValue>>
+	{
+		if self.is_var()
+		{
+			return None;
+		}
+		
+		Some(vec![SpecifiedValue::from_typed_value(&self.grow).ok()?, SpecifiedValue::from_typed_value(&self.shrink).ok()?, SpecifiedValue::from_typed_value(&self.basis).ok()?])
+	}
+	
+	fn fold(longhands: &[&SpecifiedValue]) -> Option<Self>
+	{
+		if longhands.len() != 3
+		{
+			return None;
+		}
+		
+		let flex = Self
+		{
+			grow: CalculablePropertyValue::<CssUnsignedNumber>::parse_property_value_from_css(longhands[0].original_css()).ok()?,
+			shrink: CalculablePropertyValue::<CssUnsignedNumber>::parse_property_value_from_css(longhands[1].original_css()).ok()?,
+			basis: FlexBasis::parse_property_value_from_css(longhands[2].original_css()).ok()?,
+		};
+		if flex.is_var()
+		{
+			None
+		}
+		else
+		{
+			Some(flex)
+		}
+	}
+}
+
+impl Flex
+{
+	/// The value of `flex: none`, `0 0 auto`.
+	#[inline(always)]
+	pub fn none() -> Self
+	{
+		Self
+		{
+			grow: CalculablePropertyValue::Constant(CssUnsignedNumber::Zero),
+			shrink: CalculablePropertyValue::Constant(CssUnsignedNumber::Zero),
+			basis: FlexBasis::default(),
+		}
+	}
+	
+	/// Whether any value uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		self.grow.is_var() || self.shrink.is_var() || self.basis.is_var()
+	}
+}
+
+impl PropertyValue for CalculablePropertyValue<CssUnsignedNumber>
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"flex-grow",
+		"flex-shrink",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		CssUnsignedNumber::parse_one_outside_calc_function(context, input)
+	}
+}
@@ -0,0 +1,79 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `flex-basis` property: https://drafts.csswg.org/css-flexbox-1/#flex-basis-property
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum FlexBasis
+{
+	/// `content`; an automatic size based on the flex item's content.
+	content,
+	
+	/// The same values as for `width` and `height`, including `auto`.
+	width(Size),
+}
+
+impl Default for FlexBasis
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		FlexBasis::width(Size::auto)
+	}
+}
+
+impl ToCss for FlexBasis
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::FlexBasis::*;
+		
+		match *self
+		{
+			content => dest.write_str("content"),
+			
+			width(ref size) => size.to_css(dest),
+		}
+	}
+}
+
+impl PropertyValue for FlexBasis
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"flex-basis",
+	];
+	
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::FlexBasis::*;
+		
+		if input.try(|input| input.expect_ident_matching("content")).is_ok()
+		{
+			return Ok(content);
+		}
+		
+		Ok(width(Size::parse_property_value(context, input)?))
+	}
+}
+
+impl FlexBasis
+{
+	/// The value of `flex-basis` when it is omitted from the `flex` shorthand property, `0%`.
+	#[inline(always)]
+	pub fn zero() -> Self
+	{
+		FlexBasis::width(Size::value(CalculablePropertyValue::Constant(LengthOrPercentageUnit::IsPercentage(PercentageUnit::default()))))
+	}
+	
+	/// Whether this value is a `var()`, which might be substituted by more than one value.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		match *self
+		{
+			FlexBasis::width(Size::value(ref value)) => value.is_var(),
+			_ => false,
+		}
+	}
+}
@@ -0,0 +1,125 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The `flex-flow` shorthand property: https://drafts.csswg.org/css-flexbox-1/#flex-flow-property
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct FlexFlow
+{
+	/// The value of `flex-direction`.
+	pub direction: FlexDirection,
+	
+	/// The value of `flex-wrap`.
+	pub wrap: FlexWrap,
+}
+
+/// Writes the values which are not initial values.
+impl ToCss for FlexFlow
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.wrap == FlexWrap::default()
+		{
+			self.direction.to_css(dest)
+		}
+		else if self.direction == FlexDirection::default()
+		{
+			self.wrap.to_css(dest)
+		}
+		else
+		{
+			self.direction.to_css(dest)?;
+			dest.write_char(' ')?;
+			self.wrap.to_css(dest)
+		}
+	}
+}
+
+impl PropertyValue for FlexFlow
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"flex-flow",
+	];
+	
+	fn parse_property_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut direction = None;
+		let mut wrap = None;
+		loop
+		{
+			if direction.is_none()
+			{
+				if let Ok(value) = input.try(FlexDirection::parse)
+				{
+					direction = Some(value);
+					continue;
+				}
+			}
+			
+			if wrap.is_none()
+			{
+				if let Ok(value) = input.try(FlexWrap::parse)
+				{
+					wrap = Some(value);
+					continue;
+				}
+			}
+			
+			break;
+		}
+		
+		if direction.is_none() && wrap.is_none()
+		{
+			let token = input.next()?.clone();
+			return Err(ParseError::Basic(BasicParseError::UnexpectedToken(token)));
+		}
+		
+		Ok
+		(
+			Self
+			{
+				direction: direction.unwrap_or_default(),
+				wrap: wrap.unwrap_or_default(),
+			}
+		)
+	}
+}
+
+impl Shorthand for FlexFlow
+{
+	#[inline(always)]
+	fn longhand_property_names(_shorthand_property_name: &str) -> &'static [&'static str]
+	{
+		&["flex-direction", "flex-wrap"]
+	}
+	
+	#[inline(always)]
+	fn is_related_property(_shorthand_property_name: &str, _property_name: &str) -> bool
+	{
+		false
+	}
+	
+	#[inline(always)]
+	fn expand(&self) -> Option<Vec<SpecifiedValue>>
+	{
+		Some(vec![SpecifiedValue::from_typed_value(&self.direction).ok()?, SpecifiedValue::from_typed_value(&self.wrap).ok()?])
+	}
+	
+	fn fold(longhands: &[&SpecifiedValue]) -> Option<Self>
+	{
+		if longhands.len() != 2
+		{
+			return None;
+		}
+		
+		Some
+		(
+			Self
+			{
+				direction: FlexDirection::parse_property_value_from_css(longhands[0].original_css()).ok()?,
+				wrap: FlexWrap::parse_property_value_from_css(longhands[1].original_css()).ok()?,
+			}
+		)
+	}
+}
@@ -0,0 +1,108 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The `gap` shorthand property: https://drafts.csswg.org/css-align-3/#gap-shorthand
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Gap
+{
+	/// The value of `row-gap`.
+	pub row: GapSize,
+	
+	/// The value of `column-gap`; the same as `row` if omitted.
+	pub column: GapSize,
+}
+
+impl ToCss for Gap
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		self.row.to_css(dest)?;
+		if self.column != self.row
+		{
+			dest.write_char(' ')?;
+			self.column.to_css(dest)?;
+		}
+		Ok(())
+	}
+}
+
+impl PropertyValue for Gap
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"gap",
+	];
+	
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let row = GapSize::parse_property_value(context, input)?;
+		let column = input.try(|input| GapSize::parse_property_value(context, input)).unwrap_or_else(|_| row.clone());
+		Ok(Self { row, column })
+	}
+}
+
+impl Shorthand for Gap
+{
+	#[inline(always)]
+	fn longhand_property_names(_shorthand_property_name: &str) -> &'static [&'static str]
+	{
+		&["row-gap", "column-gap"]
+	}
+	
+	/// The legacy `grid-gap`, `grid-row-gap` and `grid-column-gap` properties are aliases which set the same values.
+	#[inline(always)]
+	fn is_related_property(_shorthand_property_name: &str, property_name: &str) -> bool
+	{
+		match_ignore_ascii_case!
+		{
+			property_name,
+			
+			"grid-gap" | "grid-row-gap" | "grid-column-gap" => true,
+			
+			_ => false,
+		}
+	}
+	
+	fn expand(&self) -> Option<Vec<SpecifiedValue>>
+	{
+		if self.is_var()
+		{
+			return None;
+		}
+		
+		Some(vec![SpecifiedValue::from_typed_value(&self.row).ok()?, SpecifiedValue::from_typed_value(&self.column).ok()?])
+	}
+	
+	fn fold(longhands: &[&SpecifiedValue]) -> Option<Self>
+	{
+		if longhands.len() != 2
+		{
+			return None;
+		}
+		
+		let gap = Self
+		{
+			row: GapSize::parse_property_value_from_css(longhands[0].original_css()).ok()?,
+			column: GapSize::parse_property_value_from_css(longhands[1].original_css()).ok()?,
+		};
+		if gap.is_var()
+		{
+			None
+		}
+		else
+		{
+			Some(gap)
+		}
+	}
+}
+
+impl Gap
+{
+	/// Whether either value uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		self.row.is_var() || self.column.is_var()
+	}
+}
@@ -0,0 +1,71 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `row-gap` and `column-gap` properties: https://drafts.csswg.org/css-align-3/#column-row-gap
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum GapSize
+{
+	/// `normal`; zero, except in multi-column layout.
+	normal,
+	
+	/// invariant or calculated non-negative length or non-negative percentage
+	value(CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>),
+}
+
+impl Default for GapSize
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		GapSize::normal
+	}
+}
+
+impl ToCss for GapSize
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::GapSize::*;
+		
+		match *self
+		{
+			normal => dest.write_str("normal"),
+			
+			value(ref lengthOrPercentage) => lengthOrPercentage.to_css(dest),
+		}
+	}
+}
+
+impl PropertyValue for GapSize
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"column-gap",
+		"row-gap",
+	];
+	
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.try(|input| input.expect_ident_matching("normal")).is_ok()
+		{
+			return Ok(GapSize::normal);
+		}
+		
+		Ok(GapSize::value(LengthOrPercentageUnit::parse_one_outside_calc_function(context, input)?))
+	}
+}
+
+impl GapSize
+{
+	/// Whether this value is a `var()`, which might be substituted by more than one value.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		match *self
+		{
+			GapSize::value(ref lengthOrPercentage) => lengthOrPercentage.is_var(),
+			_ => false,
+		}
+	}
+}
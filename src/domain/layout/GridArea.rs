@@ -0,0 +1,162 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The `grid-area` shorthand property: https://drafts.csswg.org/css-grid-2/#propdef-grid-area
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct GridArea
+{
+	/// The value of `grid-row-start`.
+	pub row_start: GridLine,
+	
+	/// The value of `grid-column-start`.
+	pub column_start: GridLine,
+	
+	/// The value of `grid-row-end`.
+	pub row_end: GridLine,
+	
+	/// The value of `grid-column-end`.
+	pub column_end: GridLine,
+}
+
+/// Omits trailing values which are the same as the value they would be given if omitted.
+impl ToCss for GridArea
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		let numberOfValues = if self.column_end != self.column_start.omitted_value()
+		{
+			4
+		}
+		else if self.row_end != self.row_start.omitted_value()
+		{
+			3
+		}
+		else if self.column_start != self.row_start.omitted_value()
+		{
+			2
+		}
+		else
+		{
+			1
+		};
+		
+		let values = [&self.row_start, &self.column_start, &self.row_end, &self.column_end];
+		values[0].to_css(dest)?;
+		for value in values[1 .. numberOfValues].iter()
+		{
+			dest.write_char('/')?;
+			value.to_css(dest)?;
+		}
+		Ok(())
+	}
+}
+
+impl PropertyValue for GridArea
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"grid-area",
+	];
+	
+	fn parse_property_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let row_start = GridLine::parse(input)?;
+		
+		let parseNext = |input: &mut Parser<'i, 't>, omitted: &GridLine| -> Result<GridLine, ParseError<'i, CustomParseError<'i>>>
+		{
+			if input.try(|input| input.expect_delim('/')).is_ok()
+			{
+				GridLine::parse(input)
+			}
+			else
+			{
+				Ok(omitted.omitted_value())
+			}
+		};
+		
+		let column_start = parseNext(input, &row_start)?;
+		let row_end = parseNext(input, &row_start)?;
+		let column_end = parseNext(input, &column_start)?;
+		
+		Ok
+		(
+			Self
+			{
+				row_start,
+				column_start,
+				row_end,
+				column_end,
+			}
+		)
+	}
+}
+
+impl Shorthand for GridArea
+{
+	#[inline(always)]
+	fn longhand_property_names(_shorthand_property_name: &str) -> &'static [&'static str]
+	{
+		&["grid-row-start", "grid-column-start", "grid-row-end", "grid-column-end"]
+	}
+	
+	/// The `grid-row` and `grid-column` shorthand properties also set the values of the longhand properties.
+	#[inline(always)]
+	fn is_related_property(_shorthand_property_name: &str, property_name: &str) -> bool
+	{
+		const Prefixes: &'static [&'static str] = &["grid-row", "grid-column"];
+		
+		Prefixes.iter().any(|prefix| property_name.len() >= prefix.len() && property_name.is_char_boundary(prefix.len()) && property_name[..prefix.len()].eq_ignore_ascii_case(prefix))
+	}
+	
+	#[inline(always)]
+	fn expand(&self) -> Option<Vec<SpecifiedValue>>
+	{
+		Some
+		(
+			vec!
+			[
+				SpecifiedValue::from_typed_value(&self.row_start).ok()?,
+				SpecifiedValue::from_typed_value(&self.column_start).ok()?,
+				SpecifiedValue::from_typed_value(&self.row_end).ok()?,
+				SpecifiedValue::from_typed_value(&self.column_end).ok()?,
+			]
+		)
+	}
+	
+	fn fold(longhands: &[&SpecifiedValue]) -> Option<Self>
+	{
+		if longhands.len() != 4
+		{
+			return None;
+		}
+		
+		Some
+		(
+			Self
+			{
+				row_start: GridLine::parse_property_value_from_css(longhands[0].original_css()).ok()?,
+				column_start: GridLine::parse_property_value_from_css(longhands[1].original_css()).ok()?,
+				row_end: GridLine::parse_property_value_from_css(longhands[2].original_css()).ok()?,
+				column_end: GridLine::parse_property_value_from_css(longhands[3].original_css()).ok()?,
+			}
+		)
+	}
+}
+
+impl GridArea
+{
+	/// A `grid-area` placing an item in a named area, such as one defined by `grid-template-areas`; all four lines are the name.
+	#[inline(always)]
+	pub fn named(name: CustomIdent) -> Self
+	{
+		let line = GridLine::line { span: false, number: None, name: Some(name) };
+		Self
+		{
+			row_start: line.clone(),
+			column_start: line.clone(),
+			row_end: line.clone(),
+			column_end: line,
+		}
+	}
+}
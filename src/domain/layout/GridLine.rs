@@ -0,0 +1,176 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `grid-row-start`, `grid-row-end`, `grid-column-start` and `grid-column-end` properties: https://drafts.csswg.org/css-grid-2/#typedef-grid-row-start-grid-line
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum GridLine
+{
+	/// `auto`
+	auto,
+	
+	/// A line, or a span of lines if `span` is true, identified by a non-zero number, a name or both; the number is always positive for a span.
+	line
+	{
+		span: bool,
+		number: Option<i32>,
+		name: Option<CustomIdent>,
+	},
+}
+
+impl Default for GridLine
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		GridLine::auto
+	}
+}
+
+impl ToCss for GridLine
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::GridLine::*;
+		
+		match *self
+		{
+			auto => dest.write_str("auto"),
+			
+			line { span, number, ref name } =>
+			{
+				let mut afterFirst = false;
+				if span
+				{
+					dest.write_str("span")?;
+					afterFirst = true;
+				}
+				
+				if let Some(number) = number
+				{
+					if afterFirst
+					{
+						dest.write_char(' ')?;
+					}
+					write!(dest, "{}", number)?;
+					afterFirst = true;
+				}
+				
+				if let Some(ref name) = *name
+				{
+					if afterFirst
+					{
+						dest.write_char(' ')?;
+					}
+					name.to_css(dest)?;
+				}
+				Ok(())
+			}
+		}
+	}
+}
+
+impl PropertyValue for GridLine
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"grid-column-end",
+		"grid-column-start",
+		"grid-row-end",
+		"grid-row-start",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse(input)
+	}
+}
+
+impl GridLine
+{
+	/// Whether this is only a name, such as `header`; such a value is repeated by the `grid-area`, `grid-row` and `grid-column` shorthand properties when they omit a later value.
+	#[inline(always)]
+	pub fn is_name_only(&self) -> bool
+	{
+		match *self
+		{
+			GridLine::line { span: false, number: None, name: Some(_) } => true,
+			_ => false,
+		}
+	}
+	
+	/// The value given to an omitted later value of the `grid-area`, `grid-row` and `grid-column` shorthand properties.
+	#[inline(always)]
+	pub fn omitted_value(&self) -> Self
+	{
+		if self.is_name_only()
+		{
+			self.clone()
+		}
+		else
+		{
+			GridLine::auto
+		}
+	}
+	
+	pub(crate) fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.try(|input| input.expect_ident_matching("auto")).is_ok()
+		{
+			return Ok(GridLine::auto);
+		}
+		
+		let mut span = false;
+		let mut number = None;
+		let mut name = None;
+		loop
+		{
+			if !span
+			{
+				if input.try(|input| input.expect_ident_matching("span")).is_ok()
+				{
+					span = true;
+					continue;
+				}
+			}
+			
+			if number.is_none()
+			{
+				if let Ok(integer) = input.try(|input| input.expect_integer())
+				{
+					number = Some(integer);
+					continue;
+				}
+			}
+			
+			if name.is_none()
+			{
+				if let Ok(ident) = input.try(|input| input.expect_ident_cloned())
+				{
+					name = Some(CustomIdent::from_ident(&ident, GridLineNames::ExcludedNames)?);
+					continue;
+				}
+			}
+			
+			break;
+		}
+		
+		match number
+		{
+			None => if name.is_none()
+			{
+				return Err(ParseError::Custom(CustomParseError::GridLineMustHaveANumberOrName));
+			},
+			
+			Some(0) => return Err(ParseError::Custom(CustomParseError::GridLineNumberMustNotBeZero)),
+			
+			Some(number) => if span && number < 0
+			{
+				return Err(ParseError::Custom(CustomParseError::GridLineSpanMustBePositive(number)));
+			},
+		}
+		
+		Ok(GridLine::line { span, number, name })
+	}
+}
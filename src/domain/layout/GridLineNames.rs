@@ -0,0 +1,50 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A list of line names, `[name1 name2]`, in a grid track list: https://drafts.csswg.org/css-grid-2/#named-lines
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct GridLineNames(pub Vec<CustomIdent>);
+
+impl ToCss for GridLineNames
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		dest.write_char('[')?;
+		let mut afterFirst = false;
+		for name in self.0.iter()
+		{
+			if afterFirst
+			{
+				dest.write_char(' ')?;
+			}
+			else
+			{
+				afterFirst = true;
+			}
+			name.to_css(dest)?;
+		}
+		dest.write_char(']')
+	}
+}
+
+impl GridLineNames
+{
+	/// Line names which are reserved keywords.
+	pub(crate) const ExcludedNames: &'static [&'static str] = &["span", "auto"];
+	
+	pub(crate) fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		input.expect_square_bracket_block()?;
+		input.parse_nested_block(|input|
+		{
+			let mut names = Vec::new();
+			while !input.is_exhausted()
+			{
+				let ident = input.expect_ident_cloned()?;
+				names.push(CustomIdent::from_ident(&ident, Self::ExcludedNames)?);
+			}
+			Ok(GridLineNames(names))
+		})
+	}
+}
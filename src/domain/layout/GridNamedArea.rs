@@ -0,0 +1,23 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A named grid area defined by `grid-template-areas`, as the numbers of the grid lines bounding it; the first line is `1` and end lines are exclusive, as for `grid-area: row_start / column_start / row_end / column_end`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct GridNamedArea
+{
+	/// The name of the area.
+	pub name: Atom,
+	
+	/// The first grid row line.
+	pub row_start: u32,
+	
+	/// The last grid row line.
+	pub row_end: u32,
+	
+	/// The first grid column line.
+	pub column_start: u32,
+	
+	/// The last grid column line.
+	pub column_end: u32,
+}
@@ -0,0 +1,74 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The number of repetitions of a `repeat()` in a grid track list: https://drafts.csswg.org/css-grid-2/#repeat-notation
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum GridRepeatCount
+{
+	/// A positive number of repetitions.
+	count(CssUnsignedInteger),
+	
+	/// `auto-fill`
+	auto_fill,
+	
+	/// `auto-fit`
+	auto_fit,
+}
+
+impl ToCss for GridRepeatCount
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::GridRepeatCount::*;
+		
+		match *self
+		{
+			count(ref repetitions) => repetitions.to_css(dest),
+			
+			auto_fill => dest.write_str("auto-fill"),
+			
+			auto_fit => dest.write_str("auto-fit"),
+		}
+	}
+}
+
+impl GridRepeatCount
+{
+	/// Whether this is `auto-fill` or `auto-fit`, ie the number of repetitions depends on the size of the grid container.
+	#[inline(always)]
+	pub fn is_automatic(&self) -> bool
+	{
+		match *self
+		{
+			GridRepeatCount::count(_) => false,
+			_ => true,
+		}
+	}
+	
+	pub(crate) fn parse<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::GridRepeatCount::*;
+		
+		if let Ok(ident) = input.try(|input| input.expect_ident_cloned())
+		{
+			return match_ignore_ascii_case!
+			{
+				&*ident,
+				
+				"auto-fill" => Ok(auto_fill),
+				
+				"auto-fit" => Ok(auto_fit),
+				
+				_ => Err(ParseError::Basic(BasicParseError::UnexpectedToken(Token::Ident(ident)))),
+			}
+		}
+		
+		let repetitions = input.expect_integer()?;
+		if repetitions < 1
+		{
+			return Err(ParseError::Custom(CustomParseError::GridRepeatCountMustBePositive(repetitions)));
+		}
+		Ok(count(CssUnsignedInteger::from(repetitions as u32)))
+	}
+}
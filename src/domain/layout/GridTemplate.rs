@@ -0,0 +1,224 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The `grid-template` shorthand property: https://drafts.csswg.org/css-grid-2/#explicit-grid-shorthand
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct GridTemplate
+{
+	/// The value of `grid-template-rows`.
+	pub rows: GridTrackList,
+	
+	/// The value of `grid-template-columns`.
+	pub columns: GridTrackList,
+	
+	/// The value of `grid-template-areas`.
+	pub areas: GridTemplateAreas,
+}
+
+/// Writes `none`, `<rows>/<columns>` or, if there are areas, each row's line names, string and track size followed by `/<columns>`.
+impl ToCss for GridTemplate
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.areas.is_none()
+		{
+			if self.rows.is_none() && self.columns.is_none()
+			{
+				return dest.write_str("none");
+			}
+			
+			self.rows.to_css(dest)?;
+			dest.write_char('/')?;
+			return self.columns.to_css(dest);
+		}
+		
+		let mut afterFirst = false;
+		let mut row = 0;
+		for component in self.rows.0.iter()
+		{
+			if afterFirst
+			{
+				dest.write_char(' ')?;
+			}
+			else
+			{
+				afterFirst = true;
+			}
+			
+			match *component
+			{
+				GridTrackListComponent::track_size(ref size) =>
+				{
+					self.areas.write_row(row, dest)?;
+					row += 1;
+					if *size != GridTrackSize::default()
+					{
+						dest.write_char(' ')?;
+						size.to_css(dest)?;
+					}
+				}
+				
+				_ => component.to_css(dest)?,
+			}
+		}
+		
+		if !self.columns.is_none()
+		{
+			dest.write_char('/')?;
+			self.columns.to_css(dest)?;
+		}
+		Ok(())
+	}
+}
+
+impl PropertyValue for GridTemplate
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"grid-template",
+	];
+	
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if let Ok(gridTemplate) = input.try(|input| -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+		{
+			let rows = GridTrackList::parse_property_value(context, input)?;
+			input.expect_delim('/')?;
+			let columns = GridTrackList::parse_property_value(context, input)?;
+			Ok(GridTemplate { rows, columns, areas: GridTemplateAreas::default() })
+		})
+		{
+			return Ok(gridTemplate);
+		}
+		
+		if input.try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			return Ok(GridTemplate::default());
+		}
+		
+		let mut rows: Vec<GridTrackListComponent> = Vec::new();
+		let mut cells = Vec::new();
+		loop
+		{
+			while let Ok(names) = input.try(GridLineNames::parse)
+			{
+				// Adjacent line names, such as the trailing names of one row and the leading names of the next, are merged.
+				if let Some(&mut GridTrackListComponent::line_names(ref mut previousNames)) = rows.last_mut()
+				{
+					previousNames.0.extend(names.0);
+					continue;
+				}
+				rows.push(GridTrackListComponent::line_names(names));
+			}
+			
+			let string = if cells.is_empty()
+			{
+				input.expect_string_cloned()?
+			}
+			else
+			{
+				match input.try(|input| input.expect_string_cloned())
+				{
+					Ok(string) => string,
+					Err(_) => break,
+				}
+			};
+			cells.push(GridTemplateAreas::parse_row(&string)?);
+			
+			let size = input.try(|input| GridTrackSize::parse(context, input)).unwrap_or_default();
+			rows.push(GridTrackListComponent::track_size(size));
+		}
+		
+		let columns = if input.try(|input| input.expect_delim('/')).is_ok()
+		{
+			GridTrackList(GridTrackListComponent::parse_components(context, input, false)?)
+		}
+		else
+		{
+			GridTrackList::default()
+		};
+		
+		Ok
+		(
+			Self
+			{
+				rows: GridTrackList(rows),
+				columns,
+				areas: GridTemplateAreas::from_rows(cells)?,
+			}
+		)
+	}
+}
+
+impl Shorthand for GridTemplate
+{
+	#[inline(always)]
+	fn longhand_property_names(_shorthand_property_name: &str) -> &'static [&'static str]
+	{
+		&["grid-template-rows", "grid-template-columns", "grid-template-areas"]
+	}
+	
+	/// The `grid` shorthand property also sets the values of all of the longhand properties.
+	#[inline(always)]
+	fn is_related_property(_shorthand_property_name: &str, property_name: &str) -> bool
+	{
+		property_name.eq_ignore_ascii_case("grid")
+	}
+	
+	fn expand(&self) -> Option<Vec<SpecifiedValue>>
+	{
+		if self.is_var()
+		{
+			return None;
+		}
+		
+		Some(vec![SpecifiedValue::from_typed_value(&self.rows).ok()?, SpecifiedValue::from_typed_value(&self.columns).ok()?, SpecifiedValue::from_typed_value(&self.areas).ok()?])
+	}
+	
+	/// Only folds longhand properties which `grid-template` can represent: if there are areas, the rows must be an explicit track list with one track for each row of areas, and the columns must be `none` or an explicit track list.
+	fn fold(longhands: &[&SpecifiedValue]) -> Option<Self>
+	{
+		if longhands.len() != 3
+		{
+			return None;
+		}
+		
+		let gridTemplate = Self
+		{
+			rows: GridTrackList::parse_property_value_from_css(longhands[0].original_css()).ok()?,
+			columns: GridTrackList::parse_property_value_from_css(longhands[1].original_css()).ok()?,
+			areas: GridTemplateAreas::parse_property_value_from_css(longhands[2].original_css()).ok()?,
+		};
+		
+		if gridTemplate.is_var()
+		{
+			return None;
+		}
+		
+		if !gridTemplate.areas.is_none()
+		{
+			if !gridTemplate.rows.is_explicit() || gridTemplate.rows.track_sizes().len() != gridTemplate.areas.number_of_rows()
+			{
+				return None;
+			}
+			
+			if !gridTemplate.columns.is_none() && !gridTemplate.columns.is_explicit()
+			{
+				return None;
+			}
+		}
+		
+		Some(gridTemplate)
+	}
+}
+
+impl GridTemplate
+{
+	/// Whether any track size uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		self.rows.is_var() || self.columns.is_var()
+	}
+}
@@ -0,0 +1,224 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `grid-template-areas` property: https://drafts.csswg.org/css-grid-2/#grid-template-areas-property
+/// Each row is a list of cells, either named or null (`.`); an empty list of rows is `none`.
+/// Parsing validates that all rows have the same number of cells and that each name forms a single filled-in rectangle, so that a malformed area map is reported as an error rather than silently ignored.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct GridTemplateAreas(pub Vec<Vec<Option<Atom>>>);
+
+impl ToCss for GridTemplateAreas
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.is_none()
+		{
+			return dest.write_str("none");
+		}
+		
+		let mut afterFirst = false;
+		for row in 0 .. self.0.len()
+		{
+			if afterFirst
+			{
+				dest.write_char(' ')?;
+			}
+			else
+			{
+				afterFirst = true;
+			}
+			self.write_row(row, dest)?;
+		}
+		Ok(())
+	}
+}
+
+impl PropertyValue for GridTemplateAreas
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"grid-template-areas",
+	];
+	
+	fn parse_property_value<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			return Ok(GridTemplateAreas::default());
+		}
+		
+		let mut rows = vec![Self::parse_row(&input.expect_string_cloned()?)?];
+		while let Ok(string) = input.try(|input| input.expect_string_cloned())
+		{
+			rows.push(Self::parse_row(&string)?);
+		}
+		Self::from_rows(rows)
+	}
+}
+
+impl GridTemplateAreas
+{
+	/// Whether this is `none`.
+	#[inline(always)]
+	pub fn is_none(&self) -> bool
+	{
+		self.0.is_empty()
+	}
+	
+	/// The number of rows of cells.
+	#[inline(always)]
+	pub fn number_of_rows(&self) -> usize
+	{
+		self.0.len()
+	}
+	
+	/// The number of columns of cells; zero if `none`.
+	#[inline(always)]
+	pub fn number_of_columns(&self) -> usize
+	{
+		self.0.first().map(|row| row.len()).unwrap_or(0)
+	}
+	
+	/// The named areas, in the order their names first occur, reading each row from left to right.
+	pub fn named_areas(&self) -> Vec<GridNamedArea>
+	{
+		let mut namedAreas: Vec<GridNamedArea> = Vec::new();
+		for (rowIndex, row) in self.0.iter().enumerate()
+		{
+			for (columnIndex, cell) in row.iter().enumerate()
+			{
+				if let Some(ref name) = *cell
+				{
+					let row = rowIndex as u32 + 1;
+					let column = columnIndex as u32 + 1;
+					match namedAreas.iter().position(|namedArea| &namedArea.name == name)
+					{
+						Some(index) =>
+						{
+							let namedArea = &mut namedAreas[index];
+							namedArea.row_start = min(namedArea.row_start, row);
+							namedArea.row_end = max(namedArea.row_end, row + 1);
+							namedArea.column_start = min(namedArea.column_start, column);
+							namedArea.column_end = max(namedArea.column_end, column + 1);
+						}
+						
+						None => namedAreas.push
+						(
+							GridNamedArea
+							{
+								name: name.clone(),
+								row_start: row,
+								row_end: row + 1,
+								column_start: column,
+								column_end: column + 1,
+							}
+						),
+					}
+				}
+			}
+		}
+		namedAreas
+	}
+	
+	/// Validates rows of cells; the rows must all have the same, non-zero, number of cells and each name must form a single filled-in rectangle.
+	pub(crate) fn from_rows<'i>(rows: Vec<Vec<Option<Atom>>>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let numberOfColumns = rows[0].len();
+		if rows.iter().any(|row| row.len() != numberOfColumns)
+		{
+			return Err(ParseError::Custom(CustomParseError::GridTemplateAreasRowsMustHaveTheSameNumberOfCells));
+		}
+		
+		let gridTemplateAreas = GridTemplateAreas(rows);
+		for namedArea in gridTemplateAreas.named_areas()
+		{
+			for row in (namedArea.row_start - 1) .. (namedArea.row_end - 1)
+			{
+				for column in (namedArea.column_start - 1) .. (namedArea.column_end - 1)
+				{
+					if gridTemplateAreas.0[row as usize][column as usize].as_ref() != Some(&namedArea.name)
+					{
+						return Err(ParseError::Custom(CustomParseError::GridTemplateAreaIsNotRectangular(namedArea.name)));
+					}
+				}
+			}
+		}
+		Ok(gridTemplateAreas)
+	}
+	
+	/// Parses the cells in a row's string: a sequence of name code points is a named cell and a sequence of one or more `.` is a null cell; they may be separated by whitespace.
+	pub(crate) fn parse_row<'i>(string: &CowRcStr<'i>) -> Result<Vec<Option<Atom>>, ParseError<'i, CustomParseError<'i>>>
+	{
+		#[inline(always)]
+		fn is_name_code_point(character: char) -> bool
+		{
+			match character
+			{
+				'a' ... 'z' | 'A' ... 'Z' | '0' ... '9' | '-' | '_' => true,
+				_ => !character.is_ascii(),
+			}
+		}
+		
+		let mut cells = Vec::new();
+		let mut characters = string.chars().peekable();
+		while let Some(character) = characters.next()
+		{
+			match character
+			{
+				' ' | '\t' | '\n' | '\r' | '\x0C' => (),
+				
+				'.' =>
+				{
+					while characters.peek() == Some(&'.')
+					{
+						characters.next();
+					}
+					cells.push(None);
+				}
+				
+				_ if is_name_code_point(character) =>
+				{
+					let mut name = String::new();
+					name.push(character);
+					while let Some(&next) = characters.peek()
+					{
+						if !is_name_code_point(next)
+						{
+							break;
+						}
+						name.push(next);
+						characters.next();
+					}
+					cells.push(Some(Atom::from(name)));
+				}
+				
+				_ => return Err(ParseError::Custom(CustomParseError::GridTemplateAreasContainsAnInvalidCharacter(character))),
+			}
+		}
+		
+		if cells.is_empty()
+		{
+			return Err(ParseError::Custom(CustomParseError::GridTemplateAreasRowMustHaveAtLeastOneCell));
+		}
+		Ok(cells)
+	}
+	
+	pub(crate) fn write_row<W: fmt::Write>(&self, row: usize, dest: &mut W) -> fmt::Result
+	{
+		let mut string = String::new();
+		for cell in self.0[row].iter()
+		{
+			if !string.is_empty()
+			{
+				string.push(' ');
+			}
+			match *cell
+			{
+				None => string.push('.'),
+				Some(ref name) => string.push_str(name),
+			}
+		}
+		serialize_string(&string, dest)
+	}
+}
@@ -0,0 +1,129 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A `<track-breadth>` of a grid track: https://drafts.csswg.org/css-grid-2/#typedef-track-breadth
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum GridTrackBreadth
+{
+	/// invariant or calculated non-negative length or non-negative percentage
+	value(CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>),
+	
+	/// A non-negative flexible length, such as `1fr`.
+	flex(CssUnsignedNumber),
+	
+	/// `min-content`
+	min_content,
+	
+	/// `max-content`
+	max_content,
+	
+	/// `auto`
+	auto,
+}
+
+impl Default for GridTrackBreadth
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		GridTrackBreadth::auto
+	}
+}
+
+impl ToCss for GridTrackBreadth
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::GridTrackBreadth::*;
+		
+		match *self
+		{
+			value(ref lengthOrPercentage) => lengthOrPercentage.to_css(dest),
+			
+			flex(ref flexibleLength) =>
+			{
+				flexibleLength.to_css(dest)?;
+				dest.write_str("fr")
+			}
+			
+			min_content => dest.write_str("min-content"),
+			
+			max_content => dest.write_str("max-content"),
+			
+			auto => dest.write_str("auto"),
+		}
+	}
+}
+
+impl GridTrackBreadth
+{
+	/// Whether this is a flexible length, such as `1fr`.
+	#[inline(always)]
+	pub fn is_flexible(&self) -> bool
+	{
+		match *self
+		{
+			GridTrackBreadth::flex(_) => true,
+			_ => false,
+		}
+	}
+	
+	/// Whether this is a length or percentage, ie a `<fixed-breadth>`.
+	#[inline(always)]
+	pub fn is_fixed(&self) -> bool
+	{
+		match *self
+		{
+			GridTrackBreadth::value(_) => true,
+			_ => false,
+		}
+	}
+	
+	/// Whether this value is a `var()`, which might be substituted by more than one value.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		match *self
+		{
+			GridTrackBreadth::value(ref lengthOrPercentage) => lengthOrPercentage.is_var(),
+			_ => false,
+		}
+	}
+	
+	pub(crate) fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::GridTrackBreadth::*;
+		
+		if let Ok(ident) = input.try(|input| input.expect_ident_cloned())
+		{
+			return match_ignore_ascii_case!
+			{
+				&*ident,
+				
+				"min-content" => Ok(min_content),
+				
+				"max-content" => Ok(max_content),
+				
+				"auto" => Ok(auto),
+				
+				_ => Err(ParseError::Basic(BasicParseError::UnexpectedToken(Token::Ident(ident)))),
+			}
+		}
+		
+		if let Ok(flexibleLength) = input.try(|input|
+		{
+			match *input.next()?
+			{
+				Token::Dimension { value: number, ref unit, .. } if unit.eq_ignore_ascii_case("fr") => CssUnsignedNumber::parseNumber(number, None),
+				
+				ref unexpectedToken @ _ => CustomParseError::unexpectedToken(unexpectedToken),
+			}
+		})
+		{
+			return Ok(flex(flexibleLength));
+		}
+		
+		Ok(value(LengthOrPercentageUnit::parse_one_outside_calc_function(context, input)?))
+	}
+}
@@ -0,0 +1,99 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `grid-template-columns` and `grid-template-rows` properties: https://drafts.csswg.org/css-grid-2/#track-sizing
+/// An empty list is `none`; `subgrid` and `masonry` are not supported.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct GridTrackList(pub Vec<GridTrackListComponent>);
+
+impl ToCss for GridTrackList
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.is_none()
+		{
+			dest.write_str("none")
+		}
+		else
+		{
+			GridTrackListComponent::write_components(&self.0, dest)
+		}
+	}
+}
+
+impl PropertyValue for GridTrackList
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"grid-template-columns",
+		"grid-template-rows",
+	];
+	
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			return Ok(GridTrackList::default());
+		}
+		
+		let components = GridTrackListComponent::parse_components(context, input, true)?;
+		
+		let numberOfAutomaticRepeats = components.iter().filter(|component| match **component
+		{
+			GridTrackListComponent::repeat(count, _) => count.is_automatic(),
+			_ => false,
+		}).count();
+		
+		if numberOfAutomaticRepeats > 1
+		{
+			return Err(ParseError::Custom(CustomParseError::GridTrackListMayOnlyHaveOneAutomaticRepeat));
+		}
+		
+		if numberOfAutomaticRepeats == 1 && !components.iter().all(GridTrackListComponent::is_fixed)
+		{
+			return Err(ParseError::Custom(CustomParseError::GridTrackListWithAutomaticRepeatMustOnlyHaveFixedSizes));
+		}
+		
+		Ok(GridTrackList(components))
+	}
+}
+
+impl GridTrackList
+{
+	/// Whether this is `none`, ie there are no explicit grid tracks.
+	#[inline(always)]
+	pub fn is_none(&self) -> bool
+	{
+		self.0.is_empty()
+	}
+	
+	/// Whether any track size uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		self.0.iter().any(GridTrackListComponent::is_var)
+	}
+	
+	/// Whether this list is an `<explicit-track-list>`, which is neither `none` nor contains a `repeat()`, as required by the `grid-template` shorthand property alongside `grid-template-areas`.
+	#[inline(always)]
+	pub fn is_explicit(&self) -> bool
+	{
+		!self.is_none() && self.0.iter().all(|component| match *component
+		{
+			GridTrackListComponent::repeat(..) => false,
+			_ => true,
+		})
+	}
+	
+	/// The track sizes, not counting those in a `repeat()`.
+	#[inline(always)]
+	pub fn track_sizes(&self) -> Vec<&GridTrackSize>
+	{
+		self.0.iter().filter_map(|component| match *component
+		{
+			GridTrackListComponent::track_size(ref size) => Some(size),
+			_ => None,
+		}).collect()
+	}
+}
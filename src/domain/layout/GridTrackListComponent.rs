@@ -0,0 +1,154 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// An item of a grid track list; line names and track sizes or repetitions alternate, and line names are never adjacent.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum GridTrackListComponent
+{
+	/// Names of the grid line between two tracks, or at the start or end.
+	line_names(GridLineNames),
+	
+	/// A track.
+	track_size(GridTrackSize),
+	
+	/// `repeat(count, components)`; the components never contain a `repeat()`.
+	repeat(GridRepeatCount, Vec<GridTrackListComponent>),
+}
+
+impl ToCss for GridTrackListComponent
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::GridTrackListComponent::*;
+		
+		match *self
+		{
+			line_names(ref names) => names.to_css(dest),
+			
+			track_size(ref size) => size.to_css(dest),
+			
+			repeat(ref count, ref components) =>
+			{
+				dest.write_str("repeat(")?;
+				count.to_css(dest)?;
+				dest.write_char(',')?;
+				Self::write_components(components, dest)?;
+				dest.write_char(')')
+			}
+		}
+	}
+}
+
+impl GridTrackListComponent
+{
+	/// Whether this uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		use self::GridTrackListComponent::*;
+		
+		match *self
+		{
+			line_names(_) => false,
+			
+			track_size(ref size) => size.is_var(),
+			
+			repeat(_, ref components) => components.iter().any(Self::is_var),
+		}
+	}
+	
+	/// Whether this is line names, a `<fixed-size>` or a repetition of only them.
+	#[inline(always)]
+	pub fn is_fixed(&self) -> bool
+	{
+		use self::GridTrackListComponent::*;
+		
+		match *self
+		{
+			line_names(_) => true,
+			
+			track_size(ref size) => size.is_fixed(),
+			
+			repeat(_, ref components) => components.iter().all(Self::is_fixed),
+		}
+	}
+	
+	pub(crate) fn write_components<W: fmt::Write>(components: &[Self], dest: &mut W) -> fmt::Result
+	{
+		let mut afterFirst = false;
+		for component in components.iter()
+		{
+			if afterFirst
+			{
+				dest.write_char(' ')?;
+			}
+			else
+			{
+				afterFirst = true;
+			}
+			component.to_css(dest)?;
+		}
+		Ok(())
+	}
+	
+	/// Parses `[ <line-names>? [ <track-size> | <track-repeat> ] ]+ <line-names>?`; `repeat()` is only parsed if `allowRepeat` is true.
+	pub(crate) fn parse_components<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>, allowRepeat: bool) -> Result<Vec<Self>, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::GridTrackListComponent::*;
+		
+		let mut components = Vec::new();
+		let mut hasTrack = false;
+		loop
+		{
+			if let Ok(names) = input.try(GridLineNames::parse)
+			{
+				components.push(line_names(names));
+			}
+			
+			// The list ends at the end of the input or at the `/` separating it from another value; otherwise the error is from a malformed track.
+			let state = input.state();
+			match input.next()
+			{
+				Err(_) | Ok(&Token::Delim('/')) =>
+				{
+					input.reset(&state);
+					break;
+				}
+				
+				Ok(_) => input.reset(&state),
+			}
+			
+			if allowRepeat && input.try(|input| input.expect_function_matching("repeat")).is_ok()
+			{
+				components.push(Self::parse_repeat(context, input)?);
+			}
+			else
+			{
+				components.push(track_size(GridTrackSize::parse(context, input)?));
+			}
+			hasTrack = true;
+		}
+		
+		if !hasTrack
+		{
+			return Err(ParseError::Custom(CustomParseError::GridTrackListMustHaveAtLeastOneTrack));
+		}
+		Ok(components)
+	}
+	
+	fn parse_repeat<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		input.parse_nested_block(|input|
+		{
+			let count = GridRepeatCount::parse(input)?;
+			input.expect_comma()?;
+			let components = Self::parse_components(context, input, false)?;
+			if count.is_automatic() && !components.iter().all(Self::is_fixed)
+			{
+				return Err(ParseError::Custom(CustomParseError::GridTrackListWithAutomaticRepeatMustOnlyHaveFixedSizes));
+			}
+			Ok(GridTrackListComponent::repeat(count, components))
+		})
+	}
+}
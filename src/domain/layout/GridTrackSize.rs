@@ -0,0 +1,128 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The size of a grid track: https://drafts.csswg.org/css-grid-2/#typedef-track-size
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum GridTrackSize
+{
+	/// A single breadth, such as `1fr` or `100px`.
+	breadth(GridTrackBreadth),
+	
+	/// `minmax(minimum, maximum)`; the minimum is never a flexible length.
+	minmax(GridTrackBreadth, GridTrackBreadth),
+	
+	/// `fit-content(limit)`
+	fit_content(CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>),
+}
+
+impl Default for GridTrackSize
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		GridTrackSize::breadth(GridTrackBreadth::auto)
+	}
+}
+
+impl ToCss for GridTrackSize
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::GridTrackSize::*;
+		
+		match *self
+		{
+			breadth(ref trackBreadth) => trackBreadth.to_css(dest),
+			
+			minmax(ref minimum, ref maximum) =>
+			{
+				dest.write_str("minmax(")?;
+				minimum.to_css(dest)?;
+				dest.write_char(',')?;
+				maximum.to_css(dest)?;
+				dest.write_char(')')
+			}
+			
+			fit_content(ref limit) =>
+			{
+				dest.write_str("fit-content(")?;
+				limit.to_css(dest)?;
+				dest.write_char(')')
+			}
+		}
+	}
+}
+
+impl GridTrackSize
+{
+	/// Whether this is a `<fixed-size>`, the only sizes permitted in a track list with `repeat(auto-fill, …)` or `repeat(auto-fit, …)`.
+	#[inline(always)]
+	pub fn is_fixed(&self) -> bool
+	{
+		use self::GridTrackSize::*;
+		
+		match *self
+		{
+			breadth(ref trackBreadth) => trackBreadth.is_fixed(),
+			
+			minmax(ref minimum, ref maximum) => minimum.is_fixed() || maximum.is_fixed(),
+			
+			fit_content(_) => false,
+		}
+	}
+	
+	/// Whether this value uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		use self::GridTrackSize::*;
+		
+		match *self
+		{
+			breadth(ref trackBreadth) => trackBreadth.is_var(),
+			
+			minmax(ref minimum, ref maximum) => minimum.is_var() || maximum.is_var(),
+			
+			fit_content(ref limit) => limit.is_var(),
+		}
+	}
+	
+	pub(crate) fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::GridTrackSize::*;
+		
+		if let Ok(name) = input.try(|input|
+		{
+			let name = input.expect_function()?.clone();
+			if name.eq_ignore_ascii_case("minmax") || name.eq_ignore_ascii_case("fit-content")
+			{
+				Ok(name)
+			}
+			else
+			{
+				Err(BasicParseError::UnexpectedToken(Token::Function(name)))
+			}
+		})
+		{
+			return input.parse_nested_block(|input|
+			{
+				if name.eq_ignore_ascii_case("fit-content")
+				{
+					return Ok(fit_content(LengthOrPercentageUnit::parse_one_outside_calc_function(context, input)?));
+				}
+				
+				let minimum = GridTrackBreadth::parse(context, input)?;
+				if minimum.is_flexible()
+				{
+					return Err(ParseError::Custom(CustomParseError::GridMinmaxMinimumMustNotBeFlexible));
+				}
+				input.expect_comma()?;
+				let maximum = GridTrackBreadth::parse(context, input)?;
+				Ok(minmax(minimum, maximum))
+			});
+		}
+		
+		Ok(breadth(GridTrackBreadth::parse(context, input)?))
+	}
+}
@@ -0,0 +1,38 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+use super::properties::PropertyValue;
+use super::properties::Shorthand;
+use super::properties::SpecifiedValue;
+use ::std::cmp::max;
+use ::std::cmp::min;
+
+
+#[cfg(test)]
+mod tests;
+
+
+include!("Display.rs");
+include!("DisplayInside.rs");
+include!("DisplayInternal.rs");
+include!("DisplayOutside.rs");
+include!("Flex.rs");
+include!("FlexBasis.rs");
+include!("FlexDirection.rs");
+include!("FlexFlow.rs");
+include!("FlexWrap.rs");
+include!("Gap.rs");
+include!("GapSize.rs");
+include!("GridArea.rs");
+include!("GridLine.rs");
+include!("GridLineNames.rs");
+include!("GridNamedArea.rs");
+include!("GridRepeatCount.rs");
+include!("GridTemplate.rs");
+include!("GridTemplateAreas.rs");
+include!("GridTrackBreadth.rs");
+include!("GridTrackList.rs");
+include!("GridTrackListComponent.rs");
+include!("GridTrackSize.rs");
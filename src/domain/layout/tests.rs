@@ -0,0 +1,53 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+
+
+fn assert_area_is_not_rectangular<T: PropertyValue>(css: &str, name: &str)
+{
+	match T::parse_property_value_from_css(css)
+	{
+		Err(ParseError::Custom(CustomParseError::GridTemplateAreaIsNotRectangular(ref notRectangular))) if &notRectangular[..] == name => (),
+		result @ _ => panic!("{}: {:?}", css, result.map(|value| value.to_css_string())),
+	}
+}
+
+#[test]
+fn grid_template_areas_must_be_rectangular()
+{
+	assert_area_is_not_rectangular::<GridTemplateAreas>(r#""a a" "a b""#, "a");
+	assert_area_is_not_rectangular::<GridTemplateAreas>(r#""a b a""#, "a");
+	assert_area_is_not_rectangular::<GridTemplateAreas>(r#""a b" ". b" "a b""#, "a");
+	assert_area_is_not_rectangular::<GridTemplateAreas>(r#""a a" "a .""#, "a");
+	assert_area_is_not_rectangular::<GridTemplate>(r#""a a" 10px "b a" 20px / 1fr 1fr"#, "a");
+}
+
+#[test]
+fn grid_template_areas_rows_must_have_the_same_number_of_cells()
+{
+	match GridTemplateAreas::parse_property_value_from_css(r#""a b" "a""#)
+	{
+		Err(ParseError::Custom(CustomParseError::GridTemplateAreasRowsMustHaveTheSameNumberOfCells)) => (),
+		result @ _ => panic!("{:?}", result),
+	}
+}
+
+#[test]
+fn grid_template_areas_find_named_areas()
+{
+	let areas = GridTemplateAreas::parse_property_value_from_css(r#""head head" "nav main" ". main""#).unwrap();
+	assert_eq!(areas.number_of_rows(), 3);
+	assert_eq!(areas.number_of_columns(), 2);
+	assert_eq!
+	(
+		areas.named_areas(),
+		vec!
+		[
+			GridNamedArea { name: Atom::from("head"), row_start: 1, row_end: 2, column_start: 1, column_end: 3 },
+			GridNamedArea { name: Atom::from("nav"), row_start: 2, row_end: 3, column_start: 1, column_end: 2 },
+			GridNamedArea { name: Atom::from("main"), row_start: 2, row_end: 4, column_start: 2, column_end: 3 },
+		]
+	);
+}
@@ -5,6 +5,7 @@
 use super::*;
 use super::parsers::NestedRuleParser;
 use super::parsers::separators::*;
+use self::animations::*;
 use self::atRules::VendorPrefixedAtRule;
 use self::atRules::counterStyle::*;
 use self::atRules::document::*;
@@ -17,9 +18,18 @@ use self::atRules::namespace::*;
 use self::atRules::page::*;
 use self::atRules::supports::*;
 use self::atRules::viewport::*;
+use self::boxModel::*;
+use self::customProperties::*;
+use self::effects::*;
 use self::expressions::*;
+use self::fonts::*;
+use self::generatedContent::*;
+use self::grammar::*;
+use self::images::*;
+use self::layout::*;
 use self::numbers::*;
 use self::properties::*;
+use self::transforms::*;
 use self::units::*;
 use ::precomputed_hash::PrecomputedHash;
 use ::std::ascii::AsciiExt;
@@ -43,16 +53,28 @@ use ::std::str::FromStr;
 include!("define_css_keyword_enum.rs");
 
 
+pub mod animations;
 pub mod atRules;
+pub mod boxModel;
+pub mod colors;
+pub mod customProperties;
+pub mod effects;
 pub mod expressions;
+pub mod fonts;
+pub mod generatedContent;
+pub mod grammar;
+pub mod images;
+pub mod layout;
 pub mod units;
 pub mod numbers;
 pub mod properties;
 pub mod selectors;
+pub mod transforms;
 
 
 include!("Atom.rs");
 include!("CounterStyleIdent.rs");
+include!("CssComment.rs");
 include!("CssRule.rs");
 include!("CssRules.rs");
 include!("CssRuleType.rs");
@@ -61,6 +83,8 @@ include!("HasCssRules.rs");
 include!("HasPropertyDeclarations.rs");
 include!("HasVendorPrefix.rs");
 include!("RulesMutateError.rs");
+include!("SourceSpan.rs");
+include!("SourceSpanLocation.rs");
 include!("SpecifiedUrl.rs");
 include!("StyleRule.rs");
 include!("VendorPrefix.rs");
@@ -40,15 +40,19 @@ pub trait CssNumber: Sized
 		
 		if value.is_finite()
 		{
-			if value == NegativeZero
+			if value.is_sign_negative()
 			{
-				if Self::can_be_negative()
+				if !Self::can_be_negative()
+				{
+					Err(NegativeNumberMayNotBeAllowed)
+				}
+				else if value == NegativeZero
 				{
 					Ok(Self::_construct(PositiveZero))
 				}
 				else
 				{
-					Err(NegativeNumberMayNotBeAllowed)
+					Ok(Self::_construct(value))
 				}
 			}
 			else
@@ -318,7 +318,7 @@ impl CssNumber for CssUnsignedNumber
 	
 	const Maximum: Self = CssUnsignedNumber(::std::f32::MAX);
 	
-	const Minimum: Self = CssUnsignedNumber(::std::f32::MIN);
+	const Minimum: Self = CssUnsignedNumber(0.0);
 	
 	const DotsPerInch: Self = CssUnsignedNumber(96.0);
 	
@@ -0,0 +1,340 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A component value of a property value: https://drafts.csswg.org/css-syntax-3/#component-value
+///
+/// Whitespace is preserved (as a single space); comments, which are not tokens, are not.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComponentValue
+{
+	/// Any token other than a function or the start of a block, such as an identifier, a number, a comma or whitespace.
+	preserved_token(Token<'static>),
+	
+	/// A function, such as `calc(1px + 2em)`, with its name and arguments.
+	function(String, Vec<ComponentValue>),
+	
+	/// A `( ... )` block.
+	parenthesis_block(Vec<ComponentValue>),
+	
+	/// A `[ ... ]` block.
+	square_bracket_block(Vec<ComponentValue>),
+	
+	/// A `{ ... }` block.
+	curly_bracket_block(Vec<ComponentValue>),
+}
+
+impl ToCss for ComponentValue
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::ComponentValue::*;
+		
+		match *self
+		{
+			preserved_token(ref token) => token.to_css(dest),
+			
+			function(ref name, ref arguments) =>
+			{
+				Token::Function(CowRcStr::from(&name[..])).to_css(dest)?;
+				Self::write_list(arguments, dest)?;
+				dest.write_char(')')
+			}
+			
+			parenthesis_block(ref contents) =>
+			{
+				dest.write_char('(')?;
+				Self::write_list(contents, dest)?;
+				dest.write_char(')')
+			}
+			
+			square_bracket_block(ref contents) =>
+			{
+				dest.write_char('[')?;
+				Self::write_list(contents, dest)?;
+				dest.write_char(']')
+			}
+			
+			curly_bracket_block(ref contents) =>
+			{
+				dest.write_char('{')?;
+				Self::write_list(contents, dest)?;
+				dest.write_char('}')
+			}
+		}
+	}
+}
+
+impl ComponentValue
+{
+	/// Is this whitespace?
+	#[inline(always)]
+	pub fn is_whitespace(&self) -> bool
+	{
+		match *self
+		{
+			ComponentValue::preserved_token(Token::WhiteSpace(_)) => true,
+			_ => false,
+		}
+	}
+	
+	/// Is this a function with this name (ignoring ASCII case), such as `var`?
+	#[inline(always)]
+	pub fn is_function(&self, name: &str) -> bool
+	{
+		match *self
+		{
+			ComponentValue::function(ref functionName, _) => functionName.eq_ignore_ascii_case(name),
+			_ => false,
+		}
+	}
+	
+	/// The component values nested in this function or block, or `None` for a preserved token.
+	#[inline(always)]
+	pub fn children(&self) -> Option<&Vec<ComponentValue>>
+	{
+		use self::ComponentValue::*;
+		
+		match *self
+		{
+			preserved_token(_) => None,
+			function(_, ref componentValues) | parenthesis_block(ref componentValues) | square_bracket_block(ref componentValues) | curly_bracket_block(ref componentValues) => Some(componentValues),
+		}
+	}
+	
+	/// The component values nested in this function or block, to edit them, or `None` for a preserved token.
+	#[inline(always)]
+	pub fn children_mut(&mut self) -> Option<&mut Vec<ComponentValue>>
+	{
+		use self::ComponentValue::*;
+		
+		match *self
+		{
+			preserved_token(_) => None,
+			function(_, ref mut componentValues) | parenthesis_block(ref mut componentValues) | square_bracket_block(ref mut componentValues) | curly_bracket_block(ref mut componentValues) => Some(componentValues),
+		}
+	}
+	
+	/// If this is a `var()` function, the name of the custom property it refers to and its fallback, if any (without the comma and any whitespace after it).
+	/// A fallback may itself contain `var()` functions.
+	pub fn var_function_arguments(&self) -> Option<(&str, Option<&[ComponentValue]>)>
+	{
+		if !self.is_function("var")
+		{
+			return None;
+		}
+		
+		let arguments = self.children().unwrap();
+		let mut index = 0;
+		while index < arguments.len() && arguments[index].is_whitespace()
+		{
+			index += 1;
+		}
+		
+		let name = match arguments.get(index)
+		{
+			Some(&ComponentValue::preserved_token(Token::Ident(ref name))) => name.as_ref(),
+			_ => return None,
+		};
+		
+		let fallback = arguments[index + 1 ..].iter().position(|argument| *argument == ComponentValue::preserved_token(Token::Comma)).map(|commaIndex|
+		{
+			let mut fallbackIndex = index + 1 + commaIndex + 1;
+			while fallbackIndex < arguments.len() && arguments[fallbackIndex].is_whitespace()
+			{
+				fallbackIndex += 1;
+			}
+			&arguments[fallbackIndex ..]
+		});
+		
+		Some((name, fallback))
+	}
+	
+	/// Visits this component value and then those nested in it, at any depth, in the order they occur.
+	pub fn walk<Visit: FnMut(&ComponentValue)>(&self, visit: &mut Visit)
+	{
+		visit(self);
+		if let Some(children) = self.children()
+		{
+			for child in children.iter()
+			{
+				child.walk(visit);
+			}
+		}
+	}
+	
+	/// Visits, and may change, this component value and then those nested in it (after any change), at any depth, in the order they occur.
+	pub fn walk_mut<Visit: FnMut(&mut ComponentValue)>(&mut self, visit: &mut Visit)
+	{
+		visit(self);
+		if let Some(children) = self.children_mut()
+		{
+			for child in children.iter_mut()
+			{
+				child.walk_mut(visit);
+			}
+		}
+	}
+	
+	/// The token which starts this component value, eg `Token::Function("calc")` for `calc(1px + 2em)`.
+	#[inline(always)]
+	pub fn first_token(&self) -> Token<'static>
+	{
+		use self::ComponentValue::*;
+		
+		match *self
+		{
+			preserved_token(ref token) => token.clone(),
+			function(ref name, _) => Token::Function(CowRcStr::from(name.clone())),
+			parenthesis_block(_) => Token::ParenthesisBlock,
+			square_bracket_block(_) => Token::SquareBracketBlock,
+			curly_bracket_block(_) => Token::CurlyBracketBlock,
+		}
+	}
+	
+	/// The number of component values nested inside this one, at any depth.
+	#[inline(always)]
+	pub fn number_of_descendants(&self) -> usize
+	{
+		use self::ComponentValue::*;
+		
+		match *self
+		{
+			preserved_token(_) => 0,
+			function(_, ref componentValues) | parenthesis_block(ref componentValues) | square_bracket_block(ref componentValues) | curly_bracket_block(ref componentValues) => componentValues.iter().map(|componentValue| 1 + componentValue.number_of_descendants()).sum(),
+		}
+	}
+	
+	/// Writes a list of component values; a comment is written between adjacent tokens which would otherwise run together (such as two identifiers originally separated by a comment).
+	pub fn write_list<W: fmt::Write>(componentValues: &[ComponentValue], dest: &mut W) -> fmt::Result
+	{
+		let mut previous = TokenSerializationType::nothing();
+		for componentValue in componentValues.iter()
+		{
+			let first = componentValue.first_token().serialization_type();
+			if previous.needs_separator_when_before(first)
+			{
+				dest.write_str("/**/")?;
+			}
+			componentValue.to_css(dest)?;
+			
+			previous = match *componentValue
+			{
+				ComponentValue::preserved_token(ref token) => token.serialization_type(),
+				_ => Token::CloseParenthesis.serialization_type(),
+			};
+		}
+		Ok(())
+	}
+	
+	/// Serializes a list of component values; see `write_list()`.
+	#[inline(always)]
+	pub fn list_to_css_string(componentValues: &[ComponentValue]) -> String
+	{
+		let mut css = String::new();
+		Self::write_list(componentValues, &mut css).unwrap();
+		css
+	}
+	
+	/// Parses CSS, such as a property value, into component values.
+	#[inline(always)]
+	pub fn parse_css<'i>(css: &'i str) -> Result<Vec<Self>, ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut parserInput = ParserInput::new(css);
+		let mut input = Parser::new(&mut parserInput);
+		input.parse_entirely(Self::parse_list)
+	}
+	
+	/// Parses a list of component values until the input is exhausted.
+	pub(crate) fn parse_list<'i, 't>(input: &mut Parser<'i, 't>) -> Result<Vec<Self>, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::ComponentValue::*;
+		
+		let mut componentValues = Vec::new();
+		loop
+		{
+			let token = match input.next_including_whitespace()
+			{
+				Ok(token) => token.clone(),
+				Err(_) => return Ok(componentValues),
+			};
+			
+			let componentValue = match token
+			{
+				Token::Function(ref name) => function(name.as_ref().to_owned(), input.parse_nested_block(Self::parse_list)?),
+				
+				Token::ParenthesisBlock => parenthesis_block(input.parse_nested_block(Self::parse_list)?),
+				
+				Token::SquareBracketBlock => square_bracket_block(input.parse_nested_block(Self::parse_list)?),
+				
+				Token::CurlyBracketBlock => curly_bracket_block(input.parse_nested_block(Self::parse_list)?),
+				
+				Token::BadUrl(url) => return Err(ParseError::Custom(CustomParseError::BadUrlInDeclarationValueBlock(url))),
+				
+				Token::BadString(string) => return Err(ParseError::Custom(CustomParseError::BadStringInDeclarationValueBlock(string))),
+				
+				Token::CloseParenthesis => return Err(ParseError::Custom(CustomParseError::UnbalancedCloseParenthesisInDeclarationValueBlock)),
+				
+				Token::CloseSquareBracket => return Err(ParseError::Custom(CustomParseError::UnbalancedCloseSquareBracketInDeclarationValueBlock)),
+				
+				Token::CloseCurlyBracket => return Err(ParseError::Custom(CustomParseError::UnbalancedCloseCurlyBracketInDeclarationValueBlock)),
+				
+				token @ _ => preserved_token(Self::to_owned_token(token)),
+			};
+			
+			// Whitespace either side of a comment is preserved as a single space.
+			if componentValue.is_whitespace() && componentValues.last().map(ComponentValue::is_whitespace).unwrap_or(false)
+			{
+				continue;
+			}
+			componentValues.push(componentValue);
+		}
+	}
+	
+	fn to_owned_token<'i>(token: Token<'i>) -> Token<'static>
+	{
+		use ::cssparser::Token::*;
+		
+		#[inline(always)]
+		fn owned<'i>(value: CowRcStr<'i>) -> CowRcStr<'static>
+		{
+			CowRcStr::from(value.as_ref().to_owned())
+		}
+		
+		match token
+		{
+			Ident(value) => Ident(owned(value)),
+			AtKeyword(value) => AtKeyword(owned(value)),
+			Hash(value) => Hash(owned(value)),
+			IDHash(value) => IDHash(owned(value)),
+			QuotedString(value) => QuotedString(owned(value)),
+			UnquotedUrl(value) => UnquotedUrl(owned(value)),
+			Delim(value) => Delim(value),
+			Number { has_sign, value, int_value } => Number { has_sign, value, int_value },
+			Percentage { has_sign, unit_value, int_value } => Percentage { has_sign, unit_value, int_value },
+			Dimension { has_sign, value, int_value, unit } => Dimension { has_sign, value, int_value, unit: owned(unit) },
+			WhiteSpace(_) => WhiteSpace(" "),
+			Comment(_) => WhiteSpace(" "),
+			Colon => Colon,
+			Semicolon => Semicolon,
+			Comma => Comma,
+			IncludeMatch => IncludeMatch,
+			DashMatch => DashMatch,
+			PrefixMatch => PrefixMatch,
+			SuffixMatch => SuffixMatch,
+			SubstringMatch => SubstringMatch,
+			Column => Column,
+			CDO => CDO,
+			CDC => CDC,
+			Function(value) => Function(owned(value)),
+			ParenthesisBlock => ParenthesisBlock,
+			SquareBracketBlock => SquareBracketBlock,
+			CurlyBracketBlock => CurlyBracketBlock,
+			BadUrl(value) => BadUrl(owned(value)),
+			BadString(value) => BadString(owned(value)),
+			CloseParenthesis => CloseParenthesis,
+			CloseSquareBracket => CloseSquareBracket,
+			CloseCurlyBracket => CloseCurlyBracket,
+		}
+	}
+}
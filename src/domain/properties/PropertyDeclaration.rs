@@ -2,13 +2,71 @@
 // Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
 
 
-#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+/// Only the vendor prefix, name, value and importance are compared and hashed; comments, source spans and how the name was written are not.
+#[derive(Debug, Clone)]
 pub struct PropertyDeclaration<I: HasImportance>
 {
 	pub vendor_prefix: Option<VendorPrefix>,
 	pub name: Atom,
 	pub value: UnparsedPropertyValue,
 	pub importance: I,
+	
+	/// The names of the custom properties this declaration's value refers to in `var()` functions, including those in fallbacks.
+	/// Kept up-to-date by `set_typed_value()`; call `update_references()` after editing the value's component values.
+	pub references: BTreeSet<Atom>,
+	
+	/// The name, including the vendor prefix, as it was written in the source CSS; only present for vendor-prefixed names not written in lower case.
+	/// Used when serializing with `VendorPrefixCase::Preserve`.
+	pub vendor_prefixed_name_as_written: Option<String>,
+	
+	/// Any comments immediately before this declaration; only present if comments were preserved when parsing.
+	pub comments_before: Vec<CssComment>,
+	
+	/// Where this declaration (including any trailing semicolon) was in the source CSS, if source spans were recorded when parsing.
+	pub source_span: Option<SourceSpan>,
+}
+
+impl<I: HasImportance> PartialEq for PropertyDeclaration<I>
+{
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool
+	{
+		self.vendor_prefix == other.vendor_prefix && self.name == other.name && self.value == other.value && self.importance == other.importance
+	}
+}
+
+impl<I: HasImportance> Eq for PropertyDeclaration<I>
+{
+}
+
+impl<I: HasImportance> PartialOrd for PropertyDeclaration<I>
+{
+	#[inline(always)]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		Some(self.cmp(other))
+	}
+}
+
+impl<I: HasImportance> Ord for PropertyDeclaration<I>
+{
+	#[inline(always)]
+	fn cmp(&self, other: &Self) -> Ordering
+	{
+		(&self.vendor_prefix, &self.name, &self.value, &self.importance).cmp(&(&other.vendor_prefix, &other.name, &other.value, &other.importance))
+	}
+}
+
+impl<I: HasImportance> Hash for PropertyDeclaration<I>
+{
+	#[inline(always)]
+	fn hash<H: Hasher>(&self, state: &mut H)
+	{
+		self.vendor_prefix.hash(state);
+		self.name.hash(state);
+		self.value.hash(state);
+		self.importance.hash(state)
+	}
 }
 
 impl<I: HasImportance> ToCss for PropertyDeclaration<I>
@@ -44,8 +102,78 @@ impl<I: HasImportance> PropertyDeclaration<I>
 		self.name.eq_ignore_ascii_case(name)
 	}
 	
+	/// Parses this declaration's value as a `T`, if this declaration is for one of the properties whose values are of type `T` (the vendor prefix, if any, is ignored).
+	/// Returns `None` for other properties and for CSS-wide keywords such as `inherit`.
+	/// Values which use `var()` can not be parsed until the custom properties have been substituted, so are errors.
+	/// Parses using `ParseLimits::for_untrusted_input()`; see `typed_value_with_limits()`.
+	#[inline(always)]
+	pub fn typed_value<'a, T: PropertyValue>(&'a self) -> Option<Result<T, ParseError<'a, CustomParseError<'a>>>>
+	{
+		self.typed_value_with_limits(ParseLimits::for_untrusted_input())
+	}
+	
+	/// As `typed_value()`, but parses using the given `limits`, such as those the stylesheet was parsed with.
+	#[inline(always)]
+	pub fn typed_value_with_limits<'a, T: PropertyValue>(&'a self, limits: ParseLimits) -> Option<Result<T, ParseError<'a, CustomParseError<'a>>>>
+	{
+		if !T::is_value_of_property(&self.name)
+		{
+			return None;
+		}
+		
+		match self.value
+		{
+			UnparsedPropertyValue::CssWideKeyword(_) => None,
+			UnparsedPropertyValue::SpecifiedValue(ref specifiedValue) => Some(T::parse_property_value_from_css_with_limits(specifiedValue.original_css(), limits)),
+		}
+	}
+	
+	/// Validates this declaration's value against the grammar of its property (the vendor prefix, if any, is ignored) from the bundled table of property grammars; see `Grammar`.
+	/// CSS-wide keywords, such as `inherit`, are valid for all properties. Values which use `var()` can not be validated until the custom properties have been substituted, so are errors.
+	/// Data types, such as `<length>`, are parsed using `ParseLimits::for_untrusted_input()`; see `match_grammar_with_limits()`.
+	#[inline(always)]
+	pub fn match_grammar<'a>(&'a self) -> Result<GrammarMatch, ParseError<'a, CustomParseError<'a>>>
+	{
+		self.match_grammar_with_limits(ParseLimits::for_untrusted_input())
+	}
+	
+	/// As `match_grammar()`, but parses data types using the given `limits`, such as those the stylesheet was parsed with.
+	#[inline(always)]
+	pub fn match_grammar_with_limits<'a>(&'a self, limits: ParseLimits) -> Result<GrammarMatch, ParseError<'a, CustomParseError<'a>>>
+	{
+		let grammar = Grammar::for_property(&self.name)?;
+		
+		match self.value
+		{
+			UnparsedPropertyValue::CssWideKeyword(cssWideKeyword) => Ok(GrammarMatch::keyword(cssWideKeyword.to_css_string())),
+			UnparsedPropertyValue::SpecifiedValue(ref specifiedValue) => grammar.match_specified_value_with_limits(specifiedValue, limits),
+		}
+	}
+	
+	/// Replaces this declaration's value with a typed value, which is serialized in its shortest form.
+	/// Does not check that this declaration is for a property whose values are of type `T`.
+	/// Fails, leaving this declaration unchanged, if the serialization does not tokenize (see `SpecifiedValue::from_typed_value()`).
+	#[inline(always)]
+	pub fn set_typed_value<T: PropertyValue>(&mut self, value: &T) -> Result<(), ()>
+	{
+		self.value = UnparsedPropertyValue::SpecifiedValue(SpecifiedValue::from_typed_value(value)?);
+		self.update_references();
+		Ok(())
+	}
+	
+	/// Updates `references` from this declaration's value, such as after editing the value's component values.
+	#[inline(always)]
+	pub fn update_references(&mut self)
+	{
+		self.references = match self.value
+		{
+			UnparsedPropertyValue::CssWideKeyword(_) => BTreeSet::new(),
+			UnparsedPropertyValue::SpecifiedValue(ref specifiedValue) => specifiedValue.references(),
+		};
+	}
+	
 	#[inline(always)]
-	fn to_css_without_trailing_semicolon<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	pub(crate) fn to_css_without_trailing_semicolon<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
 	{
 		if let Some(ref vendorPrefix) = self.vendor_prefix
 		{
@@ -15,15 +15,17 @@ impl<I: HasImportance> ToCss for PropertyDeclarations<I>
 		{
 			for index in 0..(length - 1)
 			{
-				(unsafe { self.0.get_unchecked(index) }).to_css(dest)?;
+				let propertyDeclaration = unsafe { self.0.get_unchecked(index) };
+				Self::write_comments(&propertyDeclaration.comments_before, dest)?;
+				propertyDeclaration.to_css(dest)?;
 			}
 			
-			(unsafe { self.0.get_unchecked(length -1) }).to_css_without_trailing_semicolon(dest)
-		}
-		else
-		{
-			Ok(())
+			let lastPropertyDeclaration = unsafe { self.0.get_unchecked(length -1) };
+			Self::write_comments(&lastPropertyDeclaration.comments_before, dest)?;
+			lastPropertyDeclaration.to_css_without_trailing_semicolon(dest)?;
 		}
+		
+		Ok(())
 	}
 }
 
@@ -68,25 +70,357 @@ impl<I: HasImportance> PropertyDeclarations<I>
 		self.0.is_empty()
 	}
 	
-	// Parse a list of property declarations and return a property declaration block.
-	pub(crate) fn parse_property_declaration_list<'i: 't, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<PropertyDeclarations<I>, ParseError<'i, CustomParseError<'i>>>
+	/// Replaces each declaration of a shorthand property whose values are of type `S` with declarations of its longhand properties, which have the same importance.
+	/// Declarations with a vendor prefix, or whose value can not be parsed or uses `var()`, are left unchanged; a CSS-wide keyword, such as `inherit`, is given to every longhand property.
+	pub fn expand_shorthands<S: Shorthand>(&mut self)
+	{
+		let propertyDeclarations = replace(&mut self.0, Vec::new());
+		let mut expandedPropertyDeclarations = Vec::with_capacity(propertyDeclarations.len());
+		for propertyDeclaration in propertyDeclarations
+		{
+			match Self::expand_shorthand::<S>(&propertyDeclaration)
+			{
+				None => expandedPropertyDeclarations.push(propertyDeclaration),
+				Some(longhandPropertyDeclarations) => expandedPropertyDeclarations.extend(longhandPropertyDeclarations),
+			}
+		}
+		self.0 = expandedPropertyDeclarations;
+	}
+	
+	/// Replaces each complete set of declarations of the longhand properties of a shorthand property whose values are of type `S` with a declaration of the shorthand property, serialized in its shortest form.
+	/// A set is only folded if each of its longhand properties is declared exactly once without a vendor prefix, all of the declarations have the same importance, their values are either all the same CSS-wide keyword or can all be parsed without `var()`, and no declaration of the shorthand property or of a related property (such as `margin-inline-start` for `margin`) is amongst them.
+	/// The declaration of the shorthand property replaces the first of the declarations of the longhand properties, and keeps all of their comments.
+	pub fn fold_into_shorthands<S: Shorthand>(&mut self)
+	{
+		for shorthandPropertyName in S::PropertyNames.iter()
+		{
+			if let Some((mut indices, shorthandPropertyDeclaration)) = self.fold_into_shorthand::<S>(shorthandPropertyName)
+			{
+				indices.sort();
+				let first = indices[0];
+				let propertyDeclarations = replace(&mut self.0, Vec::new());
+				let mut shorthandPropertyDeclaration = Some(shorthandPropertyDeclaration);
+				for (index, propertyDeclaration) in propertyDeclarations.into_iter().enumerate()
+				{
+					if index == first
+					{
+						self.0.push(shorthandPropertyDeclaration.take().unwrap());
+					}
+					else if indices.binary_search(&index).is_err()
+					{
+						self.0.push(propertyDeclaration);
+					}
+				}
+			}
+		}
+	}
+	
+	/// Expands the `margin`, `padding` and `inset` shorthand properties, and their logical equivalents such as `margin-inline` and `padding-block`, into their longhand properties; see `expand_shorthands()`.
+	pub fn expand_box_model_shorthands(&mut self)
+	{
+		self.expand_shorthands::<FourSides<LengthOrPercentageOrAuto<CssSignedNumber>>>();
+		self.expand_shorthands::<FourSides<CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>>>();
+		self.expand_shorthands::<TwoSides<LengthOrPercentageOrAuto<CssSignedNumber>>>();
+		self.expand_shorthands::<TwoSides<CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>>>();
+	}
+	
+	/// Folds complete sets of the longhand properties of the `margin`, `padding` and `inset` shorthand properties, and their logical equivalents such as `margin-inline` and `padding-block`, into their shorthand properties; see `fold_into_shorthands()`.
+	pub fn fold_box_model_longhands(&mut self)
+	{
+		self.fold_into_shorthands::<FourSides<LengthOrPercentageOrAuto<CssSignedNumber>>>();
+		self.fold_into_shorthands::<FourSides<CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>>>();
+		self.fold_into_shorthands::<TwoSides<LengthOrPercentageOrAuto<CssSignedNumber>>>();
+		self.fold_into_shorthands::<TwoSides<CalculablePropertyValue<LengthOrPercentageUnit<CssUnsignedNumber>>>>();
+	}
+	
+	/// Expands the `font` and `font-variant` shorthand properties into their longhand properties; see `expand_shorthands()`.
+	/// The `font` shorthand property is expanded into all of the font properties it resets, too.
+	pub fn expand_font_shorthands(&mut self)
+	{
+		self.expand_shorthands::<Font>();
+		self.expand_shorthands::<FontVariant>();
+	}
+	
+	/// Folds complete sets of the longhand properties of the `font` and `font-variant` shorthand properties into their shorthand properties; see `fold_into_shorthands()`.
+	pub fn fold_font_longhands(&mut self)
+	{
+		self.fold_into_shorthands::<Font>();
+		self.fold_into_shorthands::<FontVariant>();
+	}
+	
+	/// Expands the `background` shorthand property into its longhand properties; see `expand_shorthands()`.
+	pub fn expand_background_shorthands(&mut self)
+	{
+		self.expand_shorthands::<Background>();
+	}
+	
+	/// Folds complete sets of the longhand properties of the `background` shorthand property into it; see `fold_into_shorthands()`.
+	pub fn fold_background_longhands(&mut self)
+	{
+		self.fold_into_shorthands::<Background>();
+	}
+	
+	/// Expands the `transition` and `animation` shorthand properties into their longhand properties; see `expand_shorthands()`.
+	pub fn expand_animation_shorthands(&mut self)
+	{
+		self.expand_shorthands::<Transition>();
+		self.expand_shorthands::<Animation>();
+	}
+	
+	/// Folds complete sets of the longhand properties of the `transition` and `animation` shorthand properties into them; see `fold_into_shorthands()`.
+	pub fn fold_animation_longhands(&mut self)
+	{
+		self.fold_into_shorthands::<Transition>();
+		self.fold_into_shorthands::<Animation>();
+	}
+	
+	/// Expands the `flex`, `flex-flow`, `gap`, `grid-template` and `grid-area` shorthand properties into their longhand properties; see `expand_shorthands()`.
+	pub fn expand_layout_shorthands(&mut self)
+	{
+		self.expand_shorthands::<Flex>();
+		self.expand_shorthands::<FlexFlow>();
+		self.expand_shorthands::<Gap>();
+		self.expand_shorthands::<GridTemplate>();
+		self.expand_shorthands::<GridArea>();
+	}
+	
+	/// Folds complete sets of the longhand properties of the `flex`, `flex-flow`, `gap`, `grid-template` and `grid-area` shorthand properties into them; see `fold_into_shorthands()`.
+	pub fn fold_layout_longhands(&mut self)
+	{
+		self.fold_into_shorthands::<Flex>();
+		self.fold_into_shorthands::<FlexFlow>();
+		self.fold_into_shorthands::<Gap>();
+		self.fold_into_shorthands::<GridTemplate>();
+		self.fold_into_shorthands::<GridArea>();
+	}
+	
+	/// Calls `rewrite` with every URL of an image in the values of the `background`, `background-image`, `mask-image`, `list-style-image` and `border-image-source` properties, including those of all the candidates of an `image-set()`, so that it can be changed in place.
+	/// Only values which `rewrite` changes are replaced, and they are serialized in their shortest form; values which can not be parsed or use `var()` are left unchanged.
+	pub fn rewrite_image_urls<Rewrite: FnMut(&mut SpecifiedUrl)>(&mut self, mut rewrite: Rewrite)
+	{
+		for propertyDeclaration in self.0.iter_mut()
+		{
+			Self::rewrite_typed_value::<Background, _>(propertyDeclaration, |background| background.rewrite_urls(&mut rewrite));
+			Self::rewrite_typed_value::<Layers<ImageOrNone>, _>(propertyDeclaration, |images| images.rewrite_urls(&mut rewrite));
+			Self::rewrite_typed_value::<ImageOrNone, _>(propertyDeclaration, |image| image.rewrite_urls(&mut rewrite));
+		}
+	}
+	
+	/// Calls `rewrite` with every color in the values of the `box-shadow` and `text-shadow` properties and of the `drop-shadow()` filter functions of the `filter` and `backdrop-filter` properties, so that it can be changed in place, eg to adjust shadows for a dark theme.
+	/// Colors which are omitted, and so are `currentcolor`, are not rewritten. Only values which `rewrite` changes are replaced, and they are serialized in their shortest form; values which can not be parsed or use `var()` are left unchanged.
+	pub fn rewrite_shadow_colors<Rewrite: FnMut(&mut Color)>(&mut self, mut rewrite: Rewrite)
+	{
+		for propertyDeclaration in self.0.iter_mut()
+		{
+			Self::rewrite_typed_value::<BoxShadowList, _>(propertyDeclaration, |shadows| if !shadows.is_var() { shadows.rewrite_colors(&mut rewrite) });
+			Self::rewrite_typed_value::<TextShadowList, _>(propertyDeclaration, |shadows| if !shadows.is_var() { shadows.rewrite_colors(&mut rewrite) });
+			Self::rewrite_typed_value::<FilterList, _>(propertyDeclaration, |filters| if !filters.is_var() { filters.rewrite_colors(&mut rewrite) });
+		}
+	}
+	
+	// Parse a list of property declarations and return a property declaration block, and any comments after the last property declaration.
+	pub(crate) fn parse_property_declaration_list<'i: 't, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<(PropertyDeclarations<I>, Vec<CssComment>), ParseError<'i, CustomParseError<'i>>>
 	{
 		let mut propertyDeclarations = Vec::new();
+		let mut comments = Vec::new();
 		let mut parsedPropertyDeclarations = DeclarationListParser::new(input, PropertyDeclarationParser
 		{
 			context,
 			marker: PhantomData,
 		});
 		
-		while let Some(propertyDeclaration) = parsedPropertyDeclarations.next()
+		loop
+		{
+			comments.extend(context.parse_comments(parsedPropertyDeclarations.input, true));
+			
+			let start = context.source_span_start(parsedPropertyDeclarations.input, true);
+			
+			match parsedPropertyDeclarations.next()
+			{
+				None => break,
+				
+				Some(Ok(mut propertyDeclaration)) =>
+				{
+					if propertyDeclarations.len() >= context.limits.maximum_property_declarations_per_block
+					{
+						return CustomParseError::parseLimitExceeded(ParseLimit::PropertyDeclarationsPerBlock);
+					}
+					
+					propertyDeclaration.comments_before = replace(&mut comments, Vec::new());
+					propertyDeclaration.source_span = context.source_span(parsedPropertyDeclarations.input, &start);
+					propertyDeclarations.push(propertyDeclaration)
+				}
+				
+				Some(Err(preciseParseError)) => context.recover_from_error(preciseParseError)?,
+			}
+		}
+		
+		Ok((PropertyDeclarations(propertyDeclarations), comments))
+	}
+	
+	#[inline(always)]
+	fn rewrite_typed_value<T: PropertyValue + Clone + PartialEq, Rewrite: FnMut(&mut T)>(propertyDeclaration: &mut PropertyDeclaration<I>, mut rewrite: Rewrite)
+	{
+		let value = match propertyDeclaration.typed_value::<T>()
+		{
+			Some(Ok(value)) => value,
+			_ => return,
+		};
+		
+		let mut rewritten = value.clone();
+		rewrite(&mut rewritten);
+		if rewritten != value
+		{
+			// Left unchanged if the rewritten value does not tokenize.
+			let _ = propertyDeclaration.set_typed_value(&rewritten);
+		}
+	}
+	
+	fn expand_shorthand<S: Shorthand>(propertyDeclaration: &PropertyDeclaration<I>) -> Option<Vec<PropertyDeclaration<I>>>
+	{
+		if propertyDeclaration.vendor_prefix.is_some() || !S::is_value_of_property(&propertyDeclaration.name)
+		{
+			return None;
+		}
+		
+		let longhandPropertyNames = S::longhand_property_names(&propertyDeclaration.name);
+		
+		let longhandValues: Vec<UnparsedPropertyValue> = match propertyDeclaration.value
+		{
+			UnparsedPropertyValue::CssWideKeyword(cssWideKeyword) => longhandPropertyNames.iter().map(|_| UnparsedPropertyValue::CssWideKeyword(cssWideKeyword)).collect(),
+			
+			UnparsedPropertyValue::SpecifiedValue(ref specifiedValue) =>
+			{
+				if specifiedValue.uses_var()
+				{
+					return None;
+				}
+				let shorthand = S::parse_property_value_from_css(specifiedValue.original_css()).ok()?;
+				shorthand.expand()?.into_iter().map(UnparsedPropertyValue::SpecifiedValue).collect()
+			}
+		};
+		
+		let mut comments_before = propertyDeclaration.comments_before.clone();
+		Some
+		(
+			longhandPropertyNames.iter().zip(longhandValues.into_iter()).map(|(longhandPropertyName, value)|
+			{
+				let mut longhandPropertyDeclaration = PropertyDeclaration
+				{
+					vendor_prefix: None,
+					name: Atom::from(*longhandPropertyName),
+					value,
+					importance: propertyDeclaration.importance,
+					references: BTreeSet::new(),
+					vendor_prefixed_name_as_written: None,
+					comments_before: replace(&mut comments_before, Vec::new()),
+					source_span: None,
+				};
+				longhandPropertyDeclaration.update_references();
+				longhandPropertyDeclaration
+			}).collect()
+		)
+	}
+	
+	fn fold_into_shorthand<S: Shorthand>(&self, shorthandPropertyName: &'static str) -> Option<(Vec<usize>, PropertyDeclaration<I>)>
+	{
+		let longhandPropertyNames = S::longhand_property_names(shorthandPropertyName);
+		
+		let mut indices = Vec::with_capacity(longhandPropertyNames.len());
+		for longhandPropertyName in longhandPropertyNames.iter()
+		{
+			let mut matchingIndices = self.0.iter().enumerate().filter(|&(_, propertyDeclaration)| propertyDeclaration.isNotVendorPrefixed() && propertyDeclaration.hasAsciiNameIgnoringCase(longhandPropertyName)).map(|(index, _)| index);
+			let index = matchingIndices.next()?;
+			if matchingIndices.next().is_some()
+			{
+				return None;
+			}
+			indices.push(index);
+		}
+		
+		let importance = self.0[indices[0]].importance;
+		if indices.iter().any(|&index| self.0[index].importance != importance)
 		{
-			match propertyDeclaration
+			return None;
+		}
+		
+		let start = *indices.iter().min().unwrap();
+		let end = *indices.iter().max().unwrap();
+		for index in start..end
+		{
+			if indices.contains(&index)
 			{
-				Ok(propertyDeclaration) => propertyDeclarations.push(propertyDeclaration),
-				Err(preciseParseError) => return Err(preciseParseError.error),
+				continue;
+			}
+			
+			let propertyDeclaration = &self.0[index];
+			if propertyDeclaration.hasAsciiNameIgnoringCase(shorthandPropertyName) || longhandPropertyNames.iter().any(|longhandPropertyName| propertyDeclaration.hasAsciiNameIgnoringCase(longhandPropertyName)) || S::is_related_property(shorthandPropertyName, &propertyDeclaration.name)
+			{
+				return None;
 			}
 		}
 		
-		Ok(PropertyDeclarations(propertyDeclarations))
+		let value = match self.0[indices[0]].value
+		{
+			UnparsedPropertyValue::CssWideKeyword(cssWideKeyword) =>
+			{
+				if indices.iter().any(|&index| self.0[index].value != UnparsedPropertyValue::CssWideKeyword(cssWideKeyword))
+				{
+					return None;
+				}
+				UnparsedPropertyValue::CssWideKeyword(cssWideKeyword)
+			}
+			
+			UnparsedPropertyValue::SpecifiedValue(_) =>
+			{
+				let mut longhands = Vec::with_capacity(indices.len());
+				for &index in indices.iter()
+				{
+					match self.0[index].value
+					{
+						UnparsedPropertyValue::SpecifiedValue(ref specifiedValue) if !specifiedValue.uses_var() => longhands.push(specifiedValue),
+						_ => return None,
+					}
+				}
+				UnparsedPropertyValue::SpecifiedValue(SpecifiedValue::from_typed_value(&S::fold(&longhands)?).ok()?)
+			}
+		};
+		
+		let mut sortedIndices = indices.clone();
+		sortedIndices.sort();
+		let comments_before = sortedIndices.iter().flat_map(|&index| self.0[index].comments_before.iter().cloned()).collect();
+		
+		let mut shorthandPropertyDeclaration = PropertyDeclaration
+		{
+			vendor_prefix: None,
+			name: Atom::from(shorthandPropertyName),
+			value,
+			importance,
+			references: BTreeSet::new(),
+			vendor_prefixed_name_as_written: None,
+			comments_before,
+			source_span: None,
+		};
+		shorthandPropertyDeclaration.update_references();
+		
+		Some((indices, shorthandPropertyDeclaration))
+	}
+	
+	/// Writes these property declarations as `to_css()` does, followed by `comments` (those after the last property declaration in a block).
+	#[inline(always)]
+	pub(crate) fn to_css_followed_by_comments<W: fmt::Write>(&self, comments: &[CssComment], dest: &mut W) -> fmt::Result
+	{
+		self.to_css(dest)?;
+		Self::write_comments(comments, dest)
+	}
+	
+	#[inline(always)]
+	fn write_comments<W: fmt::Write>(comments: &[CssComment], dest: &mut W) -> fmt::Result
+	{
+		for comment in comments.iter()
+		{
+			comment.to_css(dest)?;
+		}
+		Ok(())
 	}
 }
@@ -0,0 +1,42 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A typed value of one or more properties, which can be parsed from the `SpecifiedValue` of a `PropertyDeclaration` for one of those properties (see `PropertyDeclaration::typed_value()`).
+pub trait PropertyValue: ToCss + Sized
+{
+	/// The names, without any vendor prefix and in ASCII lower case, of the properties whose values are of this type.
+	const PropertyNames: &'static [&'static str];
+	
+	/// Parses a value of this type; any trailing input is an error.
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>;
+	
+	/// Whether a property has values of this type.
+	#[inline(always)]
+	fn is_value_of_property(name: &str) -> bool
+	{
+		Self::PropertyNames.iter().any(|propertyName| propertyName.eq_ignore_ascii_case(name))
+	}
+	
+	/// Parses a value of this type from a string of CSS, such as `SpecifiedValue::original_css()`, using `ParseLimits::for_untrusted_input()`.
+	#[inline(always)]
+	fn parse_property_value_from_css<'i>(css: &'i str) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse_property_value_from_css_with_limits(css, ParseLimits::for_untrusted_input())
+	}
+	
+	/// Parses a value of this type from a string of CSS, such as `SpecifiedValue::original_css()`, using the given `limits` (of which only `maximum_input_size` and `maximum_calc_expression_depth` apply to a single value).
+	#[inline(always)]
+	fn parse_property_value_from_css_with_limits<'i>(css: &'i str, limits: ParseLimits) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if css.len() > limits.maximum_input_size
+		{
+			return CustomParseError::parseLimitExceeded(ParseLimit::InputSize);
+		}
+		
+		let context = ParserContext::new_for_property_value(limits);
+		let mut parserInput = ParserInput::new(css);
+		let mut input = Parser::new(&mut parserInput);
+		input.parse_entirely(|input| Self::parse_property_value(&context, input))
+	}
+}
@@ -0,0 +1,22 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A typed value of one or more shorthand properties, which can be expanded into the values of its longhand properties and folded back from them.
+/// See `PropertyDeclarations::expand_shorthands()` and `PropertyDeclarations::fold_into_shorthands()`.
+pub trait Shorthand: PropertyValue
+{
+	/// The names, without any vendor prefix and in ASCII lower case, of the longhand properties of a shorthand property (one of `PropertyNames`), in the order used by `expand()` and `fold()`.
+	fn longhand_property_names(shorthand_property_name: &str) -> &'static [&'static str];
+	
+	/// Whether a property other than the shorthand property and its longhands sets any of the same values, such as `margin-inline-start` for `margin`; declarations of it can not be reordered with those of the shorthand property.
+	fn is_related_property(shorthand_property_name: &str, property_name: &str) -> bool;
+	
+	/// Expands into the values of the longhand properties, in the order of `longhand_property_names()`.
+	/// Returns `None` if this value uses `var()`, as the number of values it will be substituted with is not known.
+	fn expand(&self) -> Option<Vec<SpecifiedValue>>;
+	
+	/// Folds the values of all of the longhand properties, in the order of `longhand_property_names()`, into a value of this type.
+	/// Returns `None` if any value can not be parsed or can not be represented by the shorthand property, or uses `var()`.
+	fn fold(longhands: &[&SpecifiedValue]) -> Option<Self>;
+}
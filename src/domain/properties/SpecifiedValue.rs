@@ -2,22 +2,65 @@
 // Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
 
 
-/// A specified value for a property is just a set of tokens.
+/// A specified value for a property is just a set of tokens, held as a tree of component values which can be walked and edited.
 ///
-/// The original CSS is preserved for serialization, as are variable references to other property names.
-#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+/// The original CSS, including any comments, is preserved for serialization until the component values are edited, when it is serialized from them; variable references to other property names are preserved.
+#[derive(Debug, Clone)]
 pub struct SpecifiedValue
 {
+	/// The CSS written by `to_css()`; as originally parsed or, once the component values have been edited, as serialized from them.
+	/// Assigning to this does not change `component_values()`; use `edit_component_values()` or `walk_component_values_mut()` to change this value.
 	pub originalCss: String,
 	
+	component_values: Vec<ComponentValue>,
+	
 	//first_token_type: TokenSerializationType,
 	//last_token_type: TokenSerializationType,
-	// References to property names in var() functions.
-	//pub references: HashSet<Atom>,
+}
+
+impl PartialEq for SpecifiedValue
+{
+	#[inline(always)]
+	fn eq(&self, other: &Self) -> bool
+	{
+		self.originalCss == other.originalCss
+	}
+}
+
+impl Eq for SpecifiedValue
+{
+}
+
+impl PartialOrd for SpecifiedValue
+{
+	#[inline(always)]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for SpecifiedValue
+{
+	#[inline(always)]
+	fn cmp(&self, other: &Self) -> Ordering
+	{
+		self.originalCss.cmp(&other.originalCss)
+	}
+}
+
+impl Hash for SpecifiedValue
+{
+	#[inline(always)]
+	fn hash<H: Hasher>(&self, state: &mut H)
+	{
+		self.originalCss.hash(state)
+	}
 }
 
 impl ToCss for SpecifiedValue
 {
+	#[inline(always)]
 	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
 	{
 		dest.write_str(&self.originalCss)
@@ -26,24 +69,128 @@ impl ToCss for SpecifiedValue
 
 impl SpecifiedValue
 {
-	/// Parse a custom property SpecifiedValue.
-	pub(crate) fn parse<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	/// A specified value which is the serialization, in its shortest form, of a typed value.
+	/// Fails if the serialization does not tokenize into component values, which should never happen for the typed values in this crate.
+	#[inline(always)]
+	pub fn from_typed_value<T: ToCss>(value: &T) -> Result<Self, ()>
 	{
-		let mut references = Some(HashSet::new());
-		let (_first, css, _last) = Self::parse_self_contained_declaration_value(input, &mut references)?;
+		Self::from_valid_css(value.to_css_string())
+	}
+	
+	/// A specified value made from component values, such as those of another specified value.
+	#[inline(always)]
+	pub fn from_component_values(component_values: Vec<ComponentValue>) -> Self
+	{
+		SpecifiedValue
+		{
+			originalCss: ComponentValue::list_to_css_string(&component_values),
+			component_values,
+		}
+	}
+	
+	/// The CSS of this value, as originally parsed, or, once its component values have been edited, as serialized from them.
+	#[inline(always)]
+	pub fn original_css(&self) -> &str
+	{
+		&self.originalCss
+	}
+	
+	/// The component values of this value, so that it can be walked (eg to find `url()` and `var()` functions, or to validate it against a `Grammar`) without re-tokenizing it.
+	#[inline(always)]
+	pub fn component_values(&self) -> &[ComponentValue]
+	{
+		&self.component_values
+	}
+	
+	/// Visits every component value of this value, including those nested in functions and blocks, in the order they occur.
+	#[inline(always)]
+	pub fn walk_component_values<Visit: FnMut(&ComponentValue)>(&self, visit: &mut Visit)
+	{
+		for componentValue in self.component_values.iter()
+		{
+			componentValue.walk(visit);
+		}
+	}
+	
+	/// Edits the component values of this value; the CSS is then serialized from them.
+	#[inline(always)]
+	pub fn edit_component_values<R, Edit: FnOnce(&mut Vec<ComponentValue>) -> R>(&mut self, edit: Edit) -> R
+	{
+		let result = edit(&mut self.component_values);
+		self.originalCss = ComponentValue::list_to_css_string(&self.component_values);
+		result
+	}
+	
+	/// Visits, and may change, every component value of this value, including those nested in functions and blocks; the CSS is then serialized from them.
+	/// A component value is visited before those nested in it, so replacing a function visits the replacement's arguments.
+	#[inline(always)]
+	pub fn walk_component_values_mut<Visit: FnMut(&mut ComponentValue)>(&mut self, visit: &mut Visit)
+	{
+		self.edit_component_values(|componentValues|
+		{
+			for componentValue in componentValues.iter_mut()
+			{
+				componentValue.walk_mut(visit);
+			}
+		})
+	}
+	
+	/// A specified value from CSS which should tokenize without errors, such as the serialization of a typed value or a value which has already been parsed.
+	#[inline(always)]
+	pub(crate) fn from_valid_css(originalCss: String) -> Result<Self, ()>
+	{
+		let component_values = ComponentValue::parse_css(&originalCss).map_err(|_| ())?;
+		
 		Ok
 		(
 			SpecifiedValue
 			{
-				originalCss: css.into_owned(),
-				//first_token_type: first,
-				//last_token_type: last,
-				//references: references.unwrap(),
+				originalCss,
+				component_values,
 			}
 		)
 	}
 	
-	fn parse_self_contained_declaration_value<'i, 't>(input: &mut Parser<'i, 't>, references: &mut Option<HashSet<Atom>>) -> Result<(TokenSerializationType, Cow<'i, str>, TokenSerializationType), ParseError<'i, CustomParseError<'i>>>
+	/// The names of the custom properties this value refers to in `var()` functions, including those in fallbacks.
+	pub fn references(&self) -> BTreeSet<Atom>
+	{
+		let mut references = BTreeSet::new();
+		self.walk_component_values(&mut |componentValue|
+		{
+			if let Some((name, _fallback)) = componentValue.var_function_arguments()
+			{
+				references.insert(Atom::from(name));
+			}
+		});
+		references
+	}
+	
+	/// Whether this value uses a `var()` function anywhere, such as in `calc(var(--a) * 2)`, and so can not be parsed as a typed value until the custom properties have been substituted.
+	pub fn uses_var(&self) -> bool
+	{
+		let mut usesVar = false;
+		self.walk_component_values(&mut |componentValue|
+		{
+			if componentValue.is_function("var")
+			{
+				usesVar = true;
+			}
+		});
+		usesVar
+	}
+	
+	/// Parse a custom property SpecifiedValue, and the names of the custom properties it refers to in `var()` functions.
+	pub(crate) fn parse<'i, 't>(_context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<(Self, BTreeSet<Atom>), ParseError<'i, CustomParseError<'i>>>
+	{
+		let mut references = Some(BTreeSet::new());
+		let (_first, css, _last) = Self::parse_self_contained_declaration_value(input, &mut references)?;
+		//first_token_type: first,
+		//last_token_type: last,
+		let specifiedValue = Self::from_valid_css(css.into_owned()).map_err(|()| ParseError::Custom(CustomParseError::InvalidParseState))?;
+		Ok((specifiedValue, references.unwrap()))
+	}
+	
+	fn parse_self_contained_declaration_value<'i, 't>(input: &mut Parser<'i, 't>, references: &mut Option<BTreeSet<Atom>>) -> Result<(TokenSerializationType, Cow<'i, str>, TokenSerializationType), ParseError<'i, CustomParseError<'i>>>
 	{
 		let start_position = input.position();
 		let mut missing_closing_characters = String::new();
@@ -66,7 +213,7 @@ impl SpecifiedValue
 	}
 	
 	/// https://drafts.csswg.org/css-syntax-3/#typedef-declaration-value
-	fn parse_declaration_value<'i, 't>(input: &mut Parser<'i, 't>, references: &mut Option<HashSet<Atom>>, missing_closing_characters: &mut String) -> Result<(TokenSerializationType, TokenSerializationType), ParseError<'i, CustomParseError<'i>>>
+	fn parse_declaration_value<'i, 't>(input: &mut Parser<'i, 't>, references: &mut Option<BTreeSet<Atom>>, missing_closing_characters: &mut String) -> Result<(TokenSerializationType, TokenSerializationType), ParseError<'i, CustomParseError<'i>>>
 	{
 		input.parse_until_before(Delimiter::Bang | Delimiter::Semicolon, |input|
 		{
@@ -80,7 +227,7 @@ impl SpecifiedValue
 	}
 	
 	// Like parse_declaration_value, but accept `!` and `;` since they are only invalid at the top level
-	fn parse_declaration_value_block<'i, 't>(input: &mut Parser<'i, 't>, references: &mut Option<HashSet<Atom>>, missing_closing_characters: &mut String) -> Result<(TokenSerializationType, TokenSerializationType), ParseError<'i, CustomParseError<'i>>>
+	fn parse_declaration_value_block<'i, 't>(input: &mut Parser<'i, 't>, references: &mut Option<BTreeSet<Atom>>, missing_closing_characters: &mut String) -> Result<(TokenSerializationType, TokenSerializationType), ParseError<'i, CustomParseError<'i>>>
 	{
 		let mut token_start = input.position();
 		let mut token = match input.next_including_whitespace_and_comments()
@@ -220,7 +367,7 @@ impl SpecifiedValue
 	}
 	
 	// If the var function is valid, return Ok((custom_property_name, fallback))
-	fn parse_var_function<'i, 't>(input: &mut Parser<'i, 't>, references: &mut Option<HashSet<Atom>>) -> Result<(), ParseError<'i, CustomParseError<'i>>>
+	fn parse_var_function<'i, 't>(input: &mut Parser<'i, 't>, references: &mut Option<BTreeSet<Atom>>) -> Result<(), ParseError<'i, CustomParseError<'i>>>
 	{
 		let name = input.expect_ident_cloned()?;
 		if input.try(|input| input.expect_comma()).is_ok()
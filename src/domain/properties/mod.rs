@@ -3,20 +3,32 @@
 
 
 use super::*;
+use super::colors::Color;
 use super::parsers::PropertyDeclarationParser;
 use self::Importance::*;
 use ::std::borrow::Cow;
-use ::std::collections::HashSet;
+use ::std::cmp::Ordering;
+use ::std::collections::BTreeSet;
 use ::std::fmt::Debug;
+use ::std::hash::Hash;
+use ::std::hash::Hasher;
 use ::std::marker::PhantomData;
+use ::std::mem::replace;
 use ::std::iter::Iterator;
 
 
+#[cfg(test)]
+mod tests;
+
+
+include!("ComponentValue.rs");
 include!("CssWideKeyword.rs");
 include!("DoesNotHaveImportance.rs");
 include!("Importance.rs");
 include!("HasImportance.rs");
 include!("PropertyDeclaration.rs");
 include!("PropertyDeclarations.rs");
+include!("PropertyValue.rs");
+include!("Shorthand.rs");
 include!("SpecifiedValue.rs");
 include!("UnparsedPropertyValue.rs");
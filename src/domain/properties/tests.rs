@@ -0,0 +1,121 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+use ::std::collections::hash_map::DefaultHasher;
+
+
+fn style_rule(css: &str, options: &StylesheetParseOptions) -> StyleRule
+{
+	let (stylesheet, _) = Stylesheet::parse_with_options(css, options).ok().unwrap();
+	match stylesheet.rules.0.into_iter().next()
+	{
+		Some(CssRule::Style(styleRule)) => styleRule,
+		_ => panic!("not a style rule"),
+	}
+}
+
+fn hash<T: Hash>(value: &T) -> u64
+{
+	let mut hasher = DefaultHasher::new();
+	value.hash(&mut hasher);
+	hasher.finish()
+}
+
+#[test]
+fn comments_source_spans_and_name_case_are_not_compared()
+{
+	let plain = style_rule("a{-webkit-transition:none;color:red}", &StylesheetParseOptions::default());
+	let annotated = style_rule("a{/* before */-WebKit-transition:none;\n\tcolor:red;/* after */}", &StylesheetParseOptions
+	{
+		record_source_spans: true,
+		preserve_comments: true,
+		..StylesheetParseOptions::default()
+	});
+	
+	assert_eq!(annotated.property_declarations.0[0].comments_before.len(), 1);
+	assert!(annotated.property_declarations.0[1].source_span.is_some());
+	assert_eq!(annotated.comments_after_property_declarations.len(), 1);
+	
+	assert_eq!(annotated.property_declarations, plain.property_declarations);
+	assert_eq!(annotated.property_declarations.cmp(&plain.property_declarations), Ordering::Equal);
+	assert_eq!(hash(&annotated.property_declarations), hash(&plain.property_declarations));
+}
+
+#[test]
+fn values_and_importance_are_compared()
+{
+	let options = StylesheetParseOptions::default();
+	let red = style_rule("a{color:red}", &options);
+	
+	assert!(style_rule("a{color:blue}", &options).property_declarations != red.property_declarations);
+	assert!(style_rule("a{color:red!important}", &options).property_declarations != red.property_declarations);
+	assert!(style_rule("a{-webkit-color:red}", &options).property_declarations != red.property_declarations);
+}
+
+fn edited_stylesheet<Edit: FnOnce(&mut SpecifiedValue)>(css: &str, edit: Edit) -> String
+{
+	let mut stylesheet = Stylesheet::parse(css).ok().unwrap();
+	match stylesheet.rules.0[0]
+	{
+		CssRule::Style(ref mut styleRule) => match styleRule.property_declarations.0[0].value
+		{
+			UnparsedPropertyValue::SpecifiedValue(ref mut specifiedValue) => edit(specifiedValue),
+			UnparsedPropertyValue::CssWideKeyword(_) => panic!("not a specified value"),
+		},
+		_ => panic!("not a style rule"),
+	}
+	stylesheet.to_css_string(false)
+}
+
+#[test]
+fn specified_values_keep_their_original_css_until_edited()
+{
+	let css = "a{margin:1px/* gap */2px;font-family:Times/**/New Roman;color:red}";
+	assert_eq!(edited_stylesheet(css, |_| ()), css);
+	assert_eq!(edited_stylesheet(css, |specifiedValue| specifiedValue.walk_component_values(&mut |_| ())), css);
+	
+	let edited = edited_stylesheet(css, |specifiedValue| specifiedValue.walk_component_values_mut(&mut |componentValue| if let ComponentValue::preserved_token(Token::Dimension { ref mut value, ref mut int_value, .. }) = *componentValue
+	{
+		*value *= 3.;
+		*int_value = int_value.map(|int_value| int_value * 3);
+	}));
+	assert_eq!(edited, "a{margin:3px/**/6px;font-family:Times/**/New Roman;color:red}");
+}
+
+#[test]
+fn edited_specified_values_are_serialized_from_their_component_values()
+{
+	let edited = edited_stylesheet("a{width:calc(100% - var(--gutter , 1em) )}", |specifiedValue| specifiedValue.walk_component_values_mut(&mut |componentValue|
+	{
+		let fallback = match componentValue.var_function_arguments()
+		{
+			Some((_, Some(fallback))) => fallback.to_vec(),
+			_ => return,
+		};
+		*componentValue = ComponentValue::parenthesis_block(fallback);
+	}));
+	assert_eq!(edited, "a{width:calc(100% - (1em) )}");
+	
+	let edited = edited_stylesheet("a{transition:opacity 1s}", |specifiedValue| specifiedValue.edit_component_values(|componentValues|
+	{
+		componentValues.push(ComponentValue::preserved_token(Token::Comma));
+		componentValues.extend(ComponentValue::parse_css("transform 2s").unwrap());
+	}));
+	assert_eq!(edited, "a{transition:opacity 1s,transform 2s}");
+}
+
+#[test]
+fn assigning_original_css_changes_what_is_serialized()
+{
+	assert_eq!(edited_stylesheet("a{color:red}", |specifiedValue| specifiedValue.originalCss = "blue".to_owned()), "a{color:blue}");
+}
+
+#[test]
+fn specified_values_are_made_from_typed_values()
+{
+	let specifiedValue = SpecifiedValue::from_typed_value(&Color::parse_property_value_from_css("RGB(255, 0, 0)").unwrap()).unwrap();
+	assert_eq!(specifiedValue.original_css(), "red");
+	assert_eq!(specifiedValue.component_values(), &[ComponentValue::preserved_token(Token::Ident("red".into()))][..]);
+}
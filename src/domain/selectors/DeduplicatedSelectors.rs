@@ -12,7 +12,7 @@ impl ToCss for DeduplicatedSelectors
 	{
 		let mut iter = self.0.iter();
 		dest.write_str(&iter.next().unwrap().to_css_string())?;
-		for selector in iter.next()
+		for selector in iter
 		{
 			dest.write_char(',')?;
 			dest.write_str(&selector.to_css_string())?;
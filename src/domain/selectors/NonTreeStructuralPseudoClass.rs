@@ -642,7 +642,7 @@ impl NonTreeStructuralPseudoClass
 	{
 		use ::cssparser::ParseError::*;
 		
-		ourSelectorParser.parse_internal(input, OurSelectorExt::is_false_if_any_selector_is_simple_and_only_uses_the_descendant_combinator).map_err(|error|
+		ourSelectorParser.parse_internal(input, None, OurSelectorExt::is_false_if_any_selector_is_simple_and_only_uses_the_descendant_combinator).map(|(selectors, _)| selectors).map_err(|error|
 		{
 			match error
 			{
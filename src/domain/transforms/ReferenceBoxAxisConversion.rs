@@ -0,0 +1,138 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Resolves percentages against one axis of the reference box of a transform; `attr()` always uses its fallback, as transforms are composed without an element.
+pub(crate) struct ReferenceBoxAxisConversion<'a, Conversion: 'a>
+{
+	conversion: &'a Conversion,
+	one_hundred_percent_in_absolute_units: CssSignedNumber,
+}
+
+impl<'a, Conversion: FontRelativeLengthConversion<CssSignedNumber>> FontRelativeLengthConversion<CssSignedNumber> for ReferenceBoxAxisConversion<'a, Conversion>
+{
+	#[inline(always)]
+	fn em(&self) -> CssSignedNumber
+	{
+		self.conversion.em()
+	}
+	
+	#[inline(always)]
+	fn ex(&self) -> CssSignedNumber
+	{
+		self.conversion.ex()
+	}
+	
+	#[inline(always)]
+	fn ch(&self) -> CssSignedNumber
+	{
+		self.conversion.ch()
+	}
+	
+	#[inline(always)]
+	fn rem(&self) -> CssSignedNumber
+	{
+		self.conversion.rem()
+	}
+}
+
+impl<'a, Conversion: ViewportPercentageLengthConversion<CssSignedNumber>> ViewportPercentageLengthConversion<CssSignedNumber> for ReferenceBoxAxisConversion<'a, Conversion>
+{
+	#[inline(always)]
+	fn vw(&self) -> CssSignedNumber
+	{
+		self.conversion.vw()
+	}
+	
+	#[inline(always)]
+	fn vh(&self) -> CssSignedNumber
+	{
+		self.conversion.vh()
+	}
+	
+	#[inline(always)]
+	fn vmin(&self) -> CssSignedNumber
+	{
+		self.conversion.vmin()
+	}
+	
+	#[inline(always)]
+	fn vmax(&self) -> CssSignedNumber
+	{
+		self.conversion.vmax()
+	}
+}
+
+impl<'a, Conversion> PercentageConversion<CssSignedNumber> for ReferenceBoxAxisConversion<'a, Conversion>
+{
+	#[inline(always)]
+	fn one_hundred_percent_in_absolute_units(&self) -> CssSignedNumber
+	{
+		self.one_hundred_percent_in_absolute_units
+	}
+}
+
+impl<'a, Conversion, U: Unit> AttributeConversion<U> for ReferenceBoxAxisConversion<'a, Conversion>
+{
+	#[inline(always)]
+	fn attributeValue(&self, _attribute_lower_case_name: &str) -> (Option<&str>, U)
+	{
+		(None, U::default())
+	}
+}
+
+impl<'a, Conversion: CssVariableConversion> CssVariableConversion for ReferenceBoxAxisConversion<'a, Conversion>
+{
+	#[inline(always)]
+	fn cssVariableValue(&self, css_variable_name_without_leading_double_dash: &str) -> Option<&str>
+	{
+		self.conversion.cssVariableValue(css_variable_name_without_leading_double_dash)
+	}
+}
+
+impl<'a, Conversion: ReferenceBoxConversion<CssSignedNumber>> ReferenceBoxAxisConversion<'a, Conversion>
+{
+	/// Percentages are of the width of the reference box.
+	#[inline(always)]
+	pub(crate) fn horizontal(conversion: &'a Conversion) -> Self
+	{
+		Self
+		{
+			conversion,
+			one_hundred_percent_in_absolute_units: conversion.reference_box_width(),
+		}
+	}
+	
+	/// Percentages are of the height of the reference box.
+	#[inline(always)]
+	pub(crate) fn vertical(conversion: &'a Conversion) -> Self
+	{
+		Self
+		{
+			conversion,
+			one_hundred_percent_in_absolute_units: conversion.reference_box_height(),
+		}
+	}
+	
+	/// Percentages are of one, as for scale factors.
+	#[inline(always)]
+	pub(crate) fn factor(conversion: &'a Conversion) -> Self
+	{
+		Self
+		{
+			conversion,
+			one_hundred_percent_in_absolute_units: CssSignedNumber::One,
+		}
+	}
+	
+	/// Percentages are not permitted, so are zero.
+	#[inline(always)]
+	pub(crate) fn none(conversion: &'a Conversion) -> Self
+	{
+		Self
+		{
+			conversion,
+			one_hundred_percent_in_absolute_units: CssSignedNumber::Zero,
+		}
+	}
+}
@@ -0,0 +1,164 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `rotate` property: https://drafts.csswg.org/css-transforms-2/#individual-transforms
+///
+/// The axis keywords `x`, `y` and `z` are parsed as the equivalent axis vectors; a rotation about the `z` axis is an `angle`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Rotate
+{
+	/// No rotation.
+	none,
+	
+	/// A rotation about the `z` axis, ie in the plane of the screen.
+	angle(CalculablePropertyValue<AngleUnit<CssSignedNumber>>),
+	
+	/// A rotation about the axis `[x, y, z]`.
+	axis(CalculablePropertyValue<CssSignedNumber>, CalculablePropertyValue<CssSignedNumber>, CalculablePropertyValue<CssSignedNumber>, CalculablePropertyValue<AngleUnit<CssSignedNumber>>),
+}
+
+impl Default for Rotate
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Rotate::none
+	}
+}
+
+impl ToCss for Rotate
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::Rotate::*;
+		
+		match *self
+		{
+			none => dest.write_str("none"),
+			
+			angle(ref value) => value.to_css(dest),
+			
+			axis(ref x, ref y, ref z, ref value) =>
+			{
+				let is = |component: &CalculablePropertyValue<CssSignedNumber>, expected: CssSignedNumber| *component == CalculablePropertyValue::Constant(expected);
+				let (zero, one) = (CssSignedNumber::Zero, CssSignedNumber::One);
+				
+				if is(x, one) && is(y, zero) && is(z, zero)
+				{
+					dest.write_str("x ")?;
+				}
+				else if is(x, zero) && is(y, one) && is(z, zero)
+				{
+					dest.write_str("y ")?;
+				}
+				else if is(x, zero) && is(y, zero) && is(z, one)
+				{
+					dest.write_str("z ")?;
+				}
+				else
+				{
+					x.to_css(dest)?;
+					dest.write_char(' ')?;
+					y.to_css(dest)?;
+					dest.write_char(' ')?;
+					z.to_css(dest)?;
+					dest.write_char(' ')?;
+				}
+				value.to_css(dest)
+			}
+		}
+	}
+}
+
+impl PropertyValue for Rotate
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"rotate",
+	];
+	
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::Rotate::*;
+		
+		if input.try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			return Ok(none);
+		}
+		
+		let value = input.try(|input| AngleUnit::parse_one_outside_calc_function(context, input)).ok();
+		
+		let constant = |number: CssSignedNumber| CalculablePropertyValue::Constant(number);
+		let direction = if let Ok(ident) = input.try(|input| input.expect_ident_cloned())
+		{
+			match_ignore_ascii_case!
+			{
+				&*ident,
+				
+				"x" => Some((constant(CssSignedNumber::One), constant(CssSignedNumber::Zero), constant(CssSignedNumber::Zero))),
+				
+				"y" => Some((constant(CssSignedNumber::Zero), constant(CssSignedNumber::One), constant(CssSignedNumber::Zero))),
+				
+				"z" => None,
+				
+				_ => return Err(ParseError::Basic(BasicParseError::UnexpectedToken(Token::Ident(ident.clone())))),
+			}
+		}
+		else if let Ok(x) = input.try(|input| CssSignedNumber::parse_one_outside_calc_function(context, input))
+		{
+			let y = CssSignedNumber::parse_one_outside_calc_function(context, input)?;
+			let z = CssSignedNumber::parse_one_outside_calc_function(context, input)?;
+			Some((x, y, z))
+		}
+		else
+		{
+			match value
+			{
+				Some(value) => return Ok(angle(value)),
+				None => return AngleUnit::parse_one_outside_calc_function(context, input).map(angle),
+			}
+		};
+		
+		let value = match value
+		{
+			Some(value) => value,
+			None => AngleUnit::parse_one_outside_calc_function(context, input)?,
+		};
+		
+		Ok
+		(
+			match direction
+			{
+				None => angle(value),
+				Some((x, y, z)) => axis(x, y, z, value),
+			}
+		)
+	}
+}
+
+impl Rotate
+{
+	/// Whether any component uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		match *self
+		{
+			Rotate::none => false,
+			Rotate::angle(ref value) => value.is_var(),
+			Rotate::axis(ref x, ref y, ref z, ref value) => x.is_var() || y.is_var() || z.is_var() || value.is_var(),
+		}
+	}
+	
+	/// The equivalent transform function, if any.
+	pub fn to_transform_function(&self) -> Option<TransformFunction>
+	{
+		match *self
+		{
+			Rotate::none => None,
+			Rotate::angle(ref value) => Some(TransformFunction::rotate(value.clone())),
+			Rotate::axis(ref x, ref y, ref z, ref value) => Some(TransformFunction::rotate3d(x.clone(), y.clone(), z.clone(), value.clone())),
+		}
+	}
+}
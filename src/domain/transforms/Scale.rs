@@ -0,0 +1,108 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `scale` property: https://drafts.csswg.org/css-transforms-2/#individual-transforms
+#[derive(Debug, Clone, PartialEq)]
+pub enum Scale
+{
+	/// No scaling.
+	none,
+	
+	/// A scaling by `x`, `y` (the same as `x` if absent) and `z` (`1` if absent).
+	value(CalculablePropertyValue<NumberOrPercentageUnit<CssSignedNumber>>, Option<CalculablePropertyValue<NumberOrPercentageUnit<CssSignedNumber>>>, Option<CalculablePropertyValue<NumberOrPercentageUnit<CssSignedNumber>>>),
+}
+
+impl Default for Scale
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Scale::none
+	}
+}
+
+impl ToCss for Scale
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::Scale::*;
+		
+		match *self
+		{
+			none => dest.write_str("none"),
+			
+			value(ref x, ref y, ref z) =>
+			{
+				x.to_css(dest)?;
+				if let Some(ref y) = *y
+				{
+					dest.write_char(' ')?;
+					y.to_css(dest)?;
+				}
+				if let Some(ref z) = *z
+				{
+					dest.write_char(' ')?;
+					z.to_css(dest)?;
+				}
+				Ok(())
+			}
+		}
+	}
+}
+
+impl PropertyValue for Scale
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"scale",
+	];
+	
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::Scale::*;
+		
+		if input.try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			return Ok(none);
+		}
+		
+		let x = NumberOrPercentageUnit::parse_one_outside_calc_function(context, input)?;
+		let y = input.try(|input| NumberOrPercentageUnit::parse_one_outside_calc_function(context, input)).ok();
+		let z = if y.is_some()
+		{
+			input.try(|input| NumberOrPercentageUnit::parse_one_outside_calc_function(context, input)).ok()
+		}
+		else
+		{
+			None
+		};
+		Ok(value(x, y, z))
+	}
+}
+
+impl Scale
+{
+	/// Whether any component uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		match *self
+		{
+			Scale::none => false,
+			Scale::value(ref x, ref y, ref z) => x.is_var() || y.as_ref().map(CalculablePropertyValue::is_var).unwrap_or(false) || z.as_ref().map(CalculablePropertyValue::is_var).unwrap_or(false),
+		}
+	}
+	
+	/// The equivalent transform function, if any.
+	pub fn to_transform_function(&self) -> Option<TransformFunction>
+	{
+		match *self
+		{
+			Scale::none => None,
+			Scale::value(ref x, ref y, None) => Some(TransformFunction::scale(x.clone(), y.clone())),
+			Scale::value(ref x, None, Some(ref z)) => Some(TransformFunction::scale3d(x.clone(), x.clone(), z.clone())),
+			Scale::value(ref x, Some(ref y), Some(ref z)) => Some(TransformFunction::scale3d(x.clone(), y.clone(), z.clone())),
+		}
+	}
+}
@@ -0,0 +1,545 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A `<transform-function>`: https://drafts.csswg.org/css-transforms-2/#transform-functions
+///
+/// Variants are named after the functions they represent; scale factors given as percentages are kept as percentages, and `perspective(none)` is `perspective(None)`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransformFunction
+{
+	/// `matrix(a, b, c, d, e, f)`.
+	matrix([CssSignedNumber; 6]),
+	
+	/// `matrix3d()`, in column-major order.
+	matrix3d([CssSignedNumber; 16]),
+	
+	/// `translate(x)` or `translate(x, y)`.
+	translate(CalculablePropertyValue<LengthOrPercentageUnit<CssSignedNumber>>, Option<CalculablePropertyValue<LengthOrPercentageUnit<CssSignedNumber>>>),
+	
+	/// `translateX(x)`.
+	translateX(CalculablePropertyValue<LengthOrPercentageUnit<CssSignedNumber>>),
+	
+	/// `translateY(y)`.
+	translateY(CalculablePropertyValue<LengthOrPercentageUnit<CssSignedNumber>>),
+	
+	/// `translateZ(z)`.
+	translateZ(CalculablePropertyValue<LengthUnit<CssSignedNumber>>),
+	
+	/// `translate3d(x, y, z)`.
+	translate3d(CalculablePropertyValue<LengthOrPercentageUnit<CssSignedNumber>>, CalculablePropertyValue<LengthOrPercentageUnit<CssSignedNumber>>, CalculablePropertyValue<LengthUnit<CssSignedNumber>>),
+	
+	/// `scale(x)` or `scale(x, y)`.
+	scale(CalculablePropertyValue<NumberOrPercentageUnit<CssSignedNumber>>, Option<CalculablePropertyValue<NumberOrPercentageUnit<CssSignedNumber>>>),
+	
+	/// `scaleX(x)`.
+	scaleX(CalculablePropertyValue<NumberOrPercentageUnit<CssSignedNumber>>),
+	
+	/// `scaleY(y)`.
+	scaleY(CalculablePropertyValue<NumberOrPercentageUnit<CssSignedNumber>>),
+	
+	/// `scaleZ(z)`.
+	scaleZ(CalculablePropertyValue<NumberOrPercentageUnit<CssSignedNumber>>),
+	
+	/// `scale3d(x, y, z)`.
+	scale3d(CalculablePropertyValue<NumberOrPercentageUnit<CssSignedNumber>>, CalculablePropertyValue<NumberOrPercentageUnit<CssSignedNumber>>, CalculablePropertyValue<NumberOrPercentageUnit<CssSignedNumber>>),
+	
+	/// `rotate(angle)`.
+	rotate(CalculablePropertyValue<AngleUnit<CssSignedNumber>>),
+	
+	/// `rotateX(angle)`.
+	rotateX(CalculablePropertyValue<AngleUnit<CssSignedNumber>>),
+	
+	/// `rotateY(angle)`.
+	rotateY(CalculablePropertyValue<AngleUnit<CssSignedNumber>>),
+	
+	/// `rotateZ(angle)`.
+	rotateZ(CalculablePropertyValue<AngleUnit<CssSignedNumber>>),
+	
+	/// `rotate3d(x, y, z, angle)`.
+	rotate3d(CalculablePropertyValue<CssSignedNumber>, CalculablePropertyValue<CssSignedNumber>, CalculablePropertyValue<CssSignedNumber>, CalculablePropertyValue<AngleUnit<CssSignedNumber>>),
+	
+	/// `skew(x)` or `skew(x, y)`.
+	skew(CalculablePropertyValue<AngleUnit<CssSignedNumber>>, Option<CalculablePropertyValue<AngleUnit<CssSignedNumber>>>),
+	
+	/// `skewX(angle)`.
+	skewX(CalculablePropertyValue<AngleUnit<CssSignedNumber>>),
+	
+	/// `skewY(angle)`.
+	skewY(CalculablePropertyValue<AngleUnit<CssSignedNumber>>),
+	
+	/// `perspective(length)` or `perspective(none)`.
+	perspective(Option<CalculablePropertyValue<LengthUnit<CssSignedNumber>>>),
+}
+
+impl ToCss for TransformFunction
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::TransformFunction::*;
+		
+		#[inline(always)]
+		fn numbers<W: fmt::Write>(values: &[CssSignedNumber], dest: &mut W) -> fmt::Result
+		{
+			let mut afterFirst = false;
+			for value in values.iter()
+			{
+				if afterFirst
+				{
+					dest.write_char(',')?;
+				}
+				else
+				{
+					afterFirst = true;
+				}
+				value.to_css(dest)?;
+			}
+			Ok(())
+		}
+		
+		#[inline(always)]
+		fn next<W: fmt::Write, T: ToCss>(value: &T, dest: &mut W) -> fmt::Result
+		{
+			dest.write_char(',')?;
+			value.to_css(dest)
+		}
+		
+		#[inline(always)]
+		fn optional<W: fmt::Write, T: ToCss>(value: &Option<T>, dest: &mut W) -> fmt::Result
+		{
+			match *value
+			{
+				None => Ok(()),
+				Some(ref value) => next(value, dest),
+			}
+		}
+		
+		match *self
+		{
+			matrix(ref values) =>
+			{
+				dest.write_str("matrix(")?;
+				numbers(&values[..], dest)?;
+			}
+			
+			matrix3d(ref values) =>
+			{
+				dest.write_str("matrix3d(")?;
+				numbers(&values[..], dest)?;
+			}
+			
+			translate(ref x, ref y) =>
+			{
+				dest.write_str("translate(")?;
+				x.to_css(dest)?;
+				optional(y, dest)?;
+			}
+			
+			translateX(ref x) =>
+			{
+				dest.write_str("translateX(")?;
+				x.to_css(dest)?;
+			}
+			
+			translateY(ref y) =>
+			{
+				dest.write_str("translateY(")?;
+				y.to_css(dest)?;
+			}
+			
+			translateZ(ref z) =>
+			{
+				dest.write_str("translateZ(")?;
+				z.to_css(dest)?;
+			}
+			
+			translate3d(ref x, ref y, ref z) =>
+			{
+				dest.write_str("translate3d(")?;
+				x.to_css(dest)?;
+				next(y, dest)?;
+				next(z, dest)?;
+			}
+			
+			scale(ref x, ref y) =>
+			{
+				dest.write_str("scale(")?;
+				x.to_css(dest)?;
+				optional(y, dest)?;
+			}
+			
+			scaleX(ref x) =>
+			{
+				dest.write_str("scaleX(")?;
+				x.to_css(dest)?;
+			}
+			
+			scaleY(ref y) =>
+			{
+				dest.write_str("scaleY(")?;
+				y.to_css(dest)?;
+			}
+			
+			scaleZ(ref z) =>
+			{
+				dest.write_str("scaleZ(")?;
+				z.to_css(dest)?;
+			}
+			
+			scale3d(ref x, ref y, ref z) =>
+			{
+				dest.write_str("scale3d(")?;
+				x.to_css(dest)?;
+				next(y, dest)?;
+				next(z, dest)?;
+			}
+			
+			rotate(ref angle) =>
+			{
+				dest.write_str("rotate(")?;
+				angle.to_css(dest)?;
+			}
+			
+			rotateX(ref angle) =>
+			{
+				dest.write_str("rotateX(")?;
+				angle.to_css(dest)?;
+			}
+			
+			rotateY(ref angle) =>
+			{
+				dest.write_str("rotateY(")?;
+				angle.to_css(dest)?;
+			}
+			
+			rotateZ(ref angle) =>
+			{
+				dest.write_str("rotateZ(")?;
+				angle.to_css(dest)?;
+			}
+			
+			rotate3d(ref x, ref y, ref z, ref angle) =>
+			{
+				dest.write_str("rotate3d(")?;
+				x.to_css(dest)?;
+				next(y, dest)?;
+				next(z, dest)?;
+				next(angle, dest)?;
+			}
+			
+			skew(ref x, ref y) =>
+			{
+				dest.write_str("skew(")?;
+				x.to_css(dest)?;
+				optional(y, dest)?;
+			}
+			
+			skewX(ref angle) =>
+			{
+				dest.write_str("skewX(")?;
+				angle.to_css(dest)?;
+			}
+			
+			skewY(ref angle) =>
+			{
+				dest.write_str("skewY(")?;
+				angle.to_css(dest)?;
+			}
+			
+			perspective(ref length) =>
+			{
+				dest.write_str("perspective(")?;
+				match *length
+				{
+					None => dest.write_str("none")?,
+					Some(ref length) => length.to_css(dest)?,
+				}
+			}
+		}
+		
+		dest.write_char(')')
+	}
+}
+
+impl TransformFunction
+{
+	/// Whether any argument uses a `var()`.
+	pub fn is_var(&self) -> bool
+	{
+		use self::TransformFunction::*;
+		
+		match *self
+		{
+			matrix(_) | matrix3d(_) | perspective(None) => false,
+			
+			translate(ref x, ref y) => x.is_var() || y.as_ref().map(CalculablePropertyValue::is_var).unwrap_or(false),
+			
+			translateX(ref x) | translateY(ref x) => x.is_var(),
+			
+			translateZ(ref z) | perspective(Some(ref z)) => z.is_var(),
+			
+			translate3d(ref x, ref y, ref z) => x.is_var() || y.is_var() || z.is_var(),
+			
+			scale(ref x, ref y) => x.is_var() || y.as_ref().map(CalculablePropertyValue::is_var).unwrap_or(false),
+			
+			scaleX(ref x) | scaleY(ref x) | scaleZ(ref x) => x.is_var(),
+			
+			scale3d(ref x, ref y, ref z) => x.is_var() || y.is_var() || z.is_var(),
+			
+			rotate(ref angle) | rotateX(ref angle) | rotateY(ref angle) | rotateZ(ref angle) | skewX(ref angle) | skewY(ref angle) => angle.is_var(),
+			
+			rotate3d(ref x, ref y, ref z, ref angle) => x.is_var() || y.is_var() || z.is_var() || angle.is_var(),
+			
+			skew(ref x, ref y) => x.is_var() || y.as_ref().map(CalculablePropertyValue::is_var).unwrap_or(false),
+		}
+	}
+	
+	/// The matrix of this function; percentages are resolved against the reference box given by `conversion`.
+	///
+	/// Returns `None` if an argument can not be evaluated (eg it is a `var()` that `conversion` does not know).
+	pub fn to_matrix<Conversion: FontRelativeLengthConversion<CssSignedNumber> + ViewportPercentageLengthConversion<CssSignedNumber> + ReferenceBoxConversion<CssSignedNumber> + CssVariableConversion>(&self, conversion: &Conversion) -> Option<TransformMatrix>
+	{
+		use self::TransformFunction::*;
+		
+		let horizontal = ReferenceBoxAxisConversion::horizontal(conversion);
+		let vertical = ReferenceBoxAxisConversion::vertical(conversion);
+		let none = ReferenceBoxAxisConversion::none(conversion);
+		let factor = ReferenceBoxAxisConversion::factor(conversion);
+		
+		let radians = |angle: &CalculablePropertyValue<AngleUnit<CssSignedNumber>>| angle.evaluate(&none).map(|degrees| degrees.as_f32().to_radians());
+		let scaleFactor = |value: &CalculablePropertyValue<NumberOrPercentageUnit<CssSignedNumber>>| value.evaluate(&factor).map(|value| value.as_f32());
+		
+		let transformMatrix = match *self
+		{
+			matrix(ref values) => TransformMatrix::new_2d(values[0].as_f32(), values[1].as_f32(), values[2].as_f32(), values[3].as_f32(), values[4].as_f32(), values[5].as_f32()),
+			
+			matrix3d(ref values) =>
+			{
+				let mut columnMajorValues = [0.0; 16];
+				for (index, value) in values.iter().enumerate()
+				{
+					columnMajorValues[index] = value.as_f32();
+				}
+				TransformMatrix(columnMajorValues)
+			}
+			
+			translate(ref x, ref y) =>
+			{
+				let y = match *y
+				{
+					None => 0.0,
+					Some(ref y) => y.evaluate(&vertical)?.as_f32(),
+				};
+				TransformMatrix::translation(x.evaluate(&horizontal)?.as_f32(), y, 0.0)
+			}
+			
+			translateX(ref x) => TransformMatrix::translation(x.evaluate(&horizontal)?.as_f32(), 0.0, 0.0),
+			
+			translateY(ref y) => TransformMatrix::translation(0.0, y.evaluate(&vertical)?.as_f32(), 0.0),
+			
+			translateZ(ref z) => TransformMatrix::translation(0.0, 0.0, z.evaluate(&none)?.as_f32()),
+			
+			translate3d(ref x, ref y, ref z) => TransformMatrix::translation(x.evaluate(&horizontal)?.as_f32(), y.evaluate(&vertical)?.as_f32(), z.evaluate(&none)?.as_f32()),
+			
+			scale(ref x, ref y) =>
+			{
+				let x = scaleFactor(x)?;
+				let y = match *y
+				{
+					None => x,
+					Some(ref y) => scaleFactor(y)?,
+				};
+				TransformMatrix::scaling(x, y, 1.0)
+			}
+			
+			scaleX(ref x) => TransformMatrix::scaling(scaleFactor(x)?, 1.0, 1.0),
+			
+			scaleY(ref y) => TransformMatrix::scaling(1.0, scaleFactor(y)?, 1.0),
+			
+			scaleZ(ref z) => TransformMatrix::scaling(1.0, 1.0, scaleFactor(z)?),
+			
+			scale3d(ref x, ref y, ref z) => TransformMatrix::scaling(scaleFactor(x)?, scaleFactor(y)?, scaleFactor(z)?),
+			
+			rotate(ref angle) | rotateZ(ref angle) => TransformMatrix::rotation(0.0, 0.0, 1.0, radians(angle)?),
+			
+			rotateX(ref angle) => TransformMatrix::rotation(1.0, 0.0, 0.0, radians(angle)?),
+			
+			rotateY(ref angle) => TransformMatrix::rotation(0.0, 1.0, 0.0, radians(angle)?),
+			
+			rotate3d(ref x, ref y, ref z, ref angle) => TransformMatrix::rotation(x.evaluate(&none)?.as_f32(), y.evaluate(&none)?.as_f32(), z.evaluate(&none)?.as_f32(), radians(angle)?),
+			
+			skew(ref x, ref y) =>
+			{
+				let y = match *y
+				{
+					None => 0.0,
+					Some(ref y) => radians(y)?,
+				};
+				TransformMatrix::skewing(radians(x)?, y)
+			}
+			
+			skewX(ref angle) => TransformMatrix::skewing(radians(angle)?, 0.0),
+			
+			skewY(ref angle) => TransformMatrix::skewing(0.0, radians(angle)?),
+			
+			perspective(None) => TransformMatrix::identity(),
+			
+			perspective(Some(ref length)) => TransformMatrix::perspective(length.evaluate(&none)?.as_f32()),
+		};
+		
+		Some(transformMatrix)
+	}
+	
+	pub(crate) fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::TransformFunction::*;
+		
+		let name = input.expect_function()?.clone();
+		input.parse_nested_block(|input|
+		{
+			match_ignore_ascii_case!
+			{
+				&*name,
+				
+				"matrix" =>
+				{
+					let mut values = [CssSignedNumber::Zero; 6];
+					Self::parse_numbers(input, &mut values)?;
+					Ok(matrix(values))
+				}
+				
+				"matrix3d" =>
+				{
+					let mut values = [CssSignedNumber::Zero; 16];
+					Self::parse_numbers(input, &mut values)?;
+					Ok(matrix3d(values))
+				}
+				
+				"translate" =>
+				{
+					let x = LengthOrPercentageUnit::parse_one_outside_calc_function(context, input)?;
+					let y = Self::parse_optional_argument(input, |input| LengthOrPercentageUnit::parse_one_outside_calc_function(context, input))?;
+					Ok(translate(x, y))
+				}
+				
+				"translatex" => LengthOrPercentageUnit::parse_one_outside_calc_function(context, input).map(translateX),
+				
+				"translatey" => LengthOrPercentageUnit::parse_one_outside_calc_function(context, input).map(translateY),
+				
+				"translatez" => LengthUnit::parse_one_outside_calc_function(context, input).map(translateZ),
+				
+				"translate3d" =>
+				{
+					let x = LengthOrPercentageUnit::parse_one_outside_calc_function(context, input)?;
+					input.expect_comma()?;
+					let y = LengthOrPercentageUnit::parse_one_outside_calc_function(context, input)?;
+					input.expect_comma()?;
+					let z = LengthUnit::parse_one_outside_calc_function(context, input)?;
+					Ok(translate3d(x, y, z))
+				}
+				
+				"scale" =>
+				{
+					let x = NumberOrPercentageUnit::parse_one_outside_calc_function(context, input)?;
+					let y = Self::parse_optional_argument(input, |input| NumberOrPercentageUnit::parse_one_outside_calc_function(context, input))?;
+					Ok(scale(x, y))
+				}
+				
+				"scalex" => NumberOrPercentageUnit::parse_one_outside_calc_function(context, input).map(scaleX),
+				
+				"scaley" => NumberOrPercentageUnit::parse_one_outside_calc_function(context, input).map(scaleY),
+				
+				"scalez" => NumberOrPercentageUnit::parse_one_outside_calc_function(context, input).map(scaleZ),
+				
+				"scale3d" =>
+				{
+					let x = NumberOrPercentageUnit::parse_one_outside_calc_function(context, input)?;
+					input.expect_comma()?;
+					let y = NumberOrPercentageUnit::parse_one_outside_calc_function(context, input)?;
+					input.expect_comma()?;
+					let z = NumberOrPercentageUnit::parse_one_outside_calc_function(context, input)?;
+					Ok(scale3d(x, y, z))
+				}
+				
+				"rotate" => AngleUnit::parse_one_outside_calc_function(context, input).map(rotate),
+				
+				"rotatex" => AngleUnit::parse_one_outside_calc_function(context, input).map(rotateX),
+				
+				"rotatey" => AngleUnit::parse_one_outside_calc_function(context, input).map(rotateY),
+				
+				"rotatez" => AngleUnit::parse_one_outside_calc_function(context, input).map(rotateZ),
+				
+				"rotate3d" =>
+				{
+					let x = CssSignedNumber::parse_one_outside_calc_function(context, input)?;
+					input.expect_comma()?;
+					let y = CssSignedNumber::parse_one_outside_calc_function(context, input)?;
+					input.expect_comma()?;
+					let z = CssSignedNumber::parse_one_outside_calc_function(context, input)?;
+					input.expect_comma()?;
+					let angle = AngleUnit::parse_one_outside_calc_function(context, input)?;
+					Ok(rotate3d(x, y, z, angle))
+				}
+				
+				"skew" =>
+				{
+					let x = AngleUnit::parse_one_outside_calc_function(context, input)?;
+					let y = Self::parse_optional_argument(input, |input| AngleUnit::parse_one_outside_calc_function(context, input))?;
+					Ok(skew(x, y))
+				}
+				
+				"skewx" => AngleUnit::parse_one_outside_calc_function(context, input).map(skewX),
+				
+				"skewy" => AngleUnit::parse_one_outside_calc_function(context, input).map(skewY),
+				
+				"perspective" =>
+				{
+					if input.try(|input| input.expect_ident_matching("none")).is_ok()
+					{
+						return Ok(perspective(None));
+					}
+					
+					let length = LengthUnit::parse_one_outside_calc_function(context, input)?;
+					if let CalculablePropertyValue::Constant(ref constant) = length
+					{
+						if constant.to_CssNumber() < CssSignedNumber::Zero
+						{
+							return Err(ParseError::Custom(CustomParseError::TransformPerspectiveMustNotBeNegative));
+						}
+					}
+					Ok(perspective(Some(length)))
+				}
+				
+				_ => Err(ParseError::Custom(CustomParseError::UnknownTransformFunction(name.clone()))),
+			}
+		})
+	}
+	
+	#[inline(always)]
+	fn parse_numbers<'i, 't>(input: &mut Parser<'i, 't>, values: &mut [CssSignedNumber]) -> Result<(), ParseError<'i, CustomParseError<'i>>>
+	{
+		for (index, value) in values.iter_mut().enumerate()
+		{
+			if index != 0
+			{
+				input.expect_comma()?;
+			}
+			*value = CssSignedNumber::parseNumber(input.expect_number()?, None)?;
+		}
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn parse_optional_argument<'i, 't, T, Parse: FnOnce(&mut Parser<'i, 't>) -> Result<T, ParseError<'i, CustomParseError<'i>>>>(input: &mut Parser<'i, 't>, parse: Parse) -> Result<Option<T>, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.is_exhausted()
+		{
+			Ok(None)
+		}
+		else
+		{
+			input.expect_comma()?;
+			parse(input).map(Some)
+		}
+	}
+}
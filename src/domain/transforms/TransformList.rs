@@ -0,0 +1,119 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `transform` property; an empty list is `none`.
+/// See https://drafts.csswg.org/css-transforms-1/#transform-property
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TransformList(pub Vec<TransformFunction>);
+
+impl ToCss for TransformList
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.0.is_empty()
+		{
+			return dest.write_str("none");
+		}
+		
+		let mut afterFirst = false;
+		for transformFunction in self.0.iter()
+		{
+			if afterFirst
+			{
+				dest.write_char(' ')?;
+			}
+			else
+			{
+				afterFirst = true;
+			}
+			transformFunction.to_css(dest)?;
+		}
+		Ok(())
+	}
+}
+
+impl PropertyValue for TransformList
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"transform",
+	];
+	
+	#[inline(always)]
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		Self::parse(context, input)
+	}
+}
+
+impl TransformList
+{
+	/// Whether this is `none`.
+	#[inline(always)]
+	pub fn is_none(&self) -> bool
+	{
+		self.0.is_empty()
+	}
+	
+	/// Whether any function uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		self.0.iter().any(TransformFunction::is_var)
+	}
+	
+	/// The product of the matrices of the functions, in order; `none` is the identity.
+	///
+	/// Returns `None` if an argument can not be evaluated.
+	pub fn to_matrix<Conversion: FontRelativeLengthConversion<CssSignedNumber> + ViewportPercentageLengthConversion<CssSignedNumber> + ReferenceBoxConversion<CssSignedNumber> + CssVariableConversion>(&self, conversion: &Conversion) -> Option<TransformMatrix>
+	{
+		let mut matrix = TransformMatrix::identity();
+		for transformFunction in self.0.iter()
+		{
+			matrix = matrix.multiply(&transformFunction.to_matrix(conversion)?);
+		}
+		Some(matrix)
+	}
+	
+	/// The shortest equivalent list of functions, for minification; `none` is left alone, as is a list that can not be evaluated or which would be no shorter.
+	pub fn minify<Conversion: FontRelativeLengthConversion<CssSignedNumber> + ViewportPercentageLengthConversion<CssSignedNumber> + ReferenceBoxConversion<CssSignedNumber> + CssVariableConversion>(&self, conversion: &Conversion) -> Self
+	{
+		if self.is_none()
+		{
+			return self.clone();
+		}
+		
+		match self.to_matrix(conversion)
+		{
+			Some(matrix) =>
+			{
+				let decomposed = matrix.decompose();
+				if decomposed.to_css_string().len() < self.to_css_string().len()
+				{
+					decomposed
+				}
+				else
+				{
+					self.clone()
+				}
+			}
+			None => self.clone(),
+		}
+	}
+	
+	pub(crate) fn parse<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		if input.try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			return Ok(TransformList::default());
+		}
+		
+		let mut transformFunctions = vec![TransformFunction::parse(context, input)?];
+		while let Ok(transformFunction) = input.try(|input| TransformFunction::parse(context, input))
+		{
+			transformFunctions.push(transformFunction);
+		}
+		Ok(TransformList(transformFunctions))
+	}
+}
@@ -0,0 +1,508 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A 4x4 transformation matrix, stored in column-major order (the order of the arguments of `matrix3d()`).
+///
+/// Points are column vectors, so `a.multiply(&b)` applies `b` first, as in a list of transform functions.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TransformMatrix(pub [f32; 16]);
+
+impl Default for TransformMatrix
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self::identity()
+	}
+}
+
+impl TransformMatrix
+{
+	/// The identity matrix.
+	#[inline(always)]
+	pub fn identity() -> Self
+	{
+		TransformMatrix
+		(
+			[
+				1.0, 0.0, 0.0, 0.0,
+				0.0, 1.0, 0.0, 0.0,
+				0.0, 0.0, 1.0, 0.0,
+				0.0, 0.0, 0.0, 1.0,
+			]
+		)
+	}
+	
+	/// The same as `matrix(a, b, c, d, e, f)`.
+	#[inline(always)]
+	pub fn new_2d(a: f32, b: f32, c: f32, d: f32, e: f32, f: f32) -> Self
+	{
+		TransformMatrix
+		(
+			[
+				a, b, 0.0, 0.0,
+				c, d, 0.0, 0.0,
+				0.0, 0.0, 1.0, 0.0,
+				e, f, 0.0, 1.0,
+			]
+		)
+	}
+	
+	/// A translation, in `px`.
+	#[inline(always)]
+	pub fn translation(x: f32, y: f32, z: f32) -> Self
+	{
+		let mut matrix = Self::identity();
+		matrix.set(0, 3, x);
+		matrix.set(1, 3, y);
+		matrix.set(2, 3, z);
+		matrix
+	}
+	
+	/// A scaling.
+	#[inline(always)]
+	pub fn scaling(x: f32, y: f32, z: f32) -> Self
+	{
+		let mut matrix = Self::identity();
+		matrix.set(0, 0, x);
+		matrix.set(1, 1, y);
+		matrix.set(2, 2, z);
+		matrix
+	}
+	
+	/// A clockwise rotation (as seen looking towards the negative end of the axis) by `radians` about the axis `[x, y, z]`, as `rotate3d()`; an axis of zero length is the identity.
+	pub fn rotation(x: f32, y: f32, z: f32, radians: f32) -> Self
+	{
+		let length = (x * x + y * y + z * z).sqrt();
+		if length == 0.0
+		{
+			return Self::identity();
+		}
+		let (x, y, z) = (x / length, y / length, z / length);
+		
+		let halfAngle = radians / 2.0;
+		let sc = halfAngle.sin() * halfAngle.cos();
+		let sq = halfAngle.sin() * halfAngle.sin();
+		
+		let mut matrix = Self::identity();
+		matrix.set(0, 0, 1.0 - 2.0 * (y * y + z * z) * sq);
+		matrix.set(1, 0, 2.0 * (x * y * sq + z * sc));
+		matrix.set(2, 0, 2.0 * (x * z * sq - y * sc));
+		matrix.set(0, 1, 2.0 * (x * y * sq - z * sc));
+		matrix.set(1, 1, 1.0 - 2.0 * (x * x + z * z) * sq);
+		matrix.set(2, 1, 2.0 * (y * z * sq + x * sc));
+		matrix.set(0, 2, 2.0 * (x * z * sq + y * sc));
+		matrix.set(1, 2, 2.0 * (y * z * sq - x * sc));
+		matrix.set(2, 2, 1.0 - 2.0 * (x * x + y * y) * sq);
+		matrix
+	}
+	
+	/// A skew, as `skew()`.
+	#[inline(always)]
+	pub fn skewing(xRadians: f32, yRadians: f32) -> Self
+	{
+		let mut matrix = Self::identity();
+		matrix.set(0, 1, xRadians.tan());
+		matrix.set(1, 0, yRadians.tan());
+		matrix
+	}
+	
+	/// A perspective projection with the viewer `depth` `px` from the `z = 0` plane, as `perspective()`; depths of less than `1px` are treated as `1px`.
+	#[inline(always)]
+	pub fn perspective(depth: f32) -> Self
+	{
+		let mut matrix = Self::identity();
+		matrix.set(3, 2, -1.0 / depth.max(1.0));
+		matrix
+	}
+	
+	/// The value in row `row` and column `column`, both from zero.
+	#[inline(always)]
+	pub fn get(&self, row: usize, column: usize) -> f32
+	{
+		self.0[column * 4 + row]
+	}
+	
+	/// Sets the value in row `row` and column `column`, both from zero.
+	#[inline(always)]
+	pub fn set(&mut self, row: usize, column: usize, value: f32)
+	{
+		self.0[column * 4 + row] = value;
+	}
+	
+	/// `self × rhs`; the transform which applies `rhs` and then `self`.
+	pub fn multiply(&self, rhs: &Self) -> Self
+	{
+		let mut product = TransformMatrix([0.0; 16]);
+		for row in 0..4
+		{
+			for column in 0..4
+			{
+				let mut sum = 0.0;
+				for index in 0..4
+				{
+					sum += self.get(row, index) * rhs.get(index, column);
+				}
+				product.set(row, column, sum);
+			}
+		}
+		product
+	}
+	
+	/// The current transformation matrix of an element, composed from its `translate`, `rotate`, `scale`, `transform` and `transform-origin` properties.
+	///
+	/// See https://drafts.csswg.org/css-transforms-2/#ctm; percentages are resolved against the reference box given by `conversion`, and `None` is returned if a value can not be evaluated.
+	pub fn from_transform_properties<Conversion: FontRelativeLengthConversion<CssSignedNumber> + ViewportPercentageLengthConversion<CssSignedNumber> + ReferenceBoxConversion<CssSignedNumber> + CssVariableConversion>(translate: &Translate, rotate: &Rotate, scale: &Scale, transform: &TransformList, transformOrigin: &TransformOrigin, conversion: &Conversion) -> Option<Self>
+	{
+		let (originX, originY, originZ) = transformOrigin.to_px(conversion)?;
+		
+		let mut matrix = Self::translation(originX, originY, originZ);
+		for transformFunction in translate.to_transform_function().iter().chain(rotate.to_transform_function().iter()).chain(scale.to_transform_function().iter())
+		{
+			matrix = matrix.multiply(&transformFunction.to_matrix(conversion)?);
+		}
+		matrix = matrix.multiply(&transform.to_matrix(conversion)?);
+		Some(matrix.multiply(&Self::translation(-originX, -originY, -originZ)))
+	}
+	
+	/// Whether this matrix can be written as `matrix()`, ie it only transforms in the plane of the screen.
+	pub fn is_2d(&self) -> bool
+	{
+		const Flat: [(usize, usize, f32); 10] =
+		[
+			(2, 0, 0.0),
+			(3, 0, 0.0),
+			(2, 1, 0.0),
+			(3, 1, 0.0),
+			(0, 2, 0.0),
+			(1, 2, 0.0),
+			(2, 2, 1.0),
+			(3, 2, 0.0),
+			(2, 3, 0.0),
+			(3, 3, 1.0),
+		];
+		
+		Flat.iter().all(|&(row, column, value)| self.get(row, column) == value)
+	}
+	
+	/// Decomposes this matrix into the shortest equivalent list of transform functions, for minification.
+	///
+	/// A matrix without perspective which does not skew out of the plane of the screen is decomposed into `translate()`, `rotate()` (or `rotate3d()`), `skewX()` and `scale()` functions, omitting those which do nothing; the result is used unless `matrix()` or `matrix3d()` is shorter.
+	/// Values are rounded to six decimal places, which is sub-pixel accurate for any reasonable transform.
+	pub fn decompose(&self) -> TransformList
+	{
+		let matrix = TransformList(vec![self.to_transform_function()]);
+		
+		let decomposed = if self.is_2d()
+		{
+			self.decompose_2d()
+		}
+		else
+		{
+			self.decompose_3d()
+		};
+		
+		match decomposed
+		{
+			Some(decomposed) => if decomposed.to_css_string().len() < matrix.to_css_string().len()
+			{
+				decomposed
+			}
+			else
+			{
+				matrix
+			},
+			None => matrix,
+		}
+	}
+	
+	/// This matrix as `matrix()` if it is 2D, otherwise `matrix3d()`.
+	pub fn to_transform_function(&self) -> TransformFunction
+	{
+		let rounded = |value: f32| number(round(value)).unwrap_or(CssSignedNumber::Zero);
+		
+		if self.is_2d()
+		{
+			TransformFunction::matrix([rounded(self.get(0, 0)), rounded(self.get(1, 0)), rounded(self.get(0, 1)), rounded(self.get(1, 1)), rounded(self.get(0, 3)), rounded(self.get(1, 3))])
+		}
+		else
+		{
+			let mut values = [CssSignedNumber::Zero; 16];
+			for (index, value) in self.0.iter().enumerate()
+			{
+				values[index] = rounded(*value);
+			}
+			TransformFunction::matrix3d(values)
+		}
+	}
+	
+	/// `translate() rotate() skewX() scale()`; see https://drafts.csswg.org/css-transforms-1/#decomposing-a-2d-matrix
+	fn decompose_2d(&self) -> Option<TransformList>
+	{
+		let (a, b, c, d) = (self.get(0, 0), self.get(1, 0), self.get(0, 1), self.get(1, 1));
+		
+		let scaleX = (a * a + b * b).sqrt();
+		if scaleX == 0.0
+		{
+			return None;
+		}
+		let (cos, sin) = (a / scaleX, b / scaleX);
+		
+		// Rotating the second column back leaves `[skew × scaleY, scaleY]`.
+		let scaleY = d * cos - c * sin;
+		if scaleY == 0.0
+		{
+			return None;
+		}
+		let skew = (c * cos + d * sin) / scaleY;
+		
+		let mut functions = Vec::with_capacity(4);
+		
+		let (translateX, translateY) = (round(self.get(0, 3)), round(self.get(1, 3)));
+		if translateX != 0.0 || translateY != 0.0
+		{
+			functions.push(TransformFunction::translate(px(translateX)?, if translateY == 0.0 { None } else { Some(px(translateY)?) }));
+		}
+		
+		let rotation = round(b.atan2(a).to_degrees());
+		if rotation != 0.0
+		{
+			functions.push(TransformFunction::rotate(degrees(rotation)?));
+		}
+		
+		let skewX = round(skew.atan().to_degrees());
+		if skewX != 0.0
+		{
+			functions.push(TransformFunction::skewX(degrees(skewX)?));
+		}
+		
+		let (scaleX, scaleY) = (round(scaleX), round(scaleY));
+		if scaleX != 1.0 || scaleY != 1.0
+		{
+			functions.push(TransformFunction::scale(factor(scaleX)?, if scaleY == scaleX { None } else { Some(factor(scaleY)?) }));
+		}
+		
+		Some(Self::identity_if_empty(functions))
+	}
+	
+	/// `translate3d() rotate3d() skewX() scale3d()`, using 2D functions where possible; see https://drafts.csswg.org/css-transforms-2/#decomposing-a-3d-matrix
+	///
+	/// Returns `None` for matrices with perspective or which skew out of the plane of the screen, as no list of transform functions other than `matrix3d()` is equivalent to them.
+	fn decompose_3d(&self) -> Option<TransformList>
+	{
+		const Epsilon: f32 = 1e-6;
+		
+		if self.get(3, 0) != 0.0 || self.get(3, 1) != 0.0 || self.get(3, 2) != 0.0 || self.get(3, 3) != 1.0
+		{
+			return None;
+		}
+		
+		#[inline(always)]
+		fn dot(left: [f32; 3], right: [f32; 3]) -> f32
+		{
+			left[0] * right[0] + left[1] * right[1] + left[2] * right[2]
+		}
+		
+		#[inline(always)]
+		fn combine(left: [f32; 3], right: [f32; 3], rightScale: f32) -> [f32; 3]
+		{
+			[left[0] - right[0] * rightScale, left[1] - right[1] * rightScale, left[2] - right[2] * rightScale]
+		}
+		
+		#[inline(always)]
+		fn normalize(vector: [f32; 3]) -> (f32, [f32; 3])
+		{
+			let length = dot(vector, vector).sqrt();
+			(length, [vector[0] / length, vector[1] / length, vector[2] / length])
+		}
+		
+		let column = |index: usize| [self.get(0, index), self.get(1, index), self.get(2, index)];
+		
+		// Gram-Schmidt orthogonalization of the columns into a rotation, a shear and a scale.
+		let (mut scaleX, mut columnX) = normalize(column(0));
+		
+		let shearXY = dot(columnX, column(1));
+		let (mut scaleY, columnY) = normalize(combine(column(1), columnX, shearXY));
+		
+		let shearXZ = dot(columnX, column(2));
+		let shearYZ = dot(columnY, column(2));
+		let (mut scaleZ, mut columnZ) = normalize(combine(combine(column(2), columnX, shearXZ), columnY, shearYZ));
+		
+		if scaleX == 0.0 || scaleY == 0.0 || scaleZ == 0.0 || !scaleX.is_finite() || !scaleY.is_finite() || !scaleZ.is_finite()
+		{
+			return None;
+		}
+		
+		if (shearXZ / scaleZ).abs() > Epsilon || (shearYZ / scaleZ).abs() > Epsilon
+		{
+			return None;
+		}
+		
+		// The skew is of the scale before any reflection; negating all of the scales and the rotation below leaves it unchanged.
+		let skew = shearXY / scaleY;
+		
+		// A reflection; make the rotation proper by negating all of the scales.
+		let crossXY = [columnX[1] * columnY[2] - columnX[2] * columnY[1], columnX[2] * columnY[0] - columnX[0] * columnY[2], columnX[0] * columnY[1] - columnX[1] * columnY[0]];
+		let mut columnY = columnY;
+		if dot(crossXY, columnZ) < 0.0
+		{
+			scaleX = -scaleX;
+			scaleY = -scaleY;
+			scaleZ = -scaleZ;
+			columnX = [-columnX[0], -columnX[1], -columnX[2]];
+			columnY = [-columnY[0], -columnY[1], -columnY[2]];
+			columnZ = [-columnZ[0], -columnZ[1], -columnZ[2]];
+		}
+		
+		// The rotation as a unit quaternion.
+		let rotation = |row: usize, column: usize| [columnX, columnY, columnZ][column][row];
+		let trace = rotation(0, 0) + rotation(1, 1) + rotation(2, 2);
+		let (x, y, z, w) = if trace > 0.0
+		{
+			let s = (trace + 1.0).sqrt() * 2.0;
+			((rotation(2, 1) - rotation(1, 2)) / s, (rotation(0, 2) - rotation(2, 0)) / s, (rotation(1, 0) - rotation(0, 1)) / s, s / 4.0)
+		}
+		else if rotation(0, 0) > rotation(1, 1) && rotation(0, 0) > rotation(2, 2)
+		{
+			let s = (1.0 + rotation(0, 0) - rotation(1, 1) - rotation(2, 2)).sqrt() * 2.0;
+			(s / 4.0, (rotation(0, 1) + rotation(1, 0)) / s, (rotation(0, 2) + rotation(2, 0)) / s, (rotation(2, 1) - rotation(1, 2)) / s)
+		}
+		else if rotation(1, 1) > rotation(2, 2)
+		{
+			let s = (1.0 + rotation(1, 1) - rotation(0, 0) - rotation(2, 2)).sqrt() * 2.0;
+			((rotation(0, 1) + rotation(1, 0)) / s, s / 4.0, (rotation(1, 2) + rotation(2, 1)) / s, (rotation(0, 2) - rotation(2, 0)) / s)
+		}
+		else
+		{
+			let s = (1.0 + rotation(2, 2) - rotation(0, 0) - rotation(1, 1)).sqrt() * 2.0;
+			((rotation(0, 2) + rotation(2, 0)) / s, (rotation(1, 2) + rotation(2, 1)) / s, s / 4.0, (rotation(1, 0) - rotation(0, 1)) / s)
+		};
+		let sinHalfAngle = (x * x + y * y + z * z).sqrt();
+		let angle = round((2.0 * sinHalfAngle.atan2(w)).to_degrees());
+		
+		let mut functions = Vec::with_capacity(4);
+		
+		let (translateX, translateY, translateZ) = (round(self.get(0, 3)), round(self.get(1, 3)), round(self.get(2, 3)));
+		if translateZ != 0.0
+		{
+			functions.push(TransformFunction::translate3d(length_percentage_px(translateX)?, length_percentage_px(translateY)?, length_px(translateZ)?));
+		}
+		else if translateX != 0.0 || translateY != 0.0
+		{
+			functions.push(TransformFunction::translate(px(translateX)?, if translateY == 0.0 { None } else { Some(px(translateY)?) }));
+		}
+		
+		if angle != 0.0 && sinHalfAngle != 0.0
+		{
+			let (x, y, z) = (round(x / sinHalfAngle), round(y / sinHalfAngle), round(z / sinHalfAngle));
+			let angle = degrees(angle)?;
+			functions.push
+			(
+				if x == 0.0 && y == 0.0 && z == 1.0
+				{
+					TransformFunction::rotate(angle)
+				}
+				else if x == 1.0 && y == 0.0 && z == 0.0
+				{
+					TransformFunction::rotateX(angle)
+				}
+				else if x == 0.0 && y == 1.0 && z == 0.0
+				{
+					TransformFunction::rotateY(angle)
+				}
+				else
+				{
+					TransformFunction::rotate3d(constant(x)?, constant(y)?, constant(z)?, angle)
+				}
+			);
+		}
+		
+		let skewX = round(skew.atan().to_degrees());
+		if skewX != 0.0
+		{
+			functions.push(TransformFunction::skewX(degrees(skewX)?));
+		}
+		
+		let (scaleX, scaleY, scaleZ) = (round(scaleX), round(scaleY), round(scaleZ));
+		if scaleZ != 1.0
+		{
+			functions.push(TransformFunction::scale3d(factor(scaleX)?, factor(scaleY)?, factor(scaleZ)?));
+		}
+		else if scaleX != 1.0 || scaleY != 1.0
+		{
+			functions.push(TransformFunction::scale(factor(scaleX)?, if scaleY == scaleX { None } else { Some(factor(scaleY)?) }));
+		}
+		
+		Some(Self::identity_if_empty(functions))
+	}
+	
+	/// `transform: none` is not the same as an identity transform, as the latter establishes a stacking context, so an identity transform is `scale(1)`.
+	#[inline(always)]
+	fn identity_if_empty(mut functions: Vec<TransformFunction>) -> TransformList
+	{
+		if functions.is_empty()
+		{
+			functions.push(TransformFunction::scale(CalculablePropertyValue::Constant(NumberOrPercentageUnit::IsNumber(CssSignedNumber::One)), None));
+		}
+		TransformList(functions)
+	}
+}
+
+#[inline(always)]
+fn round(value: f32) -> f32
+{
+	const Scale: f64 = 1_000_000.0;
+	
+	let rounded = ((value as f64) * Scale).round() / Scale;
+	if rounded == 0.0
+	{
+		0.0
+	}
+	else
+	{
+		rounded as f32
+	}
+}
+
+#[inline(always)]
+fn number(value: f32) -> Option<CssSignedNumber>
+{
+	CssSignedNumber::new(value).ok()
+}
+
+#[inline(always)]
+fn constant(value: f32) -> Option<CalculablePropertyValue<CssSignedNumber>>
+{
+	number(value).map(CalculablePropertyValue::Constant)
+}
+
+#[inline(always)]
+fn factor(value: f32) -> Option<CalculablePropertyValue<NumberOrPercentageUnit<CssSignedNumber>>>
+{
+	number(value).map(|value| CalculablePropertyValue::Constant(NumberOrPercentageUnit::IsNumber(value)))
+}
+
+#[inline(always)]
+fn degrees(value: f32) -> Option<CalculablePropertyValue<AngleUnit<CssSignedNumber>>>
+{
+	number(value).map(|value| CalculablePropertyValue::Constant(AngleUnit::deg(value)))
+}
+
+#[inline(always)]
+fn length_px(value: f32) -> Option<CalculablePropertyValue<LengthUnit<CssSignedNumber>>>
+{
+	number(value).map(|value| CalculablePropertyValue::Constant(LengthUnit::Absolute(AbsoluteLength::px(value))))
+}
+
+#[inline(always)]
+fn length_percentage_px(value: f32) -> Option<CalculablePropertyValue<LengthOrPercentageUnit<CssSignedNumber>>>
+{
+	number(value).map(|value| CalculablePropertyValue::Constant(LengthOrPercentageUnit::IsLength(LengthUnit::Absolute(AbsoluteLength::px(value)))))
+}
+
+#[inline(always)]
+fn px(value: f32) -> Option<CalculablePropertyValue<LengthOrPercentageUnit<CssSignedNumber>>>
+{
+	length_percentage_px(value)
+}
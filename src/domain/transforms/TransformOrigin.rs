@@ -0,0 +1,126 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `transform-origin` property: https://drafts.csswg.org/css-transforms-1/#transform-origin-property
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransformOrigin
+{
+	/// The origin in the plane of the screen; only the one and two value syntaxes of `<position>` are permitted.
+	pub position: Position,
+	
+	/// The offset of the origin along the `z` axis.
+	pub z: CalculablePropertyValue<LengthUnit<CssSignedNumber>>,
+}
+
+/// `center center 0`, the initial value.
+impl Default for TransformOrigin
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Self
+		{
+			position: Position::center(),
+			z: CalculablePropertyValue::Constant(LengthUnit::Absolute(AbsoluteLength::px(CssSignedNumber::Zero))),
+		}
+	}
+}
+
+impl ToCss for TransformOrigin
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		if self.z == Self::default().z
+		{
+			return self.position.to_css(dest);
+		}
+		
+		// The three value syntax requires both components of the position.
+		self.position.horizontal.to_css(dest)?;
+		dest.write_char(' ')?;
+		self.position.vertical.to_css(dest)?;
+		dest.write_char(' ')?;
+		self.z.to_css(dest)
+	}
+}
+
+impl PropertyValue for TransformOrigin
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"transform-origin",
+	];
+	
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let position = Position::parse_at_most(context, input, 2)?;
+		let z = match input.try(|input| LengthUnit::parse_one_outside_calc_function(context, input))
+		{
+			Ok(z) => z,
+			Err(_) => Self::default().z,
+		};
+		
+		Ok
+		(
+			Self
+			{
+				position,
+				z,
+			}
+		)
+	}
+}
+
+impl TransformOrigin
+{
+	/// Whether any component uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		self.position.is_var() || self.z.is_var()
+	}
+	
+	/// The origin, in `px` from the top left of the reference box given by `conversion`.
+	///
+	/// Returns `None` if a component can not be evaluated.
+	pub fn to_px<Conversion: FontRelativeLengthConversion<CssSignedNumber> + ViewportPercentageLengthConversion<CssSignedNumber> + ReferenceBoxConversion<CssSignedNumber> + CssVariableConversion>(&self, conversion: &Conversion) -> Option<(f32, f32, f32)>
+	{
+		#[inline(always)]
+		fn component<Side, Conversion: FontRelativeLengthConversion<CssSignedNumber> + ViewportPercentageLengthConversion<CssSignedNumber> + PercentageConversion<CssSignedNumber> + CssVariableConversion + AttributeConversion<LengthOrPercentageUnit<CssSignedNumber>>>(component: &PositionComponent<Side>, isEndSide: fn(&Side) -> bool, conversion: &Conversion) -> Option<f32>
+		{
+			let size = conversion.one_hundred_percent_in_absolute_units().as_f32();
+			
+			let fromStart = match *component
+			{
+				PositionComponent::center => size / 2.0,
+				
+				PositionComponent::value(ref offset) => offset.evaluate(conversion)?.as_f32(),
+				
+				PositionComponent::side(ref side, ref offset) =>
+				{
+					let offset = match *offset
+					{
+						None => 0.0,
+						Some(ref offset) => offset.evaluate(conversion)?.as_f32(),
+					};
+					
+					if isEndSide(side)
+					{
+						size - offset
+					}
+					else
+					{
+						offset
+					}
+				}
+			};
+			Some(fromStart)
+		}
+		
+		let x = component(&self.position.horizontal, |side| *side == HorizontalPositionKeyword::right, &ReferenceBoxAxisConversion::horizontal(conversion))?;
+		let y = component(&self.position.vertical, |side| *side == VerticalPositionKeyword::bottom, &ReferenceBoxAxisConversion::vertical(conversion))?;
+		let z = self.z.evaluate(&ReferenceBoxAxisConversion::none(conversion))?.as_f32();
+		Some((x, y, z))
+	}
+}
@@ -0,0 +1,108 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The value of the `translate` property: https://drafts.csswg.org/css-transforms-2/#individual-transforms
+#[derive(Debug, Clone, PartialEq)]
+pub enum Translate
+{
+	/// No translation.
+	none,
+	
+	/// A translation by `x`, `y` (`0px` if absent) and `z` (`0px` if absent).
+	value(CalculablePropertyValue<LengthOrPercentageUnit<CssSignedNumber>>, Option<CalculablePropertyValue<LengthOrPercentageUnit<CssSignedNumber>>>, Option<CalculablePropertyValue<LengthUnit<CssSignedNumber>>>),
+}
+
+impl Default for Translate
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		Translate::none
+	}
+}
+
+impl ToCss for Translate
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		use self::Translate::*;
+		
+		match *self
+		{
+			none => dest.write_str("none"),
+			
+			value(ref x, ref y, ref z) =>
+			{
+				x.to_css(dest)?;
+				if let Some(ref y) = *y
+				{
+					dest.write_char(' ')?;
+					y.to_css(dest)?;
+				}
+				if let Some(ref z) = *z
+				{
+					dest.write_char(' ')?;
+					z.to_css(dest)?;
+				}
+				Ok(())
+			}
+		}
+	}
+}
+
+impl PropertyValue for Translate
+{
+	const PropertyNames: &'static [&'static str] = &
+	[
+		"translate",
+	];
+	
+	fn parse_property_value<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::Translate::*;
+		
+		if input.try(|input| input.expect_ident_matching("none")).is_ok()
+		{
+			return Ok(none);
+		}
+		
+		let x = LengthOrPercentageUnit::parse_one_outside_calc_function(context, input)?;
+		let y = input.try(|input| LengthOrPercentageUnit::parse_one_outside_calc_function(context, input)).ok();
+		let z = if y.is_some()
+		{
+			input.try(|input| LengthUnit::parse_one_outside_calc_function(context, input)).ok()
+		}
+		else
+		{
+			None
+		};
+		Ok(value(x, y, z))
+	}
+}
+
+impl Translate
+{
+	/// Whether any component uses a `var()`.
+	#[inline(always)]
+	pub fn is_var(&self) -> bool
+	{
+		match *self
+		{
+			Translate::none => false,
+			Translate::value(ref x, ref y, ref z) => x.is_var() || y.as_ref().map(CalculablePropertyValue::is_var).unwrap_or(false) || z.as_ref().map(CalculablePropertyValue::is_var).unwrap_or(false),
+		}
+	}
+	
+	/// The equivalent transform function, if any.
+	pub fn to_transform_function(&self) -> Option<TransformFunction>
+	{
+		match *self
+		{
+			Translate::none => None,
+			Translate::value(ref x, None, _) => Some(TransformFunction::translate(x.clone(), None)),
+			Translate::value(ref x, Some(ref y), None) => Some(TransformFunction::translate(x.clone(), Some(y.clone()))),
+			Translate::value(ref x, Some(ref y), Some(ref z)) => Some(TransformFunction::translate3d(x.clone(), y.clone(), z.clone())),
+		}
+	}
+}
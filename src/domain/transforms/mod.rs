@@ -0,0 +1,25 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+use super::images::HorizontalPositionKeyword;
+use super::images::Position;
+use super::images::PositionComponent;
+use super::images::VerticalPositionKeyword;
+use super::properties::PropertyValue;
+use super::units::conversions::*;
+
+
+#[cfg(test)]
+mod tests;
+
+
+include!("ReferenceBoxAxisConversion.rs");
+include!("Rotate.rs");
+include!("Scale.rs");
+include!("TransformFunction.rs");
+include!("TransformList.rs");
+include!("TransformMatrix.rs");
+include!("TransformOrigin.rs");
+include!("Translate.rs");
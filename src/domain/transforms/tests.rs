@@ -0,0 +1,75 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+
+
+fn conversion() -> SimplisticExampleOfConversion<LengthUnit<CssSignedNumber>>
+{
+	SimplisticExampleOfConversion
+	{
+		reference_box_width: CssSignedNumber::new(200.0).unwrap(),
+		reference_box_height: CssSignedNumber::new(100.0).unwrap(),
+		..SimplisticExampleOfConversion::default()
+	}
+}
+
+fn matrix(css: &str) -> TransformMatrix
+{
+	TransformList::parse_property_value_from_css(css).unwrap().to_matrix(&conversion()).unwrap()
+}
+
+fn assert_matrices_equal(left: &TransformMatrix, right: &TransformMatrix, description: &str)
+{
+	for row in 0 .. 4
+	{
+		for column in 0 .. 4
+		{
+			assert!((left.get(row, column) - right.get(row, column)).abs() < 1e-4, "{}: {:?} is not {:?}", description, left, right);
+		}
+	}
+}
+
+fn assert_round_trips(css: &str, decomposed: Option<TransformList>)
+{
+	let original = matrix(css);
+	let decomposed = decomposed.unwrap_or_else(|| panic!("{} did not decompose", css));
+	assert_matrices_equal(&decomposed.to_matrix(&conversion()).unwrap(), &original, &format!("{} decomposed to {}", css, decomposed.to_css_string()));
+	assert_matrices_equal(&original.decompose().to_matrix(&conversion()).unwrap(), &original, css);
+}
+
+#[test]
+fn two_dimensional_matrices_decompose_to_equivalent_transforms()
+{
+	for css in &["translate(10px, 20px) rotate(30deg) skewX(20deg) scale(2, 3)", "scale(-1, 1)", "rotate(120deg) scale(1, -2)", "skew(10deg, 20deg)", "translate(50%, 10px) rotate(-45deg)"]
+	{
+		assert_round_trips(css, matrix(css).decompose_2d());
+	}
+}
+
+#[test]
+fn three_dimensional_matrices_decompose_to_equivalent_transforms()
+{
+	for css in &["rotate3d(1, 2, 3, 45deg) scale3d(2, 3, 4)", "translate3d(1px, 2px, 3px) rotateY(60deg) skewX(-15deg) scale3d(2, 3, 1)", "rotateX(30deg) skewX(10deg)"]
+	{
+		assert_round_trips(css, matrix(css).decompose_3d());
+	}
+}
+
+#[test]
+fn reflected_three_dimensional_matrices_decompose_to_equivalent_transforms()
+{
+	for css in &["skewX(30deg) scale3d(1, 1, -1)", "rotateY(60deg) skewX(-15deg) scale3d(-2, 3, 1)", "translateZ(5px) skewX(45deg) scale3d(-1, -1, -1)"]
+	{
+		assert_round_trips(css, matrix(css).decompose_3d());
+	}
+}
+
+#[test]
+fn decomposition_is_used_when_shorter()
+{
+	assert_eq!(matrix("rotate(45deg)").decompose().to_css_string(), "rotate(45deg)");
+	assert_eq!(matrix("translateX(10px) rotate(90deg)").decompose().to_css_string(), "matrix(0,1,-1,0,10,0)");
+	assert_eq!(matrix("none").decompose().to_css_string(), "scale(1)");
+}
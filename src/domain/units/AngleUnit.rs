@@ -0,0 +1,399 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// An angle: https://www.w3.org/TR/css3-values/#angle-value
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub enum AngleUnit<Number: CssNumber>
+{
+	/// A "deg" value, degrees; there are 360 degrees in a full circle
+	deg(Number),
+	
+	/// A "grad" value, gradians; there are 400 gradians in a full circle
+	grad(Number),
+	
+	/// A "rad" value, radians; there are 2π radians in a full circle
+	rad(Number),
+	
+	/// A "turn" value, turns; there is 1 turn in a full circle
+	turn(Number),
+}
+
+impl<Number: CssNumber> ToCss for AngleUnit<Number>
+{
+	fn to_css<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		match *self
+		{
+			deg(angle) => serialize_dimension(angle, "deg", dest),
+			grad(angle) => serialize_dimension(angle, "grad", dest),
+			rad(angle) => serialize_dimension(angle, "rad", dest),
+			turn(angle) => serialize_dimension(angle, "turn", dest),
+		}
+	}
+}
+
+impl<Number: CssNumber> Default for AngleUnit<Number>
+{
+	#[inline(always)]
+	fn default() -> Self
+	{
+		deg(Number::default())
+	}
+}
+
+impl<Number: CssNumber> Add<Number> for AngleUnit<Number>
+{
+	type Output = AngleUnit<Number>;
+	
+	#[inline(always)]
+	fn add(self, rhs: Number) -> Self::Output
+	{
+		match self
+		{
+			deg(angle) => deg(angle + rhs),
+			grad(angle) => grad(angle + rhs),
+			rad(angle) => rad(angle + rhs),
+			turn(angle) => turn(angle + rhs),
+		}
+	}
+}
+
+impl<Number: CssNumber> AddAssign<Number> for AngleUnit<Number>
+{
+	#[inline(always)]
+	fn add_assign(&mut self, rhs: Number)
+	{
+		match *self
+		{
+			deg(ref mut angle) => *angle = *angle + rhs,
+			grad(ref mut angle) => *angle = *angle + rhs,
+			rad(ref mut angle) => *angle = *angle + rhs,
+			turn(ref mut angle) => *angle = *angle + rhs,
+		}
+	}
+}
+
+impl<Number: CssNumber> Sub<Number> for AngleUnit<Number>
+{
+	type Output = AngleUnit<Number>;
+	
+	#[inline(always)]
+	fn sub(self, rhs: Number) -> Self::Output
+	{
+		match self
+		{
+			deg(angle) => deg(angle - rhs),
+			grad(angle) => grad(angle - rhs),
+			rad(angle) => rad(angle - rhs),
+			turn(angle) => turn(angle - rhs),
+		}
+	}
+}
+
+impl<Number: CssNumber> SubAssign<Number> for AngleUnit<Number>
+{
+	#[inline(always)]
+	fn sub_assign(&mut self, rhs: Number)
+	{
+		match *self
+		{
+			deg(ref mut angle) => *angle = *angle - rhs,
+			grad(ref mut angle) => *angle = *angle - rhs,
+			rad(ref mut angle) => *angle = *angle - rhs,
+			turn(ref mut angle) => *angle = *angle - rhs,
+		}
+	}
+}
+
+impl<Number: CssNumber> Mul<Number> for AngleUnit<Number>
+{
+	type Output = AngleUnit<Number>;
+	
+	#[inline(always)]
+	fn mul(self, rhs: Number) -> Self::Output
+	{
+		match self
+		{
+			deg(angle) => deg(angle * rhs),
+			grad(angle) => grad(angle * rhs),
+			rad(angle) => rad(angle * rhs),
+			turn(angle) => turn(angle * rhs),
+		}
+	}
+}
+
+impl<Number: CssNumber> MulAssign<Number> for AngleUnit<Number>
+{
+	#[inline(always)]
+	fn mul_assign(&mut self, rhs: Number)
+	{
+		match *self
+		{
+			deg(ref mut angle) => *angle = *angle * rhs,
+			grad(ref mut angle) => *angle = *angle * rhs,
+			rad(ref mut angle) => *angle = *angle * rhs,
+			turn(ref mut angle) => *angle = *angle * rhs,
+		}
+	}
+}
+
+impl<Number: CssNumber> Div<Number> for AngleUnit<Number>
+{
+	type Output = AngleUnit<Number>;
+	
+	#[inline(always)]
+	fn div(self, rhs: Number) -> Self::Output
+	{
+		match self
+		{
+			deg(angle) => deg(angle / rhs),
+			grad(angle) => grad(angle / rhs),
+			rad(angle) => rad(angle / rhs),
+			turn(angle) => turn(angle / rhs),
+		}
+	}
+}
+
+impl<Number: CssNumber> DivAssign<Number> for AngleUnit<Number>
+{
+	#[inline(always)]
+	fn div_assign(&mut self, rhs: Number)
+	{
+		match *self
+		{
+			deg(ref mut angle) => *angle = *angle / rhs,
+			grad(ref mut angle) => *angle = *angle / rhs,
+			rad(ref mut angle) => *angle = *angle / rhs,
+			turn(ref mut angle) => *angle = *angle / rhs,
+		}
+	}
+}
+
+impl<Number: CssNumber> Rem<Number> for AngleUnit<Number>
+{
+	type Output = AngleUnit<Number>;
+	
+	#[inline(always)]
+	fn rem(self, rhs: Number) -> Self::Output
+	{
+		match self
+		{
+			deg(angle) => deg(angle % rhs),
+			grad(angle) => grad(angle % rhs),
+			rad(angle) => rad(angle % rhs),
+			turn(angle) => turn(angle % rhs),
+		}
+	}
+}
+
+impl<Number: CssNumber> RemAssign<Number> for AngleUnit<Number>
+{
+	#[inline(always)]
+	fn rem_assign(&mut self, rhs: Number)
+	{
+		match *self
+		{
+			deg(ref mut angle) => *angle = *angle % rhs,
+			grad(ref mut angle) => *angle = *angle % rhs,
+			rad(ref mut angle) => *angle = *angle % rhs,
+			turn(ref mut angle) => *angle = *angle % rhs,
+		}
+	}
+}
+
+impl<Number: CssNumber> Neg for AngleUnit<Number>
+{
+	type Output = AngleUnit<Number>;
+	
+	#[inline(always)]
+	fn neg(self) -> Self::Output
+	{
+		match self
+		{
+			deg(angle) => deg(-angle),
+			grad(angle) => grad(-angle),
+			rad(angle) => rad(-angle),
+			turn(angle) => turn(-angle),
+		}
+	}
+}
+
+impl<Number: CssNumber> CssNumberNewType<Number> for AngleUnit<Number>
+{
+	#[inline(always)]
+	fn to_f32(&self) -> f32
+	{
+		self.to_CssNumber().to_f32()
+	}
+	
+	#[inline(always)]
+	fn as_CssNumber(&self) -> &Number
+	{
+		match *self
+		{
+			deg(ref angle) => angle,
+			grad(ref angle) => angle,
+			rad(ref angle) => angle,
+			turn(ref angle) => angle,
+		}
+	}
+}
+
+impl<NumberX: CssNumber> Unit for AngleUnit<NumberX>
+{
+	type Number = NumberX;
+	
+	const HasDimension: bool = true;
+	
+	#[inline(always)]
+	fn parse_one_outside_calc_function<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<CalculablePropertyValue<Self>, ParseError<'i, CustomParseError<'i>>>
+	{
+		use ::cssparser::Token::*;
+		use self::CalculablePropertyValue::*;
+		
+		let functionParser = match *input.next()?
+		{
+			Number { value, .. } => if value == 0.
+			{
+				return Ok(Constant(Self::default()))
+			}
+			else
+			{
+				return CustomParseError::dimensionless(value)
+			},
+			
+			Dimension { value, ref unit, .. } => return Self::parseDimension(value, unit).map(Constant),
+			
+			Function(ref name) => FunctionParser::parser(name)?,
+			
+			ref unexpectedToken @ _ => return CustomParseError::unexpectedToken(unexpectedToken),
+		};
+		functionParser.parse_one_outside_calc_function(context, input)
+	}
+	
+	#[inline(always)]
+	fn parse_one_inside_calc_function<'i, 't>(context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<Either<CalculablePropertyValue<Self>, CalcExpression<Self>>, ParseError<'i, CustomParseError<'i>>>
+	{
+		use self::CalculablePropertyValue::*;
+		
+		let functionParser = match *input.next()?
+		{
+			Token::Number { value, .. } => return Self::number_inside_calc_function(value),
+			
+			Token::Percentage { unit_value, .. } => return PercentageUnit::parse_percentage(unit_value).map(|value| Left(Percentage(value))),
+			
+			Token::Dimension { value, ref unit, .. } => return Self::parseDimension(value, unit).map(|value| Left(Constant(value))),
+			
+			Token::ParenthesisBlock => FunctionParser::parentheses,
+			
+			Token::Function(ref name) => FunctionParser::parser(name)?,
+			
+			ref unexpectedToken @ _ => return CustomParseError::unexpectedToken(unexpectedToken),
+		};
+		functionParser.parse_one_inside_calc_function(context, input)
+	}
+	
+	#[inline(always)]
+	fn to_canonical_dimension(self) -> Self
+	{
+		match self
+		{
+			canonical @ deg(_) => canonical,
+			_ => deg(self.to_degrees()),
+		}
+	}
+	
+	#[inline(always)]
+	fn to_canonical_dimension_value<Conversion: FontRelativeLengthConversion<Self::Number> + ViewportPercentageLengthConversion<Self::Number>>(&self, _conversion: &Conversion) -> Self::Number
+	{
+		self.to_degrees()
+	}
+	
+	#[inline(always)]
+	fn from_raw_css_for_var_expression_evaluation(value: &str, _is_not_in_page_rule: bool) -> Option<Self>
+	{
+		fn from_raw_css_for_var_expression_evaluation_internal<'i: 't, 't, Number: CssNumber>(input: &mut Parser<'i, 't>) -> Result<AngleUnit<Number>, ParseError<'i, CustomParseError<'i>>>
+		{
+			let value = match *input.next()?
+			{
+				Token::Number { value, .. } =>
+				{
+					if value == 0.
+					{
+						Ok(AngleUnit::default())
+					}
+					else
+					{
+						CustomParseError::dimensionless(value)
+					}
+				}
+				
+				Token::Dimension { value, ref unit, .. } => AngleUnit::parseDimension(value, unit),
+				
+				ref unexpectedToken @ _ => CustomParseError::unexpectedToken(unexpectedToken),
+			};
+			
+			input.skip_whitespace();
+			
+			input.expect_exhausted()?;
+			
+			value
+		}
+		
+		const LineNumberingIsZeroBased: u32 = 0;
+		
+		let mut parserInput = ParserInput::new_with_line_number_offset(value, LineNumberingIsZeroBased);
+		let mut input = Parser::new(&mut parserInput);
+		
+		from_raw_css_for_var_expression_evaluation_internal(&mut input).ok()
+	}
+}
+
+impl<Number: CssNumber> AngleUnit<Number>
+{
+	/// This angle in degrees.
+	#[inline(always)]
+	pub fn to_degrees(&self) -> Number
+	{
+		match *self
+		{
+			deg(angle) => angle,
+			grad(angle) => angle * Number::_construct(360.0 / 400.0),
+			rad(angle) => angle * Number::_construct(180.0 / ::std::f32::consts::PI),
+			turn(angle) => angle * Number::_construct(360.0),
+		}
+	}
+	
+	/// This angle in radians, for use with trigonometric functions.
+	#[inline(always)]
+	pub fn to_radians(&self) -> f32
+	{
+		match *self
+		{
+			rad(angle) => angle.as_f32(),
+			_ => self.to_degrees().as_f32().to_radians(),
+		}
+	}
+	
+	#[inline(always)]
+	fn parseDimension<'i>(value: f32, unit: &CowRcStr<'i>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
+	{
+		let cssNumber = Number::new(value).map_err(|cssNumberConversionError| ParseError::Custom(CouldNotParseCssSignedNumber(cssNumberConversionError, value)))?;
+		
+		match_ignore_ascii_case!
+		{
+			&*unit,
+			
+			"deg" => return Ok(deg(cssNumber)),
+			
+			"grad" => return Ok(grad(cssNumber)),
+			
+			"rad" => return Ok(rad(cssNumber)),
+			
+			"turn" => return Ok(turn(cssNumber)),
+			
+			_ => return Err(ParseError::Custom(CouldNotParseDimension(value, unit.clone()))),
+		}
+	}
+}
@@ -267,6 +267,18 @@ impl<NumberX: CssNumber> Unit for LengthOrPercentageUnit<NumberX>
 		functionParser.parse_one_inside_calc_function(context, input)
 	}
 	
+	#[inline(always)]
+	fn to_canonical_dimension(self) -> Self
+	{
+		use self::LengthOrPercentageUnit::*;
+		
+		match self
+		{
+			IsLength(length) => IsLength(length.to_canonical_dimension()),
+			unchanged @ IsPercentage(_) => unchanged,
+		}
+	}
+	
 	#[inline(always)]
 	fn to_canonical_dimension_value<Conversion: FontRelativeLengthConversion<Self::Number> + ViewportPercentageLengthConversion<Self::Number> + PercentageConversion<Self::Number>>(&self, conversion: &Conversion) -> Self::Number
 	{
@@ -283,8 +283,8 @@ impl<NumberX: CssNumber> Unit for ResolutionUnit<NumberX>
 	{
 		match self
 		{
-			dpi(value) => dpi(value / NumberX::_construct(96.0)),
-			dpcm(value) => dppx(value / NumberX::_construct(96.0 * 2.54)),
+			dpi(value) => dppx(value / NumberX::_construct(96.0)),
+			dpcm(value) => dppx(value * NumberX::_construct(2.54 / 96.0)),
 			canonical @ _ => canonical,
 		}
 	}
@@ -292,12 +292,7 @@ impl<NumberX: CssNumber> Unit for ResolutionUnit<NumberX>
 	#[inline(always)]
 	fn to_canonical_dimension_value<Conversion: FontRelativeLengthConversion<Self::Number> + ViewportPercentageLengthConversion<Self::Number>>(&self, _conversion: &Conversion) -> Self::Number
 	{
-		match *self
-		{
-			dpi(value) => value / NumberX::_construct(96.0),
-			dpcm(value) => value / NumberX::_construct(96.0 * 2.54),
-			dppx(value) => value,
-		}
+		self.to_dppx()
 	}
 	
 	#[inline(always)]
@@ -342,6 +337,18 @@ impl<NumberX: CssNumber> Unit for ResolutionUnit<NumberX>
 
 impl<Number: CssNumber> ResolutionUnit<Number>
 {
+	/// This resolution in dots-per-pixel (`dppx`, also written `x`), the unit of a device pixel ratio.
+	#[inline(always)]
+	pub fn to_dppx(&self) -> Number
+	{
+		match *self
+		{
+			dpi(value) => value / Number::_construct(96.0),
+			dpcm(value) => value * Number::_construct(2.54 / 96.0),
+			dppx(value) => value,
+		}
+	}
+	
 	#[inline(always)]
 	fn parseDimension<'i>(value: f32, unit: &CowRcStr<'i>) -> Result<Self, ParseError<'i, CustomParseError<'i>>>
 	{
@@ -355,7 +362,9 @@ impl<Number: CssNumber> ResolutionUnit<Number>
 			
 			"dppx" => Ok(dppx(cssNumber)),
 			
-			"dpcm" => Ok(dppx(cssNumber)),
+			"x" => Ok(dppx(cssNumber)),
+			
+			"dpcm" => Ok(dpcm(cssNumber)),
 			
 			_ => Err(ParseError::Custom(CouldNotParseDimension(value, unit.clone()))),
 		}
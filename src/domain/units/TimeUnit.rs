@@ -268,7 +268,7 @@ impl<NumberX: CssNumber> Unit for TimeUnit<NumberX>
 		match self
 		{
 			s(seconds) => s(seconds),
-			ms(milliseconds) => ms(milliseconds / NumberX::_construct(1000_f32)),
+			ms(milliseconds) => s(milliseconds / NumberX::_construct(1000_f32)),
 		}
 	}
 	
@@ -4,6 +4,7 @@
 
 pub trait CssVariableConversion
 {
+	/// The raw CSS of the value of a custom property, if defined; custom property names are case-sensitive, so `--Main-Color` is looked up as `Main-Color`, not `main-color`.
 	#[inline(always)]
-	fn cssVariableValue(&self, css_variable_lower_case_name_without_leading_double_dash: &str) -> Option<&str>;
+	fn cssVariableValue(&self, css_variable_name_without_leading_double_dash: &str) -> Option<&str>;
 }
@@ -0,0 +1,15 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// The size of the reference box of a transform (usually the border box), against which percentages in `translate()` and `transform-origin` are resolved.
+pub trait ReferenceBoxConversion<Number: CssNumber>
+{
+	/// The width of the reference box in absolute units (`px`); percentages of horizontal values are of this.
+	#[inline(always)]
+	fn reference_box_width(&self) -> Number;
+	
+	/// The height of the reference box in absolute units (`px`); percentages of vertical values are of this.
+	#[inline(always)]
+	fn reference_box_height(&self) -> Number;
+}
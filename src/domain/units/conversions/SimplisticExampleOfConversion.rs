@@ -20,8 +20,12 @@ pub struct SimplisticExampleOfConversion<U: Unit>
 	// Percent conversion
 	pub one_hundred_percent_in_absolute_units: U::Number,
 	
+	// Reference box conversion
+	pub reference_box_width: U::Number,
+	pub reference_box_height: U::Number,
+	
 	// Simplistic approach
-	pub cssVariablesWithLowerCaseNamesWithoutLeadingDoubleDashToRawCss: HashMap<String, String>,
+	pub cssVariablesWithNamesWithoutLeadingDoubleDashToRawCss: HashMap<String, String>,
 	pub attributesWithLowerCaseNames: HashMap<String, String>,
 }
 
@@ -88,12 +92,27 @@ impl<U: Unit> PercentageConversion<U::Number> for SimplisticExampleOfConversion<
 	}
 }
 
+impl<U: Unit> ReferenceBoxConversion<U::Number> for SimplisticExampleOfConversion<U>
+{
+	#[inline(always)]
+	fn reference_box_width(&self) -> U::Number
+	{
+		self.reference_box_width
+	}
+	
+	#[inline(always)]
+	fn reference_box_height(&self) -> U::Number
+	{
+		self.reference_box_height
+	}
+}
+
 impl<U: Unit> CssVariableConversion for SimplisticExampleOfConversion<U>
 {
 	#[inline(always)]
-	fn cssVariableValue(&self, css_variable_lower_case_name_without_leading_double_dash: &str) -> Option<&str>
+	fn cssVariableValue(&self, css_variable_name_without_leading_double_dash: &str) -> Option<&str>
 	{
-		match self.cssVariablesWithLowerCaseNamesWithoutLeadingDoubleDashToRawCss.get(css_variable_lower_case_name_without_leading_double_dash)
+		match self.cssVariablesWithNamesWithoutLeadingDoubleDashToRawCss.get(css_variable_name_without_leading_double_dash)
 		{
 			Some(value) => Some(&value[..]),
 			None => None,
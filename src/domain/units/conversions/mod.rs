@@ -10,5 +10,6 @@ include!("AttributeConversion.rs");
 include!("CssVariableConversion.rs");
 include!("FontRelativeLengthConversion.rs");
 include!("PercentageConversion.rs");
+include!("ReferenceBoxConversion.rs");
 include!("SimplisticExampleOfConversion.rs");
 include!("ViewportPercentageLengthConversion.rs");
@@ -4,6 +4,7 @@
 
 use super::*;
 use self::AbsoluteLength::*;
+use self::AngleUnit::*;
 use self::LengthUnit::*;
 use self::either::Left;
 use self::either::Right;
@@ -34,6 +35,7 @@ pub mod conversions;
 
 
 include!("AbsoluteLength.rs");
+include!("AngleUnit.rs");
 include!("AppUnitsPer.rs");
 include!("FontRelativeLength.rs");
 include!("LengthOrPercentageUnit.rs");
@@ -69,30 +69,59 @@
 #[macro_use] extern crate bitflags;
 #[macro_use] pub extern crate cssparser;
 extern crate either;
+pub extern crate encoding_rs;
 pub extern crate ordermap;
 extern crate phf;
 extern crate precomputed_hash;
 #[macro_use] extern crate quick_error;
+extern crate serde_json;
 // To be re-introduced once selectors 0.19.0 lands in crates.io
 // pub extern crate selectors;
 pub extern crate smallvec;
 
 
 use self::domain::*;
+use self::domain::animations::UndefinedAnimationName;
+use self::domain::animations::undefined_animation_names;
 use self::domain::atRules::counterStyle::System;
+use self::domain::atRules::fontFace::FontFaceAtRule;
+use self::domain::atRules::fontFace::Source;
 use self::domain::atRules::namespace::Namespaces;
+use self::domain::customProperties::CustomPropertyGraph;
+use self::domain::expressions::TypeOrUnit;
+use self::domain::generatedContent::UndeclaredCounterStyle;
+use self::domain::generatedContent::undeclared_counter_styles;
+use self::domain::layout::DisplayInside;
+use self::domain::properties::ComponentValue;
+use self::domain::properties::HasImportance;
+use self::domain::properties::PropertyDeclaration;
+use self::domain::properties::PropertyDeclarations;
+use self::domain::properties::SpecifiedValue;
+use self::domain::properties::UnparsedPropertyValue;
 use self::domain::selectors::*;
+use self::domain::units::conversions::CssVariableConversion;
 use self::parsers::*;
 use self::serializers::*;
+use self::sourceMaps::SourceMapGenerator;
 use ::cssparser::*;
+use ::encoding_rs::Encoding;
+use ::encoding_rs::UTF_16BE;
+use ::encoding_rs::UTF_16LE;
+use ::encoding_rs::UTF_8;
 use ::quick_error::ResultExt;
 use ::selectors::parser::SelectorParseError;
+use ::std::cell::Cell;
+use ::std::cell::RefCell;
+use ::std::collections::HashMap;
+use ::std::collections::HashSet;
 use ::std::fmt;
 use ::std::fs::File;
 use ::std::io::Read;
+use ::std::mem::replace;
 use ::std::mem::uninitialized;
 use ::std::path::Path;
 use ::std::path::PathBuf;
+use ::std::rc::Rc;
 
 
 /// Contains definitions of objects used in Stylesheet.
@@ -100,6 +129,12 @@ pub mod domain;
 pub(crate) mod parsers;
 pub(crate) mod serializers;
 
+/// Source Map (revision 3) generation, for use with `Stylesheet::to_css_with_source_map()`.
+pub mod sourceMaps;
+
+#[cfg(test)]
+mod tests;
+
 
 // This module is forked from the servo repository 'https://github.com/servo/servo' component selectors crate (components/selectors) at revision 4f984a6428a0f497e311a0800efa55166c15aac6
 // To be removed once selectors 0.19.0 lands in crates.io
@@ -119,7 +154,24 @@ pub mod servo_arc;
 
 
 include!("BlockingIoOnlyStdFmtWriteToStdIoWriteAdaptor.rs");
+include!("CommentSerialization.rs");
 include!("CustomParseError.rs");
+include!("CustomPropertyInliner.rs");
+include!("CustomPropertyInliningPolicy.rs");
+include!("CustomPropertyInliningRefusal.rs");
+include!("CustomPropertyInliningRefusalReason.rs");
+include!("EncodingRsSupport.rs");
+include!("LosslessStylesheet.rs");
 include!("parse_css_selector.rs");
+include!("ParseLimit.rs");
+include!("ParseLimits.rs");
+include!("RecoveredParseError.rs");
+include!("Sanitizer.rs");
+include!("SanitizerPolicy.rs");
+include!("SanitizerRemoval.rs");
+include!("SanitizerRemovalReason.rs");
+include!("SerializerOptions.rs");
 include!("Stylesheet.rs");
 include!("StylesheetError.rs");
+include!("StylesheetParseOptions.rs");
+include!("VendorPrefixCase.rs");
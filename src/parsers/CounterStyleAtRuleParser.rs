@@ -16,14 +16,15 @@ impl<'a, 'i> AtRuleParser<'i> for CounterStyleAtRuleParser<'a>
 	
 	type PreludeBlock = ();
 	
-	type AtRule = ();
+	type AtRule = &'static str;
 	
 	type Error = CustomParseError<'i>;
 }
 
 impl<'a, 'i> DeclarationParser<'i> for CounterStyleAtRuleParser<'a>
 {
-	type Declaration = ();
+	/// The name of the descriptor, as serialized.
+	type Declaration = &'static str;
 	
 	type Error = CustomParseError<'i>;
 	
@@ -33,29 +34,67 @@ impl<'a, 'i> DeclarationParser<'i> for CounterStyleAtRuleParser<'a>
 		{
 			&name,
 			
-			"system" => self.rule.system = Some(input.parse_entirely(|input| Parse::parse(self.context, input))?),
+			"system" =>
+			{
+				self.rule.system = Some(input.parse_entirely(|input| Parse::parse(self.context, input))?);
+				Ok("system")
+			}
 			
-			"negative" => self.rule.negative = Some(input.parse_entirely(|input| Parse::parse(self.context, input))?),
+			"negative" =>
+			{
+				self.rule.negative = Some(input.parse_entirely(|input| Parse::parse(self.context, input))?);
+				Ok("negative")
+			}
 			
-			"prefix" => self.rule.prefix = Some(input.parse_entirely(|input| Parse::parse(self.context, input))?),
+			"prefix" =>
+			{
+				self.rule.prefix = Some(input.parse_entirely(|input| Parse::parse(self.context, input))?);
+				Ok("prefix")
+			}
 			
-			"suffix" => self.rule.suffix = Some(input.parse_entirely(|input| Parse::parse(self.context, input))?),
+			"suffix" =>
+			{
+				self.rule.suffix = Some(input.parse_entirely(|input| Parse::parse(self.context, input))?);
+				Ok("suffix")
+			}
 			
-			"range" => self.rule.range = Some(input.parse_entirely(|input| Parse::parse(self.context, input))?),
+			"range" =>
+			{
+				self.rule.range = Some(input.parse_entirely(|input| Parse::parse(self.context, input))?);
+				Ok("range")
+			}
 			
-			"pad" => self.rule.pad = Some(input.parse_entirely(|input| Parse::parse(self.context, input))?),
+			"pad" =>
+			{
+				self.rule.pad = Some(input.parse_entirely(|input| Parse::parse(self.context, input))?);
+				Ok("pad")
+			}
 			
-			"fallback" => self.rule.fallback = Some(input.parse_entirely(|input| Parse::parse(self.context, input))?),
+			"fallback" =>
+			{
+				self.rule.fallback = Some(input.parse_entirely(|input| Parse::parse(self.context, input))?);
+				Ok("fallback")
+			}
 			
-			"symbols" => self.rule.symbols = Some(input.parse_entirely(|input| Parse::parse(self.context, input))?),
+			"symbols" =>
+			{
+				self.rule.symbols = Some(input.parse_entirely(|input| Parse::parse(self.context, input))?);
+				Ok("symbols")
+			}
 			
-			"additive-symbols" => self.rule.additive_symbols = Some(input.parse_entirely(|input| Parse::parse(self.context, input))?),
+			"additive-symbols" =>
+			{
+				self.rule.additive_symbols = Some(input.parse_entirely(|input| Parse::parse(self.context, input))?);
+				Ok("additive-symbols")
+			}
 			
-			"speak-as" => self.rule.speak_as = Some(input.parse_entirely(|input| Parse::parse(self.context, input))?),
+			"speak-as" =>
+			{
+				self.rule.speak_as = Some(input.parse_entirely(|input| Parse::parse(self.context, input))?);
+				Ok("speak-as")
+			}
 			
-			_ => return Err(ParseError::Custom(CustomParseError::UnsupportedCounterStyleProperty(name.clone())))
+			_ => Err(ParseError::Custom(CustomParseError::UnsupportedCounterStyleProperty(name.clone())))
 		}
-		
-		Ok(())
 	}
 }
@@ -15,14 +15,15 @@ impl<'a, 'i> AtRuleParser<'i> for FontFaceAtRuleParser<'a>
 	
 	type PreludeBlock = ();
 	
-	type AtRule = ();
+	type AtRule = &'static str;
 	
 	type Error = CustomParseError<'i>;
 }
 
 impl<'a, 'i> DeclarationParser<'i> for FontFaceAtRuleParser<'a>
 {
-	type Declaration = ();
+	/// The name of the descriptor, as serialized.
+	type Declaration = &'static str;
 	
 	type Error = CustomParseError<'i>;
 	
@@ -34,27 +35,61 @@ impl<'a, 'i> DeclarationParser<'i> for FontFaceAtRuleParser<'a>
 		{
 			&name,
 			
-			"font-family" => self.rule.family = Some(input.parse_entirely(|i| Parse::parse(self.context, i))?),
+			"font-family" =>
+			{
+				self.rule.family = Some(input.parse_entirely(|i| Parse::parse(self.context, i))?);
+				Ok("font-family")
+			}
 			
-			"src" => self.rule.sources = Some(input.parse_entirely(|i| Parse::parse(self.context, i))?),
+			"src" =>
+			{
+				self.rule.sources = Some(input.parse_entirely(|i| Parse::parse(self.context, i))?);
+				Ok("src")
+			}
 			
-			"font-style" => self.rule.style = Some(input.parse_entirely(|i| Parse::parse(self.context, i))?),
+			"font-style" =>
+			{
+				self.rule.style = Some(input.parse_entirely(|i| Parse::parse(self.context, i))?);
+				Ok("font-style")
+			}
 			
-			"font-weight" => self.rule.weight = Some(input.parse_entirely(|i| Parse::parse(self.context, i))?),
+			"font-weight" =>
+			{
+				self.rule.weight = Some(input.parse_entirely(|i| Parse::parse(self.context, i))?);
+				Ok("font-weight")
+			}
 			
-			"font-stretch" => self.rule.stretch = Some(input.parse_entirely(|i| Parse::parse(self.context, i))?),
+			"font-stretch" =>
+			{
+				self.rule.stretch = Some(input.parse_entirely(|i| Parse::parse(self.context, i))?);
+				Ok("font-stretch")
+			}
 			
-			"font-display" => self.rule.display = Some(input.parse_entirely(|i| Parse::parse(self.context, i))?),
+			"font-display" =>
+			{
+				self.rule.display = Some(input.parse_entirely(|i| Parse::parse(self.context, i))?);
+				Ok("font-display")
+			}
 			
-			"unicode-range" => self.rule.unicode_range = Some(input.parse_entirely(|i| Parse::parse(self.context, i))?),
+			"unicode-range" =>
+			{
+				self.rule.unicode_range = Some(input.parse_entirely(|i| Parse::parse(self.context, i))?);
+				Ok("unicode-range")
+			}
 			
-			"font-feature-settings" => self.rule.feature_settings = Some(input.parse_entirely(|i| Parse::parse(self.context, i))?),
+			"font-feature-settings" =>
+			{
+				self.rule.feature_settings = Some(input.parse_entirely(|i| Parse::parse(self.context, i))?);
+				Ok("font-feature-settings")
+			}
 			
-			"font-language-override" => self.rule.language_override = Some(input.parse_entirely(|i| FontLanguageOverride::parse(i))?),
+			"font-language-override" =>
+			{
+				self.rule.language_override = Some(input.parse_entirely(|i| FontLanguageOverride::parse(i))?);
+				Ok("font-language-override")
+			}
 			
-			_ => return Err(ParseError::Custom(CustomParseError::UnsupportedFontFaceProperty(name.clone())))
+			_ => Err(ParseError::Custom(CustomParseError::UnsupportedFontFaceProperty(name.clone())))
 		}
-		
-		Ok(())
 	}
 }
@@ -17,28 +17,28 @@ impl<'a, 'i, T: 'a + Parse + ToCss> AtRuleParser<'i> for FontFeatureValuesDeclar
 	
 	type PreludeBlock = ();
 	
-	type AtRule = ();
+	type AtRule = usize;
 	
 	type Error = CustomParseError<'i>;
 }
 
 impl<'a, 'i, T: 'a + Parse + ToCss> DeclarationParser<'i> for FontFeatureValuesDeclarationsParser<'a, T>
 {
-	type Declaration = ();
+	/// The index of the declaration parsed.
+	type Declaration = usize;
 	
 	type Error = CustomParseError<'i>;
 	
-	fn parse_value<'t>(&mut self, name: CowRcStr<'i>, input: &mut Parser<'i, 't>) -> Result<(), ParseError<'i, CustomParseError<'i>>>
+	fn parse_value<'t>(&mut self, name: CowRcStr<'i>, input: &mut Parser<'i, 't>) -> Result<usize, ParseError<'i, CustomParseError<'i>>>
 	{
 		let fontFeatureValuesDeclaration = FontFeatureValuesDeclaration
 		{
 			name: Atom::from(&*name),
 			value: input.parse_entirely(|i| T::parse(self.context, i))?,
+			source_span: None,
 		};
 		
-		self.update_or_push(fontFeatureValuesDeclaration);
-		
-		Ok(())
+		Ok(self.update_or_push(fontFeatureValuesDeclaration))
 	}
 }
 
@@ -53,19 +53,25 @@ impl<'a, 'b: 'a, T: 'a + Parse + ToCss> FontFeatureValuesDeclarationsParser<'a,
 		};
 		
 		let mut iter = DeclarationListParser::new(input, parser);
-		while let Some(possiblePreciseParseError) = iter.next()
+		loop
 		{
-			if possiblePreciseParseError.is_err()
+			let start = context.source_span_start(iter.input, true);
+			
+			match iter.next()
 			{
-				return Err(possiblePreciseParseError.unwrap_err().error);
+				None => break,
+				
+				Some(Ok(index)) => iter.parser.declarations[index].source_span = context.source_span(iter.input, &start),
+				
+				Some(Err(preciseParseError)) => context.recover_from_error(preciseParseError)?,
 			}
 		}
 		
 		Ok(())
 	}
 	
-	/// Updates with new value if same `ident` exists, otherwise pushes to the vector.
-	fn update_or_push(&mut self, newDeclaration: FontFeatureValuesDeclaration<T>)
+	/// Updates with new value if same `ident` exists, otherwise pushes to the vector; returns the index of the declaration.
+	fn update_or_push(&mut self, newDeclaration: FontFeatureValuesDeclaration<T>) -> usize
 	{
 		let declarations = &mut self.declarations;
 		
@@ -74,10 +80,12 @@ impl<'a, 'b: 'a, T: 'a + Parse + ToCss> FontFeatureValuesDeclarationsParser<'a,
 		if let Some(index) = position
 		{
 			declarations[index].value = newDeclaration.value;
+			index
 		}
 		else
 		{
 			declarations.push(newDeclaration);
+			declarations.len() - 1
 		}
 	}
 }
@@ -37,6 +37,8 @@ impl<'a, 'i> QualifiedRuleParser<'i> for KeyframeListParser<'a>
 	
 	fn parse_prelude<'t>(&mut self, input: &mut Parser<'i, 't>) -> Result<Self::Prelude, ParseError<'i, CustomParseError<'i>>>
 	{
+		self.context.count_rule()?;
+		
 		match KeyframeSelector::parse(input)
 		{
 			Ok(selector) => Ok
@@ -55,12 +57,16 @@ impl<'a, 'i> QualifiedRuleParser<'i> for KeyframeListParser<'a>
 	{
 		let context = ParserContext::new_with_rule_type(self.context, CssRuleType::Keyframe);
 		
+		let (property_declarations, comments_after_property_declarations) = PropertyDeclarations::parse_property_declaration_list(&context, input)?;
+		
 		Ok
 		(
 			Keyframe
 			{
 				selector: prelude.selector,
-				property_declarations: PropertyDeclarations::parse_property_declaration_list(&context, input)?,
+				property_declarations,
+				comments_after_property_declarations,
+				source_span: None,
 			}
 		)
 	}
@@ -78,13 +84,21 @@ impl<'a> KeyframeListParser<'a>
 		
 		let mut keyframes = Vec::new();
 		
-		while let Some(keyframe) = iter.next()
+		loop
 		{
-			match keyframe
+			let start = context.source_span_start(iter.input, false);
+			
+			match iter.next()
 			{
-				Ok(keyframe) => keyframes.push(keyframe),
+				None => break,
+				
+				Some(Ok(mut keyframe)) =>
+				{
+					keyframe.source_span = context.source_span(iter.input, &start);
+					keyframes.push(keyframe)
+				}
 				
-				Err(preciseParseError) => return Err(preciseParseError.error),
+				Some(Err(preciseParseError)) => context.recover_from_error(preciseParseError)?,
 			}
 		}
 		
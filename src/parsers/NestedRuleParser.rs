@@ -13,7 +13,7 @@ impl<'a, 'i> AtRuleParser<'i> for NestedRuleParser<'a>
 {
 	type PreludeNoBlock = CssRule;
 	
-	type PreludeBlock = AtRuleBlockPrelude;
+	type PreludeBlock = (AtRuleBlockPrelude, Option<SourceSpan>);
 	
 	type AtRule = CssRule;
 	
@@ -25,87 +25,110 @@ impl<'a, 'i> AtRuleParser<'i> for NestedRuleParser<'a>
 		use self::AtRuleBlockPrelude::*;
 		use self::VendorPrefix::*;
 		
-		match_ignore_ascii_case!
+		self.context.count_rule()?;
+		
+		let start = self.context.source_span_start(input, false);
+		
+		let prelude = match_ignore_ascii_case!
 		{
 			&name,
 			
-			"counter-style" => Ok(WithBlock(CounterStyle(CounterStyleIdent::parseForCounterStyleAtRule(input)?))),
+			"counter-style" => CounterStyle(CounterStyleIdent::parseForCounterStyleAtRule(input)?),
 			
-			"document" => Ok(WithBlock(Document(None, DocumentCondition::parse(self.context, input)?))),
+			"document" => Document(None, DocumentCondition::parse(self.context, input)?),
 			
-			"-moz-document" => Ok(WithBlock(Document(Some(moz), DocumentCondition::parse(self.context, input)?))),
+			"-moz-document" => Document(Some(moz), DocumentCondition::parse(self.context, input)?),
 			
-			"font-face" => Ok(WithBlock(FontFace)),
+			"font-face" => FontFace,
 			
-			"font-feature-values" => Ok(WithBlock(FontFeatureValues(FamilyName::parse_family_name_list(self.context, input)?))),
+			"font-feature-values" => FontFeatureValues(FamilyName::parse_family_name_list(self.context, input)?),
 			
-			"keyframes" => Ok(WithBlock(Keyframes(None, KeyframesName::parse(input)?))),
+			"keyframes" => Keyframes(None, KeyframesName::parse(input)?),
 			
-			"-webkit-keyframes" => Ok(WithBlock(Keyframes(Some(webkit), KeyframesName::parse(input)?))),
+			"-webkit-keyframes" => Keyframes(Some(webkit), KeyframesName::parse(input)?),
 			
-			"-moz-keyframes" => Ok(WithBlock(Keyframes(Some(moz), KeyframesName::parse(input)?))),
+			"-moz-keyframes" => Keyframes(Some(moz), KeyframesName::parse(input)?),
 			
-			"media" => Ok(WithBlock(Media(MediaList::parse_media_query_list(self.context, input, false)?))),
+			"media" => Media(MediaList::parse_media_query_list(self.context, input, false)?),
 			
-			"page" => Ok(WithBlock(Page(PageSelectorPseudoClass::parse(input)?))),
+			"page" => Page(PageSelectorPseudoClass::parse(input)?),
 			
-			"supports" => Ok(WithBlock(Supports(SupportsCondition::parse(input)?))),
+			"supports" => Supports(SupportsCondition::parse(input)?),
 			
-			"viewport" => Ok(WithBlock(Viewport(None))),
+			"viewport" => Viewport(None),
 			
-			"-ms-viewport" => Ok(WithBlock(Viewport(Some(ms)))),
+			"-ms-viewport" => Viewport(Some(ms)),
 			
-			"-o-viewport" => Ok(WithBlock(Viewport(Some(o)))),
+			"-o-viewport" => Viewport(Some(o)),
 			
-			_ => Err(ParseError::Custom(CustomParseError::UnsupportedAtRule(name.clone())))
-		}
+			_ => return Err(ParseError::Custom(CustomParseError::UnsupportedAtRule(name.clone())))
+		};
+		
+		Ok(WithBlock((prelude, self.context.source_span(input, &start))))
 	}
 	
-	fn parse_block<'t>(&mut self, prelude: AtRuleBlockPrelude, input: &mut Parser<'i, 't>) -> Result<Self::AtRule, ParseError<'i, Self::Error>>
+	fn parse_block<'t>(&mut self, prelude: Self::PreludeBlock, input: &mut Parser<'i, 't>) -> Result<Self::AtRule, ParseError<'i, Self::Error>>
 	{
 		use self::AtRuleBlockPrelude::*;
 		
+		let (prelude, prelude_source_span) = prelude;
+		
 		let cssRule = match prelude
 		{
-			CounterStyle(name) => CssRule::CounterStyle(CounterStyleAtRule::parse_body(name, &CssRuleType::CounterStyle.context(self), input)?),
+			CounterStyle(name) => CssRule::CounterStyle(CounterStyleAtRule::parse_body(name, prelude_source_span, &CssRuleType::CounterStyle.context(self), input)?),
 			
 			Document(vendor_prefix, condition) => CssRule::Document(DocumentAtRule
 			{
 				vendor_prefix,
 				condition,
 				rules: self.parse_nested_rules(input, CssRuleType::Document)?,
+				source_span: None,
+				prelude_source_span,
 			}),
 			
-			FontFace => CssRule::FontFace(FontFaceAtRule::parse_body(&CssRuleType::FontFace.context(self), input)?),
+			FontFace => CssRule::FontFace(FontFaceAtRule::parse_body(prelude_source_span, &CssRuleType::FontFace.context(self), input)?),
 			
-			FontFeatureValues(family_names) => CssRule::FontFeatureValues(FontFeatureValuesAtRule::parse_body(&CssRuleType::FontFeatureValues.context(self), input, family_names)?),
+			FontFeatureValues(family_names) => CssRule::FontFeatureValues(FontFeatureValuesAtRule::parse_body(&CssRuleType::FontFeatureValues.context(self), input, family_names, prelude_source_span)?),
 			
 			Keyframes(vendor_prefix, name) => CssRule::Keyframes(KeyframesAtRule
 			{
 				vendor_prefix,
 				name,
 				keyframes: KeyframeListParser::parse_keyframe_list(&CssRuleType::Keyframes.context(self), input)?,
+				source_span: None,
+				prelude_source_span,
 			}),
 			
 			Media(media_queries) => CssRule::Media(MediaAtRule
 			{
 				media_queries,
 				rules: self.parse_nested_rules(input, CssRuleType::Media)?,
+				source_span: None,
+				prelude_source_span,
 			}),
 			
-			Page(page_selector_pseudo_class) => CssRule::Page(PageAtRule
+			Page(page_selector_pseudo_class) =>
 			{
-				page_selector_pseudo_class,
-				property_declarations: PropertyDeclarations::parse_property_declaration_list(&CssRuleType::Page.context(self), input)?,
-			}),
+				let (property_declarations, comments_after_property_declarations) = PropertyDeclarations::parse_property_declaration_list(&CssRuleType::Page.context(self), input)?;
+				CssRule::Page(PageAtRule
+				{
+					page_selector_pseudo_class,
+					property_declarations,
+					comments_after_property_declarations,
+					source_span: None,
+					prelude_source_span,
+				})
+			}
 			
 			Supports(condition) => CssRule::Supports(SupportsAtRule
 			{
 				condition,
 				rules: self.parse_nested_rules(input, CssRuleType::Supports)?,
+				source_span: None,
+				prelude_source_span,
 			}),
 			
-			Viewport(vendor_prefix) => CssRule::Viewport(ViewportAtRule::parse_body(vendor_prefix, &CssRuleType::Viewport.context(self), input)?),
+			Viewport(vendor_prefix) => CssRule::Viewport(ViewportAtRule::parse_body(vendor_prefix, prelude_source_span, &CssRuleType::Viewport.context(self), input)?),
 		};
 		
 		Ok(cssRule)
@@ -122,6 +145,8 @@ impl<'a, 'i> QualifiedRuleParser<'i> for NestedRuleParser<'a>
 	
 	fn parse_prelude<'t>(&mut self, input: &mut Parser<'i, 't>) -> Result<Self::Prelude, ParseError<'i, Self::Error>>
 	{
+		self.context.count_rule()?;
+		
 		let applyVendorPrefixToPseudoClasses = HashMap::default();
 		let applyVendorPrefixToPseudoElements = HashMap::default();
 		let ourSelectorParser = OurSelectorParser
@@ -131,13 +156,14 @@ impl<'a, 'i> QualifiedRuleParser<'i> for NestedRuleParser<'a>
 			applyVendorPrefixToPseudoElements: &applyVendorPrefixToPseudoElements,
 		};
 		
-		let selectors = ourSelectorParser.parse(input)?;
+		let (selectors, selector_source_spans) = ourSelectorParser.parse_in_context(self.context, input)?;
 		
 		Ok
 		(
 			QualifiedRuleParserPrelude
 			{
 				selectors,
+				selector_source_spans,
 			}
 		)
 	}
@@ -146,10 +172,15 @@ impl<'a, 'i> QualifiedRuleParser<'i> for NestedRuleParser<'a>
 	{
 		let context = ParserContext::new_with_rule_type(self.context, CssRuleType::Style);
 		
+		let (property_declarations, comments_after_property_declarations) = PropertyDeclarations::parse_property_declaration_list(&context, input)?;
+		
 		let styleRule = StyleRule
 		{
 			selectors: prelude.selectors,
-			property_declarations: PropertyDeclarations::parse_property_declaration_list(&context, input)?,
+			selector_source_spans: prelude.selector_source_spans,
+			property_declarations,
+			comments_after_property_declarations,
+			source_span: None,
 		};
 		
 		Ok(CssRule::Style(styleRule))
@@ -166,7 +197,8 @@ impl<'a> NestedRuleParser<'a>
 	
 	fn parse_nested_rules<'i: 't, 't>(&mut self, input: &mut Parser<'i, 't>, rule_type: CssRuleType) -> Result<CssRules, ParseError<'i, CustomParseError<'i>>>
 	{
-		let context = rule_type.context(self);
+		let mut context = rule_type.context(self);
+		context.nest()?;
 		
 		let nested_parser = NestedRuleParser
 		{
@@ -176,12 +208,26 @@ impl<'a> NestedRuleParser<'a>
 		
 		let mut iter = RuleListParser::new_for_nested_rule(input, nested_parser);
 		let mut rules = Vec::new();
-		while let Some(result) = iter.next()
+		loop
 		{
-			match result
+			for comment in context.parse_comments(iter.input, false)
+			{
+				rules.push(CssRule::Comment(comment));
+			}
+			
+			let start = context.source_span_start(iter.input, false);
+			
+			match iter.next()
 			{
-				Ok(rule) => rules.push(rule),
-				Err(preciseParseError) => return Err(preciseParseError.error),
+				None => break,
+				
+				Some(Ok(mut rule)) =>
+				{
+					*rule.source_span_mut() = context.source_span(iter.input, &start);
+					rules.push(rule)
+				}
+				
+				Some(Err(preciseParseError)) => context.recover_from_error(preciseParseError)?,
 			}
 		}
 		Ok(CssRules(rules))
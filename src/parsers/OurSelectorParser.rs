@@ -58,18 +58,26 @@ impl<'a> OurSelectorParser<'a>
 	#[inline(always)]
 	pub(crate) fn parse<'i, 't>(&self, input: &mut Parser<'i, 't>) -> Result<DeduplicatedSelectors, ParseError<'i, CustomParseError<'i>>>
 	{
-		self.parse_internal(input, |_| false)
+		self.parse_internal(input, None, |_| false).map(|(selectors, _)| selectors)
 	}
 	
+	/// As for `parse()`, but also returns the source span of each selector, in the same order, if the context is recording source spans (otherwise there are none).
 	#[inline(always)]
-	pub(crate) fn parse_internal<'i, 't, F: Fn(&OurSelector) -> bool>(&self, input: &mut Parser<'i, 't>, isInvalidSelector: F) -> Result<DeduplicatedSelectors, ParseError<'i, CustomParseError<'i>>>
+	pub(crate) fn parse_in_context<'i, 't>(&self, context: &ParserContext, input: &mut Parser<'i, 't>) -> Result<(DeduplicatedSelectors, Vec<SourceSpan>), ParseError<'i, CustomParseError<'i>>>
 	{
-		let selectors = self.parse_selectors(input).map_err(|parseError|
+		self.parse_internal(input, Some(context), |_| false)
+	}
+	
+	#[inline(always)]
+	pub(crate) fn parse_internal<'i, 't, F: Fn(&OurSelector) -> bool>(&self, input: &mut Parser<'i, 't>, context: Option<&ParserContext>, isInvalidSelector: F) -> Result<(DeduplicatedSelectors, Vec<SourceSpan>), ParseError<'i, CustomParseError<'i>>>
+	{
+		let selectors = self.parse_selectors(input, context).map_err(|parseError|
 		{
 			match parseError
 			{
 				// We are changing from a ParseError<SelectorParseError> to a ParseError<CustomParseError<'i>>, hence this superficially looking redundant code
 				ParseError::Basic(basicParseError) => ParseError::Basic(basicParseError),
+				ParseError::Custom(SelectorParseError::Custom(CustomParseError::ParseLimitExceeded(parseLimit))) => ParseError::Custom(CustomParseError::ParseLimitExceeded(parseLimit)),
 				ParseError::Custom(selectorParseError) => ParseError::Custom(CustomParseError::SpecificSelectorParseError(Box::new(selectorParseError))),
 			}
 		})?;
@@ -80,7 +88,7 @@ impl<'a> OurSelectorParser<'a>
 		}
 		
 		let mut deduplicatedSelectors = OrderMap::with_capacity(selectors.len());
-		for selector in selectors
+		for (selector, sourceSpan) in selectors
 		{
 			let selectorCss = selector.to_css_string();
 			if isInvalidSelector(&selector)
@@ -89,22 +97,58 @@ impl<'a> OurSelectorParser<'a>
 			}
 			
 			// Selector does not implement Eq or Hash... Grrr...
-			deduplicatedSelectors.insert(selectorCss, selector);
+			// The source span of the first occurrence of a duplicated selector is kept.
+			deduplicatedSelectors.entry(selectorCss).or_insert((selector, sourceSpan));
 		}
 		
 		let mut listOfSelectors = Vec::with_capacity(deduplicatedSelectors.len());
-		for (_css, selector) in deduplicatedSelectors.drain(..)
+		let mut listOfSourceSpans = Vec::new();
+		for (_css, (selector, sourceSpan)) in deduplicatedSelectors.drain(..)
 		{
-			listOfSelectors.push(selector)
+			listOfSelectors.push(selector);
+			if let Some(sourceSpan) = sourceSpan
+			{
+				listOfSourceSpans.push(sourceSpan);
+			}
 		}
 		
-		Ok(DeduplicatedSelectors(listOfSelectors))
+		Ok((DeduplicatedSelectors(listOfSelectors), listOfSourceSpans))
 	}
 	
 	#[inline(always)]
-	fn parse_selectors<'i, 't>(&self, input: &mut Parser<'i, 't>) -> Result<SmallVec<[OurSelector; 1]>, ParseError<'i, SelectorParseError<'i, CustomParseError<'i>>>>
+	fn parse_selectors<'i, 't>(&self, input: &mut Parser<'i, 't>, context: Option<&ParserContext>) -> Result<SmallVec<[(OurSelector, Option<SourceSpan>); 1]>, ParseError<'i, SelectorParseError<'i, CustomParseError<'i>>>>
 	{
-		let selectorList = SelectorList::parse(self, input)?;
-		Ok(selectorList.0)
+		match context
+		{
+			Some(context) =>
+			{
+				// Equivalent to `SelectorList::parse()`, but noting where each selector starts and ends and limiting how many selectors there can be.
+				let mut selectors = SmallVec::new();
+				loop
+				{
+					if selectors.len() >= context.limits.maximum_selector_list_length
+					{
+						return Err(ParseError::Custom(SelectorParseError::Custom(CustomParseError::ParseLimitExceeded(ParseLimit::SelectorListLength))));
+					}
+					
+					let start = context.source_span_start(input, false);
+					let selector = input.parse_until_before(Delimiter::Comma, |input| parse_selector(self, input))?;
+					selectors.push((selector, context.source_span(input, &start)));
+					
+					match input.next()
+					{
+						Err(_) => return Ok(selectors),
+						Ok(&Token::Comma) => continue,
+						Ok(_) => unreachable!(),
+					}
+				}
+			}
+			
+			_ =>
+			{
+				let selectorList = SelectorList::parse(self, input)?;
+				Ok(selectorList.0.into_iter().map(|selector| (selector, None)).collect())
+			}
+		}
 	}
 }
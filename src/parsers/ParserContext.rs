@@ -10,6 +10,27 @@ pub struct ParserContext
 	
 	/// The mode to use when parsing.
 	pub(crate) parsing_mode: ParsingMode,
+	
+	/// Present if invalid rules and declarations should be skipped, rather than causing parsing to fail.
+	pub(crate) recovered_parse_errors: Option<Rc<RefCell<Vec<RecoveredParseError>>>>,
+	
+	/// Present if source spans should be recorded; the position of the start of the stylesheet, from which byte offsets are measured.
+	pub(crate) record_source_spans_from: Option<SourcePosition>,
+	
+	/// Whether comments between rules and between declarations are kept.
+	pub(crate) preserve_comments: bool,
+	
+	/// Limits on what parsing may consume.
+	pub(crate) limits: ParseLimits,
+	
+	/// How deeply `@media`, `@supports` and `@document` rules are nested.
+	pub(crate) nesting_depth: usize,
+	
+	/// The number of rules parsed so far in the stylesheet.
+	pub(crate) rule_count: Rc<Cell<usize>>,
+	
+	/// How deeply `calc()` expressions currently being parsed are nested.
+	pub(crate) calc_expression_depth: Cell<usize>,
 }
 
 impl ParserContext
@@ -21,6 +42,169 @@ impl ParserContext
 		{
 			rule_type: Some(rule_type),
 			parsing_mode: context.parsing_mode,
+			recovered_parse_errors: context.recovered_parse_errors.clone(),
+			record_source_spans_from: context.record_source_spans_from,
+			preserve_comments: context.preserve_comments,
+			limits: context.limits,
+			nesting_depth: context.nesting_depth,
+			rule_count: context.rule_count.clone(),
+			calc_expression_depth: Cell::new(context.calc_expression_depth.get()),
+		}
+	}
+	
+	/// Creates a parser context for parsing the value of a property declaration in a style rule after the stylesheet has been parsed (see `PropertyValue`).
+	pub(crate) fn new_for_property_value(limits: ParseLimits) -> ParserContext
+	{
+		Self
+		{
+			rule_type: Some(CssRuleType::Style),
+			parsing_mode: ParsingMode::Default,
+			recovered_parse_errors: None,
+			record_source_spans_from: None,
+			preserve_comments: false,
+			limits,
+			nesting_depth: 0,
+			rule_count: Rc::new(Cell::new(0)),
+			calc_expression_depth: Cell::new(0),
+		}
+	}
+	
+	/// Notes that the rules about to be parsed are nested one level deeper, failing if they are nested too deeply.
+	#[inline(always)]
+	pub(crate) fn nest<'i>(&mut self) -> Result<(), ParseError<'i, CustomParseError<'i>>>
+	{
+		if self.nesting_depth >= self.limits.maximum_nesting_depth
+		{
+			return CustomParseError::parseLimitExceeded(ParseLimit::NestingDepth);
+		}
+		self.nesting_depth += 1;
+		Ok(())
+	}
+	
+	/// Counts another rule, failing if there are too many.
+	#[inline(always)]
+	pub(crate) fn count_rule<'i>(&self) -> Result<(), ParseError<'i, CustomParseError<'i>>>
+	{
+		let rule_count = self.rule_count.get();
+		if rule_count >= self.limits.maximum_rule_count
+		{
+			return CustomParseError::parseLimitExceeded(ParseLimit::RuleCount);
+		}
+		self.rule_count.set(rule_count + 1);
+		Ok(())
+	}
+	
+	/// Parses a `calc()` expression (or parentheses within one) nested one level deeper, failing if it is nested too deeply.
+	#[inline(always)]
+	pub(crate) fn parse_nested_calc_expression<'i, T, F: FnOnce() -> Result<T, ParseError<'i, CustomParseError<'i>>>>(&self, parse: F) -> Result<T, ParseError<'i, CustomParseError<'i>>>
+	{
+		let calc_expression_depth = self.calc_expression_depth.get();
+		if calc_expression_depth >= self.limits.maximum_calc_expression_depth
+		{
+			return CustomParseError::parseLimitExceeded(ParseLimit::CalcExpressionDepth);
+		}
+		
+		self.calc_expression_depth.set(calc_expression_depth + 1);
+		let result = parse();
+		self.calc_expression_depth.set(calc_expression_depth);
+		result
+	}
+	
+	/// If preserving comments, consumes any whitespace and comments (and, if `skipSemicolons`, any stray semicolons) before the next rule or declaration, returning the comments.
+	pub(crate) fn parse_comments<'i, 't>(&self, input: &mut Parser<'i, 't>, skipSemicolons: bool) -> Vec<CssComment>
+	{
+		let mut comments = Vec::new();
+		
+		if !self.preserve_comments
+		{
+			return comments;
+		}
+		
+		loop
+		{
+			let start = input.state();
+			
+			match input.next_including_whitespace_and_comments().map(|token| token.clone())
+			{
+				Ok(Token::WhiteSpace(_)) => (),
+				
+				Ok(Token::Semicolon) if skipSemicolons => (),
+				
+				Ok(Token::Comment(text)) =>
+				{
+					let source_span = self.source_span(input, &Some(start));
+					comments.push(CssComment
+					{
+						text: text.to_owned(),
+						source_span,
+					})
+				}
+				
+				_ =>
+				{
+					input.reset(&start);
+					return comments;
+				}
+			}
+		}
+	}
+	
+	/// If recording source spans, skips any whitespace and comments (and, if `skipSemicolons`, any stray semicolons) before the next rule or declaration and returns the state it starts at.
+	#[inline(always)]
+	pub(crate) fn source_span_start<'i, 't>(&self, input: &mut Parser<'i, 't>, skipSemicolons: bool) -> Option<ParserState>
+	{
+		if self.record_source_spans_from.is_none()
+		{
+			return None;
+		}
+		
+		if skipSemicolons
+		{
+			while input.try(|input| input.expect_semicolon()).is_ok()
+			{
+			}
+		}
+		input.skip_whitespace();
+		
+		Some(input.state())
+	}
+	
+	/// If recording source spans, the span from `start` to the current position of `input`.
+	#[inline(always)]
+	pub(crate) fn source_span<'i, 't>(&self, input: &Parser<'i, 't>, start: &Option<ParserState>) -> Option<SourceSpan>
+	{
+		match (self.record_source_spans_from, start.as_ref())
+		{
+			(Some(stylesheetStart), Some(start)) => Some(SourceSpan::new(stylesheetStart, input, start)),
+			_ => None,
+		}
+	}
+	
+	/// If recovering from errors, records the error so that the invalid rule or declaration is skipped; otherwise, returns the error.
+	/// Exceeding a parse limit is never recovered from.
+	#[inline(always)]
+	pub(crate) fn recover_from_error<'i>(&self, preciseParseError: PreciseParseError<'i, CustomParseError<'i>>) -> Result<(), ParseError<'i, CustomParseError<'i>>>
+	{
+		match self.recovered_parse_errors
+		{
+			Some(ref recovered_parse_errors) if Self::is_recoverable(&preciseParseError) =>
+			{
+				recovered_parse_errors.borrow_mut().push(RecoveredParseError::from(preciseParseError));
+				Ok(())
+			}
+			
+			_ => Err(preciseParseError.error),
+		}
+	}
+	
+	/// Whether an error can be recovered from (by skipping the invalid rule or declaration).
+	#[inline(always)]
+	pub(crate) fn is_recoverable<'i>(preciseParseError: &PreciseParseError<'i, CustomParseError<'i>>) -> bool
+	{
+		match preciseParseError.error
+		{
+			ParseError::Custom(CustomParseError::ParseLimitExceeded(_)) => false,
+			_ => true,
 		}
 	}
 	
@@ -29,19 +29,37 @@ impl<'a, 'i, I: HasImportance> DeclarationParser<'i> for PropertyDeclarationPars
 	
 	fn parse_value<'t>(&mut self, name: CowRcStr<'i>, input: &mut Parser<'i, 't>) -> Result<Self::Declaration, ParseError<'i, Self::Error>>
 	{
-		let (vendor_prefix, unprefixedPropertyName) = VendorPrefix::findPrefixIfAnyForAsciiLowerCaseName(name.to_ascii_lowercase());
+		// Custom property names are case-sensitive: https://drafts.csswg.org/css-variables/#defining-variables
+		let (vendor_prefix, unprefixedPropertyName) = if name.starts_with("--")
+		{
+			(None, name.as_ref().to_owned())
+		}
+		else
+		{
+			VendorPrefix::findPrefixIfAnyForAsciiLowerCaseName(name.to_ascii_lowercase())
+		};
+		
+		let vendor_prefixed_name_as_written = if vendor_prefix.is_some() && name.bytes().any(|byte| byte.is_ascii_uppercase())
+		{
+			Some(name.as_ref().to_owned())
+		}
+		else
+		{
+			None
+		};
 		
 		let name = Atom::from(unprefixedPropertyName);
 		
-		let value = input.parse_until_before(Delimiter::Bang, |input|
+		let (value, references) = input.parse_until_before(Delimiter::Bang, |input|
 		{
 			if let Ok(cssWideKeyword) = input.try(|input| CssWideKeyword::parse(input))
 			{
-				Ok(UnparsedPropertyValue::CssWideKeyword(cssWideKeyword))
+				Ok((UnparsedPropertyValue::CssWideKeyword(cssWideKeyword), BTreeSet::new()))
 			}
 			else
 			{
-				Ok(UnparsedPropertyValue::SpecifiedValue(SpecifiedValue::parse(self.context, input)?))
+				let (specifiedValue, references) = SpecifiedValue::parse(self.context, input)?;
+				Ok((UnparsedPropertyValue::SpecifiedValue(specifiedValue), references))
 			}
 		})?;
 		
@@ -57,6 +75,10 @@ impl<'a, 'i, I: HasImportance> DeclarationParser<'i> for PropertyDeclarationPars
 				name,
 				value,
 				importance,
+				references,
+				vendor_prefixed_name_as_written,
+				comments_before: Vec::new(),
+				source_span: None,
 			}
 		)
 	}
@@ -5,4 +5,5 @@
 pub(crate) struct QualifiedRuleParserPrelude
 {
 	selectors: DeduplicatedSelectors,
+	selector_source_spans: Vec<SourceSpan>,
 }
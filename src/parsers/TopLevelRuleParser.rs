@@ -18,7 +18,7 @@ impl<'i> AtRuleParser<'i> for TopLevelRuleParser
 {
 	type PreludeNoBlock = CssRule;
 	
-	type PreludeBlock = AtRuleBlockPrelude;
+	type PreludeBlock = (AtRuleBlockPrelude, Option<SourceSpan>);
 	
 	type AtRule = CssRule;
 	
@@ -34,7 +34,7 @@ impl<'i> AtRuleParser<'i> for TopLevelRuleParser
 			
 			"charset" =>
 			{
-				// @charset is removed by cssparser if it’s the first rule in the stylesheet; anything left is invalid.
+				// @charset is skipped by Stylesheet::parse_with_options() wherever it is in the stylesheet; anything left is invalid.
 				Err(ParseError::Custom(CustomParseError::UnexpectedCharsetAtRule))
 			}
 			
@@ -45,6 +45,7 @@ impl<'i> AtRuleParser<'i> for TopLevelRuleParser
 					return Err(ParseError::Custom(CustomParseError::AtRuleImportMustBeBeforeAnyRuleExceptAtRuleCharset));
 				}
 				
+				self.context.count_rule()?;
 				Ok(WithoutBlock(CssRule::Import(self.parseImportAtRule(input)?)))
 			}
 			
@@ -56,6 +57,7 @@ impl<'i> AtRuleParser<'i> for TopLevelRuleParser
 					return Err(ParseError::Custom(CustomParseError::AtRuleNamespaceMustBeBeforeAnyRuleExceptAtRuleCharsetAndAtRuleImport));
 				}
 				
+				self.context.count_rule()?;
 				Ok(WithoutBlock(CssRule::Namespace(self.parseNamespaceAtRule(input)?)))
 			}
 			
@@ -165,12 +167,20 @@ impl TopLevelRuleParser
 	#[inline(always)]
 	fn parseImportAtRule<'i, 't>(&self, input: &mut Parser<'i, 't>) -> Result<ImportAtRule, ParseError<'i, CustomParseError<'i>>>
 	{
+		let start = self.context.source_span_start(input, false);
+		
+		let url = SpecifiedUrl(input.expect_url_or_string()?.as_ref().to_owned());
+		
+		let media_list = MediaList::parse_media_query_list(&self.context, input, false)?;
+		
 		Ok
 		(
 			ImportAtRule
 			{
-				url: SpecifiedUrl(input.expect_url_or_string()?.as_ref().to_owned()),
-				media_list: MediaList::parse_media_query_list(&self.context, input, false)?,
+				url,
+				media_list,
+				source_span: None,
+				prelude_source_span: self.context.source_span(input, &start),
 			}
 		)
 	}
@@ -178,6 +188,8 @@ impl TopLevelRuleParser
 	#[inline(always)]
 	fn parseNamespaceAtRule<'i, 't>(&mut self, input: &mut Parser<'i, 't>) -> Result<NamespaceAtRule, ParseError<'i, CustomParseError<'i>>>
 	{
+		let start = self.context.source_span_start(input, false);
+		
 		let prefix: Result<_, ParseError<CustomParseError>> = input.try(|i|
 		{
 			let ident: &CowRcStr = i.expect_ident()?;
@@ -203,6 +215,8 @@ impl TopLevelRuleParser
 			{
 				prefix,
 				url,
+				source_span: None,
+				prelude_source_span: self.context.source_span(input, &start),
 			}
 		)
 	}
@@ -19,8 +19,11 @@ use self::separators::*;
 use ::ordermap::OrderMap;
 use ::selectors::parser::SelectorImpl;
 use ::selectors::parser::SelectorList;
+use ::selectors::parser::parse_selector;
 use ::smallvec::SmallVec;
 use ::std::ascii::AsciiExt;
+use ::std::cell::Cell;
+use ::std::collections::BTreeSet;
 use ::std::collections::HashMap;
 use ::std::marker::PhantomData;
 use ::std::rc::Rc;
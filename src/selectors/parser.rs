@@ -1050,7 +1050,7 @@ fn display_to_css_identifier<T: Display, W: fmt::Write>(x: &T, dest: &mut W) ->
 /// selector : simple_selector_sequence [ combinator simple_selector_sequence ]* ;
 ///
 /// `Err` means invalid selector.
-fn parse_selector<'i, 't, P, E, Impl>(
+pub(crate) fn parse_selector<'i, 't, P, E, Impl>(
         parser: &P,
         input: &mut CssParser<'i, 't>)
         -> Result<Selector<Impl>, ParseError<'i, SelectorParseError<'i, E>>>
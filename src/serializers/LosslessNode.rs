@@ -0,0 +1,110 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A rule, keyframe or property declaration within a block that `LosslessSerializer` can write.
+pub(crate) trait LosslessNode: ToCss
+{
+	/// Identifies a node as parsed; a node in the current tree with the same key as one in the original tree is the same node, possibly mutated.
+	/// `None` for nodes that were not parsed (ie were added after parsing).
+	#[inline(always)]
+	fn key(&self) -> Option<usize>
+	{
+		self.region().map(|(start, _end)| start)
+	}
+	
+	/// Byte offsets of the start and end of this node, including anything attached to it (such as preceding comments), in the original CSS.
+	fn region(&self) -> Option<(usize, usize)>;
+	
+	/// Character that must separate this node from a following one, if any.
+	#[inline(always)]
+	fn separator() -> Option<char>
+	{
+		None
+	}
+	
+	/// Writes this node, which was added after parsing.
+	#[inline(always)]
+	fn write_added<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		self.to_css(dest)
+	}
+	
+	/// Writes this node, reusing as much of `original`, which has the same key, as possible.
+	fn write<'a, W: fmt::Write>(&self, original: &Self, serializer: &mut LosslessSerializer<'a, W>) -> fmt::Result;
+}
+
+impl LosslessNode for CssRule
+{
+	#[inline(always)]
+	fn region(&self) -> Option<(usize, usize)>
+	{
+		self.source_span().map(|source_span| (source_span.start.byte_offset, source_span.end.byte_offset))
+	}
+	
+	#[inline(always)]
+	fn write<'a, W: fmt::Write>(&self, original: &Self, serializer: &mut LosslessSerializer<'a, W>) -> fmt::Result
+	{
+		serializer.write_css_rule(self, original)
+	}
+}
+
+impl LosslessNode for Keyframe
+{
+	#[inline(always)]
+	fn region(&self) -> Option<(usize, usize)>
+	{
+		self.source_span.map(|source_span| (source_span.start.byte_offset, source_span.end.byte_offset))
+	}
+	
+	#[inline(always)]
+	fn write<'a, W: fmt::Write>(&self, original: &Self, serializer: &mut LosslessSerializer<'a, W>) -> fmt::Result
+	{
+		serializer.write_keyframe(self, original)
+	}
+}
+
+impl<I: HasImportance> LosslessNode for PropertyDeclaration<I>
+{
+	#[inline(always)]
+	fn key(&self) -> Option<usize>
+	{
+		self.source_span.map(|source_span| source_span.start.byte_offset)
+	}
+	
+	#[inline(always)]
+	fn region(&self) -> Option<(usize, usize)>
+	{
+		self.source_span.map(|source_span|
+		{
+			let start = match self.comments_before.first().and_then(|comment| comment.source_span)
+			{
+				None => source_span.start.byte_offset,
+				Some(comment_source_span) => comment_source_span.start.byte_offset,
+			};
+			(start, source_span.end.byte_offset)
+		})
+	}
+	
+	#[inline(always)]
+	fn separator() -> Option<char>
+	{
+		Some(';')
+	}
+	
+	#[inline(always)]
+	fn write_added<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		for comment in self.comments_before.iter()
+		{
+			comment.to_css(dest)?;
+		}
+		self.to_css(dest)
+	}
+	
+	#[inline(always)]
+	fn write<'a, W: fmt::Write>(&self, original: &Self, serializer: &mut LosslessSerializer<'a, W>) -> fmt::Result
+	{
+		serializer.write_property_declaration(self, original)
+	}
+}
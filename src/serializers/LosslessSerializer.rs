@@ -0,0 +1,410 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Serializes rules by copying the original CSS (whitespace, comments and all) for every node that has not been mutated since it was parsed, only serializing afresh those nodes that have been mutated or added.
+/// Nodes are matched to the originals they were parsed as by their source spans.
+pub(crate) struct LosslessSerializer<'a, W: 'a + fmt::Write>
+{
+	destination: &'a mut W,
+	css: &'a str,
+	last_character: Option<char>,
+}
+
+impl<'a, W: fmt::Write> fmt::Write for LosslessSerializer<'a, W>
+{
+	#[inline(always)]
+	fn write_str(&mut self, s: &str) -> fmt::Result
+	{
+		if let Some(character) = s.chars().last()
+		{
+			self.last_character = Some(character);
+		}
+		self.destination.write_str(s)
+	}
+}
+
+impl<'a, W: fmt::Write> LosslessSerializer<'a, W>
+{
+	/// `css` is the original CSS that was parsed.
+	#[inline(always)]
+	pub(crate) fn new(destination: &'a mut W, css: &'a str) -> Self
+	{
+		Self
+		{
+			destination,
+			css,
+			last_character: None,
+		}
+	}
+	
+	/// `originalCssRules` are the rules as originally parsed from the CSS.
+	#[inline(always)]
+	pub(crate) fn write_stylesheet(&mut self, cssRules: &CssRules, originalCssRules: &CssRules) -> fmt::Result
+	{
+		let end = self.css.len();
+		self.write_nodes(&cssRules.0, &originalCssRules.0, 0, end)
+	}
+	
+	pub(crate) fn write_css_rule(&mut self, cssRule: &CssRule, original: &CssRule) -> fmt::Result
+	{
+		use self::CssRule::*;
+		
+		let (start, end) = match original.region()
+		{
+			None => return cssRule.to_css(self),
+			Some(region) => region,
+		};
+		
+		if cssRule.to_css_string() == original.to_css_string()
+		{
+			return self.write_original(start, end);
+		}
+		
+		match (cssRule, original)
+		{
+			(&Document(ref rule), &Document(ref originalRule)) =>
+			{
+				let head = Self::head(|dest| Self::write_at_rule_head(dest, &rule.vendor_prefix, "document ", &rule.condition));
+				let originalHead = Self::head(|dest| Self::write_at_rule_head(dest, &originalRule.vendor_prefix, "document ", &originalRule.condition));
+				self.write_block(cssRule, head, originalHead, start, end, |serializer, start, end| serializer.write_nodes(&rule.rules.0, &originalRule.rules.0, start, end))
+			}
+			
+			(&Keyframes(ref rule), &Keyframes(ref originalRule)) =>
+			{
+				let head = Self::head(|dest| Self::write_at_rule_head(dest, &rule.vendor_prefix, "keyframes ", &rule.name));
+				let originalHead = Self::head(|dest| Self::write_at_rule_head(dest, &originalRule.vendor_prefix, "keyframes ", &originalRule.name));
+				self.write_block(cssRule, head, originalHead, start, end, |serializer, start, end| serializer.write_nodes(&rule.keyframes, &originalRule.keyframes, start, end))
+			}
+			
+			(&Media(ref rule), &Media(ref originalRule)) =>
+			{
+				let head = Self::head(|dest| Self::write_at_rule_head(dest, &None, "media ", &rule.media_queries));
+				let originalHead = Self::head(|dest| Self::write_at_rule_head(dest, &None, "media ", &originalRule.media_queries));
+				self.write_block(cssRule, head, originalHead, start, end, |serializer, start, end| serializer.write_nodes(&rule.rules.0, &originalRule.rules.0, start, end))
+			}
+			
+			(&Page(ref rule), &Page(ref originalRule)) =>
+			{
+				let head = Self::head(|dest| Self::write_page_head(dest, rule));
+				let originalHead = Self::head(|dest| Self::write_page_head(dest, originalRule));
+				self.write_block(cssRule, head, originalHead, start, end, |serializer, start, end| serializer.write_property_declarations(&rule.property_declarations, &rule.comments_after_property_declarations, &originalRule.property_declarations, &originalRule.comments_after_property_declarations, start, end))
+			}
+			
+			(&Style(ref rule), &Style(ref originalRule)) =>
+			{
+				let head = rule.selectors.to_css_string();
+				let originalHead = originalRule.selectors.to_css_string();
+				self.write_block(cssRule, head, originalHead, start, end, |serializer, start, end| serializer.write_property_declarations(&rule.property_declarations, &rule.comments_after_property_declarations, &originalRule.property_declarations, &originalRule.comments_after_property_declarations, start, end))
+			}
+			
+			(&Supports(ref rule), &Supports(ref originalRule)) =>
+			{
+				let head = Self::head(|dest| Self::write_at_rule_head(dest, &None, "supports ", &rule.condition));
+				let originalHead = Self::head(|dest| Self::write_at_rule_head(dest, &None, "supports ", &originalRule.condition));
+				self.write_block(cssRule, head, originalHead, start, end, |serializer, start, end| serializer.write_nodes(&rule.rules.0, &originalRule.rules.0, start, end))
+			}
+			
+			_ => cssRule.to_css(self),
+		}
+	}
+	
+	pub(crate) fn write_keyframe(&mut self, keyframe: &Keyframe, original: &Keyframe) -> fmt::Result
+	{
+		let (start, end) = match original.region()
+		{
+			None => return keyframe.to_css(self),
+			Some(region) => region,
+		};
+		
+		if keyframe.to_css_string() == original.to_css_string()
+		{
+			return self.write_original(start, end);
+		}
+		
+		let head = keyframe.selector.to_css_string();
+		let originalHead = original.selector.to_css_string();
+		self.write_block(keyframe, head, originalHead, start, end, |serializer, start, end| serializer.write_property_declarations(&keyframe.property_declarations, &keyframe.comments_after_property_declarations, &original.property_declarations, &original.comments_after_property_declarations, start, end))
+	}
+	
+	pub(crate) fn write_property_declaration<I: HasImportance>(&mut self, propertyDeclaration: &PropertyDeclaration<I>, original: &PropertyDeclaration<I>) -> fmt::Result
+	{
+		let css = self.css;
+		
+		let (start, end) = match (original.region(), original.source_span)
+		{
+			(Some((start, _)), Some(source_span)) => (start, source_span.start.byte_offset),
+			_ => return propertyDeclaration.write_added(self),
+		};
+		
+		if propertyDeclaration.comments_before == original.comments_before
+		{
+			self.write_str(&css[start .. end])?;
+		}
+		else
+		{
+			for comment in propertyDeclaration.comments_before.iter()
+			{
+				comment.to_css(self)?;
+			}
+			
+			if let Some(lastCommentSourceSpan) = original.comments_before.last().and_then(|comment| comment.source_span)
+			{
+				self.write_str(&css[lastCommentSourceSpan.end.byte_offset .. end])?;
+			}
+		}
+		
+		let originalText = original.source_span.unwrap().slice(css);
+		
+		if Self::declaration(propertyDeclaration) == Self::declaration(original)
+		{
+			return self.write_str(originalText);
+		}
+		
+		match originalText.find(':')
+		{
+			Some(colon) if Self::name(propertyDeclaration) == Self::name(original) => self.write_str(&originalText[.. colon + 1])?,
+			
+			_ =>
+			{
+				self.write_str(&Self::name(propertyDeclaration))?;
+				self.write_char(':')?;
+			}
+		}
+		propertyDeclaration.value.to_css(self)?;
+		propertyDeclaration.importance.to_css(self)?;
+		
+		if originalText.ends_with(';')
+		{
+			self.write_char(';')?;
+		}
+		
+		Ok(())
+	}
+	
+	/// Writes `nodes` in place of `originalNodes`, which were parsed from between `start` and `end` in the original CSS.
+	/// The original whitespace before each node that was parsed is kept; nodes that have been added are preceded by the same whitespace as the node before them.
+	fn write_nodes<N: LosslessNode>(&mut self, nodes: &[N], originalNodes: &[N], start: usize, end: usize) -> fmt::Result
+	{
+		let css = self.css;
+		
+		let regions: Vec<Option<(usize, usize)>> = originalNodes.iter().map(|originalNode| originalNode.region()).collect();
+		
+		let (head, tail) = match (regions.iter().filter_map(|region| *region).next(), regions.iter().rev().filter_map(|region| *region).next())
+		{
+			(Some((firstStart, _)), Some((_, lastEnd))) => (&css[start .. firstStart], &css[lastEnd .. end]),
+			_ => (&css[start .. end], ""),
+		};
+		
+		let mut whitespace = Self::trailing_whitespace(head);
+		let mut afterFirst = false;
+		for node in nodes.iter()
+		{
+			let originalIndex = node.key().and_then(|key| originalNodes.iter().position(|originalNode| originalNode.key() == Some(key)));
+			
+			if afterFirst
+			{
+				if let Some(separator) = N::separator()
+				{
+					if self.last_character != Some(separator)
+					{
+						self.write_char(separator)?;
+					}
+				}
+				
+				match originalIndex.and_then(|index| regions[index].map(|(regionStart, _)| (index, regionStart)))
+				{
+					Some((index, regionStart)) =>
+					{
+						let gapStart = regions[.. index].iter().rev().filter_map(|region| *region).next().map(|(_, previousEnd)| previousEnd).unwrap_or(start);
+						let gap = &css[gapStart .. regionStart];
+						self.write_str(gap)?;
+						whitespace = Self::trailing_whitespace(gap);
+					}
+					
+					None => self.write_str(whitespace)?,
+				}
+			}
+			else
+			{
+				self.write_str(head)?;
+				afterFirst = true;
+			}
+			
+			match originalIndex
+			{
+				Some(index) => node.write(&originalNodes[index], self)?,
+				None => node.write_added(self)?,
+			}
+		}
+		
+		if !afterFirst
+		{
+			self.write_str(head.trim_right())?;
+		}
+		
+		self.write_str(tail)
+	}
+	
+	/// `commentsAfter` and `originalCommentsAfter` are any comments after the last property declaration.
+	fn write_property_declarations<I: HasImportance>(&mut self, propertyDeclarations: &PropertyDeclarations<I>, commentsAfter: &[CssComment], original: &PropertyDeclarations<I>, originalCommentsAfter: &[CssComment], start: usize, end: usize) -> fmt::Result
+	{
+		if commentsAfter == originalCommentsAfter
+		{
+			return self.write_nodes(&propertyDeclarations.0, &original.0, start, end);
+		}
+		
+		let trailingCommentsStart = originalCommentsAfter.first().and_then(|comment| comment.source_span).map(|source_span| source_span.start.byte_offset).unwrap_or(end);
+		let trailingCommentsEnd = originalCommentsAfter.last().and_then(|comment| comment.source_span).map(|source_span| source_span.end.byte_offset).unwrap_or(end);
+		
+		self.write_nodes(&propertyDeclarations.0, &original.0, start, trailingCommentsStart)?;
+		for comment in commentsAfter.iter()
+		{
+			comment.to_css(self)?;
+		}
+		let css = self.css;
+		self.write_str(&css[trailingCommentsEnd .. end])
+	}
+	
+	/// Writes a block rule (`{` to `}`) using the original CSS from `start` to `end`.
+	/// `head` is what comes before the `{`, as it would be serialized.
+	fn write_block<T: ToCss, F: FnOnce(&mut Self, usize, usize) -> fmt::Result>(&mut self, node: &T, head: String, originalHead: String, start: usize, end: usize, write_body: F) -> fmt::Result
+	{
+		let css = self.css;
+		
+		let openingBrace = match Self::find_opening_brace(&css[start .. end])
+		{
+			None => return node.to_css(self),
+			Some(index) => start + index,
+		};
+		
+		if head == originalHead
+		{
+			self.write_str(&css[start .. openingBrace + 1])?;
+		}
+		else
+		{
+			self.write_str(&head)?;
+			self.write_str(Self::trailing_whitespace(&css[start .. openingBrace]))?;
+			self.write_char('{')?;
+		}
+		
+		// A block at the end of the CSS need not be closed.
+		let closingBrace = if css[.. end].ends_with('}')
+		{
+			end - 1
+		}
+		else
+		{
+			end
+		};
+		
+		write_body(self, openingBrace + 1, closingBrace)?;
+		
+		self.write_str(&css[closingBrace .. end])
+	}
+	
+	#[inline(always)]
+	fn write_original(&mut self, start: usize, end: usize) -> fmt::Result
+	{
+		let css = self.css;
+		self.write_str(&css[start .. end])
+	}
+	
+	#[inline(always)]
+	fn write_at_rule_head<T: ToCss>(dest: &mut String, vendor_prefix: &Option<VendorPrefix>, name: &str, prelude: &T) -> fmt::Result
+	{
+		dest.write_char('@')?;
+		if let Some(ref vendor_prefix) = *vendor_prefix
+		{
+			vendor_prefix.to_css(dest)?;
+		}
+		dest.write_str(name)?;
+		prelude.to_css(dest)
+	}
+	
+	#[inline(always)]
+	fn write_page_head(dest: &mut String, rule: &PageAtRule) -> fmt::Result
+	{
+		dest.write_str("@page")?;
+		if let Some(ref page_selector_pseudo_class) = rule.page_selector_pseudo_class
+		{
+			dest.write_char(' ')?;
+			page_selector_pseudo_class.to_css(dest)?;
+		}
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn head<F: FnOnce(&mut String) -> fmt::Result>(write: F) -> String
+	{
+		let mut head = String::new();
+		write(&mut head).unwrap();
+		head
+	}
+	
+	#[inline(always)]
+	fn name<I: HasImportance>(propertyDeclaration: &PropertyDeclaration<I>) -> String
+	{
+		let mut name = String::new();
+		if let Some(ref vendor_prefix) = propertyDeclaration.vendor_prefix
+		{
+			vendor_prefix.to_css(&mut name).unwrap();
+		}
+		propertyDeclaration.name.to_css(&mut name).unwrap();
+		name
+	}
+	
+	#[inline(always)]
+	fn declaration<I: HasImportance>(propertyDeclaration: &PropertyDeclaration<I>) -> String
+	{
+		let mut declaration = String::new();
+		propertyDeclaration.to_css_without_trailing_semicolon(&mut declaration).unwrap();
+		declaration
+	}
+	
+	#[inline(always)]
+	fn trailing_whitespace(css: &str) -> &str
+	{
+		&css[css.trim_right().len() ..]
+	}
+	
+	/// Finds the `{` that starts a block, ignoring any in comments, strings or escapes.
+	fn find_opening_brace(css: &str) -> Option<usize>
+	{
+		let bytes = css.as_bytes();
+		let mut index = 0;
+		while index < bytes.len()
+		{
+			match bytes[index]
+			{
+				b'{' => return Some(index),
+				
+				b'\\' => index += 1,
+				
+				b'/' if bytes.get(index + 1) == Some(&b'*') => match css[index + 2 ..].find("*/")
+				{
+					None => return None,
+					Some(commentLength) => index += 2 + commentLength + 1,
+				},
+				
+				quote @ b'"' | quote @ b'\'' =>
+				{
+					index += 1;
+					while index < bytes.len() && bytes[index] != quote
+					{
+						if bytes[index] == b'\\'
+						{
+							index += 1;
+						}
+						index += 1;
+					}
+				}
+				
+				_ => (),
+			}
+			index += 1;
+		}
+		None
+	}
+}
@@ -0,0 +1,404 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Serializes rules as laid out by `SerializerOptions` (with the default options, exactly as `ToCss` does), optionally noting where each rule, at-rule prelude, selector, keyframe, property declaration and descriptor was in the original source.
+pub(crate) struct StylesheetSerializer<'a, W: 'a + fmt::Write>
+{
+	destination: &'a mut W,
+	options: &'a SerializerOptions,
+	source_map: Option<(&'a mut SourceMapGenerator, usize)>,
+	depth: usize,
+}
+
+impl<'a, W: fmt::Write> fmt::Write for StylesheetSerializer<'a, W>
+{
+	#[inline(always)]
+	fn write_str(&mut self, s: &str) -> fmt::Result
+	{
+		self.destination.write_str(s)?;
+		if let Some((ref mut source_map_generator, _)) = self.source_map
+		{
+			source_map_generator.advance(s);
+		}
+		Ok(())
+	}
+}
+
+impl<'a, W: fmt::Write> StylesheetSerializer<'a, W>
+{
+	/// `source_map` is the generator to add mappings to and the index of the source being serialized.
+	#[inline(always)]
+	pub(crate) fn new(destination: &'a mut W, options: &'a SerializerOptions, source_map: Option<(&'a mut SourceMapGenerator, usize)>) -> Self
+	{
+		Self
+		{
+			destination,
+			options,
+			source_map,
+			depth: 0,
+		}
+	}
+	
+	pub(crate) fn write_stylesheet(&mut self, cssRules: &CssRules) -> fmt::Result
+	{
+		self.write_css_rules(cssRules)?;
+		
+		if self.options.rules_are_on_lines_of_their_own() && !self.is_empty(cssRules)
+		{
+			self.write_char('\n')?;
+		}
+		
+		Ok(())
+	}
+	
+	fn write_css_rules(&mut self, cssRules: &CssRules) -> fmt::Result
+	{
+		let mut afterFirst = false;
+		for cssRule in cssRules.0.iter()
+		{
+			if !self.keeps(cssRule)
+			{
+				continue;
+			}
+			
+			if afterFirst
+			{
+				self.write_rule_separator()?;
+			}
+			else
+			{
+				afterFirst = true;
+			}
+			self.write_css_rule(cssRule)?;
+		}
+		
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn keeps(&self, cssRule: &CssRule) -> bool
+	{
+		match *cssRule
+		{
+			CssRule::Comment(ref comment) => self.options.comments.keeps(comment),
+			_ => true,
+		}
+	}
+	
+	#[inline(always)]
+	fn is_empty(&self, cssRules: &CssRules) -> bool
+	{
+		!cssRules.0.iter().any(|cssRule| self.keeps(cssRule))
+	}
+	
+	fn write_css_rule(&mut self, cssRule: &CssRule) -> fmt::Result
+	{
+		use self::CssRule::*;
+		
+		self.map(cssRule.source_span());
+		
+		match *cssRule
+		{
+			CounterStyle(ref rule) => rule.to_css_mapping_descriptors(self, |serializer, source_span| serializer.map(source_span)),
+			
+			Document(ref rule) =>
+			{
+				self.write_char('@')?;
+				if let Some(ref vendor_prefix) = rule.vendor_prefix
+				{
+					vendor_prefix.to_css(self)?;
+				}
+				self.write_str("document ")?;
+				self.map(rule.prelude_source_span);
+				rule.condition.to_css(self)?;
+				self.write_nested_css_rules(&rule.rules)
+			}
+			
+			FontFace(ref rule) => rule.to_css_mapping_descriptors(self, |serializer, source_span| serializer.map(source_span)),
+			
+			FontFeatureValues(ref rule) => rule.to_css_mapping_declarations(self, |serializer, source_span| serializer.map(source_span)),
+			
+			Keyframes(ref rule) =>
+			{
+				self.write_char('@')?;
+				if let Some(ref vendor_prefix) = rule.vendor_prefix
+				{
+					vendor_prefix.to_css(self)?;
+				}
+				self.write_str("keyframes ")?;
+				self.map(rule.prelude_source_span);
+				rule.name.to_css(self)?;
+				
+				self.write_block_start()?;
+				self.depth += 1;
+				for (index, keyframe) in rule.keyframes.iter().enumerate()
+				{
+					if index != 0
+					{
+						self.write_rule_separator()?;
+					}
+					else if self.options.rules_are_on_lines_of_their_own()
+					{
+						self.write_new_line()?;
+					}
+					self.map(keyframe.source_span);
+					keyframe.selector.to_css(self)?;
+					self.write_property_declarations(&keyframe.property_declarations, &keyframe.comments_after_property_declarations)?;
+				}
+				self.depth -= 1;
+				self.write_block_end(rule.keyframes.is_empty())
+			}
+			
+			Media(ref rule) =>
+			{
+				self.write_str("@media ")?;
+				self.map(rule.prelude_source_span);
+				rule.media_queries.to_css(self)?;
+				self.write_nested_css_rules(&rule.rules)
+			}
+			
+			Page(ref rule) =>
+			{
+				self.write_str("@page")?;
+				if let Some(ref page_selector_pseudo_class) = rule.page_selector_pseudo_class
+				{
+					self.write_char(' ')?;
+					self.map(rule.prelude_source_span);
+					page_selector_pseudo_class.to_css(self)?;
+				}
+				self.write_property_declarations(&rule.property_declarations, &rule.comments_after_property_declarations)
+			}
+			
+			Style(ref rule) =>
+			{
+				self.write_selectors(&rule.selectors, &rule.selector_source_spans)?;
+				self.write_property_declarations(&rule.property_declarations, &rule.comments_after_property_declarations)
+			}
+			
+			Supports(ref rule) =>
+			{
+				self.write_str("@supports ")?;
+				self.map(rule.prelude_source_span);
+				rule.condition.to_css(self)?;
+				self.write_nested_css_rules(&rule.rules)
+			}
+			
+			Viewport(ref rule) => rule.to_css_mapping_declarations(self, |serializer, source_span| serializer.map(source_span)),
+			
+			_ => cssRule.to_css(self),
+		}
+	}
+	
+	#[inline(always)]
+	fn write_nested_css_rules(&mut self, cssRules: &CssRules) -> fmt::Result
+	{
+		self.write_block_start()?;
+		self.depth += 1;
+		let isEmpty = self.is_empty(cssRules);
+		if self.options.rules_are_on_lines_of_their_own() && !isEmpty
+		{
+			self.write_new_line()?;
+		}
+		self.write_css_rules(cssRules)?;
+		self.depth -= 1;
+		self.write_block_end(isEmpty)
+	}
+	
+	#[inline(always)]
+	fn write_selectors(&mut self, selectors: &DeduplicatedSelectors, sourceSpans: &[SourceSpan]) -> fmt::Result
+	{
+		for (index, selector) in selectors.0.iter().enumerate()
+		{
+			if index != 0
+			{
+				self.write_char(',')?;
+				if self.options.rules_are_on_lines_of_their_own()
+				{
+					self.write_char(' ')?;
+				}
+			}
+			self.map(sourceSpans.get(index).cloned());
+			self.write_str(&selector.to_css_string())?;
+		}
+		Ok(())
+	}
+	
+	/// `commentsAfter` are any comments after the last property declaration.
+	fn write_property_declarations<I: HasImportance>(&mut self, propertyDeclarations: &PropertyDeclarations<I>, commentsAfter: &[CssComment]) -> fmt::Result
+	{
+		self.write_block_start()?;
+		
+		if self.options.one_declaration_per_line
+		{
+			self.depth += 1;
+			let mut isEmpty = true;
+			for propertyDeclaration in propertyDeclarations.0.iter()
+			{
+				for comment in propertyDeclaration.comments_before.iter()
+				{
+					self.write_comment_on_new_line(comment)?;
+				}
+				self.write_new_line()?;
+				self.write_property_declaration(propertyDeclaration)?;
+				self.write_char(';')?;
+				isEmpty = false;
+			}
+			for comment in commentsAfter.iter()
+			{
+				if self.write_comment_on_new_line(comment)?
+				{
+					isEmpty = false;
+				}
+			}
+			self.depth -= 1;
+			self.write_block_end(isEmpty)
+		}
+		else
+		{
+			for (index, propertyDeclaration) in propertyDeclarations.0.iter().enumerate()
+			{
+				if index != 0
+				{
+					self.write_char(';')?;
+				}
+				self.write_comments(&propertyDeclaration.comments_before)?;
+				self.write_property_declaration(propertyDeclaration)?;
+			}
+			self.write_comments(commentsAfter)?;
+			self.write_char('}')
+		}
+	}
+	
+	#[inline(always)]
+	fn write_comments(&mut self, comments: &[CssComment]) -> fmt::Result
+	{
+		for comment in comments.iter()
+		{
+			if self.options.comments.keeps(comment)
+			{
+				self.map(comment.source_span);
+				comment.to_css(self)?;
+			}
+		}
+		Ok(())
+	}
+	
+	/// Returns `true` if the comment was written.
+	#[inline(always)]
+	fn write_comment_on_new_line(&mut self, comment: &CssComment) -> Result<bool, fmt::Error>
+	{
+		if self.options.comments.keeps(comment)
+		{
+			self.write_new_line()?;
+			self.map(comment.source_span);
+			comment.to_css(self)?;
+			Ok(true)
+		}
+		else
+		{
+			Ok(false)
+		}
+	}
+	
+	#[inline(always)]
+	fn write_property_declaration<I: HasImportance>(&mut self, propertyDeclaration: &PropertyDeclaration<I>) -> fmt::Result
+	{
+		self.map(propertyDeclaration.source_span);
+		
+		match (self.options.vendor_prefix_case, &propertyDeclaration.vendor_prefixed_name_as_written)
+		{
+			(VendorPrefixCase::Preserve, &Some(ref vendor_prefixed_name_as_written)) => self.write_str(vendor_prefixed_name_as_written)?,
+			
+			_ =>
+			{
+				if let Some(ref vendor_prefix) = propertyDeclaration.vendor_prefix
+				{
+					vendor_prefix.to_css(self)?;
+				}
+				propertyDeclaration.name.to_css(self)?;
+			}
+		}
+		
+		self.write_char(':')?;
+		if self.options.space_after_colon
+		{
+			// Values are kept as written, including any whitespace around them.
+			self.write_char(' ')?;
+			self.write_str(propertyDeclaration.value.to_css_string().trim())?;
+			if propertyDeclaration.importance.isImportant()
+			{
+				self.write_char(' ')?;
+			}
+		}
+		else
+		{
+			propertyDeclaration.value.to_css(self)?;
+		}
+		propertyDeclaration.importance.to_css(self)
+	}
+	
+	#[inline(always)]
+	fn write_block_start(&mut self) -> fmt::Result
+	{
+		if self.options.rules_are_on_lines_of_their_own()
+		{
+			self.write_str(" {")
+		}
+		else
+		{
+			self.write_char('{')
+		}
+	}
+	
+	#[inline(always)]
+	fn write_block_end(&mut self, isEmpty: bool) -> fmt::Result
+	{
+		if self.options.rules_are_on_lines_of_their_own() && !isEmpty
+		{
+			self.write_new_line()?;
+		}
+		self.write_char('}')
+	}
+	
+	#[inline(always)]
+	fn write_rule_separator(&mut self) -> fmt::Result
+	{
+		if self.options.blank_line_between_rules
+		{
+			self.write_char('\n')?;
+		}
+		
+		if self.options.rules_are_on_lines_of_their_own()
+		{
+			self.write_new_line()?;
+		}
+		
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn write_new_line(&mut self) -> fmt::Result
+	{
+		self.write_char('\n')?;
+		for _ in 0 .. self.depth
+		{
+			let indentation = self.options.indentation.as_str();
+			self.destination.write_str(indentation)?;
+			if let Some((ref mut source_map_generator, _)) = self.source_map
+			{
+				source_map_generator.advance(indentation);
+			}
+		}
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn map(&mut self, source_span: Option<SourceSpan>)
+	{
+		if let (Some(source_span), Some((ref mut source_map_generator, source_index))) = (source_span, self.source_map.as_mut())
+		{
+			source_map_generator.add_mapping(*source_index, source_span.start);
+		}
+	}
+}
@@ -3,9 +3,23 @@
 
 
 use super::*;
+use super::domain::atRules::keyframes::Keyframe;
+use super::domain::atRules::page::PageAtRule;
 use super::domain::numbers::CssNumber;
+use super::domain::properties::HasImportance;
+use super::domain::properties::PropertyDeclaration;
+use super::domain::properties::PropertyDeclarations;
+use super::sourceMaps::SourceMapGenerator;
+use ::std::fmt::Write;
 
 
+#[cfg(test)]
+mod tests;
+
+
+include!("LosslessNode.rs");
+include!("LosslessSerializer.rs");
 include!("serialize_dimension.rs");
 include!("serialize_percentage.rs");
+include!("StylesheetSerializer.rs");
 
@@ -0,0 +1,164 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+use super::domain::properties::Importance;
+
+
+fn serialize(css: &str, options: &SerializerOptions) -> String
+{
+	let parseOptions = StylesheetParseOptions
+	{
+		preserve_comments: true,
+		..StylesheetParseOptions::default()
+	};
+	let (stylesheet, _) = Stylesheet::parse_with_options(css, &parseOptions).ok().unwrap();
+	stylesheet.to_css_string_with_options(options)
+}
+
+#[test]
+fn default_options_are_the_same_as_to_css()
+{
+	let css = "a{color:red;top:0!important}@media print{b{margin:0}}@keyframes k{50%{top:0}}";
+	let stylesheet = Stylesheet::parse(css).ok().unwrap();
+	
+	assert_eq!(stylesheet.to_css_string_with_options(&SerializerOptions::default()), stylesheet.to_css_string(false));
+	assert_eq!(serialize(css, &SerializerOptions::default()), css);
+}
+
+#[test]
+fn pretty_options_lay_out_nested_rules_and_declarations()
+{
+	let css = "a,b{color:red;top:0!important}@media print{c{margin:0}d{}}@keyframes k{50%{top:0}100%{top:1px}}@page :first{margin:1in}";
+	
+	assert_eq!(serialize(css, &SerializerOptions::pretty()), "a, b {\n\tcolor: red;\n\ttop: 0 !important;\n}\n\n@media print {\n\tc {\n\t\tmargin: 0;\n\t}\n\n\td {}\n}\n\n@keyframes k {\n\t50% {\n\t\ttop: 0;\n\t}\n\n\t100% {\n\t\ttop: 1px;\n\t}\n}\n\n@page :first {\n\tmargin: 1in;\n}\n");
+}
+
+#[test]
+fn each_option_can_be_used_on_its_own()
+{
+	let css = "a{color:red;top:0!important}b{margin:0}";
+	
+	let oneDeclarationPerLine = SerializerOptions
+	{
+		indentation: "  ".to_owned(),
+		one_declaration_per_line: true,
+		..SerializerOptions::default()
+	};
+	assert_eq!(serialize(css, &oneDeclarationPerLine), "a {\n  color:red;\n  top:0!important;\n}\nb {\n  margin:0;\n}\n");
+	
+	let spaceAfterColon = SerializerOptions
+	{
+		space_after_colon: true,
+		..SerializerOptions::default()
+	};
+	assert_eq!(serialize("a{color:  red ;top:0 !important}", &spaceAfterColon), "a{color: red;top: 0 !important}");
+	
+	let blankLineBetweenRules = SerializerOptions
+	{
+		blank_line_between_rules: true,
+		..SerializerOptions::default()
+	};
+	assert_eq!(serialize(css, &blankLineBetweenRules), "a {color:red;top:0!important}\n\nb {margin:0}\n");
+}
+
+#[test]
+fn vendor_prefixed_names_are_normalised_unless_preserved()
+{
+	let css = "a{-WebKit-transition:none}";
+	
+	assert_eq!(serialize(css, &SerializerOptions::default()), "a{-webkit-transition:none}");
+	
+	let preserve = SerializerOptions
+	{
+		vendor_prefix_case: VendorPrefixCase::Preserve,
+		..SerializerOptions::default()
+	};
+	assert_eq!(serialize(css, &preserve), css);
+}
+
+#[test]
+fn comments_are_kept_as_requested()
+{
+	let css = "/*! licence */a{/* before */color:red;/* after */}/* between */b{top:0}";
+	
+	assert_eq!(serialize(css, &SerializerOptions::default()), "/*! licence */a{/* before */color:red/* after */}/* between */b{top:0}");
+	
+	let keepOnlySignificant = SerializerOptions
+	{
+		comments: CommentSerialization::KeepOnlySignificant,
+		..SerializerOptions::default()
+	};
+	assert_eq!(serialize(css, &keepOnlySignificant), "/*! licence */a{color:red}b{top:0}");
+	
+	let keepNone = SerializerOptions
+	{
+		comments: CommentSerialization::KeepNone,
+		..SerializerOptions::default()
+	};
+	assert_eq!(serialize(css, &keepNone), "a{color:red}b{top:0}");
+	
+	assert_eq!(serialize(css, &SerializerOptions::pretty()), "/*! licence */\n\na {\n\t/* before */\n\tcolor: red;\n\t/* after */\n}\n\n/* between */\n\nb {\n\ttop: 0;\n}\n");
+}
+
+#[test]
+fn empty_stylesheets_and_blocks_are_not_padded()
+{
+	assert_eq!(serialize("", &SerializerOptions::pretty()), "");
+	assert_eq!(serialize("a{}@media print{}", &SerializerOptions::pretty()), "a {}\n\n@media print {}\n");
+	assert_eq!(serialize("/* gone */", &SerializerOptions { comments: CommentSerialization::KeepNone, ..SerializerOptions::pretty() }), "");
+}
+
+fn lossless_round_trip(css: &str) -> String
+{
+	let (losslessStylesheet, _) = LosslessStylesheet::parse(css, &StylesheetParseOptions::default()).ok().unwrap();
+	losslessStylesheet.to_css_string()
+}
+
+fn edit_lossless_style_rule<Edit: FnOnce(&mut StyleRule)>(css: &str, index: usize, edit: Edit) -> String
+{
+	let (mut losslessStylesheet, _) = LosslessStylesheet::parse(css, &StylesheetParseOptions::default()).ok().unwrap();
+	match losslessStylesheet.stylesheet.rules.0[index]
+	{
+		CssRule::Style(ref mut styleRule) => edit(styleRule),
+		_ => panic!("not a style rule"),
+	}
+	losslessStylesheet.to_css_string()
+}
+
+#[test]
+fn lossless_serialization_reproduces_comments_and_whitespace()
+{
+	let css = "/*! licence */\n\n/* rules */\na ,  b\t{\n\t/* colour */ color :  red ;;\n\n  top:0 /* zero */ ;\n  /* trailing */\n}\r\n@media   print {\n  c { margin : 0 }\n}\n";
+	
+	assert_eq!(lossless_round_trip(css), css);
+}
+
+#[test]
+fn lossless_serialization_reproduces_vendor_prefixes_and_importance()
+{
+	let css = "a{-WebKit-Transition : none;-moz-box-sizing:border-box  !IMPORTANT;color:red! important ;top:0 !important}\n@-webkit-keyframes k {\n  50% { -webkit-transform : none }\n}\n";
+	
+	assert_eq!(lossless_round_trip(css), css);
+}
+
+#[test]
+fn lossless_serialization_reproduces_at_rules_it_does_not_lay_out()
+{
+	let css = "@charset \"utf-8\";\n@import url(a.css)  print;\n@font-face {\n  font-family : x ;\n  src: url(x.woff)\n}\n@page :first { margin : 1in }\n";
+	
+	assert_eq!(lossless_round_trip(css), css);
+}
+
+#[test]
+fn lossless_serialization_rewrites_only_the_edited_declaration()
+{
+	let css = "a {\n  /* colour */ color :  red ;\n  -WebKit-Transition:none   ! important;\n  top : 0 ;\n  margin:0\n}\nb { color : blue }\n";
+	
+	let edited = edit_lossless_style_rule(css, 0, |styleRule| styleRule.property_declarations.0[2].importance = Importance::Important);
+	assert_eq!(edited, "a {\n  /* colour */ color :  red ;\n  -WebKit-Transition:none   ! important;\n  top : 0 !important;\n  margin:0\n}\nb { color : blue }\n");
+	
+	let edited = edit_lossless_style_rule(css, 0, |styleRule| { styleRule.property_declarations.0.remove(1); });
+	assert_eq!(edited, "a {\n  /* colour */ color :  red ;\n  top : 0 ;\n  margin:0\n}\nb { color : blue }\n");
+}
@@ -0,0 +1,440 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A [Source Map (revision 3)](https://sourcemaps.info/spec.html).
+///
+/// Usually created using a `SourceMapGenerator`.
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct SourceMap
+{
+	/// The name of the generated file this source map is for, if known.
+	pub file: Option<String>,
+	
+	/// Prepended to the entries in `sources`, if present.
+	pub source_root: Option<String>,
+	
+	/// The original sources (typically URLs or file paths).
+	pub sources: Vec<String>,
+	
+	/// The original sources' content, indexed as for `sources`; may be empty.
+	pub sources_content: Vec<Option<String>>,
+	
+	/// Symbol names referred to by `mappings`.
+	pub names: Vec<String>,
+	
+	/// Mappings, sorted by generated line and then generated column.
+	pub mappings: Vec<SourceMapping>,
+}
+
+impl SourceMap
+{
+	/// Reads a source map from JSON, such as that produced by Sass or another preprocessor for the CSS it generated.
+	pub fn from_json(json: &str) -> Result<Self, SourceMapError>
+	{
+		let value: Value = ::serde_json::from_str(json)?;
+		let object = match value
+		{
+			Value::Object(object) => object,
+			_ => return Err(SourceMapError::NotAnObject),
+		};
+		
+		if object.get("version").and_then(Value::as_u64) != Some(3)
+		{
+			return Err(SourceMapError::UnsupportedVersion);
+		}
+		
+		if object.contains_key("sections")
+		{
+			return Err(SourceMapError::IndexSourceMapsAreNotSupported);
+		}
+		
+		let sources = Self::strings_from_json(&object, "sources")?.into_iter().map(|source| source.unwrap_or_default()).collect::<Vec<_>>();
+		
+		let sources_content = if object.contains_key("sourcesContent")
+		{
+			Self::strings_from_json(&object, "sourcesContent")?
+		}
+		else
+		{
+			Vec::new()
+		};
+		
+		let names = if object.contains_key("names")
+		{
+			Self::strings_from_json(&object, "names")?.into_iter().map(|name| name.unwrap_or_default()).collect()
+		}
+		else
+		{
+			Vec::new()
+		};
+		
+		let mappings = match object.get("mappings")
+		{
+			Some(&Value::String(ref mappings)) => Self::read_mappings(mappings, sources.len(), names.len())?,
+			_ => return Err(SourceMapError::InvalidField("mappings")),
+		};
+		
+		Ok
+		(
+			Self
+			{
+				file: Self::optional_string_from_json(&object, "file")?,
+				source_root: Self::optional_string_from_json(&object, "sourceRoot")?,
+				sources,
+				sources_content,
+				names,
+				mappings,
+			}
+		)
+	}
+	
+	/// Composes this source map, which maps generated CSS back to the source `source_index`, with `input_source_map`, which maps that source back to its own original sources (eg the `.scss` files Sass compiled it from).
+	///
+	/// The result maps the generated CSS straight back to the original sources of `input_source_map`; mappings into other sources are unchanged.
+	/// Mappings into `source_index` at locations that `input_source_map` does not map are discarded.
+	pub fn compose(&self, source_index: usize, input_source_map: &SourceMap) -> Self
+	{
+		let mut composed = Self
+		{
+			file: self.file.clone(),
+			source_root: self.source_root.clone(),
+			sources: Vec::with_capacity(self.sources.len() + input_source_map.sources.len()),
+			sources_content: Vec::new(),
+			names: Vec::with_capacity(self.names.len() + input_source_map.names.len()),
+			mappings: Vec::with_capacity(self.mappings.len()),
+		};
+		
+		let mut ourSourceIndices = Vec::with_capacity(self.sources.len());
+		for (index, source) in self.sources.iter().enumerate()
+		{
+			ourSourceIndices.push(if index == source_index
+			{
+				None
+			}
+			else
+			{
+				Some(composed.add_source(source.clone(), self.sources_content.get(index).cloned().unwrap_or(None)))
+			});
+		}
+		
+		let inputSourceIndices = input_source_map.sources.iter().enumerate().map(|(index, source)|
+		{
+			let source = match input_source_map.source_root
+			{
+				Some(ref source_root) if !source_root.is_empty() => if source_root.ends_with('/')
+				{
+					format!("{}{}", source_root, source)
+				}
+				else
+				{
+					format!("{}/{}", source_root, source)
+				},
+				_ => source.clone(),
+			};
+			composed.add_source(source, input_source_map.sources_content.get(index).cloned().unwrap_or(None))
+		}).collect::<Vec<_>>();
+		
+		for mapping in self.mappings.iter()
+		{
+			match ourSourceIndices.get(mapping.source_index)
+			{
+				Some(&Some(composedSourceIndex)) =>
+				{
+					let name_index = mapping.name_index.map(|name_index| composed.add_name(&self.names[name_index]));
+					composed.mappings.push(SourceMapping
+					{
+						source_index: composedSourceIndex,
+						name_index,
+						.. *mapping
+					});
+				}
+				
+				Some(&None) => if let Some(inputMapping) = input_source_map.find_mapping(mapping.original_line, mapping.original_column)
+				{
+					let name_index = inputMapping.name_index.map(|name_index| composed.add_name(&input_source_map.names[name_index])).or_else(|| mapping.name_index.map(|name_index| composed.add_name(&self.names[name_index])));
+					composed.mappings.push(SourceMapping
+					{
+						generated_line: mapping.generated_line,
+						generated_column: mapping.generated_column,
+						source_index: inputSourceIndices[inputMapping.source_index],
+						original_line: inputMapping.original_line,
+						original_column: inputMapping.original_column,
+						name_index,
+					});
+				},
+				
+				None => (),
+			}
+		}
+		
+		if composed.sources_content.iter().all(Option::is_none)
+		{
+			composed.sources_content.clear();
+		}
+		
+		composed
+	}
+	
+	/// Finds the mapping for the generated `line` and `column`; this is the last mapping on the same line that does not start after `column`.
+	pub fn find_mapping(&self, line: u32, column: u32) -> Option<&SourceMapping>
+	{
+		let index = match self.mappings.binary_search_by(|mapping| (mapping.generated_line, mapping.generated_column).cmp(&(line, column)))
+		{
+			Ok(index) => index,
+			Err(0) => return None,
+			Err(insertionIndex) => insertionIndex - 1,
+		};
+		
+		let mapping = &self.mappings[index];
+		if mapping.generated_line == line
+		{
+			Some(mapping)
+		}
+		else
+		{
+			None
+		}
+	}
+	
+	/// Serializes this source map as JSON.
+	pub fn to_json<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		#[inline(always)]
+		fn strings(strings: &[String]) -> Value
+		{
+			Value::Array(strings.iter().map(|string| Value::String(string.clone())).collect())
+		}
+		
+		let mut object = Map::new();
+		
+		object.insert("version".to_owned(), Value::from(3));
+		
+		if let Some(ref file) = self.file
+		{
+			object.insert("file".to_owned(), Value::String(file.clone()));
+		}
+		
+		if let Some(ref source_root) = self.source_root
+		{
+			object.insert("sourceRoot".to_owned(), Value::String(source_root.clone()));
+		}
+		
+		object.insert("sources".to_owned(), strings(&self.sources));
+		
+		if self.sources_content.iter().any(Option::is_some)
+		{
+			object.insert("sourcesContent".to_owned(), Value::Array(self.sources_content.iter().map(|source_content| match *source_content
+			{
+				None => Value::Null,
+				Some(ref source_content) => Value::String(source_content.clone()),
+			}).collect()));
+		}
+		
+		object.insert("names".to_owned(), strings(&self.names));
+		
+		let mut mappings = String::new();
+		self.write_mappings(&mut mappings)?;
+		object.insert("mappings".to_owned(), Value::String(mappings));
+		
+		write!(dest, "{}", Value::Object(object))
+	}
+	
+	/// Serializes this source map as a JSON string.
+	/// Convenience method wrapped `to_json()`.
+	#[inline(always)]
+	pub fn to_json_string(&self) -> String
+	{
+		let mut string = String::new();
+		self.to_json(&mut string).unwrap();
+		string
+	}
+	
+	/// Writes the `mappings` field's value: lines separated by `;`, segments by `,`, each segment a list of Base64 VLQ fields relative to the previous segment.
+	fn write_mappings<W: fmt::Write>(&self, dest: &mut W) -> fmt::Result
+	{
+		let mut previousGeneratedLine = 0;
+		let mut previousGeneratedColumn = 0;
+		let mut previousSourceIndex = 0;
+		let mut previousOriginalLine = 0;
+		let mut previousOriginalColumn = 0;
+		let mut previousNameIndex = 0;
+		
+		let mut afterFirst = false;
+		for mapping in self.mappings.iter()
+		{
+			if mapping.generated_line != previousGeneratedLine
+			{
+				while previousGeneratedLine < mapping.generated_line
+				{
+					dest.write_char(';')?;
+					previousGeneratedLine += 1;
+				}
+				previousGeneratedColumn = 0;
+			}
+			else if afterFirst
+			{
+				dest.write_char(',')?;
+			}
+			afterFirst = true;
+			
+			encode_base64_variable_length_quantity(mapping.generated_column as i64 - previousGeneratedColumn as i64, dest)?;
+			encode_base64_variable_length_quantity(mapping.source_index as i64 - previousSourceIndex as i64, dest)?;
+			encode_base64_variable_length_quantity(mapping.original_line as i64 - previousOriginalLine as i64, dest)?;
+			encode_base64_variable_length_quantity(mapping.original_column as i64 - previousOriginalColumn as i64, dest)?;
+			if let Some(name_index) = mapping.name_index
+			{
+				encode_base64_variable_length_quantity(name_index as i64 - previousNameIndex as i64, dest)?;
+				previousNameIndex = name_index;
+			}
+			
+			previousGeneratedColumn = mapping.generated_column;
+			previousSourceIndex = mapping.source_index;
+			previousOriginalLine = mapping.original_line;
+			previousOriginalColumn = mapping.original_column;
+		}
+		
+		Ok(())
+	}
+	
+	#[inline(always)]
+	fn add_source(&mut self, source: String, source_content: Option<String>) -> usize
+	{
+		if let Some(index) = self.sources.iter().position(|existing| existing == &source)
+		{
+			return index;
+		}
+		
+		self.sources.push(source);
+		self.sources_content.push(source_content);
+		self.sources.len() - 1
+	}
+	
+	#[inline(always)]
+	fn add_name(&mut self, name: &str) -> usize
+	{
+		if let Some(index) = self.names.iter().position(|existing| existing == name)
+		{
+			return index;
+		}
+		
+		self.names.push(name.to_owned());
+		self.names.len() - 1
+	}
+	
+	/// The reverse of `write_mappings()`.
+	/// Segments with only a generated column (which map to nothing) are skipped.
+	fn read_mappings(mappings: &str, numberOfSources: usize, numberOfNames: usize) -> Result<Vec<SourceMapping>, SourceMapError>
+	{
+		#[inline(always)]
+		fn relative(previous: i64, delta: i64) -> Result<i64, SourceMapError>
+		{
+			let value = previous.checked_add(delta).ok_or(SourceMapError::InvalidMappings)?;
+			if value < 0 || value > ::std::u32::MAX as i64
+			{
+				Err(SourceMapError::InvalidMappings)
+			}
+			else
+			{
+				Ok(value)
+			}
+		}
+		
+		let mut generatedLine = 0;
+		let mut previousSourceIndex = 0;
+		let mut previousOriginalLine = 0;
+		let mut previousOriginalColumn = 0;
+		let mut previousNameIndex = 0;
+		
+		let mut decodedMappings = Vec::new();
+		
+		for line in mappings.split(';')
+		{
+			let mut previousGeneratedColumn = 0;
+			
+			for segment in line.split(',').filter(|segment| !segment.is_empty())
+			{
+				let mut characters = segment.bytes().peekable();
+				
+				let generatedColumn = relative(previousGeneratedColumn, decode_base64_variable_length_quantity(&mut characters)?)?;
+				previousGeneratedColumn = generatedColumn;
+				
+				if characters.peek().is_none()
+				{
+					continue;
+				}
+				
+				let sourceIndex = relative(previousSourceIndex, decode_base64_variable_length_quantity(&mut characters)?)?;
+				previousSourceIndex = sourceIndex;
+				let originalLine = relative(previousOriginalLine, decode_base64_variable_length_quantity(&mut characters)?)?;
+				previousOriginalLine = originalLine;
+				let originalColumn = relative(previousOriginalColumn, decode_base64_variable_length_quantity(&mut characters)?)?;
+				previousOriginalColumn = originalColumn;
+				
+				let nameIndex = if characters.peek().is_some()
+				{
+					let nameIndex = relative(previousNameIndex, decode_base64_variable_length_quantity(&mut characters)?)?;
+					previousNameIndex = nameIndex;
+					if nameIndex as usize >= numberOfNames
+					{
+						return Err(SourceMapError::InvalidMappings);
+					}
+					Some(nameIndex as usize)
+				}
+				else
+				{
+					None
+				};
+				
+				if characters.peek().is_some() || sourceIndex as usize >= numberOfSources
+				{
+					return Err(SourceMapError::InvalidMappings);
+				}
+				
+				decodedMappings.push(SourceMapping
+				{
+					generated_line: generatedLine,
+					generated_column: generatedColumn as u32,
+					source_index: sourceIndex as usize,
+					original_line: originalLine as u32,
+					original_column: originalColumn as u32,
+					name_index: nameIndex,
+				});
+			}
+			
+			generatedLine += 1;
+		}
+		
+		// Segments within a line are not required to be in order.
+		decodedMappings.sort();
+		
+		Ok(decodedMappings)
+	}
+	
+	#[inline(always)]
+	fn strings_from_json(object: &Map<String, Value>, name: &'static str) -> Result<Vec<Option<String>>, SourceMapError>
+	{
+		match object.get(name)
+		{
+			Some(&Value::Array(ref values)) => values.iter().map(|value| match *value
+			{
+				Value::Null => Ok(None),
+				Value::String(ref string) => Ok(Some(string.clone())),
+				_ => Err(SourceMapError::InvalidField(name)),
+			}).collect(),
+			
+			_ => Err(SourceMapError::InvalidField(name)),
+		}
+	}
+	
+	#[inline(always)]
+	fn optional_string_from_json(object: &Map<String, Value>, name: &'static str) -> Result<Option<String>, SourceMapError>
+	{
+		match object.get(name)
+		{
+			None | Some(&Value::Null) => Ok(None),
+			Some(&Value::String(ref string)) => Ok(Some(string.clone())),
+			_ => Err(SourceMapError::InvalidField(name)),
+		}
+	}
+}
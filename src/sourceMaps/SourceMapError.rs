@@ -0,0 +1,51 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+quick_error!
+{
+	/// Represents all the things that can go wrong when reading a source map.
+	#[derive(Debug)]
+	pub enum SourceMapError
+	{
+		/// The source map was not valid JSON.
+		Json(cause: ::serde_json::Error)
+		{
+			cause(cause)
+			description(cause.description())
+			display("Source map was not valid JSON: '{}'", cause)
+			from()
+		}
+		
+		/// The source map was not a JSON object.
+		NotAnObject
+		{
+			description("Source map was not a JSON object")
+		}
+		
+		/// The source map's version was not 3.
+		UnsupportedVersion
+		{
+			description("Source map version was not 3")
+		}
+		
+		/// Index source maps (those with `sections`) are not supported.
+		IndexSourceMapsAreNotSupported
+		{
+			description("Index source maps (those with sections) are not supported")
+		}
+		
+		/// A field was missing or of the wrong JSON type.
+		InvalidField(name: &'static str)
+		{
+			description("A source map field was missing or of the wrong JSON type")
+			display("Source map field '{}' was missing or of the wrong JSON type", name)
+		}
+		
+		/// The `mappings` were not validly encoded, or referred to a source or name that does not exist.
+		InvalidMappings
+		{
+			description("Source map mappings were invalid")
+		}
+	}
+}
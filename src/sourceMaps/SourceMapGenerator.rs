@@ -0,0 +1,115 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Builds a `SourceMap` whilst one or more stylesheets are serialized, one after another, to the same destination using `Stylesheet::to_css_with_source_map()`.
+///
+/// Mappings are only created for stylesheets parsed with `StylesheetParseOptions.record_source_spans` set.
+#[derive(Default, Debug, Clone)]
+pub struct SourceMapGenerator
+{
+	file: Option<String>,
+	sources: Vec<String>,
+	sources_content: Vec<Option<String>>,
+	mappings: Vec<SourceMapping>,
+	generated_line: u32,
+	generated_column: u32,
+}
+
+impl SourceMapGenerator
+{
+	/// Creates a new generator; `file` is the name of the generated CSS file, if known.
+	#[inline(always)]
+	pub fn new(file: Option<String>) -> Self
+	{
+		Self
+		{
+			file,
+			.. Default::default()
+		}
+	}
+	
+	/// Adds an original source (typically the URL or file path the stylesheet was parsed from), optionally with its content, and returns its index for use with `Stylesheet::to_css_with_source_map()`.
+	/// Adding the same source more than once returns the same index.
+	pub fn add_source(&mut self, source: String, source_content: Option<String>) -> usize
+	{
+		if let Some(index) = self.sources.iter().position(|existing| existing == &source)
+		{
+			if source_content.is_some()
+			{
+				self.sources_content[index] = source_content;
+			}
+			return index;
+		}
+		
+		self.sources.push(source);
+		self.sources_content.push(source_content);
+		self.sources.len() - 1
+	}
+	
+	/// Notes that CSS not produced by `Stylesheet::to_css_with_source_map()`, such as a separator or comment, has been written to the destination, so that subsequent mappings are correct.
+	#[inline(always)]
+	pub fn advance(&mut self, css: &str)
+	{
+		for character in css.chars()
+		{
+			if character == '\n'
+			{
+				self.generated_line += 1;
+				self.generated_column = 0;
+			}
+			else
+			{
+				self.generated_column += character.len_utf16() as u32;
+			}
+		}
+	}
+	
+	/// Maps the current generated position to `original` in the source `source_index`.
+	/// If there is already a mapping at the current generated position it is replaced, as later mappings are for more specific parts of the CSS (eg a selector rather than the rule containing it).
+	pub(crate) fn add_mapping(&mut self, source_index: usize, original: SourceSpanLocation)
+	{
+		let mapping = SourceMapping
+		{
+			generated_line: self.generated_line,
+			generated_column: self.generated_column,
+			source_index,
+			original_line: original.line,
+			original_column: original.column.saturating_sub(1),
+			name_index: None,
+		};
+		
+		if let Some(last) = self.mappings.last_mut()
+		{
+			if last.generated_line == mapping.generated_line && last.generated_column == mapping.generated_column
+			{
+				*last = mapping;
+				return;
+			}
+		}
+		
+		self.mappings.push(mapping)
+	}
+	
+	/// The source map for everything serialized so far.
+	#[inline(always)]
+	pub fn source_map(&self) -> SourceMap
+	{
+		SourceMap
+		{
+			file: self.file.clone(),
+			source_root: None,
+			sources: self.sources.clone(),
+			sources_content: if self.sources_content.iter().any(Option::is_some)
+			{
+				self.sources_content.clone()
+			}
+			else
+			{
+				Vec::new()
+			},
+			names: Vec::new(),
+			mappings: self.mappings.clone(),
+		}
+	}
+}
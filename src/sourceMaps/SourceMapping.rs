@@ -0,0 +1,27 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// A single mapping from a location in generated CSS to a location in an original source.
+/// All lines and columns are zero-based, as they are in the Source Map format; columns are counted in UTF-16 code units.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct SourceMapping
+{
+	/// Line in the generated CSS.
+	pub generated_line: u32,
+	
+	/// Column in the generated CSS.
+	pub generated_column: u32,
+	
+	/// Index into `SourceMap.sources`.
+	pub source_index: usize,
+	
+	/// Line in the original source.
+	pub original_line: u32,
+	
+	/// Column in the original source.
+	pub original_column: u32,
+	
+	/// Index into `SourceMap.names`, if any.
+	pub name_index: Option<usize>,
+}
@@ -0,0 +1,50 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Decodes one signed Base64 Variable Length Quantity (VLQ) from the start of `characters`, as used in the `mappings` of a Source Map.
+pub(crate) fn decode_base64_variable_length_quantity<I: Iterator<Item=u8>>(characters: &mut Peekable<I>) -> Result<i64, SourceMapError>
+{
+	const ContinuationBit: u64 = 0b10_0000;
+	const DigitMask: u64 = 0b01_1111;
+	const DigitShift: u64 = 5;
+	const MaximumShift: u64 = 60;
+	
+	let mut value: u64 = 0;
+	let mut shift = 0;
+	loop
+	{
+		let digit = match characters.next()
+		{
+			Some(character @ b'A' ... b'Z') => character - b'A',
+			Some(character @ b'a' ... b'z') => character - b'a' + 26,
+			Some(character @ b'0' ... b'9') => character - b'0' + 52,
+			Some(b'+') => 62,
+			Some(b'/') => 63,
+			_ => return Err(SourceMapError::InvalidMappings),
+		} as u64;
+		
+		if shift > MaximumShift
+		{
+			return Err(SourceMapError::InvalidMappings);
+		}
+		value |= (digit & DigitMask) << shift;
+		shift += DigitShift;
+		
+		if digit & ContinuationBit == 0
+		{
+			break;
+		}
+	}
+	
+	// The sign is stored in the least significant bit.
+	let magnitude = (value >> 1) as i64;
+	if value & 1 == 1
+	{
+		Ok(-magnitude)
+	}
+	else
+	{
+		Ok(magnitude)
+	}
+}
@@ -0,0 +1,39 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+/// Encodes a signed value as a Base64 Variable Length Quantity (VLQ), as used in the `mappings` of a Source Map.
+pub(crate) fn encode_base64_variable_length_quantity<W: fmt::Write>(value: i64, dest: &mut W) -> fmt::Result
+{
+	const Base64Alphabet: &'static [u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+	const ContinuationBit: u64 = 0b10_0000;
+	const DigitMask: u64 = 0b01_1111;
+	const DigitShift: u64 = 5;
+	
+	// The sign is stored in the least significant bit.
+	let mut remaining = if value < 0
+	{
+		((-value as u64) << 1) | 1
+	}
+	else
+	{
+		(value as u64) << 1
+	};
+	
+	loop
+	{
+		let mut digit = remaining & DigitMask;
+		remaining >>= DigitShift;
+		if remaining != 0
+		{
+			digit |= ContinuationBit;
+		}
+		
+		dest.write_char(Base64Alphabet[digit as usize] as char)?;
+		
+		if remaining == 0
+		{
+			return Ok(());
+		}
+	}
+}
@@ -0,0 +1,21 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+use ::serde_json::Map;
+use ::serde_json::Value;
+use ::std::fmt;
+use ::std::iter::Peekable;
+
+
+#[cfg(test)]
+mod tests;
+
+
+include!("decode_base64_variable_length_quantity.rs");
+include!("encode_base64_variable_length_quantity.rs");
+include!("SourceMap.rs");
+include!("SourceMapError.rs");
+include!("SourceMapGenerator.rs");
+include!("SourceMapping.rs");
@@ -0,0 +1,199 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+
+
+/// Serializes `css` with a source map; each mapping is `(generated_line, generated_column, original_line, original_column)`.
+fn serialize_with_source_map(css: &str) -> (String, Vec<(u32, u32, u32, u32)>, SourceMap)
+{
+	let options = StylesheetParseOptions
+	{
+		record_source_spans: true,
+		..StylesheetParseOptions::default()
+	};
+	let (stylesheet, _) = Stylesheet::parse_with_options(css, &options).ok().unwrap();
+	
+	let mut source_map_generator = SourceMapGenerator::new(Some("out.css".to_owned()));
+	let source_index = source_map_generator.add_source("in.css".to_owned(), None);
+	let mut generated = String::new();
+	stylesheet.to_css_with_source_map(&mut generated, &SerializerOptions::default(), &mut source_map_generator, source_index).unwrap();
+	
+	let source_map = source_map_generator.source_map();
+	let mappings = source_map.mappings.iter().map(|mapping| (mapping.generated_line, mapping.generated_column, mapping.original_line, mapping.original_column)).collect();
+	(generated, mappings, source_map)
+}
+
+#[test]
+fn style_rule_selectors_and_declarations_are_mapped()
+{
+	let (generated, mappings, _) = serialize_with_source_map("a, b {\n  color:red;\n  top:0;\n}");
+	
+	assert_eq!(generated, "a,b{color:red;top:0}");
+	assert_eq!(mappings, vec![(0, 0, 0, 0), (0, 2, 0, 3), (0, 4, 1, 2), (0, 14, 2, 2)]);
+}
+
+#[test]
+fn keyframes_and_their_declarations_are_mapped()
+{
+	let (generated, mappings, _) = serialize_with_source_map("@keyframes k {\n  50% {top:0}\n  100% {top:1px}\n}");
+	
+	assert_eq!(generated, "@keyframes k{50%{top:0}100%{top:1px}}");
+	assert_eq!(mappings, vec![(0, 0, 0, 0), (0, 11, 0, 11), (0, 13, 1, 2), (0, 17, 1, 7), (0, 23, 2, 2), (0, 28, 2, 8)]);
+}
+
+#[test]
+fn font_face_descriptors_are_mapped()
+{
+	let (generated, mappings, _) = serialize_with_source_map("@font-face {\n  src:url(x.woff);\n  font-family:x\n}");
+	
+	assert_eq!(generated, "@font-face{font-family:x;src:url(x.woff)}");
+	assert_eq!(mappings, vec![(0, 0, 0, 0), (0, 11, 2, 2), (0, 25, 1, 2)]);
+}
+
+#[test]
+fn counter_style_viewport_and_font_feature_values_declarations_are_mapped()
+{
+	let (_, mappings, _) = serialize_with_source_map("@counter-style c {\n  system: cyclic;\n  symbols: x\n}\n@viewport {\n  zoom: 1\n}\n@font-feature-values f {\n  @swash {\n    s: 1\n  }\n}");
+	
+	let originalLines = mappings.iter().map(|&(_, _, original_line, _)| original_line).collect::<Vec<_>>();
+	assert_eq!(originalLines, vec![0, 1, 2, 4, 5, 7, 9]);
+}
+
+#[test]
+fn source_map_is_serialized_as_json()
+{
+	let (_, _, source_map) = serialize_with_source_map("a {\n  color: red\n}");
+	
+	assert_eq!(source_map.to_json_string(), r#"{"file":"out.css","mappings":"AAAA,EACE","names":[],"sources":["in.css"],"version":3}"#);
+	assert_eq!(SourceMap::from_json(&source_map.to_json_string()).unwrap(), source_map);
+}
+
+#[test]
+fn json_strings_are_escaped()
+{
+	let source_map = SourceMap
+	{
+		sources: vec!["a \"b\"\\c\n.css".to_owned()],
+		sources_content: vec![Some("\u{1}".to_owned())],
+		..SourceMap::default()
+	};
+	
+	assert_eq!(source_map.to_json_string(), r#"{"mappings":"","names":[],"sources":["a \"b\"\\c\n.css"],"sourcesContent":["\u0001"],"version":3}"#);
+}
+
+fn decode(encoded: &str) -> Result<i64, SourceMapError>
+{
+	decode_base64_variable_length_quantity(&mut encoded.bytes().peekable())
+}
+
+fn is_invalid_mappings<T>(result: Result<T, SourceMapError>) -> bool
+{
+	match result
+	{
+		Err(SourceMapError::InvalidMappings) => true,
+		_ => false,
+	}
+}
+
+fn mapping(generated_line: u32, generated_column: u32, source_index: usize, original_line: u32, original_column: u32) -> SourceMapping
+{
+	SourceMapping
+	{
+		generated_line,
+		generated_column,
+		source_index,
+		original_line,
+		original_column,
+		name_index: None,
+	}
+}
+
+#[test]
+fn variable_length_quantities_are_decoded()
+{
+	assert_eq!(decode("A").unwrap(), 0);
+	assert_eq!(decode("C").unwrap(), 1);
+	assert_eq!(decode("D").unwrap(), -1);
+	assert_eq!(decode("e").unwrap(), 15);
+	assert_eq!(decode("gB").unwrap(), 16);
+	assert_eq!(decode("hB").unwrap(), -16);
+}
+
+#[test]
+fn variable_length_quantities_round_trip()
+{
+	for &value in [0, 1, -1, 15, -15, 16, -16, 1000, -123456, ::std::u32::MAX as i64, -(::std::u32::MAX as i64), ::std::i64::MAX].iter()
+	{
+		let mut encoded = String::new();
+		encode_base64_variable_length_quantity(value, &mut encoded).unwrap();
+		assert_eq!(decode(&encoded).unwrap(), value, "{}", encoded);
+	}
+}
+
+#[test]
+fn invalid_variable_length_quantities_are_rejected()
+{
+	assert!(is_invalid_mappings(decode("")));
+	assert!(is_invalid_mappings(decode("*")));
+	assert!(is_invalid_mappings(decode("g")));
+	assert!(is_invalid_mappings(decode("gggggggggggggggA")));
+}
+
+#[test]
+fn mappings_that_overflow_are_rejected()
+{
+	let mut maximum = String::new();
+	encode_base64_variable_length_quantity(::std::i64::MAX, &mut maximum).unwrap();
+	
+	let json = format!(r#"{{"version":3,"sources":["a.css"],"mappings":"CAAA,{}AAA"}}"#, maximum);
+	assert!(is_invalid_mappings(SourceMap::from_json(&json)));
+	
+	let json = format!(r#"{{"version":3,"sources":["a.css"],"mappings":"AAAC,AAA{}"}}"#, maximum);
+	assert!(is_invalid_mappings(SourceMap::from_json(&json)));
+}
+
+#[test]
+fn mappings_are_read_relative_to_the_previous_segment()
+{
+	let source_map = SourceMap::from_json(r#"{"version":3,"sources":["a.css","b.css"],"names":["x"],"mappings":"AAAA,EACE;GCDFA"}"#).unwrap();
+	
+	assert_eq!(source_map.mappings, vec!
+	[
+		mapping(0, 0, 0, 0, 0),
+		mapping(0, 2, 0, 1, 2),
+		SourceMapping
+		{
+			name_index: Some(0),
+			.. mapping(1, 3, 1, 0, 0)
+		},
+	]);
+	assert_eq!(SourceMap::from_json(&source_map.to_json_string()).unwrap(), source_map);
+}
+
+#[test]
+fn composed_mappings_point_at_the_original_sources()
+{
+	// `out.css` was generated from `in.css`, which Sass generated from `in.scss`.
+	let source_map = SourceMap
+	{
+		file: Some("out.css".to_owned()),
+		sources: vec!["other.css".to_owned(), "in.css".to_owned()],
+		mappings: vec![mapping(0, 0, 1, 0, 0), mapping(0, 5, 1, 1, 4), mapping(0, 9, 0, 3, 1), mapping(0, 12, 1, 7, 0)],
+		..SourceMap::default()
+	};
+	let input_source_map = SourceMap
+	{
+		source_root: Some("styles".to_owned()),
+		sources: vec!["in.scss".to_owned()],
+		mappings: vec![mapping(0, 0, 0, 2, 0), mapping(1, 2, 0, 5, 6)],
+		..SourceMap::default()
+	};
+	
+	let composed = source_map.compose(1, &input_source_map);
+	
+	assert_eq!(composed.file, Some("out.css".to_owned()));
+	assert_eq!(composed.sources, vec!["other.css".to_owned(), "styles/in.scss".to_owned()]);
+	assert_eq!(composed.mappings, vec![mapping(0, 0, 1, 2, 0), mapping(0, 5, 1, 5, 6), mapping(0, 9, 0, 3, 1)]);
+}
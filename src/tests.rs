@@ -0,0 +1,422 @@
+// This file is part of css. It is subject to the license terms in the COPYRIGHT file found in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT. No part of predicator, including this file, may be copied, modified, propagated, or distributed except according to the terms contained in the COPYRIGHT file.
+// Copyright © 2017 The developers of css. See the COPYRIGHT file in the top-level directory of this distribution and at https://raw.githubusercontent.com/lemonrock/css/master/COPYRIGHT.
+
+
+use super::*;
+use super::domain::boxModel::LengthOrPercentageOrAuto;
+use super::domain::numbers::CssSignedNumber;
+use super::domain::properties::Importance;
+use super::domain::properties::PropertyValue;
+use ::encoding_rs::UTF_16LE;
+use ::encoding_rs::UTF_8;
+use ::encoding_rs::WINDOWS_1251;
+use ::encoding_rs::WINDOWS_1252;
+
+
+fn sanitizer_policy_allowing_hosts(hosts: &[&str]) -> SanitizerPolicy
+{
+	SanitizerPolicy
+	{
+		allowed_url_hosts: Some(hosts.iter().map(|host| host.to_string()).collect()),
+		..SanitizerPolicy::default()
+	}
+}
+
+#[test]
+fn sanitizer_policy_allows_urls_to_allowed_hosts()
+{
+	let policy = sanitizer_policy_allowing_hosts(&["good.example"]);
+	
+	assert_eq!(policy.check_url("https://good.example/image.png"), Ok(()));
+	assert_eq!(policy.check_url("HTTPS://GOOD.EXAMPLE:8443/image.png"), Ok(()));
+	assert_eq!(policy.check_url("//good.example/image.png"), Ok(()));
+	assert_eq!(policy.check_url("https:good.example/image.png"), Ok(()));
+	assert_eq!(policy.check_url("images/image.png"), Ok(()));
+	assert_eq!(policy.check_url("/images/image.png"), Ok(()));
+}
+
+#[test]
+fn sanitizer_policy_finds_hosts_after_any_slashes_for_special_schemes()
+{
+	let policy = sanitizer_policy_allowing_hosts(&["good.example"]);
+	
+	for url in &["https://evil.example/x", "https:evil.example/x", "http:/evil.example/x", "https:\\\\evil.example/x", "https:///evil.example/x", "//evil.example/x", "\\\\evil.example/x", "///evil.example/x", "https://good.example@evil.example/x", "https://evil.example./x"]
+	{
+		assert_eq!(policy.check_url(url), Err(SanitizerRemovalReason::DisallowedUrlHost(url.to_string())), "{}", url);
+	}
+}
+
+#[test]
+fn sanitizer_policy_fails_closed_for_absolute_urls_without_a_host()
+{
+	let mut policy = sanitizer_policy_allowing_hosts(&["good.example"]);
+	policy.allowed_url_schemes.insert("data".to_owned());
+	policy.allowed_url_schemes.insert("file".to_owned());
+	
+	for url in &["data:image/png;base64,AAAA", "file:///etc/passwd", "https://", "https:///"]
+	{
+		assert_eq!(policy.check_url(url), Err(SanitizerRemovalReason::DisallowedUrlHost(url.to_string())), "{}", url);
+	}
+	
+	policy.allowed_url_hosts = None;
+	assert_eq!(policy.check_url("data:image/png;base64,AAAA"), Ok(()));
+}
+
+#[test]
+fn sanitizer_policy_rejects_disallowed_schemes_and_relative_urls()
+{
+	let mut policy = SanitizerPolicy::default();
+	
+	assert_eq!(policy.check_url("java\nscript:alert(1)"), Err(SanitizerRemovalReason::DisallowedUrlScheme("javascript:alert(1)".to_owned())));
+	
+	policy.allow_relative_urls = false;
+	assert_eq!(policy.check_url("images/image.png"), Err(SanitizerRemovalReason::DisallowedRelativeUrl("images/image.png".to_owned())));
+	assert_eq!(policy.check_url("//evil.example/x"), Ok(()));
+}
+
+#[test]
+fn sanitizer_policy_treats_tabs_and_new_lines_in_urls_as_absent()
+{
+	let policy = SanitizerPolicy::default();
+	
+	assert_eq!(policy.check_url(" \tht\ttps://good.example/x\r\n"), Ok(()));
+	assert_eq!(policy.check_url("d\ta\rta:text/css,a"), Err(SanitizerRemovalReason::DisallowedUrlScheme("data:text/css,a".to_owned())));
+	assert_eq!(policy.check_url("web+app:x"), Err(SanitizerRemovalReason::DisallowedUrlScheme("web+app:x".to_owned())));
+}
+
+#[test]
+fn sanitizer_keeps_at_rules_that_can_not_load_resources_unless_their_rule_type_is_disallowed()
+{
+	let css = "@counter-style c{system:cyclic;symbols:x}@viewport{zoom:1}@font-feature-values f{@swash{s:1}}";
+	
+	let mut stylesheet = Stylesheet::parse(css).ok().unwrap();
+	let unsanitized = stylesheet.to_css_string(false);
+	assert!(stylesheet.sanitize(&SanitizerPolicy::default()).is_empty());
+	assert_eq!(stylesheet.to_css_string(false), unsanitized);
+	
+	let policy = SanitizerPolicy
+	{
+		allowed_rule_types: Some([CssRuleType::Style].iter().cloned().collect()),
+		..SanitizerPolicy::default()
+	};
+	let removals = stylesheet.sanitize(&policy);
+	assert_eq!(removals.iter().map(|removal| removal.reason.clone()).collect::<Vec<_>>(), vec![SanitizerRemovalReason::DisallowedRuleType(CssRuleType::CounterStyle), SanitizerRemovalReason::DisallowedRuleType(CssRuleType::Viewport), SanitizerRemovalReason::DisallowedRuleType(CssRuleType::FontFeatureValues)]);
+	assert_eq!(stylesheet.to_css_string(false), "");
+}
+
+fn first_style_rule_property_declarations(stylesheet: &mut Stylesheet) -> &mut PropertyDeclarations<Importance>
+{
+	match stylesheet.rules.0[0]
+	{
+		CssRule::Style(ref mut styleRule) => &mut styleRule.property_declarations,
+		_ => panic!("Not a style rule"),
+	}
+}
+
+fn style_rule(stylesheet: &Stylesheet, index: usize) -> &StyleRule
+{
+	match stylesheet.rules.0[index]
+	{
+		CssRule::Style(ref styleRule) => styleRule,
+		_ => panic!("Not a style rule"),
+	}
+}
+
+#[test]
+fn selector_source_spans_are_recorded_beside_the_selectors()
+{
+	let css = "a, b {}\n  b,a{}";
+	let options = StylesheetParseOptions
+	{
+		record_source_spans: true,
+		..StylesheetParseOptions::default()
+	};
+	let (stylesheet, _) = Stylesheet::parse_with_options(css, &options).ok().unwrap();
+	
+	let first = style_rule(&stylesheet, 0);
+	assert_eq!(first.selector_source_spans.iter().map(|sourceSpan| sourceSpan.slice(css)).collect::<Vec<_>>(), vec!["a", "b"]);
+	assert_eq!(first.selector_source_spans[1].start.column, 4);
+	
+	let second = style_rule(&stylesheet, 1);
+	assert_eq!(second.selector_source_spans[0].start.line, 1);
+	assert_eq!(second.selectors.to_css_string(), "b,a");
+	
+	let (again, _) = Stylesheet::parse_with_options("\n\n a,b{}", &options).ok().unwrap();
+	assert_eq!(style_rule(&again, 0).selectors, first.selectors);
+	assert!(style_rule(&Stylesheet::parse(css).ok().unwrap(), 0).selector_source_spans.is_empty());
+}
+
+#[test]
+fn setting_a_typed_value_updates_references()
+{
+	let mut stylesheet = Stylesheet::parse("a{margin-left:var(--a);margin-right:var(--b)}").ok().unwrap();
+	let propertyDeclarations = first_style_rule_property_declarations(&mut stylesheet);
+	
+	let typedValue = LengthOrPercentageOrAuto::<CssSignedNumber>::parse_property_value_from_css("calc(var(--gap) * 2 + var(--offset))").unwrap();
+	propertyDeclarations.0[0].set_typed_value(&typedValue).unwrap();
+	assert_eq!(propertyDeclarations.0[0].references.iter().map(|name| name.to_string()).collect::<Vec<_>>(), vec!["--gap", "--offset"]);
+	
+	let typedValue = LengthOrPercentageOrAuto::<CssSignedNumber>::parse_property_value_from_css("auto").unwrap();
+	propertyDeclarations.0[1].set_typed_value(&typedValue).unwrap();
+	assert!(propertyDeclarations.0[1].references.is_empty());
+}
+
+fn inline_custom_properties(css: &str) -> (String, Vec<CustomPropertyInliningRefusalReason>)
+{
+	let mut stylesheet = Stylesheet::parse(css).ok().unwrap();
+	let policy = CustomPropertyInliningPolicy
+	{
+		keep_original_declarations: false,
+		..CustomPropertyInliningPolicy::default()
+	};
+	let refusals = stylesheet.inline_custom_properties(&policy);
+	(stylesheet.to_css_string(false), refusals.into_iter().map(|refusal| refusal.reason).collect())
+}
+
+#[test]
+fn inlining_custom_properties_uses_exact_names()
+{
+	let (css, refusals) = inline_custom_properties(":root{--A:red;--a:blue}a{color:var(--A);background-color:var(--a)}");
+	assert_eq!(css, ":root{--A:red;--a:blue}a{color:red;background-color:blue}");
+	assert!(refusals.is_empty());
+}
+
+#[test]
+fn expanded_shorthands_are_inlined()
+{
+	let css = ":root{--gap:4px}a{margin:calc(var(--gap) * 2) 0;padding:1px var(--gap)}";
+	let mut stylesheet = Stylesheet::parse(css).ok().unwrap();
+	{
+		let propertyDeclarations = match stylesheet.rules.0[1]
+		{
+			CssRule::Style(ref mut styleRule) => &mut styleRule.property_declarations,
+			_ => panic!("Not a style rule"),
+		};
+		propertyDeclarations.expand_box_model_shorthands();
+		assert!(propertyDeclarations.0.iter().all(|propertyDeclaration| !propertyDeclaration.references.is_empty()));
+	}
+	
+	let policy = CustomPropertyInliningPolicy
+	{
+		keep_original_declarations: false,
+		..CustomPropertyInliningPolicy::default()
+	};
+	assert!(stylesheet.inline_custom_properties(&policy).is_empty());
+	assert_eq!(stylesheet.to_css_string(false), ":root{--gap:4px}a{margin:calc(4px * 2) 0;padding:1px 4px}");
+	
+	let propertyDeclarations = match stylesheet.rules.0[1]
+	{
+		CssRule::Style(ref mut styleRule) => &mut styleRule.property_declarations,
+		_ => panic!("Not a style rule"),
+	};
+	propertyDeclarations.expand_box_model_shorthands();
+	assert_eq!(propertyDeclarations.to_css_string(), "margin-top:calc(4px*2);margin-right:0px;margin-bottom:calc(4px*2);margin-left:0px;padding-top:1px;padding-right:4px;padding-bottom:1px;padding-left:4px");
+}
+
+#[test]
+fn inlining_custom_properties_substitutes_nested_fallbacks()
+{
+	let (css, refusals) = inline_custom_properties(":root{--size:2px;--border:var(--width,var(--size)) solid}a{border:var(--border);margin:var(--undefined,var(--also-undefined,var(--size)))}");
+	assert_eq!(css, ":root{--size:2px;--border:var(--width,var(--size)) solid}a{border:2px solid;margin:2px}");
+	assert!(refusals.is_empty());
+}
+
+#[test]
+fn inlining_custom_properties_refuses_redefinitions_undefined_names_and_cycles()
+{
+	let (css, refusals) = inline_custom_properties(":root{--color:red;--x:var(--y);--y:var(--x)}.dark{--color:white}a{color:var(--color,blue);margin:var(--missing);padding:var(--x);width:var(--x,1px)}");
+	assert_eq!(css, ":root{--color:red;--x:var(--y);--y:var(--x)}.dark{--color:white}a{color:var(--color,blue);margin:var(--missing);padding:var(--x);width:1px}");
+	assert_eq!(refusals, vec![CustomPropertyInliningRefusalReason::RedefinedInDescendantScope(Atom::from("--color")), CustomPropertyInliningRefusalReason::Undefined(Atom::from("--missing")), CustomPropertyInliningRefusalReason::Cycle(Atom::from("--x"))]);
+}
+
+#[test]
+fn custom_property_graph_finds_cycles()
+{
+	let stylesheet = Stylesheet::parse(":root{--a:var(--b);--b:var(--c) var(--a);--c:1px;--self:var(--self);--d:var(--e,var(--a))}.dark{--e:var(--d)}a{width:var(--d)}").ok().unwrap();
+	let graph = stylesheet.custom_property_graph();
+	
+	let cycles: Vec<Vec<String>> = graph.cycles().iter().map(|cycle| cycle.iter().map(|name| name.to_string()).collect()).collect();
+	assert_eq!(cycles, vec![vec!["--a", "--b"], vec!["--d", "--e"], vec!["--self"]]);
+	
+	assert!(graph.undefined_uses_without_fallbacks().is_empty());
+	assert!(graph.unused_definitions().is_empty());
+}
+
+fn parse_recovering_from_errors(css: &str) -> (String, Vec<RecoveredParseError>)
+{
+	let options = StylesheetParseOptions
+	{
+		recover_from_errors: true,
+		..StylesheetParseOptions::default()
+	};
+	let (stylesheet, recoveredParseErrors) = Stylesheet::parse_with_options(css, &options).ok().unwrap();
+	(stylesheet.to_css_string(false), recoveredParseErrors)
+}
+
+#[test]
+fn parsing_recovers_from_invalid_rules_and_declarations()
+{
+	let css = "a{color:red}\n1x{color:blue}\nb{color red;margin:0}@media print{c{;top:0}2y{}}d{}";
+	assert!(Stylesheet::parse(css).is_err());
+	
+	let (serialized, recoveredParseErrors) = parse_recovering_from_errors(css);
+	assert_eq!(serialized, "a{color:red}b{margin:0}@media print{c{top:0}}d{}");
+	assert_eq!(recoveredParseErrors.iter().map(|error| (error.location.line, error.location.column, &error.skipped_css[..])).collect::<Vec<_>>(), vec![(1, 1, "1x{"), (2, 3, "color red;"), (2, 44, "2y{")]);
+}
+
+fn parse_bytes(bytes: &[u8], protocol_encoding_label: Option<&str>, fallback_encoding: Option<&'static Encoding>) -> (String, &'static str)
+{
+	let (stylesheet, _, encoding) = Stylesheet::parse_bytes(bytes, protocol_encoding_label, fallback_encoding, &StylesheetParseOptions::default()).ok().unwrap();
+	(stylesheet.to_css_string(false), encoding.name())
+}
+
+#[test]
+fn parse_bytes_defaults_to_utf_8()
+{
+	assert_eq!(parse_bytes(b"a{content:\"\xC3\xA9\"}", None, None), ("a{content:\"\u{E9}\"}".to_owned(), UTF_8.name()));
+	assert_eq!(parse_bytes(b"a{content:\"\xE9\"}", None, None), ("a{content:\"\u{FFFD}\"}".to_owned(), UTF_8.name()));
+}
+
+#[test]
+fn parse_bytes_prefers_a_byte_order_mark()
+{
+	assert_eq!(parse_bytes(b"\xEF\xBB\xBFa{content:\"\xC3\xA9\"}", Some("windows-1251"), Some(WINDOWS_1252)), ("a{content:\"\u{E9}\"}".to_owned(), UTF_8.name()));
+	assert_eq!(parse_bytes(b"\xFF\xFEa\x00{\x00}\x00", Some("utf-8"), None), ("a{}".to_owned(), UTF_16LE.name()));
+}
+
+#[test]
+fn parse_bytes_prefers_the_protocol_encoding_to_a_charset_rule()
+{
+	let bytes = b"@charset \"windows-1251\";a{content:\"\xE9\"}";
+	
+	assert_eq!(parse_bytes(bytes, Some("ISO-8859-1"), None).1, WINDOWS_1252.name());
+	assert_eq!(parse_bytes(bytes, None, Some(WINDOWS_1252)).1, WINDOWS_1251.name());
+	assert_eq!(parse_bytes(bytes, Some("not-an-encoding"), None).1, WINDOWS_1251.name());
+}
+
+#[test]
+fn parse_bytes_uses_the_fallback_encoding_last()
+{
+	assert_eq!(parse_bytes(b"a{content:\"\xE9\"}", None, Some(WINDOWS_1252)), ("a{content:\"\u{E9}\"}".to_owned(), WINDOWS_1252.name()));
+	assert_eq!(parse_bytes(b"@charset \"bogus\";a{}", None, Some(WINDOWS_1251)).1, WINDOWS_1251.name());
+}
+
+#[test]
+fn parse_bytes_treats_a_utf_16_charset_rule_as_utf_8()
+{
+	assert_eq!(parse_bytes(b"@charset \"utf-16le\";a{}", None, None).1, UTF_8.name());
+	assert_eq!(parse_bytes(b"@charset \"utf-16be\";a{}", None, Some(WINDOWS_1252)).1, UTF_8.name());
+}
+
+/// The limit that parsing `css` with `limits` exceeded, if any, both when failing on the first error and when recovering from errors.
+fn exceeded_parse_limit(css: &str, limits: ParseLimits) -> Option<ParseLimit>
+{
+	let exceeded = |recover_from_errors|
+	{
+		let options = StylesheetParseOptions
+		{
+			recover_from_errors,
+			limits,
+			..StylesheetParseOptions::default()
+		};
+		match Stylesheet::parse_with_options(css, &options)
+		{
+			Err(PreciseParseError { error: ParseError::Custom(CustomParseError::ParseLimitExceeded(parseLimit)), .. }) => Some(parseLimit),
+			Err(_) => panic!("failed for a reason other than a parse limit"),
+			Ok(_) => None,
+		}
+	};
+	
+	let exceededWhenNotRecovering = exceeded(false);
+	assert_eq!(exceeded(true), exceededWhenNotRecovering);
+	exceededWhenNotRecovering
+}
+
+#[test]
+fn nesting_depth_is_limited()
+{
+	let limits = ParseLimits
+	{
+		maximum_nesting_depth: 2,
+		..ParseLimits::unlimited()
+	};
+	
+	assert_eq!(exceeded_parse_limit("@media print{@supports (top:0){a{}}}", limits), None);
+	assert_eq!(exceeded_parse_limit("@media print{@supports (top:0){@media screen{a{}}}}", limits), Some(ParseLimit::NestingDepth));
+}
+
+#[test]
+fn rule_count_is_limited()
+{
+	let limits = ParseLimits
+	{
+		maximum_rule_count: 3,
+		..ParseLimits::unlimited()
+	};
+	
+	assert_eq!(exceeded_parse_limit("a{}@media print{b{}}", limits), None);
+	assert_eq!(exceeded_parse_limit("a{}@media print{b{}c{}}", limits), Some(ParseLimit::RuleCount));
+	assert_eq!(exceeded_parse_limit("@keyframes k{0%{}50%{}100%{}}", limits), Some(ParseLimit::RuleCount));
+}
+
+#[test]
+fn property_declarations_per_block_are_limited()
+{
+	let limits = ParseLimits
+	{
+		maximum_property_declarations_per_block: 2,
+		..ParseLimits::unlimited()
+	};
+	
+	assert_eq!(exceeded_parse_limit("a{top:0;left:0}b{top:0;left:0}", limits), None);
+	assert_eq!(exceeded_parse_limit("a{top:0;left:0;right:0}", limits), Some(ParseLimit::PropertyDeclarationsPerBlock));
+	assert_eq!(exceeded_parse_limit("@page{top:0;left:0;right:0}", limits), Some(ParseLimit::PropertyDeclarationsPerBlock));
+}
+
+#[test]
+fn selector_list_length_and_input_size_are_limited()
+{
+	let limits = ParseLimits
+	{
+		maximum_selector_list_length: 2,
+		maximum_input_size: 16,
+		..ParseLimits::unlimited()
+	};
+	
+	assert_eq!(exceeded_parse_limit("a,b{}", limits), None);
+	assert_eq!(exceeded_parse_limit("a,b,c{}", limits), Some(ParseLimit::SelectorListLength));
+	assert_eq!(exceeded_parse_limit("a{}b{}c{}d{}e{}f{}", limits), Some(ParseLimit::InputSize));
+}
+
+#[test]
+fn property_values_are_parsed_with_the_given_limits()
+{
+	let limits = ParseLimits
+	{
+		maximum_calc_expression_depth: 2,
+		maximum_input_size: 32,
+		..ParseLimits::unlimited()
+	};
+	
+	let calcExpressionDepth = |css| match LengthOrPercentageOrAuto::<CssSignedNumber>::parse_property_value_from_css_with_limits(css, limits)
+	{
+		Err(ParseError::Custom(CustomParseError::ParseLimitExceeded(parseLimit))) => Some(parseLimit),
+		Err(_) => panic!("failed for a reason other than a parse limit"),
+		Ok(_) => None,
+	};
+	assert_eq!(calcExpressionDepth("calc((1px + 2px) * 2)"), None);
+	assert_eq!(calcExpressionDepth("calc(((1px + 2px) * 2) * 2)"), Some(ParseLimit::CalcExpressionDepth));
+	assert_eq!(calcExpressionDepth("calc(1px + 2px + 3px + 4px + 5px)"), Some(ParseLimit::InputSize));
+	assert!(LengthOrPercentageOrAuto::<CssSignedNumber>::parse_property_value_from_css("calc(((1px + 2px) * 2) * 2)").is_ok());
+	
+	let mut stylesheet = Stylesheet::parse("a{bottom:calc(((1px + 2px) * 2) * 2)}").ok().unwrap();
+	let propertyDeclaration = first_style_rule_property_declarations(&mut stylesheet).0[0].clone();
+	assert!(propertyDeclaration.typed_value::<LengthOrPercentageOrAuto<CssSignedNumber>>().unwrap().is_ok());
+	match propertyDeclaration.typed_value_with_limits::<LengthOrPercentageOrAuto<CssSignedNumber>>(limits).unwrap()
+	{
+		Err(ParseError::Custom(CustomParseError::ParseLimitExceeded(ParseLimit::CalcExpressionDepth))) => (),
+		result => panic!("{:?}", result.map(|value| value.to_css_string())),
+	}
+	assert!(propertyDeclaration.match_grammar().is_ok());
+	assert!(propertyDeclaration.match_grammar_with_limits(limits).is_err());
+}